@@ -1,64 +1,305 @@
-use clap::Parser;
-use dbc_codegen::Config;
+use clap::{Parser, Subcommand, ValueEnum};
+use dbc_codegen::{Config, DocFormat, Impact, InputFormat, ModuleSplit};
 use std::fs::File;
+use std::path::Path;
 use std::{path::PathBuf, process::exit};
 
 /// Generate Rust `struct`s from a `dbc` file.
 #[derive(Debug, Parser)]
-#[command(version)]
+#[command(version, subcommand_negates_reqs = true)]
 struct Cli {
-    /// Path to a `.dbc` file
-    dbc_path: PathBuf,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Path to a `.dbc`, `.kcd`, `.arxml`, `.sym` or `.ldf` file
+    #[arg(required = true)]
+    dbc_path: Option<PathBuf>,
 
     /// Target directory to write Rust source file(s) to
-    out_path: PathBuf,
+    #[arg(required = true)]
+    out_path: Option<PathBuf>,
 
     /// Enable debug printing
-    #[arg(long)]
+    #[arg(long, global = true)]
     debug: bool,
+
+    /// Write a `messages/` module with one file per message or transmitting node instead of a
+    /// single `messages.rs`
+    #[arg(long, value_enum)]
+    split: Option<Split>,
+
+    /// Language to generate: Rust `messages.rs`, or C `<name>.h` and `<name>.c` named after the
+    /// input file
+    #[arg(long, value_enum, default_value = "rust", conflicts_with = "split")]
+    language: Language,
+
+    /// Bus to generate from files describing several (KCD bus, ARXML CAN cluster), instead of
+    /// the first one
+    #[arg(long)]
+    bus: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List the changes between two revisions of a bus and how they affect generated code
+    Diff {
+        /// Old revision, in any supported input format
+        old_path: PathBuf,
+
+        /// New revision, in any supported input format
+        new_path: PathBuf,
+
+        /// Exit with an error if any change has at least this impact
+        #[arg(long, value_enum)]
+        fail_on: Option<FailOn>,
+
+        /// Bus to compare in files describing several, instead of the first one
+        #[arg(long)]
+        bus: Option<String>,
+    },
+
+    /// Write a Markdown or HTML report of a bus: messages, bit layouts, multiplexing and value
+    /// descriptions
+    Doc {
+        /// Path to a `.dbc`, `.kcd`, `.arxml`, `.sym` or `.ldf` file
+        dbc_path: PathBuf,
+
+        /// File to write the report to
+        out_path: PathBuf,
+
+        /// Report format, by default HTML for `.html` and `.htm` files and Markdown otherwise
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+
+        /// Bus to document from files describing several, instead of the first one
+        #[arg(long)]
+        bus: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    /// GitHub flavored Markdown
+    Markdown,
+    /// A standalone HTML page
+    Html,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum FailOn {
+    /// Any change, including documentation
+    Cosmetic,
+    /// Changes in behavior, e.g. of range checks
+    WireCompatible,
+    /// Changes in how frames are encoded
+    WireBreaking,
+    /// Changes that may break code using the generated API
+    ApiBreaking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Language {
+    /// Rust structs
+    Rust,
+    /// C structs and pack/unpack functions
+    C,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Split {
+    /// One file per message
+    Message,
+    /// One file per transmitting node
+    Transmitter,
 }
 
 fn main() {
     let args = Cli::parse();
-    let dbc_file = std::fs::read(&args.dbc_path).unwrap_or_else(|e| {
-        eprintln!("could not read `{}`: {}", args.dbc_path.display(), e);
-        exit(exitcode::NOINPUT);
-    });
-    let dbc_file_name = args
-        .dbc_path
-        .file_name()
-        .unwrap_or_else(|| args.dbc_path.as_ref())
-        .to_string_lossy();
+    match &args.command {
+        Some(Command::Diff {
+            old_path,
+            new_path,
+            fail_on,
+            bus,
+        }) => {
+            diff(old_path, new_path, *fail_on, bus.as_deref(), args.debug);
+            return;
+        }
+        Some(Command::Doc {
+            dbc_path,
+            out_path,
+            format,
+            bus,
+        }) => {
+            doc(dbc_path, out_path, *format, bus.as_deref(), args.debug);
+            return;
+        }
+        None => {}
+    }
+    let (Some(dbc_path), Some(out_path)) = (&args.dbc_path, &args.out_path) else {
+        unreachable!("clap requires both paths without a subcommand");
+    };
+
+    let dbc_file = read(dbc_path);
+    let dbc_file_name = file_name(dbc_path);
 
-    if !args.out_path.is_dir() {
+    if !out_path.is_dir() {
         eprintln!(
             "Output path needs to point to a directory (checked {})",
-            args.out_path.display()
+            out_path.display()
         );
         exit(exitcode::CANTCREAT);
     }
 
-    let messages_path = args.out_path.join("messages.rs");
-    let mut messages_code = File::create(messages_path).unwrap_or_else(|e| {
+    let config = Config::builder()
+        .dbc_name(&dbc_file_name)
+        .dbc_content(&dbc_file)
+        .input_format(input_format(dbc_path))
+        .bus(args.bus.as_deref())
+        .debug_prints(args.debug)
+        .module_split(match args.split {
+            Some(Split::Transmitter) => ModuleSplit::Transmitter,
+            Some(Split::Message) | None => ModuleSplit::Message,
+        })
+        .build();
+
+    let result = if args.language == Language::C {
+        dbc_codegen::codegen_c(config, out_path)
+    } else if args.split.is_some() {
+        let messages_dir = out_path.join("messages");
+        std::fs::create_dir_all(&messages_dir).unwrap_or_else(|e| {
+            eprintln!(
+                "Could not create `messages` directory in {}: {:?}",
+                out_path.display(),
+                e
+            );
+            exit(exitcode::CANTCREAT);
+        });
+        dbc_codegen::codegen_to_dir(config, messages_dir)
+    } else {
+        let messages_path = out_path.join("messages.rs");
+        let mut messages_code = File::create(messages_path).unwrap_or_else(|e| {
+            eprintln!(
+                "Could not create `messages.rs` file in {}: {:?}",
+                out_path.display(),
+                e
+            );
+            exit(exitcode::CANTCREAT);
+        });
+        dbc_codegen::codegen(config, &mut messages_code)
+    };
+
+    result.unwrap_or_else(|e| {
+        eprintln!("could not convert `{}`: {}", dbc_path.display(), e);
+        if args.debug {
+            eprintln!("details: {:?}", e);
+        }
+        exit(exitcode::NOINPUT)
+    })
+}
+
+fn diff(old_path: &Path, new_path: &Path, fail_on: Option<FailOn>, bus: Option<&str>, debug: bool) {
+    let (old_file, new_file) = (read(old_path), read(new_path));
+    let (old_name, new_name) = (file_name(old_path), file_name(new_path));
+    let old = Config::builder()
+        .dbc_name(&old_name)
+        .dbc_content(&old_file)
+        .input_format(input_format(old_path))
+        .bus(bus)
+        .debug_prints(debug)
+        .build();
+    let new = Config::builder()
+        .dbc_name(&new_name)
+        .dbc_content(&new_file)
+        .input_format(input_format(new_path))
+        .bus(bus)
+        .debug_prints(debug)
+        .build();
+
+    let diff = dbc_codegen::diff_dbc(old, new).unwrap_or_else(|e| {
         eprintln!(
-            "Could not create `messages.rs` file in {}: {:?}",
-            args.out_path.display(),
+            "could not compare `{}` and `{}`: {}",
+            old_path.display(),
+            new_path.display(),
             e
         );
-        exit(exitcode::CANTCREAT);
+        if debug {
+            eprintln!("details: {:?}", e);
+        }
+        exit(exitcode::NOINPUT)
     });
+    for change in &diff.changes {
+        println!("{change}");
+    }
 
+    let threshold = fail_on.map(|fail_on| match fail_on {
+        FailOn::Cosmetic => Impact::Cosmetic,
+        FailOn::WireCompatible => Impact::WireCompatible,
+        FailOn::WireBreaking => Impact::WireBreaking,
+        FailOn::ApiBreaking => Impact::ApiBreaking,
+    });
+    if let (Some(threshold), Some(impact)) = (threshold, diff.impact()) {
+        if impact >= threshold {
+            exit(exitcode::DATAERR);
+        }
+    }
+}
+
+fn doc(dbc_path: &Path, out_path: &Path, format: Option<Format>, bus: Option<&str>, debug: bool) {
+    let dbc_file = read(dbc_path);
+    let dbc_file_name = file_name(dbc_path);
     let config = Config::builder()
         .dbc_name(&dbc_file_name)
         .dbc_content(&dbc_file)
-        .debug_prints(args.debug)
+        .input_format(input_format(dbc_path))
+        .bus(bus)
+        .debug_prints(debug)
         .build();
 
-    dbc_codegen::codegen(config, &mut messages_code).unwrap_or_else(|e| {
-        eprintln!("could not convert `{}`: {}", args.dbc_path.display(), e);
-        if args.debug {
+    let format = match format {
+        Some(Format::Markdown) => DocFormat::Markdown,
+        Some(Format::Html) => DocFormat::Html,
+        None => match out_path.extension() {
+            Some(ext) if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") => {
+                DocFormat::Html
+            }
+            _ => DocFormat::Markdown,
+        },
+    };
+
+    let out = File::create(out_path).unwrap_or_else(|e| {
+        eprintln!("could not create `{}`: {}", out_path.display(), e);
+        exit(exitcode::CANTCREAT);
+    });
+    dbc_codegen::codegen_docs(config, format, out).unwrap_or_else(|e| {
+        eprintln!("could not document `{}`: {}", dbc_path.display(), e);
+        if debug {
             eprintln!("details: {:?}", e);
         }
         exit(exitcode::NOINPUT)
     })
 }
+
+fn read(path: &Path) -> Vec<u8> {
+    std::fs::read(path).unwrap_or_else(|e| {
+        eprintln!("could not read `{}`: {}", path.display(), e);
+        exit(exitcode::NOINPUT);
+    })
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn input_format(path: &Path) -> InputFormat {
+    match path.extension() {
+        Some(ext) if ext.eq_ignore_ascii_case("kcd") => InputFormat::Kcd,
+        Some(ext) if ext.eq_ignore_ascii_case("arxml") => InputFormat::Arxml,
+        Some(ext) if ext.eq_ignore_ascii_case("sym") => InputFormat::Sym,
+        Some(ext) if ext.eq_ignore_ascii_case("ldf") => InputFormat::Ldf,
+        _ => InputFormat::Dbc,
+    }
+}
@@ -8,7 +8,7 @@ use cantools_messages::{
 };
 
 fuzz_target!(|dbc_codegen_bar: can_messages::Bar| {
-    let dbc_codegen_bar = can_messages::Bar::new(3, 2.0, 4, 5, false).unwrap();
+    let dbc_codegen_bar = can_messages::Bar::new_raw(3, 2.0, 4, 5, false).unwrap();
 
     println!(
         "{} {} {} {} {}",
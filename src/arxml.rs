@@ -0,0 +1,440 @@
+//! Reading CAN clusters from AUTOSAR (4.x) system descriptions.
+//!
+//! Every frame triggering of the cluster becomes a message named after its frame, with the
+//! signals of all PDUs mapped into the frame. Multiplexed PDUs get a multiplexor signal named
+//! `Selector` for their selector field, the signals of their static part and those of each
+//! dynamic part alternative, multiplexed by its selector code. Linear computation methods
+//! become factor and offset, text tables become value descriptions, and physical data
+//! constraints become the signal's range.
+//!
+//! Signal start positions are DBC start bits: the least significant bit of little endian
+//! (`MOST-SIGNIFICANT-BYTE-LAST`) and the most significant bit of big endian
+//! (`MOST-SIGNIFICANT-BYTE-FIRST`) signals.
+
+use crate::dbc_file::{raw_range, DbcFile, FileMessage, FileSignal, FileValueDescription};
+use crate::parse::{child, descendants, is, parse_f64, parse_u32, parse_u64, required_text, text};
+use anyhow::{anyhow, bail, Context, Result};
+use can_dbc::{
+    ByteOrder, Comment, MessageId, MultiplexIndicator, SignalExtendedValueType, Transmitter,
+    ValueType,
+};
+use roxmltree::{Document, Node};
+use std::collections::BTreeMap;
+
+/// Read the CAN cluster named `cluster` (or the first one) of an ARXML file
+pub(crate) fn parse_arxml(content: &[u8], cluster: Option<&str>) -> Result<DbcFile> {
+    let text = std::str::from_utf8(content).context("ARXML file is not valid UTF-8")?;
+    let doc = Document::parse(text).context("Could not parse ARXML file")?;
+
+    let mut paths = BTreeMap::new();
+    index(doc.root_element(), "", &mut paths);
+    let reader = Reader { paths: &paths };
+
+    let clusters: Vec<Node> = doc.descendants().filter(|n| is(n, "CAN-CLUSTER")).collect();
+    let selected = match cluster {
+        None => clusters.first(),
+        Some(name) => clusters.iter().find(|c| short_name(**c) == Some(name)),
+    };
+    let Some(selected) = selected else {
+        let names: Vec<&str> = clusters.iter().filter_map(|c| short_name(*c)).collect();
+        bail!(
+            "ARXML file has no CAN cluster {} (available: {})",
+            cluster.map(|name| format!("`{name}`")).unwrap_or_default(),
+            names.join(", ")
+        );
+    };
+
+    let mut file = DbcFile::default();
+    for triggering in selected
+        .descendants()
+        .filter(|n| is(n, "CAN-FRAME-TRIGGERING"))
+    {
+        let msg = reader.message(&mut file, triggering).with_context(|| {
+            format!(
+                "in ARXML frame triggering `{}`",
+                short_name(triggering).unwrap_or("?")
+            )
+        })?;
+        file.messages.push(msg);
+    }
+
+    Ok(file)
+}
+
+/// Find elements by their absolute path of short names, e.g. `/Package/Frames/Foo`
+fn index<'a, 'input>(
+    node: Node<'a, 'input>,
+    prefix: &str,
+    paths: &mut BTreeMap<String, Node<'a, 'input>>,
+) {
+    let path = match short_name(node) {
+        Some(name) => {
+            let path = format!("{prefix}/{name}");
+            paths.insert(path.clone(), node);
+            path
+        }
+        None => prefix.to_string(),
+    };
+    for child in node.children().filter(Node::is_element) {
+        index(child, &path, paths);
+    }
+}
+
+struct Reader<'r, 'a, 'input> {
+    paths: &'r BTreeMap<String, Node<'a, 'input>>,
+}
+
+impl<'a, 'input> Reader<'_, 'a, 'input> {
+    fn message(&self, file: &mut DbcFile, triggering: Node<'a, 'input>) -> Result<FileMessage> {
+        let frame = self.reference(triggering, "FRAME-REF")?;
+        let name = short_name(frame).ok_or_else(|| anyhow!("frame without `SHORT-NAME`"))?;
+
+        let raw_id = parse_u32(required_text(triggering, "IDENTIFIER")?)?;
+        let id = match text(triggering, "CAN-ADDRESSING-MODE") {
+            Some("EXTENDED") => MessageId::Extended(raw_id),
+            _ => MessageId::Standard(
+                u16::try_from(raw_id).map_err(|_| anyhow!("standard ID {raw_id} out of range"))?,
+            ),
+        };
+
+        let mut transmitter = Transmitter::VectorXXX;
+        let mut receivers = Vec::new();
+        for port_ref in descendants(triggering, "FRAME-PORT-REF") {
+            let port = self.resolve(port_ref)?;
+            let Some(ecu) = port
+                .ancestors()
+                .find(|n| is(n, "ECU-INSTANCE"))
+                .and_then(short_name)
+            else {
+                continue;
+            };
+            if !file.nodes.iter().any(|node| node == ecu) {
+                file.nodes.push(ecu.to_string());
+            }
+            match text(port, "COMMUNICATION-DIRECTION") {
+                Some("OUT") => transmitter = Transmitter::NodeName(ecu.to_string()),
+                Some("IN") => receivers.push(ecu.to_string()),
+                _ => {}
+            }
+        }
+
+        if let Some(desc) = description(frame) {
+            file.comments.push(Comment::Message {
+                message_id: id,
+                comment: desc,
+            });
+        }
+
+        let mut signals = Vec::new();
+        for mapping in descendants(frame, "PDU-TO-FRAME-MAPPING") {
+            let pdu = self.reference(mapping, "PDU-REF")?;
+            let offset = text(mapping, "START-POSITION").map_or(Ok(0), parse_u64)?;
+            let pdu_signals = PduSignals {
+                message_id: id,
+                offset,
+                receivers: &receivers,
+            };
+            match pdu.tag_name().name() {
+                "I-SIGNAL-I-PDU" => {
+                    self.pdu_signals(
+                        file,
+                        &pdu_signals,
+                        pdu,
+                        MultiplexIndicator::Plain,
+                        &mut signals,
+                    )?;
+                }
+                "MULTIPLEXED-I-PDU" => {
+                    self.multiplexed_pdu_signals(file, &pdu_signals, pdu, &mut signals)?;
+                }
+                // Network management, diagnostic and container PDUs have no signals to generate
+                _ => {}
+            }
+        }
+
+        Ok(FileMessage {
+            id,
+            name: name.to_string(),
+            size: parse_u64(required_text(frame, "FRAME-LENGTH")?)?,
+            transmitter,
+            signals,
+        })
+    }
+
+    fn multiplexed_pdu_signals(
+        &self,
+        file: &mut DbcFile,
+        pdu_signals: &PduSignals,
+        pdu: Node<'a, 'input>,
+        signals: &mut Vec<FileSignal>,
+    ) -> Result<()> {
+        let selector_start = parse_u64(required_text(pdu, "SELECTOR-FIELD-START-POSITION")?)?;
+        let selector_size = parse_u64(required_text(pdu, "SELECTOR-FIELD-LENGTH")?)?;
+        let (min, max) = raw_range(ValueType::Unsigned, selector_size, 1.0, 0.0);
+        signals.push(FileSignal {
+            name: "Selector".to_string(),
+            multiplexer: MultiplexIndicator::Multiplexor,
+            start_bit: pdu_signals.start_bit(selector_start)?,
+            size: selector_size,
+            byte_order: byte_order(text(pdu, "SELECTOR-FIELD-BYTE-ORDER")),
+            value_type: ValueType::Unsigned,
+            factor: 1.0,
+            offset: 0.0,
+            min,
+            max,
+            unit: String::new(),
+            receivers: pdu_signals.receivers.to_vec(),
+            extended_value_type: None,
+        });
+
+        for part in descendants(pdu, "STATIC-PART") {
+            let part_pdu = self.reference(part, "I-PDU-REF")?;
+            self.pdu_signals(
+                file,
+                pdu_signals,
+                part_pdu,
+                MultiplexIndicator::Plain,
+                signals,
+            )?;
+        }
+        for alternative in descendants(pdu, "DYNAMIC-PART-ALTERNATIVE") {
+            let part_pdu = self.reference(alternative, "I-PDU-REF")?;
+            let code = parse_u64(required_text(alternative, "SELECTOR-FIELD-CODE")?)?;
+            let multiplexer = MultiplexIndicator::MultiplexedSignal(code);
+            self.pdu_signals(file, pdu_signals, part_pdu, multiplexer, signals)?;
+        }
+        Ok(())
+    }
+
+    fn pdu_signals(
+        &self,
+        file: &mut DbcFile,
+        pdu_signals: &PduSignals,
+        pdu: Node<'a, 'input>,
+        multiplexer: MultiplexIndicator,
+        signals: &mut Vec<FileSignal>,
+    ) -> Result<()> {
+        for mapping in descendants(pdu, "I-SIGNAL-TO-I-PDU-MAPPING") {
+            // Signal groups only bundle signals that are mapped on their own as well
+            if child(mapping, "I-SIGNAL-REF").is_none() {
+                continue;
+            }
+            let signal = self
+                .signal(file, pdu_signals, mapping, multiplexer)
+                .with_context(|| {
+                    format!("in signal mapping `{}`", short_name(mapping).unwrap_or("?"))
+                })?;
+            signals.push(signal);
+        }
+        Ok(())
+    }
+
+    fn signal(
+        &self,
+        file: &mut DbcFile,
+        pdu_signals: &PduSignals,
+        mapping: Node<'a, 'input>,
+        multiplexer: MultiplexIndicator,
+    ) -> Result<FileSignal> {
+        let i_signal = self.reference(mapping, "I-SIGNAL-REF")?;
+        let name = short_name(i_signal)
+            .ok_or_else(|| anyhow!("signal without `SHORT-NAME`"))?
+            .to_string();
+        let system_signal = child(i_signal, "SYSTEM-SIGNAL-REF")
+            .map(|r| self.resolve(r))
+            .transpose()?;
+        // Definitions of the network representation take precedence over physical ones
+        let find_ref = |tag: &str| {
+            descendants(i_signal, tag)
+                .next()
+                .or_else(|| system_signal.and_then(|s| descendants(s, tag).next()))
+                .map(|r| self.resolve(r))
+                .transpose()
+        };
+
+        let size = parse_u64(required_text(i_signal, "LENGTH")?)?;
+        let start = parse_u64(required_text(mapping, "START-POSITION")?)?;
+        let byte_order = byte_order(text(mapping, "PACKING-BYTE-ORDER"));
+
+        let encoding = find_ref("BASE-TYPE-REF")?.and_then(|t| text(t, "BASE-TYPE-ENCODING"));
+        let (value_type, extended_value_type) = match (encoding, size) {
+            (Some("2C"), _) => (ValueType::Signed, None),
+            (Some("IEEE754"), 32) => (
+                ValueType::Signed,
+                Some(SignalExtendedValueType::IEEEfloat32Bit),
+            ),
+            (Some("IEEE754"), 64) => (
+                ValueType::Signed,
+                Some(SignalExtendedValueType::IEEEdouble64bit),
+            ),
+            (Some("IEEE754"), _) => bail!("{size} bit floating point signal `{name}`"),
+            _ => (ValueType::Unsigned, None),
+        };
+
+        let compu_method = find_ref("COMPU-METHOD-REF")?;
+        let mut factor = 1.0;
+        let mut offset = 0.0;
+        let mut labels = Vec::new();
+        for scale in compu_method
+            .into_iter()
+            .flat_map(|method| descendants(method, "COMPU-SCALE"))
+        {
+            if let Some(coeffs) = child(scale, "COMPU-RATIONAL-COEFFS") {
+                let numerator = numbers(child(coeffs, "COMPU-NUMERATOR"))?;
+                let denominator = numbers(child(coeffs, "COMPU-DENOMINATOR"))?;
+                let denominator = denominator.first().copied().unwrap_or(1.0);
+                offset = numerator.first().copied().unwrap_or(0.0) / denominator;
+                factor = numerator.get(1).copied().unwrap_or(1.0) / denominator;
+            } else if let Some(label) = descendants(scale, "VT").next().and_then(|vt| vt.text()) {
+                let lower = text(scale, "LOWER-LIMIT").map(parse_f64).transpose()?;
+                let upper = text(scale, "UPPER-LIMIT").map(parse_f64).transpose()?;
+                // Ranges of raw values have no DBC equivalent
+                if let (Some(lower), Some(upper)) = (lower, upper) {
+                    if lower == upper {
+                        labels.push((lower, label.trim().to_string()));
+                    }
+                }
+            }
+        }
+
+        let (mut min, mut max) = if extended_value_type.is_some() {
+            (0.0, 0.0)
+        } else {
+            raw_range(value_type, size, factor, offset)
+        };
+        if let Some(rule) = find_ref("DATA-CONSTR-REF")?
+            .and_then(|constr| descendants(constr, "DATA-CONSTR-RULE").next())
+        {
+            if let Some(constrs) = child(rule, "PHYS-CONSTRS") {
+                min = text(constrs, "LOWER-LIMIT").map_or(Ok(min), parse_f64)?;
+                max = text(constrs, "UPPER-LIMIT").map_or(Ok(max), parse_f64)?;
+            } else if let Some(constrs) = child(rule, "INTERNAL-CONSTRS") {
+                let lower = text(constrs, "LOWER-LIMIT").map(parse_f64).transpose()?;
+                let upper = text(constrs, "UPPER-LIMIT").map(parse_f64).transpose()?;
+                if let (Some(lower), Some(upper)) = (lower, upper) {
+                    let (a, b) = (lower * factor + offset, upper * factor + offset);
+                    (min, max) = (a.min(b), a.max(b));
+                }
+            }
+        }
+
+        let unit = match compu_method.and_then(|m| child(m, "UNIT-REF")) {
+            Some(unit_ref) => Some(self.resolve(unit_ref)?),
+            None => find_ref("UNIT-REF")?,
+        };
+        let unit = unit
+            .and_then(|u| text(u, "DISPLAY-NAME").or_else(|| short_name(u)))
+            .unwrap_or_default()
+            .to_string();
+
+        if !labels.is_empty() {
+            file.value_descriptions.push(FileValueDescription::Signal {
+                message_id: pdu_signals.message_id,
+                signal_name: name.clone(),
+                values: labels,
+            });
+        }
+        if let Some(desc) = system_signal.and_then(description) {
+            file.comments.push(Comment::Signal {
+                message_id: pdu_signals.message_id,
+                signal_name: name.clone(),
+                comment: desc,
+            });
+        }
+
+        Ok(FileSignal {
+            name,
+            multiplexer,
+            start_bit: pdu_signals.start_bit(start)?,
+            size,
+            byte_order,
+            value_type,
+            factor,
+            offset,
+            min,
+            max,
+            unit,
+            receivers: pdu_signals.receivers.to_vec(),
+            extended_value_type,
+        })
+    }
+
+    /// The element referenced by the `tag` child of `node`
+    fn reference(&self, node: Node, tag: &str) -> Result<Node<'a, 'input>> {
+        let reference = child(node, tag)
+            .ok_or_else(|| anyhow!("`{}` without `{tag}`", node.tag_name().name()))?;
+        self.resolve(reference)
+    }
+
+    fn resolve(&self, reference: Node) -> Result<Node<'a, 'input>> {
+        let path = reference.text().unwrap_or_default().trim();
+        self.paths
+            .get(path)
+            .copied()
+            .ok_or_else(|| anyhow!("unresolved reference `{path}`"))
+    }
+}
+
+/// Where the signals of a PDU end up in a message
+struct PduSignals<'a> {
+    message_id: MessageId,
+    /// Start position of the PDU in the frame
+    offset: u64,
+    receivers: &'a [String],
+}
+
+impl PduSignals<'_> {
+    fn start_bit(&self, start: u64) -> Result<u64> {
+        start
+            .checked_add(self.offset)
+            .ok_or_else(|| anyhow!("start position {start} out of range"))
+    }
+}
+
+fn byte_order(packing: Option<&str>) -> ByteOrder {
+    match packing {
+        Some("MOST-SIGNIFICANT-BYTE-FIRST") => ByteOrder::BigEndian,
+        _ => ByteOrder::LittleEndian,
+    }
+}
+
+fn short_name<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    text(node, "SHORT-NAME")
+}
+
+/// First paragraph of an element's description
+fn description(node: Node) -> Option<String> {
+    let text = child(node, "DESC")
+        .and_then(|desc| desc.children().find(Node::is_element))
+        .and_then(|paragraph| paragraph.text())?
+        .trim();
+    (!text.is_empty()).then(|| text.replace('"', "'"))
+}
+
+/// The `V` values of a numerator or denominator
+fn numbers(node: Option<Node>) -> Result<Vec<f64>> {
+    node.into_iter()
+        .flat_map(|n| n.children().filter(|c| is(c, "V")))
+        .map(|v| parse_f64(v.text().unwrap_or_default()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::generate;
+    use crate::InputFormat;
+
+    #[test]
+    fn arxml_matches_dbc() {
+        let dbc = include_bytes!("../testing/dbc-examples/example-arxml.dbc");
+        let arxml = include_bytes!("../testing/dbc-examples/example.arxml");
+        assert_eq!(
+            generate(dbc, InputFormat::Dbc, None),
+            generate(arxml, InputFormat::Arxml, None)
+        );
+
+        let diagnostics = generate(arxml, InputFormat::Arxml, Some("Diagnostics"));
+        assert!(diagnostics.contains("pub struct Climate {"));
+        assert!(!diagnostics.contains("pub struct Foo {"));
+    }
+}
@@ -0,0 +1,650 @@
+//! C backend: structs of raw signal values with pack, unpack, encode, decode and range check
+//! functions, in the style of cantools.
+
+use crate::ir::{Database, Message, MessageId, Multiplexer, Signal, ValueType};
+use crate::pad::PadAdapter;
+use crate::{
+    get_relevant_messages, scaled_signal_to_rust_int, signal_byte_chunks, signal_is_float_in_rust,
+    signal_to_rust_int, signal_to_rust_type, signal_to_rust_uint, Config,
+};
+use anyhow::{bail, Context, Result};
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use std::io::Write;
+use std::path::Path;
+
+/// C keywords and names from the standard headers that the Rust naming rules allow
+const C_KEYWORDS: [&str; 23] = [
+    "auto", "bool", "case", "char", "default", "double", "extern", "float", "goto", "inline",
+    "int", "long", "register", "restrict", "short", "signed", "sizeof", "switch", "typedef",
+    "unsigned", "void", "volatile", "size_t",
+];
+
+/// Prefix of all identifiers, the snake case file name of the DBC without extension
+pub(crate) fn prefix(config: &Config<'_>) -> String {
+    let stem = Path::new(config.dbc_name)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let prefix = stem.to_snake_case();
+    if prefix.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        prefix
+    } else {
+        format!("x{prefix}")
+    }
+}
+
+/// Fail on signals whose values need 128-bit integers in Rust, for which C has no standard
+/// type.
+pub(crate) fn check_types(dbc: &Database) -> Result<()> {
+    for msg in get_relevant_messages(dbc) {
+        for signal in &msg.signals {
+            let mut types = vec![signal_to_rust_type(signal)];
+            if signal.size != 1 && !signal_is_float_in_rust(signal) {
+                types.push(scaled_signal_to_rust_int(signal));
+            }
+            if let Some(typ) = types.iter().find(|typ| typ.ends_with("128")) {
+                bail!(
+                    "signal `{}` of message `{}` needs a 128-bit integer (`{typ}`), which C has \
+                     no standard type for",
+                    signal.name,
+                    msg.name
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn render_header(mut w: impl Write, config: &Config<'_>, dbc: &Database) -> Result<()> {
+    let prefix = prefix(config);
+    let guard = format!("{}_H", prefix.to_shouty_snake_case());
+
+    writeln!(w, "/* Generated code! */")?;
+    writeln!(w)?;
+    writeln!(w, "#ifndef {guard}")?;
+    writeln!(w, "#define {guard}")?;
+    writeln!(w)?;
+    writeln!(w, "#ifdef __cplusplus")?;
+    writeln!(w, "extern \"C\" {{")?;
+    writeln!(w, "#endif")?;
+    writeln!(w)?;
+    writeln!(w, "#include <stdint.h>")?;
+    writeln!(w, "#include <stdbool.h>")?;
+    writeln!(w, "#include <stddef.h>")?;
+    writeln!(w)?;
+    writeln!(w, "#ifndef EINVAL")?;
+    writeln!(w, "#    define EINVAL 22")?;
+    writeln!(w, "#endif")?;
+    writeln!(w)?;
+
+    writeln!(w, "/* Frame ids. */")?;
+    for msg in get_relevant_messages(dbc) {
+        let id = match msg.id {
+            MessageId::Standard(id) => u32::from(id),
+            MessageId::Extended(id) => id,
+        };
+        writeln!(w, "#define {}_FRAME_ID ({id:#x}u)", macro_name(config, msg))?;
+    }
+    writeln!(w)?;
+    writeln!(w, "/* Frame lengths in bytes. */")?;
+    for msg in get_relevant_messages(dbc) {
+        writeln!(
+            w,
+            "#define {}_LENGTH ({}u)",
+            macro_name(config, msg),
+            msg.size
+        )?;
+    }
+    writeln!(w)?;
+    writeln!(w, "/* Extended or standard frame types. */")?;
+    for msg in get_relevant_messages(dbc) {
+        let extended = matches!(msg.id, MessageId::Extended(_));
+        writeln!(
+            w,
+            "#define {}_IS_EXTENDED ({})",
+            macro_name(config, msg),
+            u8::from(extended)
+        )?;
+    }
+    writeln!(w)?;
+
+    writeln!(w, "/* Signal choices. */")?;
+    for msg in get_relevant_messages(dbc) {
+        for signal in &msg.signals {
+            let variants = &signal.values;
+            let suffix = if signal.value_type == ValueType::Unsigned {
+                "u"
+            } else {
+                ""
+            };
+            for (variant, name) in variants.iter().zip(config.enum_variant_names(variants)) {
+                writeln!(
+                    w,
+                    "#define {}_{}_{}_CHOICE ({}{suffix})",
+                    macro_name(config, msg),
+                    field_name(config, msg, signal).to_shouty_snake_case(),
+                    name.to_shouty_snake_case(),
+                    variant.0,
+                )?;
+            }
+        }
+    }
+    writeln!(w)?;
+
+    for msg in get_relevant_messages(dbc) {
+        render_struct(&mut w, config, msg)?;
+    }
+
+    for msg in get_relevant_messages(dbc) {
+        render_prototypes(&mut w, config, msg)?;
+    }
+
+    writeln!(w, "#ifdef __cplusplus")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "#endif")?;
+    writeln!(w)?;
+    writeln!(w, "#endif")?;
+    Ok(())
+}
+
+pub(crate) fn render_source(mut w: impl Write, config: &Config<'_>, dbc: &Database) -> Result<()> {
+    writeln!(w, "/* Generated code! */")?;
+    writeln!(w)?;
+    writeln!(w, "#include <string.h>")?;
+    writeln!(w)?;
+    writeln!(w, "#include \"{}.h\"", prefix(config))?;
+    writeln!(w)?;
+
+    for msg in get_relevant_messages(dbc) {
+        render_pack(&mut w, config, msg)
+            .with_context(|| format!("pack function of {}", msg.name))?;
+        render_unpack(&mut w, config, msg)
+            .with_context(|| format!("unpack function of {}", msg.name))?;
+        for signal in &msg.signals {
+            render_signal_functions(&mut w, config, msg, signal)?;
+        }
+    }
+    Ok(())
+}
+
+fn render_struct(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    writeln!(w, "/**")?;
+    writeln!(w, " * Signals in message {}.", msg.name)?;
+    if let Some(comment) = &msg.comment {
+        writeln!(w, " *")?;
+        for line in comment.trim().lines() {
+            writeln!(w, " * {line}")?;
+        }
+    }
+    writeln!(w, " *")?;
+    writeln!(w, " * All signal values are as on the CAN bus.")?;
+    writeln!(w, " */")?;
+    writeln!(w, "struct {}_t {{", function_name(config, msg))?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        if msg.signals.is_empty() {
+            writeln!(w, "/**")?;
+            writeln!(w, " * Dummy signal in empty message.")?;
+            writeln!(w, " */")?;
+            writeln!(w, "uint8_t dummy;")?;
+        }
+        for (i, signal) in msg.signals.iter().enumerate() {
+            if i > 0 {
+                writeln!(w)?;
+            }
+            writeln!(w, "/**")?;
+            writeln!(w, " * {}", signal.name)?;
+            if let Some(comment) = &signal.comment {
+                writeln!(w, " *")?;
+                for line in comment.trim().lines() {
+                    writeln!(w, " * {line}")?;
+                }
+            }
+            writeln!(w, " *")?;
+            writeln!(w, " * - Min: {}", signal.min)?;
+            writeln!(w, " * - Max: {}", signal.max)?;
+            writeln!(w, " * - Unit: {:?}", signal.unit)?;
+            writeln!(w, " * - Factor: {}", signal.factor)?;
+            writeln!(w, " * - Offset: {}", signal.offset)?;
+            writeln!(w, " */")?;
+            writeln!(
+                w,
+                "{} {};",
+                c_type(&signal_to_rust_int(signal)),
+                field_name(config, msg, signal)
+            )?;
+        }
+    }
+    writeln!(w, "}};")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+fn render_prototypes(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    let name = function_name(config, msg);
+
+    writeln!(w, "/**")?;
+    writeln!(w, " * Pack message {}.", msg.name)?;
+    writeln!(w, " *")?;
+    writeln!(w, " * @param[out] dst_p Buffer to pack the message into.")?;
+    writeln!(w, " * @param[in] src_p Data to pack.")?;
+    writeln!(w, " * @param[in] size Size of dst_p.")?;
+    writeln!(w, " *")?;
+    writeln!(w, " * @return Size of packed data, or negative error code.")?;
+    writeln!(w, " */")?;
+    writeln!(w, "{};", pack_signature(&name))?;
+    writeln!(w)?;
+    writeln!(w, "/**")?;
+    writeln!(w, " * Unpack message {}.", msg.name)?;
+    writeln!(w, " *")?;
+    writeln!(w, " * @param[out] dst_p Object to unpack the message into.")?;
+    writeln!(w, " * @param[in] src_p Message to unpack.")?;
+    writeln!(w, " * @param[in] size Size of src_p.")?;
+    writeln!(w, " *")?;
+    writeln!(w, " * @return zero(0) or negative error code.")?;
+    writeln!(w, " */")?;
+    writeln!(w, "{};", unpack_signature(&name))?;
+    writeln!(w)?;
+
+    for signal in &msg.signals {
+        let signal_name = format!("{name}_{}", field_name(config, msg, signal));
+        writeln!(w, "/**")?;
+        writeln!(w, " * Encode given signal by applying scaling and offset.")?;
+        writeln!(w, " */")?;
+        writeln!(w, "{};", encode_signature(&signal_name, signal))?;
+        writeln!(w)?;
+        writeln!(w, "/**")?;
+        writeln!(w, " * Decode given signal by applying scaling and offset.")?;
+        writeln!(w, " */")?;
+        writeln!(w, "{};", decode_signature(&signal_name, signal))?;
+        writeln!(w)?;
+        writeln!(w, "/**")?;
+        writeln!(w, " * Check that given signal is in allowed range.")?;
+        writeln!(w, " */")?;
+        writeln!(w, "{};", range_signature(&signal_name, signal))?;
+        writeln!(w)?;
+    }
+    Ok(())
+}
+
+fn render_pack(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    let size = msg.size;
+    writeln!(w, "{}", pack_signature(&function_name(config, msg)))?;
+    writeln!(w, "{{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(w, "if (size < {size}u) {{")?;
+        writeln!(w, "    return (-EINVAL);")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        writeln!(w, "memset(&dst_p[0], 0, {size});")?;
+        if msg.signals.is_empty() {
+            writeln!(w, "(void)src_p;")?;
+        } else {
+            writeln!(w)?;
+        }
+        render_by_multiplexor(&mut w, config, msg, "src_p", |w, signal| {
+            pack_signal(w, config, msg, signal)
+        })?;
+        writeln!(w)?;
+        writeln!(w, "return ({size});")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+fn render_unpack(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    writeln!(w, "{}", unpack_signature(&function_name(config, msg)))?;
+    writeln!(w, "{{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(w, "if (size < {}u) {{", msg.size)?;
+        writeln!(w, "    return (-EINVAL);")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        if msg.signals.is_empty() {
+            writeln!(w, "(void)dst_p;")?;
+            writeln!(w, "(void)src_p;")?;
+        }
+        render_by_multiplexor(&mut w, config, msg, "dst_p", |w, signal| {
+            unpack_signal(w, config, msg, signal)
+        })?;
+        writeln!(w)?;
+        writeln!(w, "return (0);")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Render `signal_fn` for all signals, multiplexed ones only if the multiplexor in `object`
+/// selects them
+fn render_by_multiplexor(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    object: &str,
+    mut signal_fn: impl FnMut(&mut dyn Write, &Signal) -> Result<()>,
+) -> Result<()> {
+    for signal in &msg.signals {
+        if !matches!(signal.multiplexer, Multiplexer::Multiplexed(_)) {
+            signal_fn(&mut w, signal)?;
+        }
+    }
+
+    let Some(tree) = msg.mux_tree().filter(|tree| !tree.groups.is_empty()) else {
+        return Ok(());
+    };
+
+    writeln!(w)?;
+    writeln!(
+        w,
+        "switch ({object}->{}) {{",
+        field_name(config, msg, tree.multiplexor)
+    )?;
+    for (switch_index, signals) in tree.groups {
+        writeln!(w, "case {switch_index}:")?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            for signal in signals {
+                signal_fn(&mut w, signal)?;
+            }
+            writeln!(w, "break;")?;
+        }
+        writeln!(w)?;
+    }
+    writeln!(w, "default:")?;
+    writeln!(w, "    break;")?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+fn pack_signal(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    signal: &Signal,
+) -> Result<()> {
+    // Signed values are packed as their two's complement bits
+    let value = format!(
+        "({})src_p->{}",
+        c_type(&signal_to_rust_uint(signal)),
+        field_name(config, msg, signal)
+    );
+    for chunk in signal_byte_chunks(signal, msg)? {
+        let mut part = value.clone();
+        if chunk.value_shift != 0 {
+            part = format!("({part} >> {})", chunk.value_shift);
+        }
+        if chunk.byte_shift != 0 {
+            part = format!("({part} << {})", chunk.byte_shift);
+        }
+        writeln!(
+            w,
+            "dst_p[{}] |= (uint8_t)({part} & {:#04x}u);",
+            chunk.byte,
+            chunk.byte_mask()?
+        )?;
+    }
+    Ok(())
+}
+
+fn unpack_signal(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    signal: &Signal,
+) -> Result<()> {
+    let uint = c_type(&signal_to_rust_uint(signal));
+    let field = field_name(config, msg, signal);
+
+    writeln!(w, "{{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(w, "{uint} raw = 0u;")?;
+        for chunk in signal_byte_chunks(signal, msg)? {
+            let mut part = format!("src_p[{}] & {:#04x}u", chunk.byte, chunk.byte_mask()?);
+            if chunk.byte_shift != 0 {
+                part = format!("({part}) >> {}", chunk.byte_shift);
+            }
+            part = format!("({uint})({part})");
+            if chunk.value_shift != 0 {
+                part = format!("({uint})({part} << {})", chunk.value_shift);
+            }
+            writeln!(w, "raw |= {part};")?;
+        }
+
+        let type_bits = uint_bits(&signal_to_rust_uint(signal));
+        if signal.value_type == ValueType::Signed && signal.size < type_bits {
+            // Sign extension: set all bits above the signal if its most significant bit is set
+            let sign_bit = 1u64
+                .checked_shl((signal.size as u32).saturating_sub(1))
+                .context("sign bit")?;
+            let value_bits = sign_bit.checked_mul(2).context("sign bit")?.wrapping_sub(1);
+            let type_mask = u64::MAX
+                .checked_shr(64u64.saturating_sub(type_bits) as u32)
+                .context("type mask")?;
+            let suffix = if type_bits > 32 { "ull" } else { "u" };
+            writeln!(w, "if ((raw & {sign_bit:#x}{suffix}) != 0u) {{")?;
+            writeln!(w, "    raw |= {:#x}{suffix};", !value_bits & type_mask)?;
+            writeln!(w, "}}")?;
+        }
+        writeln!(
+            w,
+            "dst_p->{field} = ({})raw;",
+            c_type(&signal_to_rust_int(signal))
+        )?;
+    }
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+/// Encode, decode and range check of a signal, with the same conversions as the Rust setters
+/// and getters
+fn render_signal_functions(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    signal: &Signal,
+) -> Result<()> {
+    let name = format!(
+        "{}_{}",
+        function_name(config, msg),
+        field_name(config, msg, signal)
+    );
+    let raw = c_type(&signal_to_rust_int(signal));
+    let physical = c_type(&signal_to_rust_type(signal));
+
+    writeln!(w, "{}", encode_signature(&name, signal))?;
+    writeln!(w, "{{")?;
+    if signal.size == 1 {
+        writeln!(w, "    return ({raw})(value ? 1u : 0u);")?;
+    } else if signal_is_float_in_rust(signal) {
+        writeln!(
+            w,
+            "    return ({raw})((value - {}) / {});",
+            float(signal.offset),
+            float(signal.factor)
+        )?;
+    } else {
+        writeln!(
+            w,
+            "    return ({raw})((value {}) / {});",
+            offset_term(-signal.offset),
+            signal.factor
+        )?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "{}", decode_signature(&name, signal))?;
+    writeln!(w, "{{")?;
+    if signal.size == 1 {
+        writeln!(w, "    return (value == 1u);")?;
+    } else if signal_is_float_in_rust(signal) {
+        writeln!(
+            w,
+            "    return ((float)value * {} + {});",
+            float(signal.factor),
+            float(signal.offset)
+        )?;
+    } else {
+        let scaled = c_type(&scaled_signal_to_rust_int(signal));
+        writeln!(
+            w,
+            "    return ({scaled})(({scaled})value * {} {});",
+            signal.factor,
+            offset_term(signal.offset)
+        )?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "{}", range_signature(&name, signal))?;
+    writeln!(w, "{{")?;
+    if signal.size == 1 {
+        writeln!(w, "    (void)value;")?;
+        writeln!(w)?;
+        writeln!(w, "    return (true);")?;
+    } else {
+        let (min, max) = if signal_is_float_in_rust(signal) {
+            (float(signal.min), float(signal.max))
+        } else {
+            (signal.min.to_string(), signal.max.to_string())
+        };
+        if physical.starts_with('u') && signal.min <= 0.0 {
+            // Comparing an unsigned value with zero makes compilers warn
+            writeln!(w, "    return (value <= {max});")?;
+        } else {
+            writeln!(w, "    return ((value >= {min}) && (value <= {max}));")?;
+        }
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+fn pack_signature(name: &str) -> String {
+    format!("int {name}_pack(uint8_t *dst_p, const struct {name}_t *src_p, size_t size)")
+}
+
+fn unpack_signature(name: &str) -> String {
+    format!("int {name}_unpack(struct {name}_t *dst_p, const uint8_t *src_p, size_t size)")
+}
+
+fn encode_signature(name: &str, signal: &Signal) -> String {
+    format!(
+        "{} {name}_encode({} value)",
+        c_type(&signal_to_rust_int(signal)),
+        c_type(&signal_to_rust_type(signal))
+    )
+}
+
+fn decode_signature(name: &str, signal: &Signal) -> String {
+    format!(
+        "{} {name}_decode({} value)",
+        c_type(&signal_to_rust_type(signal)),
+        c_type(&signal_to_rust_int(signal))
+    )
+}
+
+fn range_signature(name: &str, signal: &Signal) -> String {
+    format!(
+        "bool {name}_is_in_range({} value)",
+        c_type(&signal_to_rust_type(signal))
+    )
+}
+
+/// Functions and structs of a message, e.g. `example_multiplex_test`
+fn function_name(config: &Config<'_>, msg: &Message) -> String {
+    format!(
+        "{}_{}",
+        prefix(config),
+        config.type_name(&msg.name).to_snake_case()
+    )
+}
+
+/// Macros of a message, e.g. `EXAMPLE_MULTIPLEX_TEST`
+fn macro_name(config: &Config<'_>, msg: &Message) -> String {
+    function_name(config, msg).to_shouty_snake_case()
+}
+
+/// Struct member of a signal, the Rust accessor name unless it is reserved in C
+fn field_name(config: &Config<'_>, msg: &Message, signal: &Signal) -> String {
+    let name = config.field_name(msg, signal);
+    if C_KEYWORDS.contains(&name.as_str()) {
+        format!("x{name}")
+    } else {
+        name
+    }
+}
+
+/// C type of a Rust primitive as chosen by the Rust type rules
+fn c_type(rust_type: &str) -> String {
+    match rust_type {
+        "bool" => "bool".to_string(),
+        "f32" => "float".to_string(),
+        "f64" => "double".to_string(),
+        int => match int.split_at(1) {
+            ("u", bits) => format!("uint{bits}_t"),
+            (_, bits) => format!("int{bits}_t"),
+        },
+    }
+}
+
+fn uint_bits(rust_type: &str) -> u64 {
+    rust_type
+        .trim_start_matches(['u', 'i'])
+        .parse()
+        .unwrap_or(64)
+}
+
+/// Float literal with the same digits as the `_f32` literals of the Rust backend
+fn float(value: f64) -> String {
+    let digits = value.to_string();
+    if digits.contains(['.', 'e', 'i', 'N']) {
+        format!("{digits}f")
+    } else {
+        format!("{digits}.0f")
+    }
+}
+
+/// `+ offset` or `- offset` for integer arithmetic
+fn offset_term(offset: f64) -> String {
+    if offset < 0.0 {
+        format!("- {}", offset.abs())
+    } else {
+        format!("+ {}", offset.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{codegen_c, Config};
+
+    #[test]
+    fn c_rejects_128_bit_types() {
+        let dbc = br#"VERSION ""
+
+NS_ :
+
+BS_:
+
+BU_:
+
+BO_ 1 Odometer: 8 Vector__XXX
+ SG_ Distance : 0|64@1+ (1,-1) [-1|18446744073709551614] "m" Vector__XXX
+"#;
+        let config = Config::builder()
+            .dbc_name("odometer.dbc")
+            .dbc_content(dbc)
+            .build();
+        let err = codegen_c(config, std::env::temp_dir()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "signal `Distance` of message `Odometer` needs a 128-bit integer (`i128`), which C \
+             has no standard type for"
+        );
+    }
+}
@@ -0,0 +1,746 @@
+use can_dbc::{
+    AccessNode, AccessType, AttributeDefault, AttributeDefinition, AttributeValue,
+    AttributeValueForObject, AttributeValuedForObjectType, ByteOrder, Comment, EnvType,
+    EnvironmentVariable, EnvironmentVariableData, ExtendedMultiplex, MessageId, MessageTransmitter,
+    MultiplexIndicator, Signal, SignalExtendedValueType, SignalGroups, SignalType, SignalTypeRef,
+    Transmitter, ValDescription, ValueDescription, ValueType, DBC,
+};
+use std::io::{self, Write};
+
+/// Write a parsed DBC file as canonical DBC text.
+///
+/// Messages are sorted by ID, followed by the definitions referring to them (comments, value
+/// descriptions, attributes, …) in the same order. Numbers are written in their shortest form
+/// that reads back as the same value, e.g. `0.1` and `-50`.
+pub fn write_dbc(dbc: &DBC, out: impl Write) -> io::Result<()> {
+    let mut file = DbcFile::from(dbc);
+    file.sort();
+    file.write(out)
+}
+
+/// Parse DBC text and write it back in the canonical form of [write_dbc], e.g. to normalize
+/// vendor files before reviewing changes.
+///
+/// ```
+/// use dbc_codegen::normalize_dbc;
+///
+/// let dbc = include_bytes!("../testing/dbc-examples/example.dbc");
+/// let normalized = normalize_dbc(dbc).unwrap();
+/// assert_eq!(normalize_dbc(normalized.as_bytes()).unwrap(), normalized);
+/// ```
+pub fn normalize_dbc(content: &[u8]) -> anyhow::Result<String> {
+    let dbc = DBC::from_slice(content).map_err(|_| anyhow::anyhow!("Could not parse dbc file"))?;
+    let mut out = Vec::new();
+    write_dbc(&dbc, &mut out)?;
+    Ok(String::from_utf8(out)?)
+}
+
+/// Owned copy of everything `can_dbc` parses from a DBC file.
+///
+/// Unlike [DBC], this can be assembled from parts (e.g. when merging files or reading other file
+/// formats) and written back as DBC text, which is then parsed again for code generation.
+#[derive(Clone, Default)]
+pub(crate) struct DbcFile {
+    pub version: String,
+    pub new_symbols: Vec<String>,
+    pub nodes: Vec<String>,
+    pub value_tables: Vec<FileValueTable>,
+    pub messages: Vec<FileMessage>,
+    pub message_transmitters: Vec<MessageTransmitter>,
+    pub environment_variables: Vec<EnvironmentVariable>,
+    pub environment_variable_data: Vec<EnvironmentVariableData>,
+    pub signal_types: Vec<SignalType>,
+    pub comments: Vec<Comment>,
+    pub attribute_definitions: Vec<AttributeDefinition>,
+    pub attribute_defaults: Vec<AttributeDefault>,
+    pub attribute_values: Vec<AttributeValueForObject>,
+    pub value_descriptions: Vec<FileValueDescription>,
+    pub signal_type_refs: Vec<SignalTypeRef>,
+    pub signal_groups: Vec<SignalGroups>,
+    pub extended_multiplex: Vec<ExtendedMultiplex>,
+}
+
+/// A message (`BO_`) with its signals.
+#[derive(Clone)]
+pub(crate) struct FileMessage {
+    pub id: MessageId,
+    pub name: String,
+    pub size: u64,
+    pub transmitter: Transmitter,
+    pub signals: Vec<FileSignal>,
+}
+
+/// A signal (`SG_`) including its value type from `SIG_VALTYPE_`.
+#[derive(Clone, PartialEq)]
+pub(crate) struct FileSignal {
+    pub name: String,
+    pub multiplexer: MultiplexIndicator,
+    pub start_bit: u64,
+    pub size: u64,
+    pub byte_order: ByteOrder,
+    pub value_type: ValueType,
+    pub factor: f64,
+    pub offset: f64,
+    pub min: f64,
+    pub max: f64,
+    pub unit: String,
+    pub receivers: Vec<String>,
+    pub extended_value_type: Option<SignalExtendedValueType>,
+}
+
+/// A named value table (`VAL_TABLE_`).
+#[derive(Clone, PartialEq)]
+pub(crate) struct FileValueTable {
+    pub name: String,
+    pub values: Vec<(f64, String)>,
+}
+
+/// Value descriptions (`VAL_`) of a signal or environment variable.
+#[derive(Clone, PartialEq)]
+pub(crate) enum FileValueDescription {
+    Signal {
+        message_id: MessageId,
+        signal_name: String,
+        values: Vec<(f64, String)>,
+    },
+    EnvironmentVariable {
+        env_var_name: String,
+        values: Vec<(f64, String)>,
+    },
+}
+
+impl FileSignal {
+    fn from_dbc(dbc: &DBC, message_id: MessageId, signal: &Signal) -> Self {
+        let extended_value_type = dbc
+            .signal_extended_value_type_list()
+            .iter()
+            .find(|t| *t.message_id() == message_id && t.signal_name() == signal.name())
+            .map(|t| *t.signal_extended_value_type());
+        FileSignal {
+            name: signal.name().clone(),
+            multiplexer: *signal.multiplexer_indicator(),
+            start_bit: signal.start_bit,
+            size: signal.signal_size,
+            byte_order: *signal.byte_order(),
+            value_type: *signal.value_type(),
+            factor: signal.factor,
+            offset: signal.offset,
+            min: signal.min,
+            max: signal.max,
+            unit: signal.unit().clone(),
+            receivers: signal.receivers().clone(),
+            extended_value_type,
+        }
+    }
+}
+
+/// Physical range of all raw values of a signal
+pub(crate) fn raw_range(value_type: ValueType, size: u64, factor: f64, offset: f64) -> (f64, f64) {
+    let bits = i32::try_from(size).unwrap_or(i32::MAX);
+    let (raw_min, raw_max) = match value_type {
+        ValueType::Unsigned => (0.0, 2f64.powi(bits) - 1.0),
+        ValueType::Signed => (
+            -(2f64.powi(bits.saturating_sub(1))),
+            2f64.powi(bits.saturating_sub(1)) - 1.0,
+        ),
+    };
+    let (a, b) = (raw_min * factor + offset, raw_max * factor + offset);
+    (a.min(b), a.max(b))
+}
+
+fn val_descriptions(descriptions: &[ValDescription]) -> Vec<(f64, String)> {
+    descriptions
+        .iter()
+        .map(|d| (*d.a(), d.b().clone()))
+        .collect()
+}
+
+impl From<&DBC> for DbcFile {
+    fn from(dbc: &DBC) -> Self {
+        DbcFile {
+            version: dbc.version().0.clone(),
+            new_symbols: dbc.new_symbols().iter().map(|s| s.0.clone()).collect(),
+            nodes: dbc.nodes().iter().flat_map(|n| n.0.clone()).collect(),
+            value_tables: dbc
+                .value_tables()
+                .iter()
+                .map(|table| FileValueTable {
+                    name: table.value_table_name().clone(),
+                    values: val_descriptions(table.value_descriptions()),
+                })
+                .collect(),
+            messages: dbc
+                .messages()
+                .iter()
+                .map(|msg| FileMessage {
+                    id: *msg.message_id(),
+                    name: msg.message_name().clone(),
+                    size: *msg.message_size(),
+                    transmitter: msg.transmitter().clone(),
+                    signals: msg
+                        .signals()
+                        .iter()
+                        .map(|signal| FileSignal::from_dbc(dbc, *msg.message_id(), signal))
+                        .collect(),
+                })
+                .collect(),
+            message_transmitters: dbc.message_transmitters().clone(),
+            environment_variables: dbc.environment_variables().clone(),
+            environment_variable_data: dbc.environment_variable_data().clone(),
+            signal_types: dbc.signal_types().clone(),
+            comments: dbc.comments().clone(),
+            attribute_definitions: dbc.attribute_definitions().clone(),
+            attribute_defaults: dbc.attribute_defaults().clone(),
+            attribute_values: dbc.attribute_values().clone(),
+            value_descriptions: dbc
+                .value_descriptions()
+                .iter()
+                .map(|description| match description {
+                    ValueDescription::Signal {
+                        message_id,
+                        signal_name,
+                        value_descriptions,
+                    } => FileValueDescription::Signal {
+                        message_id: *message_id,
+                        signal_name: signal_name.clone(),
+                        values: val_descriptions(value_descriptions),
+                    },
+                    ValueDescription::EnvironmentVariable {
+                        env_var_name,
+                        value_descriptions,
+                    } => FileValueDescription::EnvironmentVariable {
+                        env_var_name: env_var_name.clone(),
+                        values: val_descriptions(value_descriptions),
+                    },
+                })
+                .collect(),
+            signal_type_refs: dbc.signal_type_refs().clone(),
+            signal_groups: dbc.signal_groups().clone(),
+            extended_multiplex: dbc.extended_multiplex().clone(),
+        }
+    }
+}
+
+impl DbcFile {
+    /// Sort messages by ID, and everything referring to messages, signals, nodes or environment
+    /// variables in the order of these objects. Definitions of the same object keep their order.
+    pub fn sort(&mut self) {
+        self.messages.sort_by_key(|msg| msg.id.raw());
+
+        let messages: Vec<u32> = self.messages.iter().map(|msg| msg.id.raw()).collect();
+        let message = |id: &MessageId| {
+            messages
+                .iter()
+                .position(|m| *m == id.raw())
+                .unwrap_or(usize::MAX)
+        };
+        let signal = |id: &MessageId, name: &str| {
+            let position = self
+                .messages
+                .iter()
+                .find(|msg| msg.id.raw() == id.raw())
+                .and_then(|msg| msg.signals.iter().position(|s| s.name == name));
+            (message(id), position.unwrap_or(usize::MAX))
+        };
+        let node = |name: &str| {
+            self.nodes
+                .iter()
+                .position(|n| n == name)
+                .unwrap_or(usize::MAX)
+        };
+        let env_var = |name: &str| {
+            self.environment_variables
+                .iter()
+                .position(|e| e.env_var_name() == name)
+                .unwrap_or(usize::MAX)
+        };
+
+        // Keys are the kind of object, then its position
+        let mut comments = std::mem::take(&mut self.comments);
+        comments.sort_by_cached_key(|comment| match comment {
+            Comment::Plain { .. } => (0, 0, 0),
+            Comment::Node { node_name, .. } => (1, node(node_name), 0),
+            Comment::Message { message_id, .. } => (2, message(message_id), 0),
+            Comment::Signal {
+                message_id,
+                signal_name,
+                ..
+            } => {
+                let (m, s) = signal(message_id, signal_name);
+                (3, m, s)
+            }
+            Comment::EnvVar { env_var_name, .. } => (4, env_var(env_var_name), 0),
+        });
+        self.comments = comments;
+
+        let mut values = std::mem::take(&mut self.attribute_values);
+        values.sort_by_cached_key(|value| {
+            let (kind, a, b) = match value.attribute_value() {
+                AttributeValuedForObjectType::RawAttributeValue(_) => (0, 0, 0),
+                AttributeValuedForObjectType::NetworkNodeAttributeValue(name, _) => {
+                    (1, node(name), 0)
+                }
+                AttributeValuedForObjectType::MessageDefinitionAttributeValue(id, _) => {
+                    (2, message(id), 0)
+                }
+                AttributeValuedForObjectType::SignalAttributeValue(id, name, _) => {
+                    let (m, s) = signal(id, name);
+                    (3, m, s)
+                }
+                AttributeValuedForObjectType::EnvVariableAttributeValue(name, _) => {
+                    (4, env_var(name), 0)
+                }
+            };
+            (kind, a, b, value.attribute_name().clone())
+        });
+        self.attribute_values = values;
+
+        let mut descriptions = std::mem::take(&mut self.value_descriptions);
+        descriptions.sort_by_cached_key(|description| match description {
+            FileValueDescription::Signal {
+                message_id,
+                signal_name,
+                ..
+            } => {
+                let (m, s) = signal(message_id, signal_name);
+                (0, m, s)
+            }
+            FileValueDescription::EnvironmentVariable { env_var_name, .. } => {
+                (1, env_var(env_var_name), 0)
+            }
+        });
+        self.value_descriptions = descriptions;
+
+        let mut transmitters = std::mem::take(&mut self.message_transmitters);
+        transmitters.sort_by_cached_key(|tx| message(tx.message_id()));
+        self.message_transmitters = transmitters;
+        let mut type_refs = std::mem::take(&mut self.signal_type_refs);
+        type_refs.sort_by_cached_key(|r| signal(r.message_id(), r.signal_name()));
+        self.signal_type_refs = type_refs;
+        let mut groups = std::mem::take(&mut self.signal_groups);
+        groups.sort_by_cached_key(|g| message(g.message_id()));
+        self.signal_groups = groups;
+        let mut multiplex = std::mem::take(&mut self.extended_multiplex);
+        multiplex.sort_by_cached_key(|m| signal(m.message_id(), m.signal_name()));
+        self.extended_multiplex = multiplex;
+    }
+
+    /// Write as DBC text that `can_dbc` parses back into the same definitions.
+    ///
+    /// Baud rates (`BS_`) are not accessible in `can_dbc` and always written empty.
+    pub fn write(&self, mut w: impl Write) -> io::Result<()> {
+        writeln!(w, "VERSION \"{}\"", self.version)?;
+        writeln!(w)?;
+        writeln!(w, "NS_ :")?;
+        for symbol in &self.new_symbols {
+            writeln!(w, "\t{symbol}")?;
+        }
+        writeln!(w)?;
+        writeln!(w, "BS_:")?;
+        writeln!(w)?;
+        if self.nodes.is_empty() {
+            writeln!(w, "BU_:")?;
+        } else {
+            writeln!(w, "BU_: {}", self.nodes.join(" "))?;
+        }
+        writeln!(w)?;
+
+        for table in &self.value_tables {
+            write!(w, "VAL_TABLE_ {}", table.name)?;
+            write_val_descriptions(&mut w, &table.values)?;
+            writeln!(w, " ;")?;
+        }
+        writeln!(w)?;
+
+        for msg in &self.messages {
+            write_message(&mut w, msg)?;
+            writeln!(w)?;
+        }
+
+        for tx in &self.message_transmitters {
+            let transmitters: Vec<&str> = tx.transmitter().iter().map(transmitter).collect();
+            writeln!(
+                w,
+                "BO_TX_BU_ {} : {};",
+                tx.message_id().raw(),
+                transmitters.join(",")
+            )?;
+        }
+
+        for env in &self.environment_variables {
+            write_environment_variable(&mut w, env)?;
+        }
+        for data in &self.environment_variable_data {
+            writeln!(
+                w,
+                "ENVVAR_DATA_ {}: {};",
+                data.env_var_name(),
+                data.data_size()
+            )?;
+        }
+        for typ in &self.signal_types {
+            writeln!(
+                w,
+                "SGTYPE_ {}: {}@{}{} ({},{}) [{}|{}] \"{}\" {} {};",
+                typ.signal_type_name(),
+                typ.signal_size(),
+                byte_order(typ.byte_order()),
+                value_type(typ.value_type()),
+                number(*typ.factor()),
+                number(*typ.offset()),
+                number(*typ.min()),
+                number(*typ.max()),
+                typ.unit(),
+                number(*typ.default_value()),
+                typ.value_table(),
+            )?;
+        }
+        writeln!(w)?;
+
+        for comment in &self.comments {
+            write_comment(&mut w, comment)?;
+        }
+        for definition in &self.attribute_definitions {
+            let (object, rest) = match definition {
+                AttributeDefinition::Message(rest) => ("BO_ ", rest),
+                AttributeDefinition::Node(rest) => ("BU_ ", rest),
+                AttributeDefinition::Signal(rest) => ("SG_ ", rest),
+                AttributeDefinition::EnvironmentVariable(rest) => ("EV_ ", rest),
+                AttributeDefinition::Plain(rest) => ("", rest),
+            };
+            writeln!(w, "BA_DEF_ {object}{rest};")?;
+        }
+        for default in &self.attribute_defaults {
+            writeln!(
+                w,
+                "BA_DEF_DEF_ \"{}\" {};",
+                default.attribute_name(),
+                attribute_value(default.attribute_value())
+            )?;
+        }
+        for value in &self.attribute_values {
+            write_attribute_value(&mut w, value)?;
+        }
+        for description in &self.value_descriptions {
+            match description {
+                FileValueDescription::Signal {
+                    message_id,
+                    signal_name,
+                    values,
+                } => {
+                    write!(w, "VAL_ {} {}", message_id.raw(), signal_name)?;
+                    write_val_descriptions(&mut w, values)?;
+                }
+                FileValueDescription::EnvironmentVariable {
+                    env_var_name,
+                    values,
+                } => {
+                    write!(w, "VAL_ {}", env_var_name)?;
+                    write_val_descriptions(&mut w, values)?;
+                }
+            }
+            writeln!(w, " ;")?;
+        }
+        for type_ref in &self.signal_type_refs {
+            writeln!(
+                w,
+                "SGTYPE_ {} {} : {};",
+                type_ref.message_id().raw(),
+                type_ref.signal_name(),
+                type_ref.signal_type_name()
+            )?;
+        }
+        for group in &self.signal_groups {
+            writeln!(
+                w,
+                "SIG_GROUP_ {} {} {} : {};",
+                group.message_id().raw(),
+                group.signal_group_name(),
+                group.repetitions(),
+                group.signal_names().join(" ")
+            )?;
+        }
+        for msg in &self.messages {
+            for signal in &msg.signals {
+                let Some(value_type) = signal.extended_value_type else {
+                    continue;
+                };
+                let typ = match value_type {
+                    SignalExtendedValueType::SignedOrUnsignedInteger => 0,
+                    SignalExtendedValueType::IEEEfloat32Bit => 1,
+                    SignalExtendedValueType::IEEEdouble64bit => 2,
+                };
+                writeln!(
+                    w,
+                    "SIG_VALTYPE_ {} {} : {};",
+                    msg.id.raw(),
+                    signal.name,
+                    typ
+                )?;
+            }
+        }
+        for mux in &self.extended_multiplex {
+            let mappings: Vec<String> = mux
+                .mappings()
+                .iter()
+                .map(|m| format!("{}-{}", m.min_value(), m.max_value()))
+                .collect();
+            writeln!(
+                w,
+                "SG_MUL_VAL_ {} {} {} {};",
+                mux.message_id().raw(),
+                mux.signal_name(),
+                mux.multiplexor_signal_name(),
+                mappings.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// The DBC text, see [DbcFile::write]
+    pub fn to_dbc_string(&self) -> String {
+        let mut out = Vec::new();
+        self.write(&mut out).expect("writing to a Vec can't fail");
+        String::from_utf8(out).expect("DBC text is built from strings")
+    }
+}
+
+fn write_message(mut w: impl Write, msg: &FileMessage) -> io::Result<()> {
+    writeln!(
+        w,
+        "BO_ {} {}: {} {}",
+        msg.id.raw(),
+        msg.name,
+        msg.size,
+        transmitter(&msg.transmitter)
+    )?;
+    for signal in &msg.signals {
+        let multiplexer = match signal.multiplexer {
+            MultiplexIndicator::Plain => String::new(),
+            MultiplexIndicator::Multiplexor => " M".to_string(),
+            MultiplexIndicator::MultiplexedSignal(n) => format!(" m{n}"),
+            MultiplexIndicator::MultiplexorAndMultiplexedSignal(n) => format!(" m{n}M"),
+        };
+        let receivers = if signal.receivers.is_empty() {
+            "Vector__XXX".to_string()
+        } else {
+            signal.receivers.join(",")
+        };
+        writeln!(
+            w,
+            " SG_ {}{} : {}|{}@{}{} ({},{}) [{}|{}] \"{}\" {}",
+            signal.name,
+            multiplexer,
+            signal.start_bit,
+            signal.size,
+            byte_order(&signal.byte_order),
+            value_type(&signal.value_type),
+            number(signal.factor),
+            number(signal.offset),
+            number(signal.min),
+            number(signal.max),
+            signal.unit,
+            receivers
+        )?;
+    }
+    Ok(())
+}
+
+fn write_environment_variable(mut w: impl Write, env: &EnvironmentVariable) -> io::Result<()> {
+    let typ = match env.env_var_type() {
+        EnvType::EnvTypeFloat => 0,
+        EnvType::EnvTypeu64 => 1,
+        EnvType::EnvTypeData => 2,
+    };
+    let access = match env.access_type() {
+        AccessType::DummyNodeVector0 => 0,
+        AccessType::DummyNodeVector1 => 1,
+        AccessType::DummyNodeVector2 => 2,
+        AccessType::DummyNodeVector3 => 3,
+    };
+    let nodes: Vec<&str> = env
+        .access_nodes()
+        .iter()
+        .map(|node| match node {
+            AccessNode::AccessNodeVectorXXX => "VECTOR_XXX",
+            AccessNode::AccessNodeName(name) => name,
+        })
+        .collect();
+    writeln!(
+        w,
+        "EV_ {}: {} [{}|{}] \"{}\" {} {} DUMMY_NODE_VECTOR{} {};",
+        env.env_var_name(),
+        typ,
+        env.min(),
+        env.max(),
+        env.unit(),
+        env.initial_value(),
+        env.ev_id(),
+        access,
+        nodes.join(",")
+    )
+}
+
+fn write_comment(mut w: impl Write, comment: &Comment) -> io::Result<()> {
+    match comment {
+        Comment::Node { node_name, comment } => writeln!(w, "CM_ BU_ {node_name} \"{comment}\";"),
+        Comment::Message {
+            message_id,
+            comment,
+        } => writeln!(w, "CM_ BO_ {} \"{comment}\";", message_id.raw()),
+        Comment::Signal {
+            message_id,
+            signal_name,
+            comment,
+        } => writeln!(
+            w,
+            "CM_ SG_ {} {signal_name} \"{comment}\";",
+            message_id.raw()
+        ),
+        Comment::EnvVar {
+            env_var_name,
+            comment,
+        } => writeln!(w, "CM_ EV_ {env_var_name} \"{comment}\";"),
+        Comment::Plain { comment } => writeln!(w, "CM_ \"{comment}\";"),
+    }
+}
+
+fn write_attribute_value(mut w: impl Write, value: &AttributeValueForObject) -> io::Result<()> {
+    let name = value.attribute_name();
+    match value.attribute_value() {
+        AttributeValuedForObjectType::RawAttributeValue(v) => {
+            writeln!(w, "BA_ \"{name}\" {};", attribute_value(v))
+        }
+        AttributeValuedForObjectType::NetworkNodeAttributeValue(node, v) => {
+            writeln!(w, "BA_ \"{name}\" BU_ {node} {};", attribute_value(v))
+        }
+        AttributeValuedForObjectType::MessageDefinitionAttributeValue(id, v) => {
+            let v = v.as_ref().map(attribute_value).unwrap_or_default();
+            writeln!(w, "BA_ \"{name}\" BO_ {} {v};", id.raw())
+        }
+        AttributeValuedForObjectType::SignalAttributeValue(id, signal, v) => writeln!(
+            w,
+            "BA_ \"{name}\" SG_ {} {signal} {};",
+            id.raw(),
+            attribute_value(v)
+        ),
+        AttributeValuedForObjectType::EnvVariableAttributeValue(env, v) => {
+            writeln!(w, "BA_ \"{name}\" EV_ {env} {};", attribute_value(v))
+        }
+    }
+}
+
+fn write_val_descriptions(mut w: impl Write, descriptions: &[(f64, String)]) -> io::Result<()> {
+    for (value, label) in descriptions {
+        write!(w, " {} \"{label}\"", number(*value))?;
+    }
+    Ok(())
+}
+
+fn attribute_value(value: &AttributeValue) -> String {
+    match value {
+        AttributeValue::AttributeValueU64(x) => x.to_string(),
+        AttributeValue::AttributeValueI64(x) => x.to_string(),
+        AttributeValue::AttributeValueF64(x) => number(*x),
+        AttributeValue::AttributeValueCharString(x) => format!("\"{x}\""),
+    }
+}
+
+/// Shortest decimal form reading back as the same value, without negative zero
+fn number(value: f64) -> String {
+    if value == 0.0 {
+        "0".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn transmitter(transmitter: &Transmitter) -> &str {
+    match transmitter {
+        Transmitter::NodeName(name) => name,
+        Transmitter::VectorXXX => "Vector__XXX",
+    }
+}
+
+fn byte_order(order: &ByteOrder) -> char {
+    match order {
+        ByteOrder::LittleEndian => '1',
+        ByteOrder::BigEndian => '0',
+    }
+}
+
+fn value_type(typ: &ValueType) -> char {
+    match typ {
+        ValueType::Signed => '-',
+        ValueType::Unsigned => '+',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::normalize_dbc;
+    use can_dbc::DBC;
+
+    #[test]
+    fn dbc_writer_round_trip() {
+        fn same_items<T: PartialEq + std::fmt::Debug>(a: &[T], b: &[T]) {
+            assert_eq!(a.len(), b.len());
+            for item in a {
+                assert!(b.contains(item), "{item:?} is missing");
+            }
+        }
+
+        let original = include_bytes!("../testing/dbc-examples/example.dbc");
+        let normalized = normalize_dbc(original).unwrap();
+        assert_eq!(normalize_dbc(normalized.as_bytes()).unwrap(), normalized);
+
+        let before = DBC::from_slice(original).unwrap();
+        let after = DBC::from_slice(normalized.as_bytes()).unwrap();
+        let mut messages = before.messages().clone();
+        messages.sort_by_key(|m| m.message_id().raw());
+        assert_eq!(after.messages(), &messages);
+        assert_eq!(after.version(), before.version());
+        assert_eq!(after.nodes(), before.nodes());
+        assert_eq!(after.value_tables(), before.value_tables());
+        same_items(after.comments(), before.comments());
+        same_items(after.value_descriptions(), before.value_descriptions());
+        same_items(
+            after.attribute_definitions(),
+            before.attribute_definitions(),
+        );
+        same_items(after.attribute_defaults(), before.attribute_defaults());
+        same_items(after.attribute_values(), before.attribute_values());
+        same_items(
+            after.signal_extended_value_type_list(),
+            before.signal_extended_value_type_list(),
+        );
+
+        // Messages are sorted, numbers normalized
+        let ids: Vec<u32> = after
+            .messages()
+            .iter()
+            .map(|m| m.message_id().raw())
+            .collect();
+        assert!(ids.windows(2).all(|w| w[0] <= w[1]));
+        let normalized_signal = normalize_dbc(
+            br#"VERSION ""
+
+NS_ :
+
+BS_:
+
+BU_:
+
+BO_ 2 B: 1 Vector__XXX
+ SG_ S : 0|8@1- (0.50,-0.0) [-64.000|63.5] "" Vector__XXX
+
+BO_ 1 A: 1 Vector__XXX
+"#,
+        )
+        .unwrap();
+        assert!(normalized_signal.contains(
+            "BO_ 1 A: 1 Vector__XXX\n\nBO_ 2 B: 1 Vector__XXX\n SG_ S : 0|8@1- (0.5,0) [-64|63.5]"
+        ));
+    }
+}
@@ -0,0 +1,608 @@
+use crate::ir::{Database, Message, Signal};
+use crate::{get_relevant_messages, parse_input, resolve_names, value_enum_name, Config};
+use anyhow::Result;
+use std::fmt;
+
+/// How a change between two revisions of a bus affects generated code, see [diff_dbc].
+///
+/// Ordered by severity, so the maximum over all changes is the impact of the whole revision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Impact {
+    /// Only documentation changes, e.g. comments, units or receivers
+    Cosmetic,
+
+    /// Frames are encoded and decoded as before and the generated API is unchanged, but its
+    /// behavior differs, e.g. the range accepted by setters
+    WireCompatible,
+
+    /// The generated API is unchanged, but frames are encoded or decoded differently, e.g. a
+    /// signal moved or was rescaled without changing its Rust type
+    WireBreaking,
+
+    /// Code using the generated API of the old revision may no longer compile, e.g. a message
+    /// or signal was added, removed or renamed, or a signal's Rust type changed
+    ApiBreaking,
+}
+
+/// What changed about a message or signal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// Only in the new revision
+    Added,
+    /// Only in the old revision
+    Removed,
+    /// DBC name of a message or signal whose Rust identifier stayed the same, e.g. through
+    /// [Renames](crate::Renames)
+    Name,
+    /// CAN ID of a message
+    Id,
+    /// Payload size of a message
+    Size,
+    /// Transmitting node of a message
+    Transmitter,
+    /// Comment of a message or signal
+    Comment,
+    /// Start bit, size, byte order or signedness of a signal
+    Layout,
+    /// Role of a signal in multiplexing
+    Multiplexing,
+    /// Rust type of a signal's accessors, e.g. `u8`, `f32` or a value table enum
+    Type,
+    /// Factor or offset of a signal
+    Scaling,
+    /// Minimum or maximum of a signal
+    Range,
+    /// Unit of a signal
+    Unit,
+    /// Receiving nodes of a signal
+    Receivers,
+    /// Value descriptions of a signal
+    Values,
+}
+
+/// A single difference found by [diff_dbc]
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Change {
+    /// DBC name of the message, as in the new revision unless it was removed
+    pub message: String,
+    /// DBC name of the signal, if the change is about one
+    pub signal: Option<String>,
+    /// What changed
+    pub kind: ChangeKind,
+    /// How the change affects generated code
+    pub impact: Impact,
+    /// Old and new value, e.g. `0|8@1+ -> 0|16@1+`, empty for added and removed messages and
+    /// signals
+    pub details: String,
+}
+
+/// Differences between two revisions of a bus, returned by [diff_dbc]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct DbcDiff {
+    /// Changes in the order of the old revision's messages and signals, followed by added ones
+    pub changes: Vec<Change>,
+}
+
+impl DbcDiff {
+    /// Whether both revisions describe the same messages and signals
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The most severe impact of all changes, `None` if there are none
+    pub fn impact(&self) -> Option<Impact> {
+        self.changes.iter().map(|change| change.impact).max()
+    }
+}
+
+/// Compare two revisions of a bus and classify each change by its [Impact] on generated code.
+///
+/// Both configs are read and named as for [codegen](crate::codegen), so any
+/// [InputFormat](crate::InputFormat), patches or [ir::Database](crate::ir::Database) can be
+/// compared. Messages are matched by their struct names and signals by their accessor names,
+/// so a message renamed in the DBC but mapped back with [Renames](crate::Renames) is still the
+/// same message. Whether a change breaks the generated API is decided by the Rust types of the
+/// accessors, including value table enums.
+///
+/// ```
+/// use dbc_codegen::{diff_dbc, Config, Impact};
+///
+/// let old = include_bytes!("../testing/dbc-examples/example.dbc");
+/// let new = String::from_utf8_lossy(old).replace(
+///     r#"SG_ One : 15|2@0+ (1,0) [0|3] "" Dolor"#,
+///     r#"SG_ One : 15|2@0+ (1,0) [0|2] "" Dolor"#,
+/// );
+///
+/// let diff = diff_dbc(
+///     Config::builder().dbc_name("old.dbc").dbc_content(old).build(),
+///     Config::builder().dbc_name("new.dbc").dbc_content(new.as_bytes()).build(),
+/// )
+/// .unwrap();
+/// assert_eq!(diff.impact(), Some(Impact::WireCompatible));
+/// assert_eq!(
+///     diff.changes[0].to_string(),
+///     "wire-compatible: range of signal `One` of message `Bar` changed: [0|3] -> [0|2]"
+/// );
+/// ```
+pub fn diff_dbc(mut old: Config<'_>, mut new: Config<'_>) -> Result<DbcDiff> {
+    let (old_dbc, _) = parse_input(&old)?;
+    let (new_dbc, _) = parse_input(&new)?;
+    resolve_names(&mut old, &old_dbc)?;
+    resolve_names(&mut new, &new_dbc)?;
+    let old = Revision {
+        config: &old,
+        dbc: &old_dbc,
+    };
+    let new = Revision {
+        config: &new,
+        dbc: &new_dbc,
+    };
+
+    let mut diff = DbcDiff::default();
+    for old_msg in get_relevant_messages(old.dbc) {
+        match new.message(&old.type_name(old_msg)) {
+            Some(new_msg) => diff_message(&mut diff, &old, old_msg, &new, new_msg),
+            None => diff.push(
+                &old_msg.name,
+                None,
+                ChangeKind::Removed,
+                Impact::ApiBreaking,
+            ),
+        }
+    }
+    for new_msg in get_relevant_messages(new.dbc) {
+        if old.message(&new.type_name(new_msg)).is_none() {
+            // Adds a variant to the exhaustive `Messages` enum
+            diff.push(&new_msg.name, None, ChangeKind::Added, Impact::ApiBreaking);
+        }
+    }
+    Ok(diff)
+}
+
+/// One revision with the identifiers its code is generated with
+struct Revision<'r, 'c> {
+    config: &'r Config<'c>,
+    dbc: &'r Database,
+}
+
+impl Revision<'_, '_> {
+    fn type_name(&self, msg: &Message) -> String {
+        self.config.type_name(&msg.name)
+    }
+
+    /// Message generated as the struct `type_name`
+    fn message(&self, type_name: &str) -> Option<&Message> {
+        get_relevant_messages(self.dbc).find(|msg| self.type_name(msg) == type_name)
+    }
+
+    /// Signal of `msg` with the accessor `field_name`
+    fn signal<'m>(&self, msg: &'m Message, field_name: &str) -> Option<&'m Signal> {
+        msg.signals
+            .iter()
+            .find(|signal| self.config.field_name(msg, signal) == field_name)
+    }
+
+    /// Type returned by the getter, the value table enum if there is one
+    fn signal_type(&self, msg: &Message, signal: &Signal) -> String {
+        value_enum_name(self.config, self.dbc, msg, signal).unwrap_or_else(|| signal.rust_type())
+    }
+
+    /// Variants of the value table enum, sorted
+    fn variants(&self, msg: &Message, signal: &Signal) -> Vec<String> {
+        if value_enum_name(self.config, self.dbc, msg, signal).is_none() {
+            return Vec::new();
+        }
+        let mut variants = self.config.enum_variant_names(&signal.values);
+        variants.sort_unstable();
+        variants
+    }
+}
+
+impl DbcDiff {
+    fn push(&mut self, message: &str, signal: Option<&str>, kind: ChangeKind, impact: Impact) {
+        self.changes.push(Change {
+            message: message.to_string(),
+            signal: signal.map(str::to_string),
+            kind,
+            impact,
+            details: String::new(),
+        });
+    }
+
+    /// Record a change if `old` and `new` differ
+    fn compare(
+        &mut self,
+        message: &str,
+        signal: Option<&str>,
+        kind: ChangeKind,
+        impact: Impact,
+        old: String,
+        new: String,
+    ) {
+        if old != new {
+            self.changed(message, signal, kind, impact, format!("{old} -> {new}"));
+        }
+    }
+
+    fn changed(
+        &mut self,
+        message: &str,
+        signal: Option<&str>,
+        kind: ChangeKind,
+        impact: Impact,
+        details: String,
+    ) {
+        self.changes.push(Change {
+            message: message.to_string(),
+            signal: signal.map(str::to_string),
+            kind,
+            impact,
+            details,
+        });
+    }
+}
+
+fn diff_message(
+    diff: &mut DbcDiff,
+    old_rev: &Revision<'_, '_>,
+    old: &Message,
+    new_rev: &Revision<'_, '_>,
+    new: &Message,
+) {
+    let name = &new.name;
+    diff.compare(
+        name,
+        None,
+        ChangeKind::Name,
+        Impact::Cosmetic,
+        format!("{:?}", old.name),
+        format!("{:?}", new.name),
+    );
+    diff.compare(
+        name,
+        None,
+        ChangeKind::Id,
+        // `MESSAGE_ID` is an `embedded_can::Id` for standard and extended IDs alike
+        Impact::WireBreaking,
+        format!("{:?}", old.id),
+        format!("{:?}", new.id),
+    );
+    diff.compare(
+        name,
+        None,
+        ChangeKind::Size,
+        // The payload is a `[u8; SIZE]`
+        Impact::ApiBreaking,
+        old.size.to_string(),
+        new.size.to_string(),
+    );
+    diff.compare(
+        name,
+        None,
+        ChangeKind::Transmitter,
+        Impact::Cosmetic,
+        optional(&old.transmitter),
+        optional(&new.transmitter),
+    );
+    diff.compare(
+        name,
+        None,
+        ChangeKind::Comment,
+        Impact::Cosmetic,
+        optional(&old.comment),
+        optional(&new.comment),
+    );
+
+    for old_signal in &old.signals {
+        let field_name = old_rev.config.field_name(old, old_signal);
+        match new_rev.signal(new, &field_name) {
+            Some(new_signal) => diff_signal(
+                diff,
+                name,
+                (old_rev, old, old_signal),
+                (new_rev, new, new_signal),
+            ),
+            None => diff.push(
+                name,
+                Some(&old_signal.name),
+                ChangeKind::Removed,
+                Impact::ApiBreaking,
+            ),
+        }
+    }
+    for new_signal in &new.signals {
+        let field_name = new_rev.config.field_name(new, new_signal);
+        if old_rev.signal(old, &field_name).is_none() {
+            // Adds accessors and an argument of `new`, or a multiplexed variant
+            diff.push(
+                name,
+                Some(&new_signal.name),
+                ChangeKind::Added,
+                Impact::ApiBreaking,
+            );
+        }
+    }
+}
+
+/// A signal with the message and revision it belongs to
+type SignalIn<'a, 'r, 'c> = (&'a Revision<'r, 'c>, &'a Message, &'a Signal);
+
+fn diff_signal(
+    diff: &mut DbcDiff,
+    message: &str,
+    (old_rev, old_msg, old): SignalIn<'_, '_, '_>,
+    (new_rev, new_msg, new): SignalIn<'_, '_, '_>,
+) {
+    let signal = Some(new.name.as_str());
+
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Name,
+        Impact::Cosmetic,
+        format!("{:?}", old.name),
+        format!("{:?}", new.name),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Type,
+        Impact::ApiBreaking,
+        old_rev.signal_type(old_msg, old),
+        new_rev.signal_type(new_msg, new),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Layout,
+        Impact::WireBreaking,
+        layout(old),
+        layout(new),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Multiplexing,
+        // Accessors move between the message and the multiplexed variants
+        Impact::ApiBreaking,
+        format!("{:?}", old.multiplexer),
+        format!("{:?}", new.multiplexer),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Scaling,
+        Impact::WireBreaking,
+        format!("({},{})", old.factor, old.offset),
+        format!("({},{})", new.factor, new.offset),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Range,
+        Impact::WireCompatible,
+        format!("[{}|{}]", old.min, old.max),
+        format!("[{}|{}]", new.min, new.max),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Unit,
+        Impact::Cosmetic,
+        format!("{:?}", old.unit),
+        format!("{:?}", new.unit),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Receivers,
+        Impact::Cosmetic,
+        old.receivers.join(", "),
+        new.receivers.join(", "),
+    );
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Comment,
+        Impact::Cosmetic,
+        optional(&old.comment),
+        optional(&new.comment),
+    );
+
+    // Labels become enum variants, so only their raw values may change without breaking code
+    diff.compare(
+        message,
+        signal,
+        ChangeKind::Values,
+        if old_rev.variants(old_msg, old) == new_rev.variants(new_msg, new) {
+            Impact::WireBreaking
+        } else {
+            Impact::ApiBreaking
+        },
+        values(old),
+        values(new),
+    );
+}
+
+/// Layout in DBC notation, e.g. `0|8@1+`
+fn layout(signal: &Signal) -> String {
+    format!(
+        "{}|{}@{}{}",
+        signal.start_bit,
+        signal.size,
+        match signal.byte_order {
+            crate::ir::ByteOrder::LittleEndian => 1,
+            crate::ir::ByteOrder::BigEndian => 0,
+        },
+        match signal.value_type {
+            crate::ir::ValueType::Unsigned => '+',
+            crate::ir::ValueType::Signed => '-',
+        },
+    )
+}
+
+/// Value descriptions sorted by raw value, e.g. `0 "Off" 1 "On"`
+fn values(signal: &Signal) -> String {
+    let mut values = signal.values.clone();
+    values.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let values: Vec<String> = values
+        .iter()
+        .map(|(value, label)| format!("{value} {label:?}"))
+        .collect();
+    if values.is_empty() {
+        "none".to_string()
+    } else {
+        values.join(" ")
+    }
+}
+
+fn optional(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("{value:?}"),
+        None => "none".to_string(),
+    }
+}
+
+impl fmt::Display for Impact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Impact::Cosmetic => "cosmetic",
+            Impact::WireCompatible => "wire-compatible",
+            Impact::WireBreaking => "wire-breaking",
+            Impact::ApiBreaking => "API-breaking",
+        })
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Name => "DBC name",
+            ChangeKind::Id => "ID",
+            ChangeKind::Size => "size",
+            ChangeKind::Transmitter => "transmitter",
+            ChangeKind::Comment => "comment",
+            ChangeKind::Layout => "layout",
+            ChangeKind::Multiplexing => "multiplexing",
+            ChangeKind::Type => "Rust type",
+            ChangeKind::Scaling => "scaling",
+            ChangeKind::Range => "range",
+            ChangeKind::Unit => "unit",
+            ChangeKind::Receivers => "receivers",
+            ChangeKind::Values => "value descriptions",
+        })
+    }
+}
+
+/// E.g. ``API-breaking: signal `Speed` of message `Abs` removed`` or
+/// ``cosmetic: unit of signal `Speed` of message `Abs` changed: "km/h" -> "m/s"``
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.impact)?;
+        let subject = match &self.signal {
+            Some(signal) => format!("signal `{signal}` of message `{}`", self.message),
+            None => format!("message `{}`", self.message),
+        };
+        match self.kind {
+            ChangeKind::Added | ChangeKind::Removed => write!(f, "{subject} {}", self.kind),
+            kind => write!(f, "{kind} of {subject} changed: {}", self.details),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{diff_dbc, Config, Impact, InputFormat, Renames};
+
+    #[test]
+    fn diff_revisions() {
+        let old = include_bytes!("../testing/dbc-examples/example.dbc");
+        let old_config = || {
+            Config::builder()
+                .dbc_name("old.dbc")
+                .dbc_content(old)
+                .build()
+        };
+        assert!(diff_dbc(old_config(), old_config()).unwrap().is_empty());
+
+        let new = String::from_utf8_lossy(old)
+            .replace("(0.000976562,0)", "(0.001,0)")
+            .replace("SG_ Five : 40|1", "SG_ Five : 41|1")
+            .replace("(1,-1000) [-1000|3000]", "(0.5,-1000) [-1000|1047.5]")
+            .replace(r#""degC""#, r#""K""#)
+            .replace(r#"2 "4WD""#, r#"5 "4WD""#)
+            .replace(r#"3 "Dolor" 5 "Other""#, r#"3 "Dolor" 5 "Another""#)
+            .replace("BO_ 513 MsgWithoutSignals: 8 Ipsum", "BO_ 513 MsgWithoutSignals: 4 Ipsum")
+            .replace("BO_ 1344 NegativeFactorTest", "BO_ 1345 NegativeFactorTest")
+            .replace(
+                " SG_ Dummy : 15|2@0+ (1,0) [0|3] \"\" XXX",
+                " SG_ Dummy : 15|2@0+ (1,0) [0|3] \"\" XXX\n SG_ Extra : 16|8@0+ (1,0) [0|255] \"\" XXX",
+            );
+        let new_config = Config::builder()
+            .dbc_name("new.dbc")
+            .dbc_content(new.as_bytes())
+            .build();
+        let diff = diff_dbc(old_config(), new_config).unwrap();
+        let changes: Vec<String> = diff.changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            changes,
+            [
+                "wire-breaking: scaling of signal `Voltage` of message `Foo` changed: (0.000976562,0) -> (0.001,0)",
+                r#"wire-breaking: value descriptions of signal `_4DRIVE` of message `_4WD` changed: 0 "OFF" 1 "2WD" 2 "4WD" 3 "ALL" -> 0 "OFF" 1 "2WD" 3 "ALL" 5 "4WD""#,
+                "wire-breaking: layout of signal `Five` of message `Amet` changed: 40|1@0+ -> 41|1@0+",
+                r#"API-breaking: value descriptions of signal `OneFloat` of message `Dolor` changed: 3 "Dolor" 5 "Other" -> 3 "Dolor" 5 "Another""#,
+                r#"cosmetic: unit of signal `Celsius` of message `NamedMultiplexTest` changed: "degC" -> "K""#,
+                "wire-breaking: ID of message `NegativeFactorTest` changed: Standard(1344) -> Standard(1345)",
+                "API-breaking: Rust type of signal `Twelve` of message `LargerIntsWithOffsets` changed: i16 -> f32",
+                "wire-breaking: scaling of signal `Twelve` of message `LargerIntsWithOffsets` changed: (1,-1000) -> (0.5,-1000)",
+                "wire-compatible: range of signal `Twelve` of message `LargerIntsWithOffsets` changed: [-1000|3000] -> [-1000|1047.5]",
+                "API-breaking: size of message `MsgWithoutSignals` changed: 8 -> 4",
+                "API-breaking: signal `Extra` of message `MsgExtendedId` added",
+            ]
+        );
+        assert_eq!(diff.impact(), Some(Impact::ApiBreaking));
+
+        let kcd = include_bytes!("../testing/dbc-examples/example.kcd");
+        let kcd_config = Config::builder()
+            .dbc_name("example.kcd")
+            .dbc_content(kcd)
+            .input_format(InputFormat::Kcd)
+            .build();
+        let diff = diff_dbc(old_config(), kcd_config).unwrap();
+        let changes: Vec<String> = diff.changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            changes,
+            ["API-breaking: Rust type of signal `Five` of message `Amet` changed: OnOff -> AmetFive"]
+        );
+
+        // Renames map the new DBC names back to the old identifiers
+        let renamed = String::from_utf8_lossy(old)
+            .replace("BO_ 512 Bar:", "BO_ 512 BarV2:")
+            .replace("SG_ Three : 13|3", "SG_ Three_V2 : 13|3")
+            .replace("VAL_ 512 Three ", "VAL_ 512 Three_V2 ");
+        let renamed_config = Config::builder()
+            .dbc_name("new.dbc")
+            .dbc_content(renamed.as_bytes())
+            .renames(
+                Renames::default()
+                    .message("BarV2", "Bar")
+                    .signal("Three_V2", "three"),
+            )
+            .build();
+        let diff = diff_dbc(old_config(), renamed_config).unwrap();
+        let changes: Vec<String> = diff.changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            changes,
+            [
+                r#"cosmetic: DBC name of message `BarV2` changed: "Bar" -> "BarV2""#,
+                r#"cosmetic: DBC name of signal `Three_V2` of message `BarV2` changed: "Three" -> "Three_V2""#,
+                // The value table enum is named after the DBC name of the signal
+                "API-breaking: Rust type of signal `Three_V2` of message `BarV2` changed: BarThree -> BarThreeV2",
+            ]
+        );
+    }
+}
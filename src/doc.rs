@@ -0,0 +1,554 @@
+//! Bus reports for systems engineers: message tables, bit layouts, multiplexing and value
+//! descriptions as Markdown or HTML.
+
+use crate::ir::{ByteOrder, Database, Message, MessageId, Multiplexer, Signal, ValueType};
+use crate::{
+    get_relevant_messages, multiplexor_value_label, signal_byte_chunks, signal_to_rust_type,
+    value_enum_name, Config,
+};
+use anyhow::{Context, Result};
+use std::io::Write;
+
+/// Output format of [codegen_docs](crate::codegen_docs)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DocFormat {
+    /// GitHub flavored Markdown
+    #[default]
+    Markdown,
+
+    /// A standalone HTML page
+    Html,
+}
+
+/// Format independent building blocks of a report. Text may contain `code spans`.
+enum Block {
+    Heading(usize, String),
+    Paragraph(String),
+    /// Items with their nesting depth
+    List(Vec<(usize, String)>),
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+        /// Bit layouts merge adjacent cells of the same signal in HTML
+        layout: bool,
+    },
+}
+
+pub(crate) fn render(
+    mut w: impl Write,
+    config: &Config<'_>,
+    dbc: &Database,
+    format: DocFormat,
+) -> Result<()> {
+    let blocks = document(config, dbc)?;
+    match format {
+        DocFormat::Markdown => render_markdown(&mut w, &blocks)?,
+        DocFormat::Html => render_html(&mut w, config.dbc_name, &blocks)?,
+    }
+    Ok(())
+}
+
+fn document(config: &Config<'_>, dbc: &Database) -> Result<Vec<Block>> {
+    let mut blocks = vec![Block::Heading(1, config.dbc_name.to_string())];
+
+    let mut facts = Vec::new();
+    if !dbc.version.is_empty() {
+        facts.push((0, format!("Version: {}", dbc.version)));
+    }
+    let nodes: Vec<String> = dbc.nodes.iter().map(|node| code(node)).collect();
+    if !nodes.is_empty() {
+        facts.push((0, format!("Nodes: {}", nodes.join(", "))));
+    }
+    if !facts.is_empty() {
+        blocks.push(Block::List(facts));
+    }
+
+    blocks.push(Block::Heading(2, "Messages".to_string()));
+    blocks.push(Block::Table {
+        header: cells(&["Message", "ID", "Size", "Transmitter", "Rust type"]),
+        rows: get_relevant_messages(dbc)
+            .map(|msg| {
+                vec![
+                    msg.name.clone(),
+                    message_id(msg),
+                    format!("{} bytes", msg.size),
+                    transmitter(msg).map(|t| code(&t)).unwrap_or_default(),
+                    code(&config.type_name(&msg.name)),
+                ]
+            })
+            .collect(),
+        layout: false,
+    });
+
+    for msg in get_relevant_messages(dbc) {
+        message(&mut blocks, config, dbc, msg)
+            .with_context(|| format!("documenting message {}", msg.name))?;
+    }
+
+    if !dbc.value_tables.is_empty() {
+        blocks.push(Block::Heading(2, "Value tables".to_string()));
+        for table in &dbc.value_tables {
+            blocks.push(Block::Heading(3, code(&table.name)));
+            blocks.push(Block::Table {
+                header: cells(&["Value", "Label"]),
+                rows: table
+                    .values
+                    .iter()
+                    .map(|value| vec![value.0.to_string(), value.1.clone()])
+                    .collect(),
+                layout: false,
+            });
+        }
+    }
+
+    Ok(blocks)
+}
+
+fn message(
+    blocks: &mut Vec<Block>,
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+) -> Result<()> {
+    blocks.push(Block::Heading(2, msg.name.clone()));
+
+    let mut facts = vec![
+        (0, format!("ID: {}", message_id(msg))),
+        (0, format!("Size: {} bytes", msg.size)),
+    ];
+    if let Some(transmitter) = transmitter(msg) {
+        facts.push((0, format!("Transmitter: {}", code(&transmitter))));
+    }
+    let mut receivers: Vec<&String> = msg
+        .signals
+        .iter()
+        .flat_map(|signal| &signal.receivers)
+        .collect();
+    receivers.sort();
+    receivers.dedup();
+    if !receivers.is_empty() {
+        let receivers: Vec<String> = receivers.into_iter().map(|r| code(r)).collect();
+        facts.push((0, format!("Receivers: {}", receivers.join(", "))));
+    }
+    facts.push((
+        0,
+        format!("Rust type: {}", code(&config.type_name(&msg.name))),
+    ));
+    blocks.push(Block::List(facts));
+    if let Some(comment) = &msg.comment {
+        blocks.push(Block::Paragraph(comment.trim().to_string()));
+    }
+
+    if msg.signals.is_empty() {
+        blocks.push(Block::Paragraph("No signals.".to_string()));
+        return Ok(());
+    }
+
+    blocks.push(Block::Heading(3, "Signals".to_string()));
+    blocks.push(Block::Table {
+        header: cells(&[
+            "#",
+            "Signal",
+            "Multiplexing",
+            "Start bit",
+            "Size",
+            "Byte order",
+            "Value type",
+            "Factor",
+            "Offset",
+            "Range",
+            "Unit",
+            "Receivers",
+            "Rust type",
+            "Comment",
+        ]),
+        rows: msg
+            .signals
+            .iter()
+            .enumerate()
+            .map(|(i, signal)| signal_row(config, dbc, msg, i, signal))
+            .collect(),
+        layout: false,
+    });
+
+    let tree = msg.mux_tree().filter(|tree| !tree.groups.is_empty());
+    let always: Vec<&Signal> = msg
+        .signals
+        .iter()
+        .filter(|s| !matches!(s.multiplexer, Multiplexer::Multiplexed(_)))
+        .collect();
+    match tree {
+        None => {
+            blocks.push(Block::Heading(3, "Bit layout".to_string()));
+            blocks.push(bit_layout(msg, &always)?);
+        }
+        Some(tree) => {
+            let multiplexor = tree.multiplexor;
+            for (switch_index, signals) in &tree.groups {
+                blocks.push(Block::Heading(
+                    3,
+                    format!(
+                        "Bit layout for {} = {switch_index}",
+                        code(&multiplexor.name)
+                    ),
+                ));
+                let mut present = always.clone();
+                present.extend(signals);
+                blocks.push(bit_layout(msg, &present)?);
+            }
+
+            blocks.push(Block::Heading(3, "Multiplexing".to_string()));
+            let mut items = Vec::new();
+            for (switch_index, signals) in &tree.groups {
+                let mut item = format!(
+                    "{} = {switch_index}: {}",
+                    code(&multiplexor.name),
+                    code(&config.multiplexed_enum_variant_name(msg, multiplexor, *switch_index)?)
+                );
+                if let Some(label) = multiplexor_value_label(multiplexor, *switch_index) {
+                    item = format!("{item} ({label})");
+                }
+                items.push((0, item));
+                items.extend(signals.iter().map(|s| (1, code(&s.name))));
+            }
+            blocks.push(Block::List(items));
+        }
+    }
+
+    let described: Vec<&Signal> = msg
+        .signals
+        .iter()
+        .filter(|s| !s.values.is_empty())
+        .collect();
+    if !described.is_empty() {
+        blocks.push(Block::Heading(3, "Value descriptions".to_string()));
+    }
+    for signal in described {
+        let values = &signal.values;
+        blocks.push(Block::Heading(4, code(&signal.name)));
+        let variants = config.enum_variant_names(values);
+        blocks.push(Block::Table {
+            header: cells(&["Value", "Label", "Rust variant"]),
+            rows: values
+                .iter()
+                .zip(variants)
+                .map(|(value, variant)| vec![value.0.to_string(), value.1.clone(), code(&variant)])
+                .collect(),
+            layout: false,
+        });
+    }
+
+    Ok(())
+}
+
+fn signal_row(
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+    index: usize,
+    signal: &Signal,
+) -> Vec<String> {
+    let multiplexing = match signal.multiplexer {
+        Multiplexer::Plain => String::new(),
+        Multiplexer::Multiplexor => "multiplexor".to_string(),
+        Multiplexer::Multiplexed(index) => format!("= {index}"),
+        Multiplexer::MultiplexorAndMultiplexed(index) => {
+            format!("multiplexor, = {index}")
+        }
+    };
+    let receivers: Vec<String> = signal.receivers.iter().map(|node| code(node)).collect();
+    let rust_type =
+        value_enum_name(config, dbc, msg, signal).unwrap_or_else(|| signal_to_rust_type(signal));
+    vec![
+        index.saturating_add(1).to_string(),
+        code(&signal.name),
+        multiplexing,
+        signal.start_bit.to_string(),
+        signal.size.to_string(),
+        match signal.byte_order {
+            ByteOrder::LittleEndian => "little endian",
+            ByteOrder::BigEndian => "big endian",
+        }
+        .to_string(),
+        match signal.value_type {
+            ValueType::Unsigned => "unsigned",
+            ValueType::Signed => "signed",
+        }
+        .to_string(),
+        signal.factor.to_string(),
+        signal.offset.to_string(),
+        format!("{} … {}", signal.min, signal.max),
+        signal.unit.clone(),
+        receivers.join(", "),
+        code(&rust_type),
+        signal
+            .comment
+            .as_ref()
+            .map(|comment| comment.trim().to_string())
+            .unwrap_or_default(),
+    ]
+}
+
+/// Payload bytes with their bits from most to least significant, labelled with the numbers of
+/// the signals in the signal table
+fn bit_layout(msg: &Message, signals: &[&Signal]) -> Result<Block> {
+    let size = usize::try_from(msg.size).context("message size")?;
+    let mut rows: Vec<Vec<String>> = (0..size)
+        .map(|byte| {
+            let mut row = vec![byte.to_string()];
+            row.extend(std::iter::repeat(String::new()).take(8));
+            row
+        })
+        .collect();
+
+    for signal in signals {
+        let number = msg
+            .signals
+            .iter()
+            .position(|s| s.name == signal.name)
+            .unwrap_or_default()
+            .saturating_add(1)
+            .to_string();
+        for chunk in signal_byte_chunks(signal, msg)? {
+            let row = usize::try_from(chunk.byte)
+                .ok()
+                .and_then(|byte| rows.get_mut(byte))
+                .context("signal outside of payload")?;
+            for bit in chunk.byte_shift..chunk.byte_shift.saturating_add(chunk.width) {
+                // Column 1 is bit 7
+                let column = 8u64.saturating_sub(bit);
+                if let Some(cell) = usize::try_from(column).ok().and_then(|c| row.get_mut(c)) {
+                    if !cell.is_empty() {
+                        cell.push('/');
+                    }
+                    cell.push_str(&number);
+                }
+            }
+        }
+    }
+
+    let mut header = vec!["Byte".to_string()];
+    header.extend((0..8).rev().map(|bit: u8| bit.to_string()));
+    Ok(Block::Table {
+        header,
+        rows,
+        layout: true,
+    })
+}
+
+fn message_id(msg: &Message) -> String {
+    match msg.id {
+        MessageId::Standard(id) => format!("{id:#x} ({id})"),
+        MessageId::Extended(id) => format!("{id:#x} ({id}, extended)"),
+    }
+}
+
+fn transmitter(msg: &Message) -> Option<String> {
+    msg.transmitter.clone()
+}
+
+fn code(text: &str) -> String {
+    format!("`{text}`")
+}
+
+fn cells(texts: &[&str]) -> Vec<String> {
+    texts.iter().map(|text| text.to_string()).collect()
+}
+
+fn render_markdown(mut w: impl Write, blocks: &[Block]) -> Result<()> {
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            writeln!(w)?;
+        }
+        match block {
+            Block::Heading(level, text) => writeln!(w, "{} {text}", "#".repeat(*level))?,
+            Block::Paragraph(text) => writeln!(w, "{text}")?,
+            Block::List(items) => {
+                for (depth, text) in items {
+                    writeln!(w, "{}- {text}", "  ".repeat(*depth))?;
+                }
+            }
+            Block::Table { header, rows, .. } => {
+                writeln!(w, "| {} |", markdown_cells(header).join(" | "))?;
+                let rule: Vec<&str> = header.iter().map(|_| "---").collect();
+                writeln!(w, "| {} |", rule.join(" | "))?;
+                for row in rows {
+                    writeln!(w, "| {} |", markdown_cells(row).join(" | "))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Table cells can't contain pipes or line breaks
+fn markdown_cells(cells: &[String]) -> Vec<String> {
+    cells
+        .iter()
+        .map(|cell| {
+            cell.trim()
+                .replace('|', "\\|")
+                .replace("\r\n", "<br>")
+                .replace('\n', "<br>")
+        })
+        .collect()
+}
+
+const STYLE: &str = "body { font-family: sans-serif; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #999; padding: 0.2em 0.5em; text-align: left; }
+table.layout td, table.layout th { text-align: center; min-width: 2em; }
+table.layout td.signal { background: #dde8f3; }";
+
+fn render_html(mut w: impl Write, title: &str, blocks: &[Block]) -> Result<()> {
+    writeln!(w, "<!DOCTYPE html>")?;
+    writeln!(w, "<html>")?;
+    writeln!(w, "<head>")?;
+    writeln!(w, "<meta charset=\"utf-8\">")?;
+    writeln!(w, "<title>{}</title>", escape(title))?;
+    writeln!(w, "<style>\n{STYLE}\n</style>")?;
+    writeln!(w, "</head>")?;
+    writeln!(w, "<body>")?;
+    for block in blocks {
+        match block {
+            Block::Heading(level, text) => writeln!(w, "<h{level}>{}</h{level}>", html(text))?,
+            Block::Paragraph(text) => {
+                let lines: Vec<String> = text.lines().map(html).collect();
+                writeln!(w, "<p>{}</p>", lines.join("<br>"))?;
+            }
+            Block::List(items) => {
+                let mut depth = 0;
+                writeln!(w, "<ul>")?;
+                for (i, (item_depth, text)) in items.iter().enumerate() {
+                    if i > 0 {
+                        if *item_depth > depth {
+                            writeln!(w, "<ul>")?;
+                        } else {
+                            writeln!(w, "</li>")?;
+                        }
+                    }
+                    while depth > *item_depth {
+                        writeln!(w, "</ul></li>")?;
+                        depth = depth.saturating_sub(1);
+                    }
+                    depth = *item_depth;
+                    write!(w, "<li>{}", html(text))?;
+                }
+                writeln!(w, "</li>")?;
+                for _ in 0..depth {
+                    writeln!(w, "</ul></li>")?;
+                }
+                writeln!(w, "</ul>")?;
+            }
+            Block::Table {
+                header,
+                rows,
+                layout,
+            } => {
+                if *layout {
+                    writeln!(w, "<table class=\"layout\">")?;
+                } else {
+                    writeln!(w, "<table>")?;
+                }
+                let header: Vec<String> = header
+                    .iter()
+                    .map(|cell| format!("<th>{}</th>", html(cell)))
+                    .collect();
+                writeln!(w, "<tr>{}</tr>", header.join(""))?;
+                for row in rows {
+                    writeln!(w, "<tr>{}</tr>", html_row(row, *layout))?;
+                }
+                writeln!(w, "</table>")?;
+            }
+        }
+    }
+    writeln!(w, "</body>")?;
+    writeln!(w, "</html>")?;
+    Ok(())
+}
+
+/// Cells of a row; in bit layouts, adjacent bits of the same signal share a cell
+fn html_row(row: &[String], layout: bool) -> String {
+    let mut out = String::new();
+    let mut cells = row.iter().peekable();
+    let mut first = true;
+    while let Some(cell) = cells.next() {
+        let mut span = 1usize;
+        if layout && !first && !cell.is_empty() {
+            while cells.next_if(|next| *next == cell).is_some() {
+                span = span.saturating_add(1);
+            }
+        }
+        match (layout && !first && !cell.is_empty(), span) {
+            (false, _) => out.push_str(&format!("<td>{}</td>", html(cell))),
+            (true, 1) => out.push_str(&format!("<td class=\"signal\">{}</td>", html(cell))),
+            (true, span) => out.push_str(&format!(
+                "<td class=\"signal\" colspan=\"{span}\">{}</td>",
+                html(cell)
+            )),
+        }
+        first = false;
+    }
+    out
+}
+
+/// Escaped text with `code spans` as `<code>` elements
+fn html(text: &str) -> String {
+    escape(text)
+        .split('`')
+        .enumerate()
+        .map(|(i, part)| {
+            if i % 2 == 1 {
+                format!("<code>{part}</code>")
+            } else {
+                part.to_string()
+            }
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{codegen_docs, Config, DocFormat};
+
+    #[test]
+    fn docs_report() {
+        let dbc = include_bytes!("../testing/dbc-examples/example.dbc");
+        let report = |format| {
+            let config = Config::builder()
+                .dbc_name("example.dbc")
+                .dbc_content(dbc)
+                .build();
+            let mut out = Vec::new();
+            codegen_docs(config, format, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        let markdown = report(DocFormat::Markdown);
+        assert!(markdown.contains("| Foo | 0x100 (256) | 4 bytes | `Lorem` | `Foo` |"));
+        assert!(markdown.contains("| 3 | `Three` |  | 13 | 3 | big endian | unsigned |"));
+        assert!(markdown.contains("| 1 | 1 | 1 | 3 | 3 | 3 | 4 | 4 |  |"));
+        assert!(markdown.contains("### Bit layout for `Kind` = 1"));
+        assert!(markdown
+            .contains("- `Kind` = 1: `NamedMultiplexTestKindM1` (Pressure)\n  - `Kilopascal`\n"));
+        assert!(markdown.contains("| 0 | 0Off | `X0off` |"));
+        assert!(markdown.contains("- ID: 0x1234 (4660, extended)"));
+
+        let html = report(DocFormat::Html);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            "<tr><td>1</td><td class=\"signal\" colspan=\"2\">1</td>\
+             <td class=\"signal\" colspan=\"3\">3</td>\
+             <td class=\"signal\" colspan=\"2\">4</td><td></td></tr>"
+        ));
+        assert!(html.contains("<li><code>Kind</code> = 0: <code>NamedMultiplexTestKindM0</code>"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+}
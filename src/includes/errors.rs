@@ -15,6 +15,8 @@ pub enum CanError {
         /// Multiplexor value not defined in the dbc
         multiplexor: u16,
     },
+    /// Value or label not defined in the value table
+    UndefinedValue,
 }
 
 impl core::fmt::Display for CanError {
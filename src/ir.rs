@@ -0,0 +1,808 @@
+//! Format-independent description of a CAN bus, as used for code generation.
+//!
+//! A [Database] is read from any [InputFormat](crate::InputFormat) with
+//! [Database::from_config], or built in code and checked with [Database::validate]. Code is
+//! generated from it with [codegen_from_ir](crate::codegen_from_ir) and it is written as DBC
+//! text with [Database::to_dbc_string].
+//!
+//! ```
+//! use dbc_codegen::ir::{Database, Message, MessageId, Signal, ValueType};
+//! use dbc_codegen::{codegen_from_ir, Config};
+//!
+//! let database = Database::builder()
+//!     .nodes(vec!["Abs".to_string()])
+//!     .messages(vec![Message::builder()
+//!         .id(MessageId::Standard(0x100))
+//!         .name("WheelSpeed")
+//!         .size(4)
+//!         .transmitter("Abs")
+//!         .signals(vec![
+//!             Signal::builder()
+//!                 .name("Speed")
+//!                 .start_bit(0)
+//!                 .size(16)
+//!                 .factor(0.01)
+//!                 .unit("km/h")
+//!                 .build(),
+//!             Signal::builder()
+//!                 .name("Acceleration")
+//!                 .start_bit(16)
+//!                 .size(16)
+//!                 .value_type(ValueType::Signed)
+//!                 .build(),
+//!         ])
+//!         .build()])
+//!     .build();
+//! database.validate().unwrap();
+//! assert_eq!(database.messages[0].signals[0].rust_type(), "f32");
+//! assert!(database
+//!     .to_dbc_string()
+//!     .contains(r#"SG_ Speed : 0|16@1+ (0.01,0) [0|655.35] "km/h" Vector__XXX"#));
+//!
+//! let config = Config::builder().dbc_name("wheels").dbc_content(&[]).build();
+//! let mut out = Vec::<u8>::new();
+//! codegen_from_ir(config, &database, &mut out).unwrap();
+//! ```
+
+use crate::dbc_file::{
+    raw_range, DbcFile, FileMessage, FileSignal, FileValueDescription, FileValueTable,
+};
+use crate::{parse_input, Config};
+use anyhow::{anyhow, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use typed_builder::TypedBuilder;
+
+/// Messages and named value tables of one bus
+#[derive(Clone, Debug, Default, PartialEq, TypedBuilder)]
+pub struct Database {
+    /// Version string, e.g. from `VERSION` of a DBC file
+    #[builder(default, setter(into))]
+    pub version: String,
+    /// Names of all nodes on the bus
+    #[builder(default)]
+    pub nodes: Vec<String>,
+    /// Messages, generated in this order
+    #[builder(default)]
+    pub messages: Vec<Message>,
+    /// Named value tables. Signals with the same values share their enum.
+    #[builder(default)]
+    pub value_tables: Vec<ValueTable>,
+}
+
+/// A CAN message with its signals, built with [Message::builder] or assembled directly
+#[derive(Clone, Debug, PartialEq, TypedBuilder)]
+pub struct Message {
+    /// CAN ID
+    pub id: MessageId,
+    /// Message name, turned into the struct name by the [NamingStrategy](crate::NamingStrategy)
+    #[builder(setter(into))]
+    pub name: String,
+    /// Payload size in bytes
+    pub size: u64,
+    /// Transmitting node, if any
+    #[builder(default, setter(into, strip_option))]
+    pub transmitter: Option<String>,
+    /// Documentation of the message struct
+    #[builder(default, setter(into, strip_option))]
+    pub comment: Option<String>,
+    /// Signals in definition order
+    #[builder(default)]
+    pub signals: Vec<Signal>,
+}
+
+/// CAN ID of a message
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MessageId {
+    /// 11 bit ID
+    Standard(u16),
+    /// 29 bit ID
+    Extended(u32),
+}
+
+/// A signal of a message, built with [Signal::builder] or assembled directly
+///
+/// The builder defaults to a plain, unsigned, unscaled little endian signal whose range covers
+/// all raw values.
+#[derive(Clone, Debug, PartialEq, TypedBuilder)]
+pub struct Signal {
+    /// Signal name, turned into accessor names by the [NamingStrategy](crate::NamingStrategy)
+    #[builder(setter(into))]
+    pub name: String,
+    /// Role in multiplexing
+    #[builder(default = Multiplexer::Plain)]
+    pub multiplexer: Multiplexer,
+    /// Start bit as in DBC files: the least significant bit for little endian signals, the most
+    /// significant bit for big endian ones
+    pub start_bit: u64,
+    /// Size in bits
+    pub size: u64,
+    /// Byte order of the raw value
+    #[builder(default = ByteOrder::LittleEndian)]
+    pub byte_order: ByteOrder,
+    /// Signedness of the raw value
+    #[builder(default = ValueType::Unsigned)]
+    pub value_type: ValueType,
+    /// Scale of the raw value: physical value = raw value * factor + offset
+    #[builder(default = 1.0)]
+    pub factor: f64,
+    /// Added to the scaled raw value
+    #[builder(default = 0.0)]
+    pub offset: f64,
+    /// Physical minimum
+    #[builder(default = raw_range(value_type.to_dbc(), size, factor, offset).0)]
+    pub min: f64,
+    /// Physical maximum
+    #[builder(default = raw_range(value_type.to_dbc(), size, factor, offset).1)]
+    pub max: f64,
+    /// Unit of the physical value
+    #[builder(default, setter(into))]
+    pub unit: String,
+    /// Receiving nodes, empty for `Vector__XXX`
+    #[builder(default)]
+    pub receivers: Vec<String>,
+    /// Documentation of the signal accessors
+    #[builder(default, setter(into, strip_option))]
+    pub comment: Option<String>,
+    /// Labels of raw values, generated as a value table enum
+    #[builder(default)]
+    pub values: Vec<(f64, String)>,
+}
+
+/// Role of a signal in multiplexing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Multiplexer {
+    /// Always present
+    Plain,
+    /// Selects the multiplexed signals
+    Multiplexor,
+    /// Only present if the multiplexor has this value
+    Multiplexed(u64),
+    /// Extended multiplexing, which is not generated
+    MultiplexorAndMultiplexed(u64),
+}
+
+/// Byte order of a signal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Intel
+    LittleEndian,
+    /// Motorola
+    BigEndian,
+}
+
+/// Signedness of a raw signal value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueType {
+    /// Unsigned integer
+    Unsigned,
+    /// Two's complement integer
+    Signed,
+}
+
+/// A named value table, e.g. `VAL_TABLE_` of a DBC file
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValueTable {
+    /// Table name, which becomes the enum name of all signals using it
+    pub name: String,
+    /// Labels of raw values
+    pub values: Vec<(f64, String)>,
+}
+
+/// The multiplexor of a message and the signals selected by each of its values
+#[derive(Clone, Debug, PartialEq)]
+pub struct MuxTree<'a> {
+    /// The signal selecting the multiplexed signals
+    pub multiplexor: &'a Signal,
+    /// Multiplexed signals by multiplexor value
+    pub groups: BTreeMap<u64, Vec<&'a Signal>>,
+}
+
+impl Database {
+    /// Read `config.dbc_content` in its [InputFormat](crate::InputFormat), merged with
+    /// `config.dbc_patches`.
+    ///
+    /// LIN specifics of LDF files are not part of the database.
+    pub fn from_config(config: &Config<'_>) -> Result<Self> {
+        let (database, _) = parse_input(config)?;
+        Ok(database)
+    }
+
+    /// Write as canonical DBC text, see [write_dbc](crate::write_dbc)
+    pub fn to_dbc_string(&self) -> String {
+        let mut file = DbcFile::from(self);
+        file.sort();
+        file.to_dbc_string()
+    }
+
+    /// Check that the database describes valid CAN messages, listing every problem found:
+    ///
+    /// - names are DBC identifiers, unique per database (messages, value tables) or message
+    ///   (signals),
+    /// - message IDs are unique and in range, payloads at most 64 bytes,
+    /// - signals are 1 to 64 bits, inside the payload, and don't overlap with signals present
+    ///   at the same time,
+    /// - multiplexed signals have exactly one multiplexor,
+    /// - factors are not zero and minimums not above maximums.
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+        let mut check_name = |what: &str, name: &str| {
+            if !is_identifier(name) {
+                problems.push(format!("{what} `{name}` is not a valid identifier"));
+            }
+        };
+        for node in &self.nodes {
+            check_name("node", node);
+        }
+        for table in &self.value_tables {
+            check_name("value table", &table.name);
+        }
+        for msg in &self.messages {
+            check_name("message", &msg.name);
+            for node in &msg.transmitter {
+                check_name("transmitter", node);
+            }
+            for signal in &msg.signals {
+                check_name("signal", &signal.name);
+            }
+        }
+        problems.extend(
+            duplicates(self.value_tables.iter().map(|t| &t.name))
+                .map(|name| format!("value table `{name}` is defined more than once")),
+        );
+        problems.extend(
+            duplicates(self.messages.iter().map(|m| &m.name))
+                .map(|name| format!("message `{name}` is defined more than once")),
+        );
+        problems.extend(
+            duplicates(self.messages.iter().map(|m| m.id))
+                .map(|id| format!("message ID {id:?} is used more than once")),
+        );
+        for msg in &self.messages {
+            problems.extend(
+                msg.problems()
+                    .into_iter()
+                    .map(|problem| format!("message `{}`: {problem}", msg.name)),
+            );
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!("invalid database:\n- {}", problems.join("\n- ")))
+        }
+    }
+}
+
+impl Message {
+    fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let id_in_range = match self.id {
+            MessageId::Standard(id) => id <= 0x7ff,
+            MessageId::Extended(id) => id <= 0x1fff_ffff,
+        };
+        if !id_in_range {
+            problems.push(format!("ID {:?} is out of range", self.id));
+        }
+        if self.size > 64 {
+            problems.push(format!("size of {} bytes is above 64", self.size));
+        }
+        problems.extend(
+            duplicates(self.signals.iter().map(|s| &s.name))
+                .map(|name| format!("signal `{name}` is defined more than once")),
+        );
+
+        let multiplexors = self
+            .signals
+            .iter()
+            .filter(|s| s.multiplexer == Multiplexer::Multiplexor)
+            .count();
+        let multiplexed = self
+            .signals
+            .iter()
+            .any(|s| matches!(s.multiplexer, Multiplexer::Multiplexed(_)));
+        if multiplexors > 1 {
+            problems.push("more than one multiplexor".to_string());
+        } else if multiplexed && multiplexors == 0 {
+            problems.push("multiplexed signals without multiplexor".to_string());
+        }
+
+        let payload_bits = self.size.saturating_mul(8);
+        let mut bits = BTreeMap::<u64, Vec<&Signal>>::new();
+        for signal in &self.signals {
+            if signal.size == 0 || signal.size > 64 {
+                problems.push(format!(
+                    "signal `{}` has {} bits, not 1 to 64",
+                    signal.name, signal.size
+                ));
+                continue;
+            }
+            if signal.factor == 0.0 {
+                problems.push(format!("signal `{}` has a factor of 0", signal.name));
+            }
+            if !crate::is_float_in_rust(signal.factor, signal.offset)
+                && crate::integer_rust_type(
+                    signal.value_type,
+                    signal.size,
+                    signal.factor,
+                    signal.offset,
+                )
+                .is_none()
+            {
+                problems.push(format!(
+                    "signal `{}` with a factor of {} and an offset of {} has no Rust integer type",
+                    signal.name, signal.factor, signal.offset
+                ));
+            }
+            if signal.min > signal.max {
+                problems.push(format!(
+                    "signal `{}` has a minimum of {} above its maximum of {}",
+                    signal.name, signal.min, signal.max
+                ));
+            }
+            let signal_bits = signal.bits();
+            if signal_bits.iter().any(|bit| *bit >= payload_bits) {
+                problems.push(format!(
+                    "signal `{}` does not fit into {} bytes",
+                    signal.name, self.size
+                ));
+            }
+            for bit in signal_bits {
+                bits.entry(bit).or_default().push(signal);
+            }
+        }
+        let overlaps: BTreeSet<(&str, &str)> = bits
+            .values()
+            .flat_map(|signals| {
+                signals.iter().enumerate().flat_map(move |(i, a)| {
+                    signals
+                        .iter()
+                        .skip(i.saturating_add(1))
+                        .map(move |b| (*a, *b))
+                })
+            })
+            .filter(|(a, b)| present_together(a, b))
+            .map(|(a, b)| (a.name.as_str(), b.name.as_str()))
+            .collect();
+        for (a, b) in overlaps {
+            problems.push(format!("signals `{a}` and `{b}` overlap"));
+        }
+
+        problems
+    }
+
+    /// The multiplexor and multiplexed signals, if the message has a multiplexor
+    pub fn mux_tree(&self) -> Option<MuxTree<'_>> {
+        let multiplexor = self
+            .signals
+            .iter()
+            .find(|s| s.multiplexer == Multiplexer::Multiplexor)?;
+        let mut groups = BTreeMap::<u64, Vec<&Signal>>::new();
+        for signal in &self.signals {
+            if let Multiplexer::Multiplexed(value) = signal.multiplexer {
+                groups.entry(value).or_default().push(signal);
+            }
+        }
+        Some(MuxTree {
+            multiplexor,
+            groups,
+        })
+    }
+}
+
+impl Signal {
+    /// Payload bits of the signal, numbered from bit 0 of byte 0 to bit 7 of the last byte
+    fn bits(&self) -> Vec<u64> {
+        let mut bits = Vec::new();
+        let mut bit = self.start_bit;
+        for _ in 0..self.size {
+            bits.push(bit);
+            bit = match self.byte_order {
+                ByteOrder::LittleEndian => bit.saturating_add(1),
+                // Big endian signals continue at the most significant bit of the next byte
+                ByteOrder::BigEndian if bit % 8 == 0 => bit.saturating_add(15),
+                ByteOrder::BigEndian => bit.saturating_sub(1),
+            };
+        }
+        bits
+    }
+
+    /// Rust type of the physical value in generated code, e.g. `bool`, `u8` or `f32`
+    pub fn rust_type(&self) -> String {
+        crate::rust_type(
+            &self.name,
+            self.value_type,
+            self.size,
+            self.factor,
+            self.offset,
+        )
+    }
+}
+
+impl MessageId {
+    /// The 11 or 29 bit ID, without the extended frame flag
+    pub fn raw(self) -> u32 {
+        match self {
+            MessageId::Standard(id) => id.into(),
+            MessageId::Extended(id) => id,
+        }
+    }
+}
+
+impl ValueType {
+    fn to_dbc(self) -> can_dbc::ValueType {
+        match self {
+            ValueType::Unsigned => can_dbc::ValueType::Unsigned,
+            ValueType::Signed => can_dbc::ValueType::Signed,
+        }
+    }
+}
+
+impl From<&DbcFile> for Database {
+    fn from(file: &DbcFile) -> Self {
+        let message_comment = |id: &can_dbc::MessageId| {
+            file.comments.iter().find_map(|comment| match comment {
+                can_dbc::Comment::Message {
+                    message_id,
+                    comment,
+                } if message_id == id => Some(comment.clone()),
+                _ => None,
+            })
+        };
+        let signal_comment = |id: &can_dbc::MessageId, name: &str| {
+            file.comments.iter().find_map(|comment| match comment {
+                can_dbc::Comment::Signal {
+                    message_id,
+                    signal_name,
+                    comment,
+                } if message_id == id && signal_name == name => Some(comment.clone()),
+                _ => None,
+            })
+        };
+        let signal_values = |id: &can_dbc::MessageId, name: &str| {
+            file.value_descriptions
+                .iter()
+                .find_map(|description| match description {
+                    FileValueDescription::Signal {
+                        message_id,
+                        signal_name,
+                        values,
+                    } if message_id == id && signal_name == name => Some(values.clone()),
+                    _ => None,
+                })
+                .unwrap_or_default()
+        };
+
+        let messages = file
+            .messages
+            .iter()
+            .map(|msg| Message {
+                id: match msg.id {
+                    can_dbc::MessageId::Standard(id) => MessageId::Standard(id),
+                    can_dbc::MessageId::Extended(id) => MessageId::Extended(id),
+                },
+                name: msg.name.clone(),
+                size: msg.size,
+                transmitter: match &msg.transmitter {
+                    can_dbc::Transmitter::NodeName(node) => Some(node.clone()),
+                    can_dbc::Transmitter::VectorXXX => None,
+                },
+                comment: message_comment(&msg.id),
+                signals: msg
+                    .signals
+                    .iter()
+                    .map(|signal| Signal {
+                        name: signal.name.clone(),
+                        multiplexer: match signal.multiplexer {
+                            can_dbc::MultiplexIndicator::Plain => Multiplexer::Plain,
+                            can_dbc::MultiplexIndicator::Multiplexor => Multiplexer::Multiplexor,
+                            can_dbc::MultiplexIndicator::MultiplexedSignal(value) => {
+                                Multiplexer::Multiplexed(value)
+                            }
+                            can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value) => {
+                                Multiplexer::MultiplexorAndMultiplexed(value)
+                            }
+                        },
+                        start_bit: signal.start_bit,
+                        size: signal.size,
+                        byte_order: match signal.byte_order {
+                            can_dbc::ByteOrder::LittleEndian => ByteOrder::LittleEndian,
+                            can_dbc::ByteOrder::BigEndian => ByteOrder::BigEndian,
+                        },
+                        value_type: match signal.value_type {
+                            can_dbc::ValueType::Unsigned => ValueType::Unsigned,
+                            can_dbc::ValueType::Signed => ValueType::Signed,
+                        },
+                        factor: signal.factor,
+                        offset: signal.offset,
+                        min: signal.min,
+                        max: signal.max,
+                        unit: signal.unit.clone(),
+                        receivers: signal
+                            .receivers
+                            .iter()
+                            .filter(|node| *node != "Vector__XXX")
+                            .cloned()
+                            .collect(),
+                        comment: signal_comment(&msg.id, &signal.name),
+                        values: signal_values(&msg.id, &signal.name),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Database {
+            version: file.version.clone(),
+            nodes: file.nodes.clone(),
+            messages,
+            value_tables: file
+                .value_tables
+                .iter()
+                .map(|table| ValueTable {
+                    name: table.name.clone(),
+                    values: table.values.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Database> for DbcFile {
+    fn from(database: &Database) -> Self {
+        let mut file = DbcFile {
+            version: database.version.clone(),
+            nodes: database.nodes.clone(),
+            value_tables: database
+                .value_tables
+                .iter()
+                .map(|table| FileValueTable {
+                    name: table.name.clone(),
+                    values: table.values.clone(),
+                })
+                .collect(),
+            ..DbcFile::default()
+        };
+
+        for msg in &database.messages {
+            let id = match msg.id {
+                MessageId::Standard(id) => can_dbc::MessageId::Standard(id),
+                MessageId::Extended(id) => can_dbc::MessageId::Extended(id),
+            };
+            if let Some(comment) = &msg.comment {
+                file.comments.push(can_dbc::Comment::Message {
+                    message_id: id,
+                    comment: comment.clone(),
+                });
+            }
+            let mut signals = Vec::new();
+            for signal in &msg.signals {
+                if let Some(comment) = &signal.comment {
+                    file.comments.push(can_dbc::Comment::Signal {
+                        message_id: id,
+                        signal_name: signal.name.clone(),
+                        comment: comment.clone(),
+                    });
+                }
+                if !signal.values.is_empty() {
+                    file.value_descriptions.push(FileValueDescription::Signal {
+                        message_id: id,
+                        signal_name: signal.name.clone(),
+                        values: signal.values.clone(),
+                    });
+                }
+                signals.push(FileSignal {
+                    name: signal.name.clone(),
+                    multiplexer: match signal.multiplexer {
+                        Multiplexer::Plain => can_dbc::MultiplexIndicator::Plain,
+                        Multiplexer::Multiplexor => can_dbc::MultiplexIndicator::Multiplexor,
+                        Multiplexer::Multiplexed(value) => {
+                            can_dbc::MultiplexIndicator::MultiplexedSignal(value)
+                        }
+                        Multiplexer::MultiplexorAndMultiplexed(value) => {
+                            can_dbc::MultiplexIndicator::MultiplexorAndMultiplexedSignal(value)
+                        }
+                    },
+                    start_bit: signal.start_bit,
+                    size: signal.size,
+                    byte_order: match signal.byte_order {
+                        ByteOrder::LittleEndian => can_dbc::ByteOrder::LittleEndian,
+                        ByteOrder::BigEndian => can_dbc::ByteOrder::BigEndian,
+                    },
+                    value_type: signal.value_type.to_dbc(),
+                    factor: signal.factor,
+                    offset: signal.offset,
+                    min: signal.min,
+                    max: signal.max,
+                    unit: signal.unit.clone(),
+                    receivers: signal.receivers.clone(),
+                    extended_value_type: None,
+                });
+            }
+            file.messages.push(FileMessage {
+                id,
+                name: msg.name.clone(),
+                size: msg.size,
+                transmitter: match &msg.transmitter {
+                    Some(node) => can_dbc::Transmitter::NodeName(node.clone()),
+                    None => can_dbc::Transmitter::VectorXXX,
+                },
+                signals,
+            });
+        }
+
+        file
+    }
+}
+
+/// Whether two signals of a message can be in the same payload
+fn present_together(a: &Signal, b: &Signal) -> bool {
+    match (a.multiplexer, b.multiplexer) {
+        (Multiplexer::Multiplexed(x), Multiplexer::Multiplexed(y)) => x == y,
+        _ => true,
+    }
+}
+
+/// Values occurring more than once, each reported once
+fn duplicates<T: Ord>(values: impl IntoIterator<Item = T>) -> impl Iterator<Item = T> {
+    let mut seen = BTreeSet::new();
+    let mut duplicates = BTreeSet::new();
+    for value in values {
+        if let Some(value) = seen.replace(value) {
+            duplicates.insert(value);
+        }
+    }
+    duplicates.into_iter()
+}
+
+/// DBC names are C identifiers
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{codegen, codegen_from_ir, ir, Config, FeatureConfig};
+
+    #[test]
+    fn ir_round_trip() {
+        fn config(dbc_content: &[u8]) -> Config<'_> {
+            Config::builder()
+                .dbc_name("example.dbc")
+                .dbc_content(dbc_content)
+                .impl_debug(FeatureConfig::Always)
+                .build()
+        }
+        let dbc = include_bytes!("../testing/dbc-examples/example.dbc");
+        let mut database = ir::Database::from_config(&config(dbc)).unwrap();
+
+        let multiplexed = database
+            .messages
+            .iter()
+            .find(|m| m.name == "MultiplexTest")
+            .unwrap();
+        let mux = multiplexed.mux_tree().unwrap();
+        assert_eq!(mux.multiplexor.name, "Multiplexor");
+        assert_eq!(mux.groups.keys().copied().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(mux.groups[&0][0].rust_type(), "f32");
+        assert_eq!(mux.multiplexor.rust_type(), "u8");
+
+        // IDs 9001 and 9002 are out of range for standard IDs
+        database
+            .messages
+            .retain(|m| !m.name.starts_with("Truncated"));
+        // Written DBC text has its messages sorted by ID
+        database.messages.sort_by_key(|m| m.id.raw());
+        let dbc = database.to_dbc_string();
+        let mut expected = Vec::new();
+        codegen(config(dbc.as_bytes()), &mut expected).unwrap();
+        let mut out = Vec::new();
+        codegen_from_ir(config(&[]), &database, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out), String::from_utf8(expected));
+    }
+
+    #[test]
+    fn ir_validation() {
+        use ir::{ByteOrder, Database, Message, MessageId, Multiplexer, Signal};
+
+        let signal = |name: &str, start_bit, size| {
+            Signal::builder()
+                .name(name)
+                .start_bit(start_bit)
+                .size(size)
+                .build()
+        };
+        let database = Database::builder()
+            .messages(vec![
+                Message::builder()
+                    .id(MessageId::Standard(0x800))
+                    .name("Broken")
+                    .size(2)
+                    .signals(vec![
+                        signal("A", 0, 8),
+                        signal("B", 4, 8),
+                        signal("C", 12, 8),
+                        Signal::builder()
+                            .name("D")
+                            .start_bit(7)
+                            .size(4)
+                            .byte_order(ByteOrder::BigEndian)
+                            .multiplexer(Multiplexer::Multiplexed(1))
+                            .build(),
+                    ])
+                    .build(),
+                Message::builder()
+                    .id(MessageId::Extended(0x800))
+                    .name("Broken")
+                    .size(8)
+                    .signals(vec![signal("1st", 0, 0)])
+                    .build(),
+            ])
+            .build();
+        assert_eq!(
+            database.validate().unwrap_err().to_string(),
+            "invalid database:
+- signal `1st` is not a valid identifier
+- message `Broken` is defined more than once
+- message `Broken`: ID Standard(2048) is out of range
+- message `Broken`: multiplexed signals without multiplexor
+- message `Broken`: signal `C` does not fit into 2 bytes
+- message `Broken`: signals `A` and `B` overlap
+- message `Broken`: signals `A` and `D` overlap
+- message `Broken`: signals `B` and `D` overlap
+- message `Broken`: signal `1st` has 0 bits, not 1 to 64"
+        );
+        // Code generation checks the database first
+        let config = Config::builder()
+            .dbc_name("broken")
+            .dbc_content(&[])
+            .build();
+        assert!(codegen_from_ir(config, &database, &mut Vec::new())
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid database:"));
+
+        // The factor doesn't fit into an `i64`, so there is no integer type for the values
+        let database = Database::builder()
+            .messages(vec![Message::builder()
+                .id(MessageId::Standard(1))
+                .name("Huge")
+                .size(8)
+                .signals(vec![Signal::builder()
+                    .name("Value")
+                    .start_bit(0)
+                    .size(64)
+                    .factor(1e19)
+                    .build()])
+                .build()])
+            .build();
+        assert_eq!(
+            database.validate().unwrap_err().to_string(),
+            "invalid database:
+- message `Huge`: signal `Value` with a factor of 10000000000000000000 and an offset of 0 has no Rust integer type"
+        );
+
+        let mut database = ir::Database::from_config(
+            &Config::builder()
+                .dbc_name("example.dbc")
+                .dbc_content(include_bytes!("../testing/dbc-examples/example.dbc"))
+                .build(),
+        )
+        .unwrap();
+        // IDs 9001 and 9002 are out of range for standard IDs
+        database
+            .messages
+            .retain(|m| !m.name.starts_with("Truncated"));
+        database.validate().unwrap();
+        // Written DBC text reads back as the same database, with messages sorted by ID
+        let dbc = database.to_dbc_string();
+        let read = ir::Database::from_config(
+            &Config::builder()
+                .dbc_name("example.dbc")
+                .dbc_content(dbc.as_bytes())
+                .build(),
+        )
+        .unwrap();
+        database.messages.sort_by_key(|m| m.id.raw());
+        assert_eq!(read, database);
+    }
+}
@@ -0,0 +1,270 @@
+//! Reading Kayak (KCD) network definitions.
+//!
+//! Nodes, messages, signals (including multiplexed ones), label sets and notes are mapped onto
+//! the corresponding DBC definitions. Label groups (value ranges) have no DBC equivalent and are
+//! ignored.
+
+use crate::dbc_file::{DbcFile, FileMessage, FileSignal, FileValueDescription};
+use crate::parse::{attribute, elements, parse_u32, parse_u64};
+use anyhow::{anyhow, bail, Context, Result};
+use can_dbc::{
+    ByteOrder, Comment, MessageId, MultiplexIndicator, SignalExtendedValueType, Transmitter,
+    ValueType,
+};
+use roxmltree::{Document, Node};
+use std::collections::BTreeMap;
+
+/// Read the bus named `bus` (or the first one) of a KCD file
+pub(crate) fn parse_kcd(content: &[u8], bus: Option<&str>) -> Result<DbcFile> {
+    let text = std::str::from_utf8(content).context("KCD file is not valid UTF-8")?;
+    let doc = Document::parse(text).context("Could not parse KCD file")?;
+    let root = doc.root_element();
+    if root.tag_name().name() != "NetworkDefinition" {
+        bail!("KCD file has no `NetworkDefinition`");
+    }
+
+    let mut file = DbcFile::default();
+    let mut nodes = BTreeMap::new();
+    for child in root.children().filter(Node::is_element) {
+        match child.tag_name().name() {
+            "Document" => {
+                file.version = child.attribute("version").unwrap_or_default().to_string();
+            }
+            "Node" => {
+                let name = attribute(child, "name")?;
+                nodes.insert(attribute(child, "id")?, name.to_string());
+                file.nodes.push(name.to_string());
+            }
+            _ => {}
+        }
+    }
+
+    let buses: Vec<Node> = elements(root, "Bus").collect();
+    let selected = match bus {
+        None => buses.first(),
+        Some(name) => buses.iter().find(|b| b.attribute("name") == Some(name)),
+    };
+    let Some(selected) = selected else {
+        let names: Vec<&str> = buses.iter().filter_map(|b| b.attribute("name")).collect();
+        bail!(
+            "KCD file has no bus {} (available: {})",
+            bus.map(|name| format!("`{name}`")).unwrap_or_default(),
+            names.join(", ")
+        );
+    };
+
+    let reader = Reader { nodes: &nodes };
+    for message in elements(*selected, "Message") {
+        let msg = reader.message(&mut file, message).with_context(|| {
+            format!(
+                "in KCD message `{}`",
+                message.attribute("name").unwrap_or("?")
+            )
+        })?;
+        file.messages.push(msg);
+    }
+
+    Ok(file)
+}
+
+struct Reader<'a> {
+    /// Node names by ID
+    nodes: &'a BTreeMap<&'a str, String>,
+}
+
+impl Reader<'_> {
+    fn message(&self, file: &mut DbcFile, message: Node) -> Result<FileMessage> {
+        let raw_id = parse_u32(attribute(message, "id")?)?;
+        let id = if message.attribute("format") == Some("extended") {
+            MessageId::Extended(raw_id)
+        } else {
+            let id =
+                u16::try_from(raw_id).map_err(|_| anyhow!("standard ID {raw_id} out of range"))?;
+            MessageId::Standard(id)
+        };
+
+        let transmitter = match elements(message, "Producer")
+            .flat_map(|producer| elements(producer, "NodeRef"))
+            .next()
+        {
+            Some(node_ref) => Transmitter::NodeName(self.node_ref(node_ref)?),
+            None => Transmitter::VectorXXX,
+        };
+        if let Some(notes) = notes(message) {
+            file.comments.push(Comment::Message {
+                message_id: id,
+                comment: notes,
+            });
+        }
+
+        // Multiplexed signals follow all others, like they usually do in DBC files
+        let mut signals = Vec::new();
+        let mut multiplexed = Vec::new();
+        for child in message.children().filter(Node::is_element) {
+            match child.tag_name().name() {
+                "Signal" => {
+                    signals.push(self.signal(file, id, child, MultiplexIndicator::Plain)?)
+                }
+                "Multiplex" => {
+                    signals.push(self.signal(file, id, child, MultiplexIndicator::Multiplexor)?);
+                    for group in elements(child, "MuxGroup") {
+                        let count = parse_u32(attribute(group, "count")?)?;
+                        for signal in elements(group, "Signal") {
+                            let multiplexer = MultiplexIndicator::MultiplexedSignal(count.into());
+                            multiplexed.push(self.signal(file, id, signal, multiplexer)?);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        signals.append(&mut multiplexed);
+
+        let size = match message.attribute("length") {
+            None | Some("auto") => signals
+                .iter()
+                .map(|s| kcd_offset(s).saturating_add(s.size).div_ceil(8))
+                .max()
+                .unwrap_or_default(),
+            Some(length) => length
+                .parse()
+                .map_err(|_| anyhow!("invalid length `{length}`"))?,
+        };
+
+        Ok(FileMessage {
+            id,
+            name: attribute(message, "name")?.to_string(),
+            size,
+            transmitter,
+            signals,
+        })
+    }
+
+    fn signal(
+        &self,
+        file: &mut DbcFile,
+        message_id: MessageId,
+        signal: Node,
+        multiplexer: MultiplexIndicator,
+    ) -> Result<FileSignal> {
+        let name = attribute(signal, "name")?.to_string();
+        let offset = parse_u64(attribute(signal, "offset")?)?;
+        let size = signal.attribute("length").map_or(Ok(1), parse_u64)?;
+        // Big endian offsets count bits from the most significant bit of each byte
+        let (byte_order, start_bit) = match signal.attribute("endianess") {
+            None | Some("little") => (ByteOrder::LittleEndian, offset),
+            Some("big") => (ByteOrder::BigEndian, offset ^ 7),
+            Some(other) => bail!("unknown endianess `{other}` of signal `{name}`"),
+        };
+
+        let value = elements(signal, "Value").next();
+        let value_attribute = |attr: &str| value.and_then(|v| v.attribute(attr));
+        let number = |attr: &str, default: f64| {
+            value_attribute(attr).map_or(Ok(default), |v| {
+                v.trim()
+                    .parse()
+                    .map_err(|_| anyhow!("invalid {attr} `{v}` of signal `{name}`"))
+            })
+        };
+        let (value_type, extended_value_type) = match value_attribute("type") {
+            None | Some("unsigned") => (ValueType::Unsigned, None),
+            Some("signed") => (ValueType::Signed, None),
+            Some("single") => (
+                ValueType::Signed,
+                Some(SignalExtendedValueType::IEEEfloat32Bit),
+            ),
+            Some("double") => (
+                ValueType::Signed,
+                Some(SignalExtendedValueType::IEEEdouble64bit),
+            ),
+            Some(other) => bail!("unknown value type `{other}` of signal `{name}`"),
+        };
+
+        let receivers = elements(signal, "Consumer")
+            .flat_map(|consumer| elements(consumer, "NodeRef"))
+            .map(|node_ref| self.node_ref(node_ref))
+            .collect::<Result<_>>()?;
+
+        let labels = elements(signal, "LabelSet")
+            .flat_map(|set| elements(set, "Label"))
+            .map(|label| {
+                let value = attribute(label, "value")?;
+                let value = parse_u64(value)? as f64;
+                Ok((value, attribute(label, "name")?.to_string()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if !labels.is_empty() {
+            file.value_descriptions.push(FileValueDescription::Signal {
+                message_id,
+                signal_name: name.clone(),
+                values: labels,
+            });
+        }
+        if let Some(notes) = notes(signal) {
+            file.comments.push(Comment::Signal {
+                message_id,
+                signal_name: name.clone(),
+                comment: notes,
+            });
+        }
+
+        Ok(FileSignal {
+            multiplexer,
+            start_bit,
+            size,
+            byte_order,
+            value_type,
+            factor: number("slope", 1.0)?,
+            offset: number("intercept", 0.0)?,
+            min: number("min", 0.0)?,
+            max: number("max", 1.0)?,
+            unit: value_attribute("unit").unwrap_or_default().to_string(),
+            receivers,
+            extended_value_type,
+            name,
+        })
+    }
+
+    fn node_ref(&self, node_ref: Node) -> Result<String> {
+        let id = attribute(node_ref, "id")?;
+        self.nodes
+            .get(id)
+            .cloned()
+            .ok_or_else(|| anyhow!("unknown node ID `{id}`"))
+    }
+}
+
+/// The KCD offset of a signal, which is the start bit with big endian bits numbered from the
+/// most significant bit of each byte
+fn kcd_offset(signal: &FileSignal) -> u64 {
+    match signal.byte_order {
+        ByteOrder::LittleEndian => signal.start_bit,
+        ByteOrder::BigEndian => signal.start_bit ^ 7,
+    }
+}
+
+fn notes(node: Node) -> Option<String> {
+    let text: String = elements(node, "Notes")
+        .flat_map(|notes| notes.text())
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.replace('"', "'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::generate;
+    use crate::InputFormat;
+
+    #[test]
+    fn kcd_matches_dbc() {
+        // KCD has no named value tables, everything else in example.dbc is mirrored in example.kcd
+        let dbc = include_str!("../testing/dbc-examples/example.dbc")
+            .replace("VAL_TABLE_ OnOff 1 \"On\" 0 \"Off\" ;", "");
+        let kcd = include_bytes!("../testing/dbc-examples/example.kcd");
+        assert_eq!(
+            generate(dbc.as_bytes(), InputFormat::Dbc, None),
+            generate(kcd, InputFormat::Kcd, None)
+        );
+    }
+}
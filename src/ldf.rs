@@ -0,0 +1,655 @@
+//! Reading LIN description files (LDF).
+//!
+//! Unconditional and diagnostic frames become messages with their frame ID as standard CAN ID,
+//! published by the frame's publisher and received by the signals' subscribers. LIN signals are
+//! little endian at their frame offset. Signal encodings map to factor, offset, range and unit
+//! (from the first `physical_value`) and value descriptions (from `logical_value`s).
+//!
+//! Frame IDs, checksum models and schedule tables don't fit into a DBC file and are returned
+//! separately as [LinDescription]. Schedule slots of sporadic and event triggered frames list
+//! the unconditional frames they may carry.
+
+use crate::dbc_file::{raw_range, DbcFile, FileMessage, FileSignal, FileValueDescription};
+use crate::parse::{parse_f64, parse_u64};
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use can_dbc::{ByteOrder, MessageId, MultiplexIndicator, Transmitter, ValueType};
+
+/// LIN specifics of an LDF file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct LinDescription {
+    /// Bus speed in bit/s
+    pub speed: Option<u32>,
+    pub frames: Vec<LinFrame>,
+    pub schedule_tables: Vec<LinScheduleTable>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LinFrame {
+    /// Frame name, which is also the message name
+    pub name: String,
+    pub id: u8,
+    pub checksum: LinChecksum,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LinChecksum {
+    Classic,
+    Enhanced,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LinScheduleTable {
+    pub name: String,
+    pub entries: Vec<LinScheduleEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LinScheduleEntry {
+    /// ID in the frame header, the first of `frame_ids` for sporadic frames
+    pub frame_id: u8,
+    /// Unconditional frames that may be sent in the slot, in priority order
+    pub frame_ids: Vec<u8>,
+    /// Delay in ms
+    pub delay: f64,
+}
+
+/// Frame ID of diagnostic master requests, also used by node configuration commands
+const MASTER_REQUEST_ID: u8 = 0x3c;
+const SLAVE_RESPONSE_ID: u8 = 0x3d;
+
+impl LinFrame {
+    /// The frame ID with its two parity bits
+    pub fn protected_id(&self) -> u8 {
+        let bit = |n: u8| self.id & n != 0;
+        let p0 = bit(0x01) ^ bit(0x02) ^ bit(0x04) ^ bit(0x10);
+        let p1 = !(bit(0x02) ^ bit(0x08) ^ bit(0x10) ^ bit(0x20));
+        self.id | if p0 { 0x40 } else { 0 } | if p1 { 0x80 } else { 0 }
+    }
+}
+
+/// Read the frames and schedule tables of an LDF file
+pub(crate) fn parse_ldf(content: &[u8]) -> Result<(DbcFile, LinDescription)> {
+    let text = std::str::from_utf8(content).context("LDF file is not valid UTF-8")?;
+    let tokens = tokenize(text)?;
+    let mut pos = 0;
+    let statements = statements(&tokens, &mut pos)?;
+    ensure!(pos == tokens.len(), "unexpected `}}` in LDF file");
+
+    let mut file = DbcFile::default();
+    let mut lin = LinDescription::default();
+    let mut protocol_version = String::new();
+    let mut master = None;
+    let mut signals = Vec::new();
+    let mut frames = Vec::new();
+    let mut encodings = Vec::new();
+    let mut representations = Vec::new();
+    let mut sporadic_frames = &[][..];
+    let mut event_triggered_frames = &[][..];
+    let mut schedule_tables = &[][..];
+
+    for statement in &statements {
+        let Some(Token::Word(keyword)) = statement.head.first() else {
+            continue;
+        };
+        let block = statement.block.as_deref().unwrap_or_default();
+        match keyword.as_str() {
+            "LIN_protocol_version" => {
+                protocol_version = string(value_after(&statement.head, '='))?;
+            }
+            "LIN_speed" => {
+                let value = value_after(&statement.head, '=');
+                let speed = parse_f64(word(value.first())?)?;
+                let scale = match value.get(1) {
+                    Some(Token::Word(unit)) if unit == "bps" => 1.0,
+                    _ => 1000.0,
+                };
+                lin.speed = Some((speed * scale).round() as u32);
+            }
+            "Nodes" => {
+                for node in block {
+                    let fields = fields(&node.head);
+                    let is_master =
+                        matches!(node.head.first(), Some(Token::Word(w)) if w == "Master");
+                    for (i, field) in fields.iter().enumerate() {
+                        // The master is followed by its time base and jitter
+                        if is_master && i > 0 {
+                            break;
+                        }
+                        let name = word(field.first())?.to_string();
+                        if is_master {
+                            master = Some(name.clone());
+                        }
+                        file.nodes.push(name);
+                    }
+                }
+            }
+            "Signals" | "Diagnostic_signals" => signals.extend(block),
+            "Frames" => frames.extend(block.iter().map(|f| (f, false))),
+            "Diagnostic_frames" => frames.extend(block.iter().map(|f| (f, true))),
+            "Signal_encoding_types" => encodings.extend(block),
+            "Signal_representation" => representations.extend(block),
+            "Sporadic_frames" => sporadic_frames = block,
+            "Event_triggered_frames" => event_triggered_frames = block,
+            "Schedule_tables" => schedule_tables = block,
+            _ => {}
+        }
+    }
+
+    let classic_only = protocol_version.starts_with('1');
+    let reader = Reader {
+        signals: &signals,
+        encodings: &encodings,
+        representations: &representations,
+    };
+    for (frame, diagnostic) in frames {
+        let name = word(frame.head.first())?.to_string();
+        let (msg, id) = reader
+            .frame(&mut file, frame, diagnostic, master.as_deref())
+            .with_context(|| format!("in LDF frame `{name}`"))?;
+        let checksum = if classic_only || diagnostic {
+            LinChecksum::Classic
+        } else {
+            LinChecksum::Enhanced
+        };
+        file.messages.push(msg);
+        lin.frames.push(LinFrame { name, id, checksum });
+    }
+
+    // Frame ID and unconditional frames of each frame that may be scheduled
+    let mut slots: Vec<(String, u8, Vec<u8>)> = lin
+        .frames
+        .iter()
+        .map(|frame| (frame.name.clone(), frame.id, vec![frame.id]))
+        .collect();
+    for frame in sporadic_frames {
+        let name = word(frame.head.first())?.to_string();
+        let frame_ids = frame_ids(&lin, &fields(&frame.head))
+            .with_context(|| format!("in LDF sporadic frame `{name}`"))?;
+        let first = *frame_ids
+            .first()
+            .ok_or_else(|| anyhow!("sporadic frame `{name}` without frames"))?;
+        slots.push((name, first, frame_ids));
+    }
+    for frame in event_triggered_frames {
+        let name = word(frame.head.first())?.to_string();
+        // LIN 2.2 puts the collision resolving schedule table before the ID
+        let mut fields = fields(&frame.head);
+        if fields
+            .first()
+            .is_some_and(|f| word(f.first()).and_then(parse_u64).is_err())
+        {
+            fields.remove(0);
+        }
+        let id = parse_u64(word(fields.first().and_then(|f| f.first()))?)?;
+        let id = u8::try_from(id)
+            .ok()
+            .filter(|id| *id < 0x40)
+            .ok_or_else(|| anyhow!("ID {id} of event triggered frame `{name}` out of range"))?;
+        let frame_ids = frame_ids(&lin, fields.get(1..).unwrap_or_default())
+            .with_context(|| format!("in LDF event triggered frame `{name}`"))?;
+        slots.push((name, id, frame_ids));
+    }
+
+    for table in schedule_tables {
+        let name = word(table.head.first())?.to_string();
+        let mut entries = Vec::new();
+        for entry in table.block.as_deref().unwrap_or_default() {
+            let slot = word(entry.head.first())?;
+            let delay = entry
+                .head
+                .iter()
+                .position(|t| matches!(t, Token::Word(w) if w == "delay"))
+                .and_then(|i| entry.head.get(i.saturating_add(1)))
+                .ok_or_else(|| anyhow!("slot `{slot}` of schedule table `{name}` without delay"))?;
+            let (frame_id, frame_ids) = match slots.iter().find(|(name, _, _)| name == slot) {
+                Some((_, frame_id, frame_ids)) => (*frame_id, frame_ids.clone()),
+                None => match slot {
+                    "SlaveResp" => (SLAVE_RESPONSE_ID, vec![SLAVE_RESPONSE_ID]),
+                    // Node configuration commands are master requests
+                    "MasterReq"
+                    | "AssignNAD"
+                    | "ConditionalChangeNAD"
+                    | "DataDump"
+                    | "SaveConfiguration"
+                    | "AssignFrameIdRange"
+                    | "FreeFormat"
+                    | "AssignFrameId"
+                    | "UnassignFrameId" => (MASTER_REQUEST_ID, vec![MASTER_REQUEST_ID]),
+                    _ => bail!("unknown frame `{slot}` in schedule table `{name}`"),
+                },
+            };
+            entries.push(LinScheduleEntry {
+                frame_id,
+                frame_ids,
+                delay: parse_f64(word(Some(delay))?)?,
+            });
+        }
+        lin.schedule_tables.push(LinScheduleTable { name, entries });
+    }
+
+    Ok((file, lin))
+}
+
+/// IDs of the unconditional frames named by `fields`
+fn frame_ids(lin: &LinDescription, fields: &[&[Token]]) -> Result<Vec<u8>> {
+    fields
+        .iter()
+        .map(|field| {
+            let name = word(field.first())?;
+            lin.frames
+                .iter()
+                .find(|frame| frame.name == name)
+                .map(|frame| frame.id)
+                .ok_or_else(|| anyhow!("unknown frame `{name}`"))
+        })
+        .collect()
+}
+
+struct Reader<'a> {
+    signals: &'a [&'a Statement],
+    encodings: &'a [&'a Statement],
+    representations: &'a [&'a Statement],
+}
+
+impl Reader<'_> {
+    /// `Name: id, publisher, length { signal, offset; … }` or `Name: id { … }` for diagnostic
+    /// frames
+    fn frame(
+        &self,
+        file: &mut DbcFile,
+        frame: &Statement,
+        diagnostic: bool,
+        master: Option<&str>,
+    ) -> Result<(FileMessage, u8)> {
+        let fields = fields(&frame.head);
+        let id = parse_u64(word(fields.first().and_then(|f| f.first()))?)?;
+        let id = u8::try_from(id)
+            .ok()
+            .filter(|id| *id < 0x40)
+            .ok_or_else(|| anyhow!("frame ID {id} out of range"))?;
+        let message_id = MessageId::Standard(id.into());
+
+        let (transmitter, size) = if diagnostic {
+            let transmitter = match (id, master) {
+                (MASTER_REQUEST_ID, Some(master)) => Transmitter::NodeName(master.to_string()),
+                _ => Transmitter::VectorXXX,
+            };
+            (transmitter, 8)
+        } else {
+            let publisher = word(fields.get(1).and_then(|f| f.first()))?;
+            let size = parse_u64(word(fields.get(2).and_then(|f| f.first()))?)?;
+            (Transmitter::NodeName(publisher.to_string()), size)
+        };
+
+        let mut signals = Vec::new();
+        for mapping in frame.block.as_deref().unwrap_or_default() {
+            let fields = fields_of(&mapping.head);
+            let name = word(fields.first().and_then(|f| f.first()))?;
+            let offset = parse_u64(word(fields.get(1).and_then(|f| f.first()))?)?;
+            let signal = self
+                .signal(file, message_id, name, offset)
+                .with_context(|| format!("in LDF signal `{name}`"))?;
+            signals.push(signal);
+        }
+
+        let name = word(frame.head.first())?.to_string();
+        let msg = FileMessage {
+            id: message_id,
+            name,
+            size,
+            transmitter,
+            signals,
+        };
+        Ok((msg, id))
+    }
+
+    fn signal(
+        &self,
+        file: &mut DbcFile,
+        message_id: MessageId,
+        name: &str,
+        start_bit: u64,
+    ) -> Result<FileSignal> {
+        // `Name: size, init_value, publisher, subscriber…;`
+        let definition = self
+            .signals
+            .iter()
+            .find(|s| matches!(s.head.first(), Some(Token::Word(w)) if w == name))
+            .ok_or_else(|| anyhow!("unknown signal"))?;
+        let definition = fields(&definition.head);
+        let size = parse_u64(word(definition.first().and_then(|f| f.first()))?)?;
+        let receivers = definition
+            .iter()
+            .skip(3)
+            .map(|f| word(f.first()).map(str::to_string))
+            .collect::<Result<_>>()?;
+
+        let encoding = self
+            .representations
+            .iter()
+            .find(|r| {
+                fields(&r.head)
+                    .iter()
+                    .any(|f| matches!(f.first(), Some(Token::Word(w)) if w == name))
+            })
+            .map(|r| word(r.head.first()))
+            .transpose()?
+            .map(|encoding| {
+                self.encodings
+                    .iter()
+                    .find(|e| matches!(e.head.first(), Some(Token::Word(w)) if w == encoding))
+                    .ok_or_else(|| anyhow!("unknown signal encoding `{encoding}`"))
+            })
+            .transpose()?;
+
+        let (mut factor, mut offset) = (1.0, 0.0);
+        let (mut min, mut max) = raw_range(ValueType::Unsigned, size, factor, offset);
+        let mut unit = String::new();
+        let mut labels = Vec::new();
+        let mut physical = false;
+        for value in encoding
+            .and_then(|e| e.block.as_deref())
+            .unwrap_or_default()
+        {
+            let fields = fields_of(&value.head);
+            let number = |i: usize| parse_f64(word(fields.get(i).and_then(|f| f.first()))?);
+            match fields.first().and_then(|f| f.first()) {
+                // Only the first physical range can be represented
+                Some(Token::Word(kind)) if kind == "physical_value" && !physical => {
+                    physical = true;
+                    factor = number(3)?;
+                    offset = number(4)?;
+                    let (a, b) = (number(1)? * factor + offset, number(2)? * factor + offset);
+                    (min, max) = (a.min(b), a.max(b));
+                    if let Some(text) = fields.get(5) {
+                        unit = string(text)?;
+                    }
+                }
+                Some(Token::Word(kind)) if kind == "logical_value" => {
+                    let label = fields
+                        .get(2)
+                        .map(|text| string(text))
+                        .transpose()?
+                        .unwrap_or_default();
+                    labels.push((number(1)?, label));
+                }
+                _ => {}
+            }
+        }
+        // Logical values without text can't name anything
+        labels.retain(|(_, label)| !label.is_empty());
+        if !labels.is_empty() {
+            file.value_descriptions.push(FileValueDescription::Signal {
+                message_id,
+                signal_name: name.to_string(),
+                values: labels,
+            });
+        }
+
+        Ok(FileSignal {
+            name: name.to_string(),
+            multiplexer: MultiplexIndicator::Plain,
+            start_bit,
+            size,
+            byte_order: ByteOrder::LittleEndian,
+            value_type: ValueType::Unsigned,
+            factor,
+            offset,
+            min,
+            max,
+            unit,
+            receivers,
+            extended_value_type: None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// Names, keywords and numbers
+    Word(String),
+    Str(String),
+    Punct(char),
+}
+
+/// A statement ending in `;`, or a header followed by a `{ … }` block of statements
+struct Statement {
+    head: Vec<Token>,
+    block: Option<Vec<Statement>>,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            '"' => {
+                let mut s = String::new();
+                for c in chars.by_ref() {
+                    if c == '"' {
+                        break;
+                    }
+                    s.push(c);
+                }
+                tokens.push(Token::Str(s));
+            }
+            '{' | '}' | ';' | ',' | ':' | '=' => tokens.push(Token::Punct(c)),
+            c if is_word_char(c) => {
+                let mut s = c.to_string();
+                while let Some(&c) = chars.peek().filter(|c| is_word_char(**c)) {
+                    s.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(s));
+            }
+            c => bail!("unexpected `{c}` in LDF file"),
+        }
+    }
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '+')
+}
+
+/// Statements up to the `}` closing the current block, or the end
+fn statements(tokens: &[Token], pos: &mut usize) -> Result<Vec<Statement>> {
+    let mut parsed = Vec::new();
+    let mut head = Vec::new();
+    while let Some(token) = tokens.get(*pos) {
+        match token {
+            Token::Punct(';') => {
+                *pos = pos.saturating_add(1);
+                parsed.push(Statement {
+                    head: std::mem::take(&mut head),
+                    block: None,
+                });
+            }
+            Token::Punct('}') => break,
+            Token::Punct('{') => {
+                let end = matching_brace(tokens, *pos)?;
+                let inner = &tokens[pos.saturating_add(1)..end];
+                // Braces also group values, like `{0, 0}` or `AssignNAD { Seat }`
+                let is_block = inner.is_empty()
+                    || inner.contains(&Token::Punct(';'))
+                    || inner.last() == Some(&Token::Punct('}'));
+                if is_block {
+                    *pos = pos.saturating_add(1);
+                    let block = statements(tokens, pos)?;
+                    *pos = pos.saturating_add(1);
+                    parsed.push(Statement {
+                        head: std::mem::take(&mut head),
+                        block: Some(block),
+                    });
+                } else {
+                    head.extend_from_slice(&tokens[*pos..=end]);
+                    *pos = end.saturating_add(1);
+                }
+            }
+            token => {
+                head.push(token.clone());
+                *pos = pos.saturating_add(1);
+            }
+        }
+    }
+    ensure!(head.is_empty(), "missing `;` in LDF file");
+    Ok(parsed)
+}
+
+fn matching_brace(tokens: &[Token], open: usize) -> Result<usize> {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        match token {
+            Token::Punct('{') => depth = depth.saturating_add(1),
+            Token::Punct('}') => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    bail!("missing `}}` in LDF file")
+}
+
+/// Comma separated fields after the `:` of `Name: a, b, c`
+fn fields(head: &[Token]) -> Vec<&[Token]> {
+    fields_of(value_after(head, ':'))
+}
+
+/// Comma separated fields; commas in braces don't separate
+fn fields_of(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut fields = Vec::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('{') => depth = depth.saturating_add(1),
+            Token::Punct('}') => depth = depth.saturating_sub(1),
+            Token::Punct(',') if depth == 0 => {
+                fields.push(&tokens[start..i]);
+                start = i.saturating_add(1);
+            }
+            _ => {}
+        }
+    }
+    if start < tokens.len() {
+        fields.push(&tokens[start..]);
+    }
+    fields
+}
+
+/// Tokens after the first `separator`
+fn value_after(head: &[Token], separator: char) -> &[Token] {
+    match head.iter().position(|t| *t == Token::Punct(separator)) {
+        Some(i) => &head[i.saturating_add(1)..],
+        None => &[],
+    }
+}
+
+fn word(token: Option<&Token>) -> Result<&str> {
+    match token {
+        Some(Token::Word(word)) => Ok(word),
+        Some(other) => bail!("expected a name or number, found {other:?}"),
+        None => bail!("expected a name or number"),
+    }
+}
+
+fn string(tokens: &[Token]) -> Result<String> {
+    match tokens.first() {
+        Some(Token::Str(s)) => Ok(s.replace('"', "'")),
+        other => bail!("expected a string, found {other:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{generate, generate_code};
+    use crate::{codegen, Config, InputFormat};
+
+    #[test]
+    fn ldf_matches_dbc() {
+        let dbc = include_bytes!("../testing/dbc-examples/example-ldf.dbc");
+        let ldf = generate(
+            include_bytes!("../testing/dbc-examples/example.ldf"),
+            InputFormat::Ldf,
+            None,
+        );
+        // The LIN constants follow the messages
+        let start = ldf.find("/// Checksum model of a LIN frame").unwrap();
+        let end = ldf
+            .find("\n/// This is just to make testing easier")
+            .unwrap();
+        let (messages, lin) = (ldf[..start].to_string() + &ldf[end..], &ldf[start..end]);
+        assert_eq!(generate(dbc, InputFormat::Dbc, None), messages);
+
+        assert!(lin.contains("pub const LIN_SPEED: u32 = 19200;"));
+        assert!(lin.contains("pub const SCHEDULE_NORMAL: &[LinScheduleEntry] = &["));
+        assert!(lin.contains(
+            "LinScheduleEntry { frame_id: 0x21, frame_ids: &[0x21], delay: core::time::Duration::from_micros(15000) },"
+        ));
+        assert!(lin.contains(
+            "LinScheduleEntry { frame_id: 0x10, frame_ids: &[0x10], delay: core::time::Duration::from_micros(20000) },"
+        ));
+        assert!(lin.contains(
+            "LinScheduleEntry { frame_id: 0x3a, frame_ids: &[0x21], delay: core::time::Duration::from_micros(20000) },"
+        ));
+        assert!(lin.contains("impl MirrorStatus {"));
+        assert!(lin.contains("pub const LIN_PROTECTED_ID: u8 = 0x61;"));
+        assert!(lin.contains("pub const LIN_CHECKSUM: LinChecksum = LinChecksum::Classic;"));
+    }
+
+    #[test]
+    fn ldf_patch() {
+        let config = Config::builder()
+            .dbc_name("example.ldf")
+            .dbc_content(include_bytes!("../testing/dbc-examples/example.ldf"))
+            .input_format(InputFormat::Ldf)
+            .dbc_patches([&include_bytes!("../testing/dbc-examples/example-patch.ldf")[..]])
+            .build();
+        let out = generate_code(config);
+        // Schedule tables of both files, the identical frame is no conflict
+        assert!(out.contains("pub const SCHEDULE_NORMAL: &[LinScheduleEntry] = &["));
+        assert!(out.contains("pub const SCHEDULE_MIRROR_ONLY: &[LinScheduleEntry] = &["));
+        assert!(out.contains("impl MirrorStatus {"));
+    }
+
+    #[test]
+    fn ldf_unknown_schedule_frame() {
+        let ldf = br#"
+            LIN_description_file;
+            LIN_protocol_version = "2.1";
+            Frames { }
+            Schedule_tables { Normal { Missing delay 10 ms; } }
+        "#;
+        let config = Config::builder()
+            .dbc_name("example")
+            .dbc_content(ldf)
+            .input_format(InputFormat::Ldf)
+            .build();
+        let err = codegen(config, &mut Vec::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown frame `Missing` in schedule table `Normal`"
+        );
+    }
+}
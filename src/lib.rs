@@ -27,20 +27,43 @@
 #![deny(clippy::arithmetic_side_effects)]
 
 use anyhow::{anyhow, ensure, Context, Result};
-use can_dbc::{Message, MultiplexIndicator, Signal, ValDescription, ValueDescription, DBC};
-use heck::{ToPascalCase, ToSnakeCase};
+use heck::ToSnakeCase;
+use ir::{
+    ByteOrder, Database, Message, MessageId, Multiplexer, MuxTree, Signal, ValueTable, ValueType,
+};
 use pad::PadAdapter;
 use std::cmp::{max, min};
 use std::{
     collections::{BTreeMap, BTreeSet},
     fmt::Display,
+    fs::File,
     io::{self, BufWriter, Write},
+    path::Path,
 };
 use typed_builder::TypedBuilder;
 
+mod arxml;
+mod c;
+mod dbc_file;
+mod diff;
+mod doc;
 mod includes;
+pub mod ir;
+mod kcd;
 mod keywords;
+mod ldf;
+mod merge;
+mod naming;
 mod pad;
+mod parse;
+mod sym;
+
+use dbc_file::DbcFile;
+pub use dbc_file::{normalize_dbc, write_dbc};
+pub use diff::{diff_dbc, Change, ChangeKind, DbcDiff, Impact};
+pub use doc::DocFormat;
+pub use merge::{merge_dbc, MergeConflicts, MergedDbc};
+pub use naming::{DefaultNamingStrategy, NameCollisions, NamingStrategy, Renames};
 
 /// Code generator configuration. See module-level docs for an example.
 #[derive(TypedBuilder)]
@@ -49,9 +72,34 @@ pub struct Config<'a> {
     /// Name of the dbc-file. Used for generated docs only.
     pub dbc_name: &'a str,
 
-    /// Raw bytes of a dbc-file.
+    /// Raw bytes of a dbc-file, or of a file in another `input_format`.
     pub dbc_content: &'a [u8],
 
+    /// Optional: File format of `dbc_content`. Default: `Dbc`.
+    #[builder(default)]
+    pub input_format: InputFormat,
+
+    /// Optional: Name of the bus to read from input formats that can describe several, i.e. a
+    /// KCD bus or an ARXML CAN cluster. Default: the first bus.
+    #[builder(default)]
+    pub bus: Option<&'a str>,
+
+    /// Optional: Raw bytes of further files in `input_format` merged on top of `dbc_content` in
+    /// order, adding or overriding messages, signals, value descriptions, attributes, LIN frames
+    /// and schedule tables etc. See [merge_dbc]. Default: none.
+    #[builder(default, setter(into))]
+    pub dbc_patches: Vec<&'a [u8]>,
+
+    /// Optional: How definitions that differ between `dbc_content` and `dbc_patches` are
+    /// resolved. Default: `Error`.
+    #[builder(default)]
+    pub merge_conflicts: MergeConflicts,
+
+    /// Optional: Called with every conflict resolved by `FirstWins` or `LastWins`, like
+    /// [MergedDbc::conflicts]. Default: ignore them.
+    #[builder(default = &|_| {})]
+    pub on_merge_conflict: &'a dyn Fn(&str),
+
     /// Optional: Print debug info to stdout while generating code. Default: `false`.
     #[builder(default)]
     pub debug_prints: bool,
@@ -72,6 +120,28 @@ pub struct Config<'a> {
     #[builder(default)]
     pub impl_serde: FeatureConfig<'a>,
 
+    /// Optional: How messages are represented with `impl_serde`. Default: `Raw`.
+    #[builder(default)]
+    pub serde_representation: SerdeRepresentation,
+
+    /// Optional: `derive(PartialEq, Eq)` for generated types. Default: `Never`.
+    ///
+    /// Value table enums of float signals only get `PartialEq`.
+    #[builder(default)]
+    pub impl_eq: FeatureConfig<'a>,
+
+    /// Optional: `derive(Hash)` for generated types. Default: `Never`.
+    ///
+    /// Value table enums of float signals are skipped.
+    #[builder(default)]
+    pub impl_hash: FeatureConfig<'a>,
+
+    /// Optional: `derive(PartialOrd)` for generated types. Default: `Never`.
+    ///
+    /// Also derives `PartialEq`, which `PartialOrd` requires, if `impl_eq` doesn't.
+    #[builder(default)]
+    pub impl_partial_ord: FeatureConfig<'a>,
+
     /// Optional: `impl Error` for generated error type. Default: `Never`.
     ///
     /// Note: this feature depends on `std`.
@@ -86,6 +156,103 @@ pub struct Config<'a> {
     #[builder(default = FeatureConfig::Always)]
     pub check_ranges: FeatureConfig<'a>,
 
+    /// Optional: Generate `const fn` constructors, getters and setters for integer and boolean
+    /// signals. Default: `Never`.
+    ///
+    /// These use plain bit arithmetic instead of `bitvec`, so messages can be built in `const`
+    /// and `static` items, e.g. `const HEARTBEAT: Heartbeat = Heartbeat::new_const(1);`.
+    #[builder(default)]
+    pub impl_const_fns: FeatureConfig<'a>,
+
+    /// Optional: Generate zero-copy views `FooRef<'a>` and `FooMut<'a>` over borrowed payload
+    /// buffers, with the same getters (and setters, for `FooMut`) as the owned message.
+    /// Default: `Never`.
+    #[builder(default)]
+    pub impl_views: FeatureConfig<'a>,
+
+    /// Optional: `derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::KnownLayout,
+    /// zerocopy::Immutable)` for message structs. Default: `Never`.
+    ///
+    /// Note: this requires the `zerocopy` crate (version 0.8, feature `derive`).
+    #[builder(default)]
+    pub impl_zerocopy: FeatureConfig<'a>,
+
+    /// Optional: `derive(bytemuck::Pod, bytemuck::Zeroable)` for message structs.
+    /// Default: `Never`.
+    ///
+    /// Note: this requires the `bytemuck` crate (version 1, feature `derive`).
+    #[builder(default)]
+    pub impl_bytemuck: FeatureConfig<'a>,
+
+    /// Optional: Drop the `_Other` fallback variant from value table enums whose table covers
+    /// every raw value of the signal. Converting raw values into such enums is then a fallible
+    /// `TryFrom` instead of `From`. Default: `false`.
+    #[builder(default)]
+    pub strict_enums: bool,
+
+    /// Optional: Share one enum between signals with identical value descriptions, named after
+    /// the first of them. Default: `false`.
+    ///
+    /// Signals matching a named `VAL_TABLE_` always share that table's enum.
+    #[builder(default)]
+    pub dedupe_value_tables: bool,
+
+    /// Optional: How DBC names are turned into Rust identifiers. Default: [DefaultNamingStrategy].
+    #[builder(default = &DefaultNamingStrategy)]
+    pub naming_strategy: &'a dyn NamingStrategy,
+
+    /// Optional: Explicit identifiers for some messages, signals and value labels, overriding
+    /// the `naming_strategy`. Default: none.
+    #[builder(default)]
+    pub renames: Renames<'a>,
+
+    /// Optional: What to do when messages, signals of one message, labels of one value table
+    /// or multiplexor values end up with the same identifier. Default: `Error`.
+    #[builder(default)]
+    pub name_collisions: NameCollisions,
+
+    /// Optional: How [codegen_to_dir] splits messages into files. Default: `Message`.
+    #[builder(default)]
+    pub module_split: ModuleSplit,
+
+    /// Message name → message struct name, resolved against collisions by [codegen]
+    #[builder(default, setter(skip))]
+    type_names: BTreeMap<String, String>,
+
+    /// Message name → signal field names in DBC order, resolved against collisions by [codegen]
+    #[builder(default, setter(skip))]
+    field_names: BTreeMap<String, Vec<String>>,
+
+    /// Names of the other generated types, resolved against collisions by [codegen]
+    #[builder(default, setter(skip))]
+    type_idents: BTreeMap<GeneratedType, String>,
+
+    /// Message ID → signal name → value table enum, resolved by [codegen]
+    #[builder(default, setter(skip))]
+    value_enums: BTreeMap<MessageId, BTreeMap<String, ValueEnumSource>>,
+
+    /// Frame IDs, checksums and schedule tables of an LDF input, read by [codegen]
+    #[builder(default, setter(skip))]
+    lin: Option<ldf::LinDescription>,
+
+    /// Optional: Extra attributes for message structs and their multiplexed sub-structs,
+    /// e.g. `derive(schemars::JsonSchema)`. Default: none.
+    #[builder(default, setter(into))]
+    pub message_attributes: Vec<ExtraAttribute<'a>>,
+
+    /// Optional: Extra attributes for value table enums and multiplexor index enums.
+    /// Default: none.
+    #[builder(default, setter(into))]
+    pub enum_attributes: Vec<ExtraAttribute<'a>>,
+
+    /// Optional: Extra attributes for the `Messages` enum. Default: none.
+    #[builder(default, setter(into))]
+    pub messages_enum_attributes: Vec<ExtraAttribute<'a>>,
+
+    /// Optional: Extra attributes for the `CanError` enum. Default: none.
+    #[builder(default, setter(into))]
+    pub error_attributes: Vec<ExtraAttribute<'a>>,
+
     /// Optional: Allow dead code in the generated module. Default: `false`.
     #[builder(default)]
     pub allow_dead_code: bool,
@@ -94,7 +261,7 @@ pub struct Config<'a> {
 /// Configuration for including features in the codegenerator.
 ///
 /// e.g. [Debug] impls for generated types.
-#[derive(Default)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum FeatureConfig<'a> {
     /// Generate code for this feature.
     Always,
@@ -107,63 +274,168 @@ pub enum FeatureConfig<'a> {
     Never,
 }
 
-/// Write Rust structs matching DBC input description to `out` buffer
-pub fn codegen(config: Config<'_>, out: impl Write) -> Result<()> {
-    let dbc = can_dbc::DBC::from_slice(config.dbc_content).map_err(|e| {
-        let msg = "Could not parse dbc file";
-        if config.debug_prints {
-            anyhow!("{}: {:#?}", msg, e)
-        } else {
-            anyhow!("{}", msg)
+/// Serde representation of messages, see [Config::impl_serde].
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum SerdeRepresentation {
+    /// Serialize the raw payload bytes using `serde_bytes`.
+    #[default]
+    Raw,
+
+    /// Serialize a map of physical signal values, keyed by the DBC signal names.
+    ///
+    /// Value table enums are serialized as their DBC labels and multiplexed signals as
+    /// variants tagged with the multiplexor value or its label, e.g. `{"M0": {...}}`.
+    /// Deserializing goes through the generated setters, so values are range checked again.
+    Signals,
+}
+
+/// File format of [Config::dbc_content].
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum InputFormat {
+    /// Vector CANdb++ DBC
+    #[default]
+    Dbc,
+
+    /// Kayak KCD (XML). Label groups (value ranges) are ignored.
+    Kcd,
+
+    /// CAN clusters of an AUTOSAR system description (ARXML)
+    Arxml,
+
+    /// PCAN symbol file (SYM)
+    Sym,
+
+    /// LIN description file (LDF). Frames become messages with the frame ID as standard ID,
+    /// frame IDs, checksum models and schedule tables are generated as constants.
+    Ldf,
+}
+
+/// Files written by [codegen_to_dir], next to its `mod.rs`.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModuleSplit {
+    /// One file per message, e.g. `bar.rs`.
+    #[default]
+    Message,
+
+    /// One file per transmitting node, e.g. `ipsum.rs`. Messages without a transmitter go to
+    /// `no_transmitter.rs`.
+    Transmitter,
+}
+
+/// A user-supplied attribute for generated items.
+///
+/// ```
+/// use dbc_codegen::ExtraAttribute;
+///
+/// // #[derive(schemars::JsonSchema)]
+/// let schema = ExtraAttribute::always("derive(schemars::JsonSchema)");
+/// // #[cfg_attr(feature = "postcard", derive(postcard::experimental::max_size::MaxSize))]
+/// let max_size = ExtraAttribute::gated(
+///     "derive(postcard::experimental::max_size::MaxSize)",
+///     "postcard",
+/// );
+/// ```
+pub struct ExtraAttribute<'a> {
+    /// The attribute, without the surrounding `#[…]`
+    pub attr: &'a str,
+
+    /// Whether to emit the attribute, and behind which feature
+    pub feature: FeatureConfig<'a>,
+}
+
+impl<'a> ExtraAttribute<'a> {
+    /// Attribute that is always emitted
+    pub fn always(attr: &'a str) -> Self {
+        ExtraAttribute {
+            attr,
+            feature: FeatureConfig::Always,
         }
-    })?;
-    if config.debug_prints {
-        eprintln!("{:#?}", dbc);
     }
-    let mut w = BufWriter::new(out);
 
-    writeln!(&mut w, "// Generated code!")?;
-    writeln!(
-        &mut w,
-        "#![allow(unused_comparisons, unreachable_patterns, unused_imports)]"
-    )?;
-    if config.allow_dead_code {
-        writeln!(&mut w, "#![allow(dead_code)]")?;
+    /// Attribute that is emitted behind `#[cfg_attr(feature = ...)]`
+    pub fn gated(attr: &'a str, feature: &'a str) -> Self {
+        ExtraAttribute {
+            attr,
+            feature: FeatureConfig::Gated(feature),
+        }
     }
-    writeln!(&mut w, "#![allow(clippy::let_and_return, clippy::eq_op)]")?;
-    writeln!(
-        &mut w,
-        "#![allow(clippy::useless_conversion, clippy::unnecessary_cast)]"
-    )?;
-    writeln!(
-        &mut w,
-        "#![allow(clippy::excessive_precision, clippy::manual_range_contains, clippy::absurd_extreme_comparisons, clippy::too_many_arguments)]"
-    )?;
-    writeln!(&mut w, "#![deny(clippy::arithmetic_side_effects)]")?;
-    writeln!(&mut w)?;
-    writeln!(
-        &mut w,
-        "//! Message definitions from file `{:?}`",
-        config.dbc_name
-    )?;
-    writeln!(&mut w, "//!")?;
-    writeln!(&mut w, "//! - Version: `{:?}`", dbc.version())?;
-    writeln!(&mut w)?;
-    writeln!(&mut w, "use core::ops::BitOr;")?;
-    writeln!(&mut w, "use bitvec::prelude::*;")?;
-    writeln!(&mut w, "use embedded_can::{{Id, StandardId, ExtendedId}};")?;
+}
 
-    config.impl_arbitrary.fmt_cfg(&mut w, |w| {
-        writeln!(w, "use arbitrary::{{Arbitrary, Unstructured}};")
-    })?;
+/// The type generated signal accessors are rendered for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Receiver {
+    /// The owned message struct (or a multiplexed sub-struct), storing `raw: [u8; N]`
+    Owned,
+    /// A shared view, storing `raw: &[u8; N]`
+    Ref,
+    /// An exclusive view, storing `raw: &mut [u8; N]`
+    Mut,
+}
 
-    config.impl_serde.fmt_cfg(&mut w, |w| {
-        writeln!(w, "use serde::{{Serialize, Deserialize}};")
-    })?;
+/// Types generated besides the message structs, by the DBC names they are derived from
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum GeneratedType {
+    /// Value table enum of a message's signal
+    SignalEnum(String, String),
+    /// Enum of a named value table
+    ValueTable(String),
+    /// Multiplexor index enum of a message
+    MultiplexEnum(String),
+    /// Struct holding the signals of one multiplexor value of a message
+    MultiplexedStruct(String, u64),
+    /// Shared view of a message
+    Ref(String),
+    /// Exclusive view of a message
+    Mut(String),
+}
 
-    writeln!(&mut w)?;
+impl Receiver {
+    /// Expression for the payload array, by value
+    fn raw(self) -> &'static str {
+        match self {
+            Receiver::Owned => "self.raw",
+            Receiver::Ref | Receiver::Mut => "*self.raw",
+        }
+    }
+
+    fn has_setters(self) -> bool {
+        self != Receiver::Ref
+    }
+}
+
+/// Write Rust structs matching DBC input description to `out` buffer
+pub fn codegen(mut config: Config<'_>, out: impl Write) -> Result<()> {
+    let (dbc, lin) = parse_input(&config)?;
+    config.lin = lin;
+    render_code(config, &dbc, out)
+}
+
+/// Write Rust structs for a [Database](ir::Database) to `out` buffer, like [codegen].
+///
+/// The database is checked with [Database::validate](ir::Database::validate) first.
+/// `config.dbc_content` is ignored, `dbc_patches` are read in `input_format` and merged on top
+/// of the database.
+pub fn codegen_from_ir(
+    mut config: Config<'_>,
+    database: &ir::Database,
+    out: impl Write,
+) -> Result<()> {
+    database.validate()?;
+    if config.dbc_patches.is_empty() {
+        return render_code(config, database, out);
+    }
+    let (database, lin) = apply_patches(&config, DbcFile::from(database), None)?;
+    config.lin = lin;
+    render_code(config, &database, out)
+}
+
+fn render_code(mut config: Config<'_>, dbc: &Database, out: impl Write) -> Result<()> {
+    resolve_names(&mut config, dbc)?;
+    let mut w = BufWriter::new(out);
 
-    render_dbc(&mut w, &config, &dbc).context("could not generate Rust code")?;
+    render_header(&mut w, &config, dbc)?;
+    render_dbc(&mut w, &config, dbc).context("could not generate Rust code")?;
 
     writeln!(&mut w)?;
     writeln!(&mut w, "/// This is just to make testing easier")?;
@@ -171,871 +443,2892 @@ pub fn codegen(config: Config<'_>, out: impl Write) -> Result<()> {
     writeln!(&mut w, "fn main() {{}}")?;
     writeln!(&mut w)?;
     render_error(&mut w, &config)?;
-    render_arbitrary_helpers(&mut w, &config)?;
+    render_arbitrary_helpers(&mut w, &config, "")?;
     writeln!(&mut w)?;
 
     Ok(())
 }
 
-fn render_dbc(mut w: impl Write, config: &Config<'_>, dbc: &DBC) -> Result<()> {
-    render_root_enum(&mut w, dbc, config)?;
+/// Write C structs and functions for the messages to `<name>.h` and `<name>.c` in `dir`, where
+/// `name` is the snake case [Config::dbc_name] without extension.
+///
+/// Like cantools, each message gets a struct of raw signal values with `<name>_<message>_pack`
+/// and `_unpack` functions, and each signal `_encode`, `_decode` and `_is_in_range` functions.
+/// Identifiers follow the [NamingStrategy] and physical values have the C equivalent of the
+/// Rust type used by [codegen], e.g. `float` for `f32`. Signals needing 128-bit integers are
+/// rejected, as C has no standard type for them. The directory must exist.
+pub fn codegen_c(mut config: Config<'_>, dir: impl AsRef<Path>) -> Result<()> {
+    let (dbc, _) = parse_input(&config)?;
+    resolve_names(&mut config, &dbc)?;
+    c::check_types(&dbc)?;
+
+    let prefix = c::prefix(&config);
+    let dir = dir.as_ref();
+    let header_path = dir.join(format!("{prefix}.h"));
+    let mut header = BufWriter::new(
+        File::create(&header_path)
+            .with_context(|| format!("could not create {}", header_path.display()))?,
+    );
+    c::render_header(&mut header, &config, &dbc).context("could not generate C header")?;
+    header.flush()?;
 
-    for msg in get_relevant_messages(dbc) {
-        render_message(&mut w, config, msg, dbc)
-            .with_context(|| format!("write message `{}`", msg.message_name()))?;
-        writeln!(w)?;
+    let source_path = dir.join(format!("{prefix}.c"));
+    let mut source = BufWriter::new(
+        File::create(&source_path)
+            .with_context(|| format!("could not create {}", source_path.display()))?,
+    );
+    c::render_source(&mut source, &config, &dbc).context("could not generate C source")?;
+    source.flush()?;
+    Ok(())
+}
+
+/// Write a report of the bus for readers without rustdoc: an overview of all messages and per
+/// message its signals, a bit layout diagram for each multiplexor value, the multiplexing tree
+/// and the value descriptions, all with the names used by [codegen].
+pub fn codegen_docs(mut config: Config<'_>, format: DocFormat, out: impl Write) -> Result<()> {
+    let (dbc, _) = parse_input(&config)?;
+    resolve_names(&mut config, &dbc)?;
+
+    let mut w = BufWriter::new(out);
+    doc::render(&mut w, &config, &dbc, format).context("could not generate documentation")?;
+    w.flush()?;
+    Ok(())
+}
+
+/// Write the generated code to a directory: a `mod.rs` with `Messages`, `CanError` and the
+/// shared value table enums, and one file per message or transmitter, see
+/// [Config::module_split].
+///
+/// The directory must exist. Include it with `mod messages;` if it is `src/messages/`.
+pub fn codegen_to_dir(mut config: Config<'_>, dir: impl AsRef<Path>) -> Result<()> {
+    let dir = dir.as_ref();
+    let (dbc, lin) = parse_input(&config)?;
+    config.lin = lin;
+    resolve_names(&mut config, &dbc)?;
+
+    let modules = split_modules(&config, &dbc);
+    for (module, messages) in &modules {
+        let path = dir.join(format!("{module}.rs"));
+        let file = File::create(&path).with_context(|| format!("create `{}`", path.display()))?;
+        let mut w = BufWriter::new(file);
+        writeln!(&mut w, "// Generated code!")?;
+        writeln!(&mut w)?;
+        writeln!(&mut w, "use super::*;")?;
+        writeln!(&mut w)?;
+        for msg in messages {
+            render_message(&mut w, &config, msg, &dbc)
+                .with_context(|| format!("write message `{}`", msg.name))?;
+            writeln!(w)?;
+        }
+        w.flush()?;
+    }
+
+    let path = dir.join("mod.rs");
+    let file = File::create(&path).with_context(|| format!("create `{}`", path.display()))?;
+    let mut w = BufWriter::new(file);
+    render_header(&mut w, &config, &dbc)?;
+    for (module, _) in &modules {
+        writeln!(&mut w, "mod {module};")?;
+        writeln!(&mut w, "pub use {module}::*;")?;
     }
+    writeln!(&mut w)?;
+    render_root_enum(&mut w, &dbc, &config).context("could not generate Rust code")?;
+    render_value_tables(&mut w, &config, &dbc).context("could not generate Rust code")?;
+    render_lin(&mut w, &config).context("could not generate Rust code")?;
+    writeln!(&mut w)?;
+    render_error(&mut w, &config)?;
+    render_arbitrary_helpers(&mut w, &config, "")?;
+    writeln!(&mut w)?;
+    w.flush()?;
 
     Ok(())
 }
 
-fn render_root_enum(mut w: impl Write, dbc: &DBC, config: &Config<'_>) -> Result<()> {
-    writeln!(w, "/// All messages")?;
-    writeln!(w, "#[derive(Clone)]")?;
-    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
-    config
-        .impl_defmt
-        .fmt_attr(&mut w, "derive(defmt::Format)")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
-    writeln!(w, "pub enum Messages {{")?;
+/// Write one module per bus to `out` buffer, see [BusesConfig].
+///
+/// The modules share `CanError` and the `arbitrary` helpers from a `common` module, so all buses
+/// need the same `impl_error`, `error_attributes`, `impl_arbitrary` and `allow_dead_code`. With
+/// [BusesConfig::bus_enum], they also need the same derives for `Bus`: `impl_debug`,
+/// `impl_defmt`, `impl_eq`, `impl_hash` and `impl_partial_ord`.
+pub fn codegen_buses(buses: BusesConfig<'_>, out: impl Write) -> Result<()> {
+    let mut parsed = Vec::new();
+    for (name, mut config) in buses.buses {
+        let (dbc, lin) = parse_input(&config)?;
+        config.lin = lin;
+        resolve_names(&mut config, &dbc)?;
+        parsed.push((name, config, dbc));
+    }
+    let Some((_, first, _)) = parsed.first() else {
+        return Err(anyhow!("no buses to generate"));
+    };
+    for (name, config, _) in &parsed {
+        let mismatches = shared_option_mismatches(first, config, buses.bus_enum);
+        ensure!(
+            mismatches.is_empty(),
+            "bus `{name}` needs the same `{}` as the other buses",
+            mismatches.join("`, `")
+        );
+    }
+    let idents = naming::disambiguate(
+        parsed
+            .iter()
+            .map(|(name, _, _)| module_ident(name))
+            .collect(),
+        "_",
+    );
+    let mut w = BufWriter::new(out);
+
+    writeln!(&mut w, "// Generated code!")?;
+    render_lints(&mut w, first)?;
+    writeln!(&mut w, "//! Message definitions of several buses")?;
+    writeln!(&mut w)?;
+    writeln!(&mut w, "pub use common::CanError;")?;
+    writeln!(&mut w)?;
+
+    writeln!(&mut w, "/// Error type and helpers shared by all buses")?;
+    writeln!(&mut w, "pub mod common {{")?;
     {
         let mut w = PadAdapter::wrap(&mut w);
-        for msg in get_relevant_messages(dbc) {
-            writeln!(w, "/// {}", msg.message_name())?;
-            writeln!(w, "{0}({0}),", type_name(msg.message_name()))?;
-        }
+        render_error(&mut w, first)?;
+        render_arbitrary_helpers(&mut w, first, "pub(super) ")?;
     }
     writeln!(&mut w, "}}")?;
     writeln!(&mut w)?;
 
-    writeln!(w, "impl Messages {{")?;
-    {
-        let mut w = PadAdapter::wrap(&mut w);
-        writeln!(&mut w, "/// Read message from CAN frame")?;
-        writeln!(w, "#[inline(never)]")?;
-        writeln!(
-            &mut w,
-            "pub fn from_can_message(id: Id, payload: &[u8]) -> Result<Self, CanError> {{",
-        )?;
+    for ((name, config, dbc), ident) in parsed.iter().zip(&idents) {
+        writeln!(&mut w, "pub mod {ident} {{")?;
         {
             let mut w = PadAdapter::wrap(&mut w);
+            render_imports(&mut w, config, dbc)?;
+            writeln!(&mut w, "use super::common::*;")?;
             writeln!(&mut w)?;
-            writeln!(&mut w, "let res = match id {{")?;
-            {
-                let mut w = PadAdapter::wrap(&mut w);
-                for msg in get_relevant_messages(dbc) {
-                    writeln!(
-                        w,
-                        "{0}::MESSAGE_ID => Messages::{0}({0}::try_from(payload)?),",
-                        type_name(msg.message_name())
-                    )?;
-                }
-                writeln!(w, r#"id => return Err(CanError::UnknownMessageId(id)),"#)?;
-            }
-            writeln!(&mut w, "}};")?;
-            writeln!(&mut w, "Ok(res)")?;
+            render_dbc(&mut w, config, dbc)
+                .with_context(|| format!("could not generate Rust code for bus `{name}`"))?;
         }
+        writeln!(&mut w, "}}")?;
+        writeln!(&mut w)?;
+    }
 
+    if buses.bus_enum {
+        writeln!(&mut w, "/// Messages of all buses")?;
+        writeln!(&mut w, "#[derive(Clone)]")?;
+        first.fmt_comparison_derives(&mut w, false, false)?;
+        first.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
+        first.impl_defmt.fmt_attr(&mut w, "derive(defmt::Format)")?;
+        writeln!(&mut w, "pub enum Bus {{")?;
+        for ident in &idents {
+            writeln!(&mut w, "    /// Messages of `{ident}`")?;
+            writeln!(
+                &mut w,
+                "    {}({ident}::Messages),",
+                first.naming_strategy.type_name(ident)
+            )?;
+        }
         writeln!(&mut w, "}}")?;
+        writeln!(&mut w)?;
     }
-    writeln!(&mut w, "}}")?;
-    writeln!(&mut w)?;
 
     Ok(())
 }
 
-fn render_message(mut w: impl Write, config: &Config<'_>, msg: &Message, dbc: &DBC) -> Result<()> {
-    writeln!(w, "/// {}", msg.message_name())?;
-    writeln!(w, "///")?;
-    match msg.message_id() {
-        can_dbc::MessageId::Standard(id) => writeln!(w, "/// - Standard ID: {0} (0x{0:x})", id),
-        can_dbc::MessageId::Extended(id) => writeln!(w, "/// - Extended ID: {0} (0x{0:x})", id),
-    }?;
-    writeln!(w, "/// - Size: {} bytes", msg.message_size())?;
-    if let can_dbc::Transmitter::NodeName(transmitter) = msg.transmitter() {
-        writeln!(w, "/// - Transmitter: {}", transmitter)?;
+/// Options of `config` that differ from `first`, although they configure code shared by all
+/// buses
+fn shared_option_mismatches(
+    first: &Config<'_>,
+    config: &Config<'_>,
+    bus_enum: bool,
+) -> Vec<&'static str> {
+    fn error_attributes<'a>(config: &Config<'a>) -> Vec<(&'a str, FeatureConfig<'a>)> {
+        config
+            .error_attributes
+            .iter()
+            .map(|attribute| (attribute.attr, attribute.feature))
+            .collect()
     }
-    if let Some(comment) = dbc.message_comment(*msg.message_id()) {
-        writeln!(w, "///")?;
-        for line in comment.trim().lines() {
-            writeln!(w, "/// {}", line)?;
+    let mut options = vec![
+        ("impl_error", first.impl_error == config.impl_error),
+        (
+            "error_attributes",
+            error_attributes(first) == error_attributes(config),
+        ),
+        (
+            "impl_arbitrary",
+            first.impl_arbitrary == config.impl_arbitrary,
+        ),
+        (
+            "allow_dead_code",
+            first.allow_dead_code == config.allow_dead_code,
+        ),
+    ];
+    if bus_enum {
+        options.extend([
+            ("impl_debug", first.impl_debug == config.impl_debug),
+            ("impl_defmt", first.impl_defmt == config.impl_defmt),
+            ("impl_eq", first.impl_eq == config.impl_eq),
+            ("impl_hash", first.impl_hash == config.impl_hash),
+            (
+                "impl_partial_ord",
+                first.impl_partial_ord == config.impl_partial_ord,
+            ),
+        ]);
+    }
+    options
+        .into_iter()
+        .filter(|(_, same)| !same)
+        .map(|(option, _)| option)
+        .collect()
+}
+
+/// Several DBC files generated into one file by [codegen_buses].
+///
+/// ```
+/// use dbc_codegen::{codegen_buses, BusesConfig, Config};
+///
+/// let dbc = include_bytes!("../testing/dbc-examples/example.dbc");
+/// let buses = BusesConfig::builder()
+///     .buses(vec![
+///         ("powertrain", Config::builder().dbc_name("example.dbc").dbc_content(dbc).build()),
+///         ("body", Config::builder().dbc_name("example.dbc").dbc_content(dbc).build()),
+///     ])
+///     .bus_enum(true)
+///     .build();
+///
+/// let mut out = Vec::<u8>::new();
+/// codegen_buses(buses, &mut out).unwrap();
+/// ```
+#[derive(TypedBuilder)]
+#[non_exhaustive]
+pub struct BusesConfig<'a> {
+    /// Name and [Config] of each bus. Every bus becomes a module named after it, e.g.
+    /// `powertrain::Messages`.
+    pub buses: Vec<(&'a str, Config<'a>)>,
+
+    /// Optional: Generate an enum `Bus { Powertrain(powertrain::Messages), … }`. Default: `false`.
+    #[builder(default)]
+    pub bus_enum: bool,
+}
+
+/// Read the input of `config` as a database, returning the LIN specifics of LDF input
+/// separately
+fn parse_input(config: &Config<'_>) -> Result<(Database, Option<ldf::LinDescription>)> {
+    let (base, lin) = read_input(config, config.dbc_content)?;
+    apply_patches(config, base, lin)
+}
+
+/// Read `content` in the input format of `config`
+fn read_input(
+    config: &Config<'_>,
+    content: &[u8],
+) -> Result<(DbcFile, Option<ldf::LinDescription>)> {
+    Ok(match config.input_format {
+        InputFormat::Dbc => (DbcFile::from(&dbc_from_slice(config, content)?), None),
+        InputFormat::Kcd => (kcd::parse_kcd(content, config.bus)?, None),
+        InputFormat::Arxml => (arxml::parse_arxml(content, config.bus)?, None),
+        InputFormat::Sym => (sym::parse_sym(content)?, None),
+        InputFormat::Ldf => {
+            let (file, lin) = ldf::parse_ldf(content)?;
+            (file, Some(lin))
         }
+    })
+}
+
+/// Merge `config.dbc_patches` on top of `base` and its LIN description
+fn apply_patches(
+    config: &Config<'_>,
+    base: DbcFile,
+    lin: Option<ldf::LinDescription>,
+) -> Result<(Database, Option<ldf::LinDescription>)> {
+    if config.dbc_patches.is_empty() {
+        return Ok((Database::from(&base), lin));
     }
-    writeln!(w, "#[derive(Clone, Copy)]")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
-    writeln!(w, "pub struct {} {{", type_name(msg.message_name()))?;
-    {
-        let mut w = PadAdapter::wrap(&mut w);
-        config
-            .impl_serde
-            .fmt_attr(&mut w, "serde(with = \"serde_bytes\")")?;
-        writeln!(w, "raw: [u8; {}],", msg.message_size())?;
+    let names: Vec<String> = (1..=config.dbc_patches.len())
+        .map(|i| format!("patch {i}"))
+        .collect();
+    let mut fragments = vec![(config.dbc_name, base, lin)];
+    for (name, content) in names.iter().zip(&config.dbc_patches) {
+        let (file, lin) =
+            read_input(config, content).with_context(|| format!("could not read `{name}`"))?;
+        fragments.push((name.as_str(), file, lin));
     }
-    writeln!(w, "}}")?;
-    writeln!(w)?;
+    let (file, lin, conflicts) = merge::merge_files(&fragments, config.merge_conflicts)?;
+    for conflict in &conflicts {
+        (config.on_merge_conflict)(conflict);
+    }
+    Ok((Database::from(&file), lin))
+}
 
-    writeln!(w, "impl {} {{", type_name(msg.message_name()))?;
-    {
-        let mut w = PadAdapter::wrap(&mut w);
+fn dbc_from_slice(config: &Config<'_>, content: &[u8]) -> Result<can_dbc::DBC> {
+    let dbc = can_dbc::DBC::from_slice(content).map_err(|e| {
+        let msg = "Could not parse dbc file";
+        if config.debug_prints {
+            anyhow!("{}: {:#?}", msg, e)
+        } else {
+            anyhow!("{}", msg)
+        }
+    })?;
+    if config.debug_prints {
+        eprintln!("{:#?}", dbc);
+    }
+    Ok(dbc)
+}
 
-        writeln!(
-            &mut w,
-            "pub const MESSAGE_ID: embedded_can::Id = {};",
-            match msg.message_id() {
-                // use StandardId::new().unwrap() once const_option is stable
-                can_dbc::MessageId::Standard(id) => format!(
-                    "Id::Standard(unsafe {{ StandardId::new_unchecked({0:#x})}})",
-                    id
-                ),
-                can_dbc::MessageId::Extended(id) => format!(
-                    "Id::Extended(unsafe {{ ExtendedId::new_unchecked({0:#x})}})",
-                    id
-                ),
-            }
-        )?;
-        writeln!(w)?;
+/// Lints, module docs and imports
+fn render_header(mut w: impl Write, config: &Config<'_>, dbc: &Database) -> Result<()> {
+    writeln!(w, "// Generated code!")?;
+    render_lints(&mut w, config)?;
+    render_imports(&mut w, config, dbc)
+}
 
-        for signal in msg
-            .signals()
-            .iter()
-            .filter(|sig| signal_to_rust_type(sig) != "bool")
-        {
-            let typ = signal_to_rust_type(signal);
-            writeln!(
-                &mut w,
-                "pub const {sig}_MIN: {typ} = {min}_{typ};",
-                sig = field_name(signal.name()).to_uppercase(),
-                typ = typ,
-                min = signal.min,
-            )?;
+fn render_lints(mut w: impl Write, config: &Config<'_>) -> Result<()> {
+    writeln!(
+        w,
+        "#![allow(unused_comparisons, unreachable_patterns, unused_imports)]"
+    )?;
+    if config.allow_dead_code {
+        writeln!(w, "#![allow(dead_code)]")?;
+    }
+    writeln!(w, "#![allow(clippy::let_and_return, clippy::eq_op)]")?;
+    writeln!(
+        w,
+        "#![allow(clippy::useless_conversion, clippy::unnecessary_cast)]"
+    )?;
+    writeln!(
+        w,
+        "#![allow(clippy::excessive_precision, clippy::manual_range_contains, clippy::absurd_extreme_comparisons, clippy::too_many_arguments)]"
+    )?;
+    writeln!(w, "#![deny(clippy::arithmetic_side_effects)]")?;
+    writeln!(w)?;
+    Ok(())
+}
 
-            writeln!(
-                &mut w,
-                "pub const {sig}_MAX: {typ} = {max}_{typ};",
-                sig = field_name(signal.name()).to_uppercase(),
-                typ = typ,
-                max = signal.max,
-            )?;
-        }
-        writeln!(w)?;
+/// Module docs and imports
+fn render_imports(mut w: impl Write, config: &Config<'_>, dbc: &Database) -> Result<()> {
+    writeln!(
+        w,
+        "//! Message definitions from file `{:?}`",
+        config.dbc_name
+    )?;
+    writeln!(w, "//!")?;
+    writeln!(w, "//! - Version: `Version({:?})`", dbc.version)?;
+    writeln!(w)?;
+    writeln!(w, "use core::ops::BitOr;")?;
+    writeln!(w, "use bitvec::prelude::*;")?;
+    writeln!(w, "use embedded_can::{{Id, StandardId, ExtendedId}};")?;
 
-        writeln!(
-            &mut w,
-            "/// Construct new {} from values",
-            msg.message_name()
-        )?;
-        let args: Vec<String> = msg
-            .signals()
-            .iter()
-            .filter_map(|signal| {
-                if *signal.multiplexer_indicator() == MultiplexIndicator::Plain
-                    || *signal.multiplexer_indicator() == MultiplexIndicator::Multiplexor
-                {
-                    Some(format!(
-                        "{}: {}",
-                        field_name(signal.name()),
-                        signal_to_rust_type(signal)
-                    ))
-                } else {
-                    None
-                }
-            })
-            .collect();
-        writeln!(
-            &mut w,
-            "pub fn new({}) -> Result<Self, CanError> {{",
-            args.join(", ")
-        )?;
-        {
-            let mut w = PadAdapter::wrap(&mut w);
-            writeln!(
-                &mut w,
-                "let {}res = Self {{ raw: [0u8; {}] }};",
-                if msg.signals().is_empty() { "" } else { "mut " },
-                msg.message_size()
-            )?;
-            for signal in msg.signals().iter() {
-                if *signal.multiplexer_indicator() == MultiplexIndicator::Plain {
-                    writeln!(&mut w, "res.set_{0}({0})?;", field_name(signal.name()))?;
-                }
+    config.impl_arbitrary.fmt_cfg(&mut w, |w| {
+        writeln!(w, "use arbitrary::{{Arbitrary, Unstructured}};")
+    })?;
 
-                if *signal.multiplexer_indicator() == MultiplexIndicator::Multiplexor {
-                    writeln!(&mut w, "res.set_{0}({0})?;", field_name(signal.name()))?;
-                }
-            }
-            writeln!(&mut w, "Ok(res)")?;
-        }
-        writeln!(&mut w, "}}")?;
-        writeln!(w)?;
+    config.impl_serde.fmt_cfg(&mut w, |w| {
+        writeln!(w, "use serde::{{Serialize, Deserialize}};")
+    })?;
 
-        writeln!(&mut w, "/// Access message payload raw value")?;
-        writeln!(
-            &mut w,
-            "pub fn raw(&self) -> &[u8; {}] {{",
-            msg.message_size()
-        )?;
-        {
-            let mut w = PadAdapter::wrap(&mut w);
-            writeln!(&mut w, "&self.raw")?;
-        }
-        writeln!(&mut w, "}}")?;
-        writeln!(w)?;
+    writeln!(w)?;
 
-        for signal in msg.signals().iter() {
-            match signal.multiplexer_indicator() {
-                MultiplexIndicator::Plain => render_signal(&mut w, config, signal, dbc, msg)
-                    .with_context(|| format!("write signal impl `{}`", signal.name()))?,
-                MultiplexIndicator::Multiplexor => {
-                    render_multiplexor_signal(&mut w, config, signal, msg)?
-                }
-                MultiplexIndicator::MultiplexedSignal(_) => {}
-                MultiplexIndicator::MultiplexorAndMultiplexedSignal(_) => {}
-            }
+    Ok(())
+}
+
+/// `snake_case` module name, prefixed with `x` if it is a keyword
+fn module_ident(name: &str) -> String {
+    let ident = name.to_snake_case();
+    if keywords::is_keyword(&ident) || !ident.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        format!("x{ident}")
+    } else {
+        ident
+    }
+}
+
+/// Module names for [codegen_to_dir] with their messages, in DBC order
+fn split_modules<'d>(config: &Config<'_>, dbc: &'d Database) -> Vec<(String, Vec<&'d Message>)> {
+    let mut modules: Vec<(String, Vec<&Message>)> = Vec::new();
+    for msg in get_relevant_messages(dbc) {
+        let name = match config.module_split {
+            ModuleSplit::Message => config.type_name(&msg.name),
+            ModuleSplit::Transmitter => match &msg.transmitter {
+                Some(node) => node.clone(),
+                None => "no_transmitter".to_string(),
+            },
+        };
+        match modules.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, messages)) => messages.push(msg),
+            None => modules.push((name, vec![msg])),
         }
     }
 
-    writeln!(w, "}}")?;
-    writeln!(w)?;
+    let idents = naming::disambiguate(
+        modules.iter().map(|(name, _)| module_ident(name)).collect(),
+        "_",
+    );
+    idents
+        .into_iter()
+        .zip(modules)
+        .map(|(ident, (_, messages))| (ident, messages))
+        .collect()
+}
 
-    writeln!(
-        w,
-        "impl core::convert::TryFrom<&[u8]> for {} {{",
-        type_name(msg.message_name())
-    )?;
-    {
-        let mut w = PadAdapter::wrap(&mut w);
-        writeln!(&mut w, "type Error = CanError;")?;
-        writeln!(w)?;
-        writeln!(w, "#[inline(always)]")?;
-        writeln!(
-            &mut w,
-            "fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {{"
-        )?;
+/// Check for identifiers shared by different DBC names and pin down the message struct names.
+fn resolve_names(config: &mut Config<'_>, dbc: &Database) -> Result<()> {
+    let messages: Vec<&Message> = get_relevant_messages(dbc).collect();
+    let mut collisions = Vec::new();
+
+    collisions.extend(
+        naming::collisions(
+            messages
+                .iter()
+                .map(|msg| (msg.name.as_str(), config.mangled_type_name(&msg.name))),
+        )
+        .into_iter()
+        .map(|c| format!("messages {c}")),
+    );
+    for msg in &messages {
+        collisions.extend(
+            naming::collisions(
+                msg.signals
+                    .iter()
+                    .map(|s| (s.name.as_str(), config.mangled_field_name(&s.name))),
+            )
+            .into_iter()
+            .map(|c| format!("signals of `{}`: {c}", msg.name)),
+        );
+        if let Some(multiplexor) = msg
+            .signals
+            .iter()
+            .find(|s| s.multiplexer == Multiplexer::Multiplexor)
         {
-            let mut w = PadAdapter::wrap(&mut w);
-            writeln!(
-                &mut w,
-                r#"if payload.len() != {} {{ return Err(CanError::InvalidPayloadSize); }}"#,
-                msg.message_size()
-            )?;
-            writeln!(&mut w, "let mut raw = [0u8; {}];", msg.message_size())?;
-            writeln!(
-                &mut w,
-                "raw.copy_from_slice(&payload[..{}]);",
-                msg.message_size()
-            )?;
-            writeln!(&mut w, "Ok(Self {{ raw }})")?;
+            let labels: Vec<(&str, String)> = multiplexer_indexes(msg)
+                .into_iter()
+                .filter_map(|index| multiplexor_value_label(multiplexor, index))
+                .map(|label| (label, config.mangled_enum_variant_name(label)))
+                .collect();
+            collisions.extend(
+                naming::collisions(labels)
+                    .into_iter()
+                    .map(|c| format!("values of `{}` in `{}`: {c}", multiplexor.name, msg.name)),
+            );
         }
-        writeln!(&mut w, "}}")?;
     }
-    writeln!(w, "}}")?;
-    writeln!(w)?;
-
-    render_embedded_can_frame(&mut w, config, msg)?;
+    for (_, _, msg, signal, variants) in signals_with_values(dbc) {
+        collisions.extend(
+            naming::collisions(
+                variants
+                    .iter()
+                    .map(|v| (v.1.as_str(), config.mangled_enum_variant_name(&v.1))),
+            )
+            .into_iter()
+            .map(|c| format!("values of `{}` in `{}`: {c}", signal.name, msg.name)),
+        );
+    }
 
-    render_debug_impl(&mut w, config, msg)?;
+    config.value_enums = resolve_value_enums(config, dbc);
+    config.type_names.clear();
+    config.type_idents.clear();
+    config.field_names.clear();
+
+    // Message structs keep their names over the types generated from them
+    let mut taken = BTreeSet::new();
+    let mut types = Vec::new();
+    for ident in ["Messages", "CanError"] {
+        taken.insert(ident.to_string());
+        types.push((format!("`{ident}`"), ident.to_string()));
+    }
+    let idents = naming::disambiguate(
+        messages
+            .iter()
+            .map(|msg| config.mangled_type_name(&msg.name))
+            .collect(),
+        "",
+    );
+    for (msg, ident) in messages.iter().zip(idents) {
+        let ident = naming::claim(&mut taken, ident, "", config.name_collisions);
+        types.push((format!("message `{}`", msg.name), ident.clone()));
+        config.type_names.insert(msg.name.clone(), ident);
+    }
+    for (generated, description, ident) in generated_types(config, dbc)? {
+        let ident = naming::claim(&mut taken, ident, "", config.name_collisions);
+        types.push((description, ident.clone()));
+        config.type_idents.insert(generated, ident);
+    }
+    collisions.extend(
+        naming::described_collisions(types)
+            .into_iter()
+            .map(|c| format!("types: {c}")),
+    );
 
-    render_defmt_impl(&mut w, config, msg)?;
+    for msg in &messages {
+        let (fields, members) = resolve_members(config, dbc, msg);
+        collisions.extend(
+            naming::described_collisions(members)
+                .into_iter()
+                .map(|c| format!("members of `{}`: {c}", msg.name)),
+        );
+        config.field_names.insert(msg.name.clone(), fields);
+    }
 
-    render_arbitrary(&mut w, config, msg)?;
+    if config.name_collisions == NameCollisions::Error && !collisions.is_empty() {
+        return Err(anyhow!(
+            "identifier collisions after name mangling (add renames or use `NameCollisions::Suffix`):\n- {}",
+            collisions.join("\n- ")
+        ));
+    }
+    Ok(())
+}
 
-    let enums_for_this_message = dbc.value_descriptions().iter().filter_map(|x| {
-        if let ValueDescription::Signal {
-            message_id,
-            signal_name,
-            value_descriptions,
-        } = x
+/// Types generated besides the message structs, with a description and their identifier
+/// before resolving collisions
+fn generated_types(
+    config: &Config<'_>,
+    dbc: &Database,
+) -> Result<Vec<(GeneratedType, String, String)>> {
+    let mut types = Vec::new();
+    for msg in get_relevant_messages(dbc) {
+        let name = &msg.name;
+        if config.impl_views != FeatureConfig::Never {
+            let (view_ref, view_mut) = config.view_names(name);
+            types.push((
+                GeneratedType::Ref(name.clone()),
+                format!("view of `{name}`"),
+                view_ref,
+            ));
+            types.push((
+                GeneratedType::Mut(name.clone()),
+                format!("mutable view of `{name}`"),
+                view_mut,
+            ));
+        }
+        if let Some(multiplexor) = msg
+            .signals
+            .iter()
+            .find(|s| s.multiplexer == Multiplexer::Multiplexor)
         {
-            if message_id != msg.message_id() {
-                return None;
+            types.push((
+                GeneratedType::MultiplexEnum(name.clone()),
+                format!("multiplexor `{}` in `{name}`", multiplexor.name),
+                config.multiplex_enum_name(msg, multiplexor)?,
+            ));
+            for index in multiplexer_indexes(msg) {
+                types.push((
+                    GeneratedType::MultiplexedStruct(name.clone(), index),
+                    format!("`{}` = {index} in `{name}`", multiplexor.name),
+                    config.multiplexed_enum_variant_name(msg, multiplexor, index)?,
+                ));
+            }
+        }
+        for signal in &msg.signals {
+            // Only the first of deduplicated signals defines the enum
+            if matches!(
+                value_enum(config, dbc, msg, signal),
+                Some(ValueEnum::Signal(m, s)) if m.id == msg.id && s.name == signal.name
+            ) {
+                types.push((
+                    GeneratedType::SignalEnum(name.clone(), signal.name.clone()),
+                    format!("values of `{}` in `{name}`", signal.name),
+                    config.enum_name(msg, signal),
+                ));
             }
-            let signal = dbc.signal_by_name(*message_id, signal_name).unwrap();
-            Some((signal, value_descriptions))
-        } else {
-            None
         }
-    });
-    for (signal, variants) in enums_for_this_message {
-        write_enum(&mut w, config, signal, msg, variants.as_slice())?;
     }
 
-    let multiplexor_signal = msg
-        .signals()
-        .iter()
-        .find(|s| *s.multiplexer_indicator() == MultiplexIndicator::Multiplexor);
+    let referenced: BTreeSet<usize> = config
+        .value_enums
+        .values()
+        .flat_map(|signals| signals.values())
+        .filter_map(|source| match source {
+            ValueEnumSource::Table(table) => Some(*table),
+            ValueEnumSource::Signal(..) => None,
+        })
+        .collect();
+    for (i, table) in dbc.value_tables.iter().enumerate() {
+        if referenced.contains(&i) {
+            let name = &table.name;
+            types.push((
+                GeneratedType::ValueTable(name.clone()),
+                format!("value table `{name}`"),
+                config.value_table_name(name),
+            ));
+        }
+    }
+    Ok(types)
+}
 
-    if let Some(multiplexor_signal) = multiplexor_signal {
-        render_multiplexor_enums(w, config, dbc, msg, multiplexor_signal)?;
+/// Field names of the signals of a message, unique together with the methods generated from
+/// them, and every method with a description for collision reports
+fn resolve_members(
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+) -> (Vec<String>, Vec<(String, String)>) {
+    let mut builtins = vec!["new", "raw"];
+    if config
+        .value_enums
+        .get(&msg.id)
+        .is_some_and(|signals| !signals.is_empty())
+    {
+        builtins.push("new_raw");
     }
+    if config.impl_const_fns != FeatureConfig::Never
+        && msg
+            .signals
+            .iter()
+            .filter(|s| !matches!(s.multiplexer, Multiplexer::Multiplexed(_)))
+            .all(signal_supports_const)
+    {
+        builtins.push("new_const");
+    }
+    if config.impl_views != FeatureConfig::Never {
+        builtins.extend(["view", "view_mut", "from_raw", "raw_mut"]);
+    }
+    let mut taken: BTreeSet<String> = builtins.iter().map(|m| m.to_string()).collect();
+    let mut members: Vec<(String, String)> = builtins
+        .iter()
+        .map(|m| (format!("`{m}` method"), m.to_string()))
+        .collect();
 
-    Ok(())
+    let idents = naming::disambiguate(
+        msg.signals
+            .iter()
+            .map(|s| config.mangled_field_name(&s.name))
+            .collect(),
+        "_",
+    );
+    let all: BTreeSet<String> = idents.iter().cloned().collect();
+    let mut fields = Vec::new();
+    for (signal, ident) in msg.signals.iter().zip(idents) {
+        let mut field = ident.clone();
+        if config.name_collisions == NameCollisions::Suffix {
+            let mut counter = 2_u32;
+            while signal_members(config, dbc, msg, signal, &field)
+                .iter()
+                .any(|m| taken.contains(m))
+                || (field != ident && all.contains(&field))
+            {
+                field = format!("{ident}_{counter}");
+                counter = counter.saturating_add(1);
+            }
+        }
+        for member in signal_members(config, dbc, msg, signal, &field) {
+            taken.insert(member.clone());
+            members.push((format!("signal `{}`", signal.name), member));
+        }
+        fields.push(field);
+    }
+    (fields, members)
 }
 
-fn render_signal(
-    mut w: impl Write,
+/// Methods generated for a signal with the given field name
+fn signal_members(
     config: &Config<'_>,
-    signal: &Signal,
-    dbc: &DBC,
+    dbc: &Database,
     msg: &Message,
-) -> Result<()> {
-    writeln!(w, "/// {}", signal.name())?;
-    if let Some(comment) = dbc.signal_comment(*msg.message_id(), signal.name()) {
-        writeln!(w, "///")?;
-        for line in comment.trim().lines() {
-            writeln!(w, "/// {}", line)?;
+    signal: &Signal,
+    field: &str,
+) -> Vec<String> {
+    let mut members = vec![
+        field.to_string(),
+        format!("{field}_raw"),
+        format!("set_{field}"),
+    ];
+    if value_enum(config, dbc, msg, signal).is_some() {
+        members.push(format!("set_{field}_raw"));
+    }
+    if config.impl_const_fns != FeatureConfig::Never && signal_supports_const(signal) {
+        members.push(format!("{field}_raw_const"));
+        members.push(format!("with_{field}"));
+    }
+    if signal.multiplexer == Multiplexer::Multiplexor {
+        for index in multiplexer_indexes(msg) {
+            let wrapper = multiplexed_enum_variant_wrapper_name(config, msg, signal, index);
+            members.push(format!("set_{}", wrapper.to_snake_case()));
         }
     }
-    writeln!(w, "///")?;
-    writeln!(w, "/// - Min: {}", signal.min)?;
-    writeln!(w, "/// - Max: {}", signal.max)?;
-    writeln!(w, "/// - Unit: {:?}", signal.unit())?;
-    writeln!(w, "/// - Receivers: {}", signal.receivers().join(", "))?;
-    writeln!(w, "#[inline(always)]")?;
-    if let Some(variants) = dbc.value_descriptions_for_signal(*msg.message_id(), signal.name()) {
-        let type_name = enum_name(msg, signal);
+    members
+}
 
-        writeln!(
-            w,
-            "pub fn {}(&self) -> {} {{",
-            field_name(signal.name()),
-            type_name,
-        )?;
-        {
-            let match_on_raw_type = match signal_to_rust_type(signal).as_str() {
-                "bool" => |x: f64| format!("{}", x),
-                // "f32" => |x: f64| format!("x if approx_eq!(f32, x, {}_f32, ulps = 2)", x),
-                _ => |x: f64| format!("{}", x),
-            };
-            let mut w = PadAdapter::wrap(&mut w);
-            let read_fn = match signal.byte_order() {
-                can_dbc::ByteOrder::LittleEndian => {
-                    let (start_bit, end_bit) = le_start_end_bit(signal, msg)?;
+fn render_dbc(mut w: impl Write, config: &Config<'_>, dbc: &Database) -> Result<()> {
+    render_root_enum(&mut w, dbc, config)?;
 
-                    format!(
-                        "self.raw.view_bits::<Lsb0>()[{start}..{end}].load_le::<{typ}>()",
-                        typ = signal_to_rust_uint(signal),
-                        start = start_bit,
-                        end = end_bit,
-                    )
-                }
-                can_dbc::ByteOrder::BigEndian => {
-                    let (start_bit, end_bit) = be_start_end_bit(signal, msg)?;
+    for msg in get_relevant_messages(dbc) {
+        render_message(&mut w, config, msg, dbc)
+            .with_context(|| format!("write message `{}`", msg.name))?;
+        writeln!(w)?;
+    }
 
-                    format!(
-                        "self.raw.view_bits::<Msb0>()[{start}..{end}].load_be::<{typ}>()",
-                        typ = signal_to_rust_uint(signal),
-                        start = start_bit,
-                        end = end_bit
-                    )
-                }
-            };
+    render_value_tables(&mut w, config, dbc)?;
+    render_lin(&mut w, config)
+}
 
-            writeln!(&mut w, r#"let signal = {};"#, read_fn)?;
-            writeln!(&mut w)?;
-            writeln!(&mut w, "match signal {{")?;
-            {
-                let mut w = PadAdapter::wrap(&mut w);
-                for variant in variants {
-                    let literal = match_on_raw_type(*variant.a());
-                    writeln!(
-                        &mut w,
-                        "{} => {}::{},",
-                        literal,
-                        type_name,
-                        enum_variant_name(variant.b())
-                    )?;
-                }
-                writeln!(
-                    &mut w,
-                    "_ => {}::_Other(self.{}_raw()),",
-                    type_name,
-                    field_name(signal.name())
-                )?;
-            }
-            writeln!(&mut w, "}}")?;
-        }
-        writeln!(&mut w, "}}")?;
+/// LIN frame constants and schedule tables of an LDF input
+fn render_lin(mut w: impl Write, config: &Config<'_>) -> Result<()> {
+    let Some(lin) = &config.lin else {
+        return Ok(());
+    };
+
+    writeln!(w, "/// Checksum model of a LIN frame")?;
+    writeln!(w, "#[derive(Clone, Copy, PartialEq, Eq)]")?;
+    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
+    config
+        .impl_defmt
+        .fmt_attr(&mut w, "derive(defmt::Format)")?;
+    writeln!(w, "pub enum LinChecksum {{")?;
+    writeln!(
+        w,
+        "    /// Sum over the data bytes (LIN 1.x and diagnostic frames)"
+    )?;
+    writeln!(w, "    Classic,")?;
+    writeln!(
+        w,
+        "    /// Sum over the protected identifier and the data bytes (LIN 2.x)"
+    )?;
+    writeln!(w, "    Enhanced,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "/// Slot of a LIN schedule table")?;
+    writeln!(w, "#[derive(Clone, Copy, PartialEq, Eq)]")?;
+    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
+    writeln!(w, "pub struct LinScheduleEntry {{")?;
+    writeln!(
+        w,
+        "    /// ID of the frame header sent in this slot, the first of `frame_ids` for sporadic frames"
+    )?;
+    writeln!(w, "    pub frame_id: u8,")?;
+    writeln!(
+        w,
+        "    /// Unconditional frames that may be sent in this slot, in priority order"
+    )?;
+    writeln!(w, "    pub frame_ids: &'static [u8],")?;
+    writeln!(w, "    /// Time until the next slot")?;
+    writeln!(w, "    pub delay: core::time::Duration,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    if let Some(speed) = lin.speed {
+        writeln!(w, "/// LIN bus speed in bit/s")?;
+        writeln!(w, "pub const LIN_SPEED: u32 = {speed};")?;
         writeln!(w)?;
-    } else {
+    }
+
+    for table in &lin.schedule_tables {
+        writeln!(w, "/// Schedule table `{}`", table.name)?;
         writeln!(
             w,
-            "pub fn {}(&self) -> {} {{",
-            field_name(signal.name()),
-            signal_to_rust_type(signal)
+            "pub const SCHEDULE_{}: &[LinScheduleEntry] = &[",
+            config
+                .naming_strategy
+                .field_name(&table.name)
+                .to_uppercase()
         )?;
-        {
-            let mut w = PadAdapter::wrap(&mut w);
-            writeln!(&mut w, "self.{}_raw()", field_name(signal.name()))?;
+        for entry in &table.entries {
+            let frame_ids: Vec<String> = entry
+                .frame_ids
+                .iter()
+                .map(|id| format!("{id:#04x}"))
+                .collect();
+            writeln!(
+                w,
+                "    LinScheduleEntry {{ frame_id: {:#04x}, frame_ids: &[{}], delay: core::time::Duration::from_micros({}) }},",
+                entry.frame_id,
+                frame_ids.join(", "),
+                (entry.delay * 1000.0).round() as u64
+            )?;
         }
-        writeln!(&mut w, "}}")?;
+        writeln!(w, "];")?;
         writeln!(w)?;
     }
 
-    writeln!(w, "/// Get raw value of {}", signal.name())?;
-    writeln!(w, "///")?;
-    writeln!(w, "/// - Start bit: {}", signal.start_bit)?;
-    writeln!(w, "/// - Signal size: {} bits", signal.signal_size)?;
-    writeln!(w, "/// - Factor: {}", signal.factor)?;
-    writeln!(w, "/// - Offset: {}", signal.offset)?;
-    writeln!(w, "/// - Byte order: {:?}", signal.byte_order())?;
-    writeln!(w, "/// - Value type: {:?}", signal.value_type())?;
-    writeln!(w, "#[inline(always)]")?;
-    writeln!(
-        w,
-        "pub fn {}_raw(&self) -> {} {{",
-        field_name(signal.name()),
-        signal_to_rust_type(signal)
-    )?;
-    {
-        let mut w = PadAdapter::wrap(&mut w);
-        signal_from_payload(&mut w, signal, msg).context("signal from payload")?;
+    for frame in &lin.frames {
+        writeln!(w, "impl {} {{", config.type_name(&frame.name))?;
+        writeln!(w, "    /// LIN frame ID")?;
+        writeln!(w, "    pub const LIN_FRAME_ID: u8 = {:#04x};", frame.id)?;
+        writeln!(w, "    /// LIN frame ID with its parity bits")?;
+        writeln!(
+            w,
+            "    pub const LIN_PROTECTED_ID: u8 = {:#04x};",
+            frame.protected_id()
+        )?;
+        writeln!(w, "    /// Checksum model of this frame")?;
+        writeln!(
+            w,
+            "    pub const LIN_CHECKSUM: LinChecksum = LinChecksum::{:?};",
+            frame.checksum
+        )?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
     }
-    writeln!(&mut w, "}}")?;
-    writeln!(w)?;
-
-    render_set_signal(&mut w, config, signal, msg)?;
 
     Ok(())
 }
 
-fn render_set_signal(
-    mut w: impl Write,
-    config: &Config<'_>,
-    signal: &Signal,
-    msg: &Message,
-) -> Result<()> {
-    writeln!(&mut w, "/// Set value of {}", signal.name())?;
-    writeln!(w, "#[inline(always)]")?;
-
-    // To avoid accidentially changing the multiplexor value without changing
-    // the signals accordingly this fn is kept private for multiplexors.
-    let visibility = if *signal.multiplexer_indicator() == MultiplexIndicator::Multiplexor {
-        ""
-    } else {
-        "pub "
-    };
+/// Enums of named value tables, after the messages using them
+fn render_value_tables(mut w: impl Write, config: &Config<'_>, dbc: &Database) -> Result<()> {
+    // The first referencing signal decides the raw type, unreferenced tables are skipped
+    let mut firsts = BTreeMap::new();
+    for (_, _, msg, signal, variants) in signals_with_values(dbc) {
+        if let Some(ValueEnum::Table(table)) = value_enum(config, dbc, msg, signal) {
+            firsts.entry(&table.name).or_insert((signal, variants));
+        }
+    }
+    for table in &dbc.value_tables {
+        if let Some((signal, variants)) = firsts.get(&table.name) {
+            write_enum(
+                &mut w,
+                config,
+                &config.value_table_name(&table.name),
+                &format!("value table {}", table.name),
+                signal,
+                variants,
+            )
+            .with_context(|| format!("write value table `{}`", table.name))?;
+        }
+    }
 
-    writeln!(
-        w,
-        "{}fn set_{}(&mut self, value: {}) -> Result<(), CanError> {{",
-        visibility,
-        field_name(signal.name()),
-        signal_to_rust_type(signal)
-    )?;
+    Ok(())
+}
 
+fn render_root_enum(mut w: impl Write, dbc: &Database, config: &Config<'_>) -> Result<()> {
+    writeln!(w, "/// All messages")?;
+    writeln!(w, "#[derive(Clone)]")?;
+    config.fmt_comparison_derives(&mut w, false, false)?;
+    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
+    config
+        .impl_defmt
+        .fmt_attr(&mut w, "derive(defmt::Format)")?;
+    config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
+    config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
+    fmt_extra_attrs(&mut w, &config.messages_enum_attributes)?;
+    writeln!(w, "pub enum Messages {{")?;
     {
         let mut w = PadAdapter::wrap(&mut w);
-
-        if signal.signal_size != 1 {
-            if let FeatureConfig::Gated(gate) = config.check_ranges {
-                writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?;
-            }
-
-            if let FeatureConfig::Gated(..) | FeatureConfig::Always = config.check_ranges {
-                writeln!(
-                    w,
-                    r##"if value < {min}_{typ} || {max}_{typ} < value {{"##,
-                    typ = signal_to_rust_type(signal),
-                    min = signal.min(),
-                    max = signal.max(),
-                )?;
-                {
-                    let mut w = PadAdapter::wrap(&mut w);
-                    writeln!(
-                        w,
-                        r##"return Err(CanError::ParameterOutOfRange {{ message_id: {}::MESSAGE_ID }});"##,
-                        type_name(msg.message_name())
-                    )?;
-                }
-                writeln!(w, r"}}")?;
-            }
+        for msg in get_relevant_messages(dbc) {
+            writeln!(w, "/// {}", msg.name)?;
+            writeln!(w, "{0}({0}),", config.type_name(&msg.name))?;
         }
-        signal_to_payload(&mut w, signal, msg).context("signal to payload")?;
     }
-
     writeln!(&mut w, "}}")?;
-    writeln!(w)?;
-
-    Ok(())
-}
-
-fn render_set_signal_multiplexer(
-    mut w: impl Write,
-    multiplexor: &Signal,
-    msg: &Message,
-    switch_index: u64,
-) -> Result<()> {
-    writeln!(&mut w, "/// Set value of {}", multiplexor.name())?;
-    writeln!(w, "#[inline(always)]")?;
-    writeln!(
-        w,
-        "pub fn set_{enum_variant_wrapper}(&mut self, value: {enum_variant}) -> Result<(), CanError> {{",
-        enum_variant_wrapper = multiplexed_enum_variant_wrapper_name(switch_index).to_snake_case(),
-        enum_variant = multiplexed_enum_variant_name(msg, multiplexor, switch_index)?,
-    )?;
+    writeln!(&mut w)?;
 
+    writeln!(w, "impl Messages {{")?;
     {
         let mut w = PadAdapter::wrap(&mut w);
-
-        writeln!(&mut w, "let b0 = BitArray::<_, LocalBits>::new(self.raw);")?;
-        writeln!(&mut w, "let b1 = BitArray::<_, LocalBits>::new(value.raw);")?;
-        writeln!(&mut w, "self.raw = b0.bitor(b1).into_inner();")?;
+        writeln!(&mut w, "/// Read message from CAN frame")?;
+        writeln!(w, "#[inline(never)]")?;
         writeln!(
             &mut w,
-            "self.set_{}({})?;",
-            field_name(multiplexor.name()),
-            switch_index
+            "pub fn from_can_message(id: Id, payload: &[u8]) -> Result<Self, CanError> {{",
         )?;
-        writeln!(&mut w, "Ok(())",)?;
-    }
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(&mut w)?;
+            writeln!(&mut w, "let res = match id {{")?;
+            {
+                let mut w = PadAdapter::wrap(&mut w);
+                for msg in get_relevant_messages(dbc) {
+                    writeln!(
+                        w,
+                        "{0}::MESSAGE_ID => Messages::{0}({0}::try_from(payload)?),",
+                        config.type_name(&msg.name)
+                    )?;
+                }
+                writeln!(w, r#"id => return Err(CanError::UnknownMessageId(id)),"#)?;
+            }
+            writeln!(&mut w, "}};")?;
+            writeln!(&mut w, "Ok(res)")?;
+        }
 
+        writeln!(&mut w, "}}")?;
+    }
     writeln!(&mut w, "}}")?;
-    writeln!(w)?;
+    writeln!(&mut w)?;
 
     Ok(())
 }
 
-fn render_multiplexor_signal(
+fn render_message(
     mut w: impl Write,
     config: &Config<'_>,
-    signal: &Signal,
     msg: &Message,
+    dbc: &Database,
 ) -> Result<()> {
-    writeln!(w, "/// Get raw value of {}", signal.name())?;
+    writeln!(w, "/// {}", msg.name)?;
     writeln!(w, "///")?;
-    writeln!(w, "/// - Start bit: {}", signal.start_bit)?;
-    writeln!(w, "/// - Signal size: {} bits", signal.signal_size)?;
-    writeln!(w, "/// - Factor: {}", signal.factor)?;
-    writeln!(w, "/// - Offset: {}", signal.offset)?;
-    writeln!(w, "/// - Byte order: {:?}", signal.byte_order())?;
-    writeln!(w, "/// - Value type: {:?}", signal.value_type())?;
-    writeln!(w, "#[inline(always)]")?;
-    writeln!(
-        w,
-        "pub fn {}_raw(&self) -> {} {{",
-        field_name(signal.name()),
-        signal_to_rust_type(signal)
+    match msg.id {
+        MessageId::Standard(id) => writeln!(w, "/// - Standard ID: {0} (0x{0:x})", id),
+        MessageId::Extended(id) => writeln!(w, "/// - Extended ID: {0} (0x{0:x})", id),
+    }?;
+    writeln!(w, "/// - Size: {} bytes", msg.size)?;
+    if let Some(transmitter) = &msg.transmitter {
+        writeln!(w, "/// - Transmitter: {}", transmitter)?;
+    }
+    if let Some(comment) = &msg.comment {
+        writeln!(w, "///")?;
+        for line in comment.trim().lines() {
+            writeln!(w, "/// {}", line)?;
+        }
+    }
+    writeln!(w, "#[derive(Clone, Copy)]")?;
+    config.fmt_comparison_derives(&mut w, false, false)?;
+    if config.serde_representation == SerdeRepresentation::Raw {
+        config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
+        config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
+    }
+    config.impl_zerocopy.fmt_attr(
+        &mut w,
+        "derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable)",
     )?;
+    config
+        .impl_bytemuck
+        .fmt_attr(&mut w, "derive(bytemuck::Pod, bytemuck::Zeroable)")?;
+    fmt_extra_attrs(&mut w, &config.message_attributes)?;
+    // Same layout as the payload, so the struct can be cast from and to bytes
+    let casts = match (config.impl_zerocopy, config.impl_bytemuck) {
+        (FeatureConfig::Never, casts) | (casts, FeatureConfig::Never) => casts,
+        (FeatureConfig::Always, _) | (_, FeatureConfig::Always) => FeatureConfig::Always,
+        (FeatureConfig::Gated(zerocopy), FeatureConfig::Gated(bytemuck))
+            if zerocopy != bytemuck =>
+        {
+            writeln!(
+                w,
+                "#[cfg_attr(any(feature = {zerocopy:?}, feature = {bytemuck:?}), repr(transparent))]"
+            )?;
+            FeatureConfig::Never
+        }
+        (casts, _) => casts,
+    };
+    casts.fmt_attr(&mut w, "repr(transparent)")?;
+    writeln!(w, "pub struct {} {{", config.type_name(&msg.name))?;
     {
         let mut w = PadAdapter::wrap(&mut w);
-        signal_from_payload(&mut w, signal, msg).context("signal from payload")?;
+        if config.serde_representation == SerdeRepresentation::Raw {
+            config
+                .impl_serde
+                .fmt_attr(&mut w, "serde(with = \"serde_bytes\")")?;
+        }
+        writeln!(w, "raw: [u8; {}],", msg.size)?;
     }
-    writeln!(&mut w, "}}")?;
+    writeln!(w, "}}")?;
     writeln!(w)?;
 
-    writeln!(
-        w,
-        "pub fn {}(&mut self) -> Result<{}, CanError> {{",
-        field_name(signal.name()),
-        multiplex_enum_name(msg, signal)?
-    )?;
-
-    let multiplexer_indexes: BTreeSet<u64> = msg
-        .signals()
-        .iter()
-        .filter_map(|s| {
-            if let MultiplexIndicator::MultiplexedSignal(index) = s.multiplexer_indicator() {
-                Some(index)
-            } else {
-                None
-            }
-        })
-        .cloned()
-        .collect();
-
+    writeln!(w, "impl {} {{", config.type_name(&msg.name))?;
     {
         let mut w = PadAdapter::wrap(&mut w);
-        writeln!(&mut w, "match self.{}_raw() {{", field_name(signal.name()))?;
 
-        {
-            let mut w = PadAdapter::wrap(&mut w);
-            for multiplexer_index in multiplexer_indexes.iter() {
-                writeln!(
-                    &mut w,
-                    "{idx} => Ok({enum_name}::{multiplexed_wrapper_name}({multiplexed_name}{{ raw: self.raw }})),",
-                    idx = multiplexer_index,
-                    enum_name = multiplex_enum_name(msg, signal)?,
-                    multiplexed_wrapper_name = multiplexed_enum_variant_wrapper_name(*multiplexer_index),
-                    multiplexed_name =
-                        multiplexed_enum_variant_name(msg, signal, *multiplexer_index)?
-                )?;
+        writeln!(
+            &mut w,
+            "pub const MESSAGE_ID: embedded_can::Id = {};",
+            match msg.id {
+                // use StandardId::new().unwrap() once const_option is stable
+                MessageId::Standard(id) => format!(
+                    "Id::Standard(unsafe {{ StandardId::new_unchecked({0:#x})}})",
+                    id
+                ),
+                MessageId::Extended(id) => format!(
+                    "Id::Extended(unsafe {{ ExtendedId::new_unchecked({0:#x})}})",
+                    id
+                ),
             }
+        )?;
+        writeln!(w)?;
+
+        for signal in msg
+            .signals
+            .iter()
+            .filter(|sig| signal_to_rust_type(sig) != "bool")
+        {
+            let typ = signal_to_rust_type(signal);
             writeln!(
                 &mut w,
-                "multiplexor => Err(CanError::InvalidMultiplexor {{ message_id: {}::MESSAGE_ID, multiplexor: multiplexor.into() }}),",
-                type_name(msg.message_name())
+                "pub const {sig}_MIN: {typ} = {min}_{typ};",
+                sig = config.field_name(msg, signal).to_uppercase(),
+                typ = typ,
+                min = signal.min,
             )?;
-        }
 
-        writeln!(w, "}}")?;
-    }
-    writeln!(w, "}}")?;
-
-    render_set_signal(&mut w, config, signal, msg)?;
+            writeln!(
+                &mut w,
+                "pub const {sig}_MAX: {typ} = {max}_{typ};",
+                sig = config.field_name(msg, signal).to_uppercase(),
+                typ = typ,
+                max = signal.max,
+            )?;
+        }
+        writeln!(w)?;
 
-    let mut multiplexed_signals = BTreeMap::new();
-    for signal in msg.signals() {
-        if let MultiplexIndicator::MultiplexedSignal(switch_index) = signal.multiplexer_indicator()
+        render_new(&mut w, config, msg, dbc, false)?;
+        if msg
+            .signals
+            .iter()
+            .any(|signal| value_enum_name(config, dbc, msg, signal).is_some())
         {
-            multiplexed_signals
-                .entry(switch_index)
-                .and_modify(|v: &mut Vec<&Signal>| v.push(signal))
-                .or_insert_with(|| vec![signal]);
+            render_new(&mut w, config, msg, dbc, true)?;
         }
-    }
 
-    for switch_index in multiplexer_indexes {
-        render_set_signal_multiplexer(&mut w, signal, msg, switch_index)?;
-    }
+        render_new_const(&mut w, config, msg)?;
 
-    Ok(())
-}
+        writeln!(&mut w, "/// Access message payload raw value")?;
+        writeln!(&mut w, "pub const fn raw(&self) -> &[u8; {}] {{", msg.size)?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(&mut w, "&self.raw")?;
+        }
+        writeln!(&mut w, "}}")?;
+        writeln!(w)?;
 
-fn be_start_end_bit(signal: &Signal, msg: &Message) -> Result<(u64, u64)> {
-    let err = "calculating start bit";
+        render_view_accessors(&mut w, config, msg)?;
 
-    let x = signal.start_bit.checked_div(8).context(err)?;
-    let x = x.checked_mul(8).context(err)?;
+        render_signal_accessors(&mut w, config, msg, dbc, Receiver::Owned)?;
+    }
 
-    let y = signal.start_bit.checked_rem(8).context(err)?;
-    let y = 7u64.checked_sub(y).context(err)?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
 
-    let start_bit = x.checked_add(y).context(err)?;
-    let end_bit = start_bit
-        .checked_add(signal.signal_size)
-        .context("calculating last bit position")?;
+    writeln!(
+        w,
+        "impl core::convert::TryFrom<&[u8]> for {} {{",
+        config.type_name(&msg.name)
+    )?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(&mut w, "type Error = CanError;")?;
+        writeln!(w)?;
+        writeln!(w, "#[inline(always)]")?;
+        writeln!(
+            &mut w,
+            "fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {{"
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(
+                &mut w,
+                r#"if payload.len() != {} {{ return Err(CanError::InvalidPayloadSize); }}"#,
+                msg.size
+            )?;
+            writeln!(&mut w, "let mut raw = [0u8; {}];", msg.size)?;
+            writeln!(&mut w, "raw.copy_from_slice(&payload[..{}]);", msg.size)?;
+            writeln!(&mut w, "Ok(Self {{ raw }})")?;
+        }
+        writeln!(&mut w, "}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
 
-    let msg_bits = msg.message_size().checked_mul(8).unwrap();
+    render_embedded_can_frame(&mut w, config, msg)?;
 
-    ensure!(
-        start_bit <= msg_bits,
-        "signal starts at {}, but message is only {} bits",
-        start_bit,
-        msg_bits
-    );
-    ensure!(
-        end_bit <= msg_bits,
-        "signal ends at {}, but message is only {} bits",
-        end_bit,
-        msg_bits
-    );
-    Ok((start_bit, end_bit))
-}
+    render_debug_impl(&mut w, config, msg)?;
 
-fn le_start_end_bit(signal: &Signal, msg: &Message) -> Result<(u64, u64)> {
-    let msg_bits = msg.message_size().checked_mul(8).unwrap();
-    let start_bit = signal.start_bit;
-    ensure!(
-        start_bit <= msg_bits,
-        "signal starts at {}, but message is only {} bits",
-        start_bit,
-        msg_bits
-    );
+    render_defmt_impl(&mut w, config, msg)?;
 
-    let end_bit = signal
-        .start_bit
-        .checked_add(signal.signal_size)
-        .context("overflow calculating last bit position")?;
-    ensure!(
-        end_bit <= msg_bits,
-        "signal ends at {}, but message is only {} bits",
-        end_bit,
-        msg_bits
-    );
-    Ok((start_bit, end_bit))
-}
+    render_arbitrary(&mut w, config, msg, dbc)?;
 
-fn signal_from_payload(mut w: impl Write, signal: &Signal, msg: &Message) -> Result<()> {
-    let read_fn = match signal.byte_order() {
-        can_dbc::ByteOrder::LittleEndian => {
-            let (start_bit, end_bit) = le_start_end_bit(signal, msg)?;
+    render_views(&mut w, config, msg, dbc)?;
 
-            format!(
-                "self.raw.view_bits::<Lsb0>()[{start}..{end}].load_le::<{typ}>()",
-                typ = signal_to_rust_int(signal),
-                start = start_bit,
-                end = end_bit,
-            )
-        }
-        can_dbc::ByteOrder::BigEndian => {
-            let (start_bit, end_bit) = be_start_end_bit(signal, msg)?;
+    render_serde_signals(&mut w, config, msg, dbc)?;
 
-            format!(
-                "self.raw.view_bits::<Msb0>()[{start}..{end}].load_be::<{typ}>()",
-                typ = signal_to_rust_int(signal),
-                start = start_bit,
-                end = end_bit
-            )
+    for signal in &msg.signals {
+        // Shared enums are written by their first signal, or globally for `VAL_TABLE_`s
+        let owned = matches!(
+            value_enum(config, dbc, msg, signal),
+            Some(ValueEnum::Signal(m, s)) if m.id == msg.id && s.name == signal.name
+        );
+        if owned {
+            write_enum(
+                &mut w,
+                config,
+                &config.enum_name(msg, signal),
+                &signal.name,
+                signal,
+                &signal.values,
+            )?;
         }
-    };
-
-    writeln!(&mut w, r#"let signal = {};"#, read_fn)?;
-    writeln!(&mut w)?;
+    }
 
-    if signal.signal_size == 1 {
-        writeln!(&mut w, "signal == 1")?;
-    } else if signal_is_float_in_rust(signal) {
-        // Scaling is always done on floats
-        writeln!(&mut w, "let factor = {}_f32;", signal.factor)?;
-        writeln!(&mut w, "let offset = {}_f32;", signal.offset)?;
-        writeln!(&mut w, "(signal as f32) * factor + offset")?;
-    } else {
-        writeln!(&mut w, "let factor = {};", signal.factor)?;
-        let scaled_type = scaled_signal_to_rust_int(signal);
+    if let Some(tree) = msg.mux_tree() {
+        render_multiplexor_enums(w, config, dbc, msg, &tree)?;
+    }
 
-        if scaled_type == signal_to_rust_uint(signal).replace('u', "i") {
-            // Can't do iNN::from(uNN) if they both fit in the same integer type,
-            // so cast first
-            writeln!(&mut w, "let signal = signal as {};", scaled_type)?;
-        }
+    Ok(())
+}
 
-        if signal.offset >= 0.0 {
-            writeln!(
-                &mut w,
-                "{}::from(signal).saturating_mul(factor).saturating_add({})",
-                scaled_type, signal.offset,
-            )?;
-        } else {
-            writeln!(
-                &mut w,
-                "{}::from(signal).saturating_mul(factor).saturating_sub({})",
-                scaled_type,
-                signal.offset.abs(),
-            )?;
+fn render_signal_accessors(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    dbc: &Database,
+    receiver: Receiver,
+) -> Result<()> {
+    for signal in msg.signals.iter() {
+        match signal.multiplexer {
+            Multiplexer::Plain => render_signal(&mut w, config, signal, dbc, msg, receiver)
+                .with_context(|| format!("write signal impl `{}`", signal.name))?,
+            Multiplexer::Multiplexor => {
+                render_multiplexor_signal(&mut w, config, signal, dbc, msg, receiver)?
+            }
+            Multiplexer::Multiplexed(_) => {}
+            Multiplexer::MultiplexorAndMultiplexed(_) => {}
         }
     }
     Ok(())
 }
 
-fn signal_to_payload(mut w: impl Write, signal: &Signal, msg: &Message) -> Result<()> {
-    if signal.signal_size == 1 {
-        // Map boolean to byte so we can pack it
-        writeln!(&mut w, "let value = value as u8;")?;
-    } else if signal_is_float_in_rust(signal) {
-        // Massage value into an int
-        writeln!(&mut w, "let factor = {}_f32;", signal.factor)?;
-        writeln!(&mut w, "let offset = {}_f32;", signal.offset)?;
-        writeln!(
-            &mut w,
-            "let value = ((value - offset) / factor) as {};",
-            signal_to_rust_int(signal)
-        )?;
-        writeln!(&mut w)?;
-    } else {
-        writeln!(&mut w, "let factor = {};", signal.factor)?;
-        if signal.offset >= 0.0 {
-            writeln!(&mut w, "let value = value.checked_sub({})", signal.offset)?;
-        } else {
-            writeln!(
-                &mut w,
-                "let value = value.checked_add({})",
-                signal.offset.abs()
-            )?;
+fn render_signal(
+    mut w: impl Write,
+    config: &Config<'_>,
+    signal: &Signal,
+    dbc: &Database,
+    msg: &Message,
+    receiver: Receiver,
+) -> Result<()> {
+    writeln!(w, "/// {}", signal.name)?;
+    if let Some(comment) = &signal.comment {
+        writeln!(w, "///")?;
+        for line in comment.trim().lines() {
+            writeln!(w, "/// {}", line)?;
         }
+    }
+    writeln!(w, "///")?;
+    writeln!(w, "/// - Min: {}", signal.min)?;
+    writeln!(w, "/// - Max: {}", signal.max)?;
+    writeln!(w, "/// - Unit: {:?}", signal.unit)?;
+    if signal.receivers.is_empty() {
+        writeln!(w, "/// - Receivers: Vector__XXX")?;
+    } else {
+        writeln!(w, "/// - Receivers: {}", signal.receivers.join(", "))?;
+    }
+    writeln!(w, "#[inline(always)]")?;
+    if let Some(type_name) = value_enum_name(config, dbc, msg, signal) {
+        let variants = &signal.values;
         writeln!(
-            &mut w,
-            "    .ok_or(CanError::ParameterOutOfRange {{ message_id: {}::MESSAGE_ID }})?;",
-            type_name(msg.message_name())
+            w,
+            "pub fn {}(&self) -> {} {{",
+            config.field_name(msg, signal),
+            type_name,
         )?;
+        {
+            let match_on_raw_type = match signal_to_rust_type(signal).as_str() {
+                "bool" => |x: f64| format!("{}", x),
+                // "f32" => |x: f64| format!("x if approx_eq!(f32, x, {}_f32, ulps = 2)", x),
+                _ => |x: f64| format!("{}", x),
+            };
+            let mut w = PadAdapter::wrap(&mut w);
+            let read_fn = match signal.byte_order {
+                ByteOrder::LittleEndian => {
+                    let (start_bit, end_bit) = le_start_end_bit(signal, msg)?;
+
+                    format!(
+                        "self.raw.view_bits::<Lsb0>()[{start}..{end}].load_le::<{typ}>()",
+                        typ = signal_to_rust_uint(signal),
+                        start = start_bit,
+                        end = end_bit,
+                    )
+                }
+                ByteOrder::BigEndian => {
+                    let (start_bit, end_bit) = be_start_end_bit(signal, msg)?;
+
+                    format!(
+                        "self.raw.view_bits::<Msb0>()[{start}..{end}].load_be::<{typ}>()",
+                        typ = signal_to_rust_uint(signal),
+                        start = start_bit,
+                        end = end_bit
+                    )
+                }
+            };
+
+            writeln!(&mut w, r#"let signal = {};"#, read_fn)?;
+            writeln!(&mut w)?;
+            writeln!(&mut w, "match signal {{")?;
+            {
+                let mut w = PadAdapter::wrap(&mut w);
+                let strict = enum_is_strict(config, signal, variants);
+                let variant_names = config.enum_variant_names(variants);
+                for (i, (variant, variant_name)) in variants.iter().zip(&variant_names).enumerate()
+                {
+                    // Strict tables cover every raw value, so the last one catches the rest
+                    let literal = if strict && i.saturating_add(1) == variants.len() {
+                        "_".to_string()
+                    } else {
+                        match_on_raw_type(variant.0)
+                    };
+                    writeln!(&mut w, "{} => {}::{},", literal, type_name, variant_name)?;
+                }
+                if !strict {
+                    writeln!(
+                        &mut w,
+                        "_ => {}::_Other(self.{}_raw()),",
+                        type_name,
+                        config.field_name(msg, signal)
+                    )?;
+                }
+            }
+            writeln!(&mut w, "}}")?;
+        }
+        writeln!(&mut w, "}}")?;
+        writeln!(w)?;
+    } else {
         writeln!(
-            &mut w,
-            "let value = (value / factor) as {};",
-            signal_to_rust_int(signal)
+            w,
+            "pub fn {}(&self) -> {} {{",
+            config.field_name(msg, signal),
+            signal_to_rust_type(signal)
         )?;
-        writeln!(&mut w)?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(&mut w, "self.{}_raw()", config.field_name(msg, signal))?;
+        }
+        writeln!(&mut w, "}}")?;
+        writeln!(w)?;
     }
 
-    if *signal.value_type() == can_dbc::ValueType::Signed {
+    writeln!(w, "/// Get raw value of {}", signal.name)?;
+    writeln!(w, "///")?;
+    writeln!(w, "/// - Start bit: {}", signal.start_bit)?;
+    writeln!(w, "/// - Signal size: {} bits", signal.size)?;
+    writeln!(w, "/// - Factor: {}", signal.factor)?;
+    writeln!(w, "/// - Offset: {}", signal.offset)?;
+    writeln!(w, "/// - Byte order: {:?}", signal.byte_order)?;
+    writeln!(w, "/// - Value type: {:?}", signal.value_type)?;
+    writeln!(w, "#[inline(always)]")?;
+    writeln!(
+        w,
+        "pub fn {}_raw(&self) -> {} {{",
+        config.field_name(msg, signal),
+        signal_to_rust_type(signal)
+    )?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        signal_from_payload(&mut w, signal, msg).context("signal from payload")?;
+    }
+    writeln!(&mut w, "}}")?;
+    writeln!(w)?;
+
+    if receiver.has_setters() {
+        render_set_signal(&mut w, config, signal, dbc, msg)?;
+    }
+
+    if receiver == Receiver::Owned {
+        render_const_signal(&mut w, config, signal, msg)?;
+    }
+
+    Ok(())
+}
+
+fn render_set_signal(
+    mut w: impl Write,
+    config: &Config<'_>,
+    signal: &Signal,
+    dbc: &Database,
+    msg: &Message,
+) -> Result<()> {
+    let setter = setter_name(config, dbc, msg, signal);
+    if let Some(enum_type) = value_enum_name(config, dbc, msg, signal) {
+        writeln!(&mut w, "/// Set value of {}", signal.name)?;
+        writeln!(w, "#[inline(always)]")?;
+        writeln!(
+            w,
+            "pub fn set_{}(&mut self, value: {}) -> Result<(), CanError> {{",
+            config.field_name(msg, signal),
+            enum_type,
+        )?;
+        writeln!(w, "    self.{}(value.into())", setter)?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+
+        writeln!(&mut w, "/// Set raw value of {}", signal.name)?;
+    } else {
+        writeln!(&mut w, "/// Set value of {}", signal.name)?;
+    }
+    writeln!(w, "#[inline(always)]")?;
+
+    // To avoid accidentially changing the multiplexor value without changing
+    // the signals accordingly this fn is kept private for multiplexors.
+    let visibility = if signal.multiplexer == Multiplexer::Multiplexor {
+        ""
+    } else {
+        "pub "
+    };
+
+    writeln!(
+        w,
+        "{}fn {}(&mut self, value: {}) -> Result<(), CanError> {{",
+        visibility,
+        setter,
+        signal_to_rust_type(signal)
+    )?;
+
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+
+        render_range_check(&mut w, config, signal, msg)?;
+        signal_to_payload(&mut w, config, signal, msg).context("signal to payload")?;
+    }
+
+    writeln!(&mut w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+fn render_range_check(
+    mut w: impl Write,
+    config: &Config<'_>,
+    signal: &Signal,
+    msg: &Message,
+) -> Result<()> {
+    if signal.size == 1 {
+        return Ok(());
+    }
+
+    if let FeatureConfig::Gated(gate) = config.check_ranges {
+        writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?;
+    }
+
+    if let FeatureConfig::Gated(..) | FeatureConfig::Always = config.check_ranges {
+        writeln!(
+            w,
+            r##"if value < {min}_{typ} || {max}_{typ} < value {{"##,
+            typ = signal_to_rust_type(signal),
+            min = signal.min,
+            max = signal.max,
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(
+                w,
+                r##"return Err(CanError::ParameterOutOfRange {{ message_id: {}::MESSAGE_ID }});"##,
+                config.type_name(&msg.name)
+            )?;
+        }
+        writeln!(w, r"}}")?;
+    }
+
+    Ok(())
+}
+
+fn render_set_signal_multiplexer(
+    mut w: impl Write,
+    config: &Config<'_>,
+    multiplexor: &Signal,
+    msg: &Message,
+    switch_index: u64,
+    receiver: Receiver,
+) -> Result<()> {
+    writeln!(&mut w, "/// Set value of {}", multiplexor.name)?;
+    writeln!(w, "#[inline(always)]")?;
+    writeln!(
+        w,
+        "pub fn set_{enum_variant_wrapper}(&mut self, value: {enum_variant}) -> Result<(), CanError> {{",
+        enum_variant_wrapper =
+            multiplexed_enum_variant_wrapper_name(config, msg, multiplexor, switch_index)
+                .to_snake_case(),
+        enum_variant = config.multiplexed_enum_variant_name(msg, multiplexor, switch_index)?,
+    )?;
+
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+
         writeln!(
             &mut w,
-            "let value = {}::from_ne_bytes(value.to_ne_bytes());",
-            signal_to_rust_uint(signal)
+            "let b0 = BitArray::<_, LocalBits>::new({});",
+            receiver.raw()
+        )?;
+        writeln!(&mut w, "let b1 = BitArray::<_, LocalBits>::new(value.raw);")?;
+        writeln!(&mut w, "{} = b0.bitor(b1).into_inner();", receiver.raw())?;
+        writeln!(
+            &mut w,
+            "self.set_{}({})?;",
+            config.field_name(msg, multiplexor),
+            switch_index
+        )?;
+        writeln!(&mut w, "Ok(())",)?;
+    }
+
+    writeln!(&mut w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+fn render_multiplexor_signal(
+    mut w: impl Write,
+    config: &Config<'_>,
+    signal: &Signal,
+    dbc: &Database,
+    msg: &Message,
+    receiver: Receiver,
+) -> Result<()> {
+    writeln!(w, "/// Get raw value of {}", signal.name)?;
+    writeln!(w, "///")?;
+    writeln!(w, "/// - Start bit: {}", signal.start_bit)?;
+    writeln!(w, "/// - Signal size: {} bits", signal.size)?;
+    writeln!(w, "/// - Factor: {}", signal.factor)?;
+    writeln!(w, "/// - Offset: {}", signal.offset)?;
+    writeln!(w, "/// - Byte order: {:?}", signal.byte_order)?;
+    writeln!(w, "/// - Value type: {:?}", signal.value_type)?;
+    writeln!(w, "#[inline(always)]")?;
+    writeln!(
+        w,
+        "pub fn {}_raw(&self) -> {} {{",
+        config.field_name(msg, signal),
+        signal_to_rust_type(signal)
+    )?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        signal_from_payload(&mut w, signal, msg).context("signal from payload")?;
+    }
+    writeln!(&mut w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "pub fn {}(&self) -> Result<{}, CanError> {{",
+        config.field_name(msg, signal),
+        config.multiplex_enum_name(msg, signal)?
+    )?;
+
+    let multiplexer_indexes = multiplexer_indexes(msg);
+
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(
+            &mut w,
+            "match self.{}_raw() {{",
+            config.field_name(msg, signal)
         )?;
+
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            for multiplexer_index in multiplexer_indexes.iter() {
+                writeln!(
+                    &mut w,
+                    "{idx} => Ok({enum_name}::{multiplexed_wrapper_name}({multiplexed_name}{{ raw: {raw} }})),",
+                    idx = multiplexer_index,
+                    raw = receiver.raw(),
+                    enum_name = config.multiplex_enum_name(msg, signal)?,
+                    multiplexed_wrapper_name =
+                        multiplexed_enum_variant_wrapper_name(config, msg, signal, *multiplexer_index),
+                    multiplexed_name =
+                        config.multiplexed_enum_variant_name(msg, signal, *multiplexer_index)?
+                )?;
+            }
+            writeln!(
+                &mut w,
+                "multiplexor => Err(CanError::InvalidMultiplexor {{ message_id: {}::MESSAGE_ID, multiplexor: multiplexor.into() }}),",
+                config.type_name(&msg.name)
+            )?;
+        }
+
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "}}")?;
+
+    if !receiver.has_setters() {
+        return Ok(());
+    }
+
+    render_set_signal(&mut w, config, signal, dbc, msg)?;
+
+    if receiver == Receiver::Owned {
+        render_const_signal(&mut w, config, signal, msg)?;
+    }
+
+    for switch_index in multiplexer_indexes {
+        render_set_signal_multiplexer(&mut w, config, signal, msg, switch_index, receiver)?;
+    }
+
+    Ok(())
+}
+
+/// All multiplexor values that select multiplexed signals in a message.
+fn multiplexer_indexes(msg: &Message) -> BTreeSet<u64> {
+    msg.mux_tree()
+        .map(|tree| tree.groups.into_keys().collect())
+        .unwrap_or_default()
+}
+
+fn be_start_end_bit(signal: &Signal, msg: &Message) -> Result<(u64, u64)> {
+    let err = "calculating start bit";
+
+    let x = signal.start_bit.checked_div(8).context(err)?;
+    let x = x.checked_mul(8).context(err)?;
+
+    let y = signal.start_bit.checked_rem(8).context(err)?;
+    let y = 7u64.checked_sub(y).context(err)?;
+
+    let start_bit = x.checked_add(y).context(err)?;
+    let end_bit = start_bit
+        .checked_add(signal.size)
+        .context("calculating last bit position")?;
+
+    let msg_bits = msg.size.checked_mul(8).unwrap();
+
+    ensure!(
+        start_bit <= msg_bits,
+        "signal starts at {}, but message is only {} bits",
+        start_bit,
+        msg_bits
+    );
+    ensure!(
+        end_bit <= msg_bits,
+        "signal ends at {}, but message is only {} bits",
+        end_bit,
+        msg_bits
+    );
+    Ok((start_bit, end_bit))
+}
+
+fn le_start_end_bit(signal: &Signal, msg: &Message) -> Result<(u64, u64)> {
+    let msg_bits = msg.size.checked_mul(8).unwrap();
+    let start_bit = signal.start_bit;
+    ensure!(
+        start_bit <= msg_bits,
+        "signal starts at {}, but message is only {} bits",
+        start_bit,
+        msg_bits
+    );
+
+    let end_bit = signal
+        .start_bit
+        .checked_add(signal.size)
+        .context("overflow calculating last bit position")?;
+    ensure!(
+        end_bit <= msg_bits,
+        "signal ends at {}, but message is only {} bits",
+        end_bit,
+        msg_bits
+    );
+    Ok((start_bit, end_bit))
+}
+
+fn signal_from_payload(mut w: impl Write, signal: &Signal, msg: &Message) -> Result<()> {
+    let read_fn = match signal.byte_order {
+        ByteOrder::LittleEndian => {
+            let (start_bit, end_bit) = le_start_end_bit(signal, msg)?;
+
+            format!(
+                "self.raw.view_bits::<Lsb0>()[{start}..{end}].load_le::<{typ}>()",
+                typ = signal_to_rust_int(signal),
+                start = start_bit,
+                end = end_bit,
+            )
+        }
+        ByteOrder::BigEndian => {
+            let (start_bit, end_bit) = be_start_end_bit(signal, msg)?;
+
+            format!(
+                "self.raw.view_bits::<Msb0>()[{start}..{end}].load_be::<{typ}>()",
+                typ = signal_to_rust_int(signal),
+                start = start_bit,
+                end = end_bit
+            )
+        }
     };
 
-    match signal.byte_order() {
-        can_dbc::ByteOrder::LittleEndian => {
-            let (start_bit, end_bit) = le_start_end_bit(signal, msg)?;
-            writeln!(
-                &mut w,
-                r#"self.raw.view_bits_mut::<Lsb0>()[{start_bit}..{end_bit}].store_le(value);"#,
-                start_bit = start_bit,
-                end_bit = end_bit,
-            )?;
-        }
-        can_dbc::ByteOrder::BigEndian => {
-            let (start_bit, end_bit) = be_start_end_bit(signal, msg)?;
-            writeln!(
-                &mut w,
-                r#"self.raw.view_bits_mut::<Msb0>()[{start_bit}..{end_bit}].store_be(value);"#,
-                start_bit = start_bit,
-                end_bit = end_bit,
-            )?;
-        }
-    };
+    writeln!(&mut w, r#"let signal = {};"#, read_fn)?;
+    writeln!(&mut w)?;
+
+    if signal.size == 1 {
+        writeln!(&mut w, "signal == 1")?;
+    } else if signal_is_float_in_rust(signal) {
+        // Scaling is always done on floats
+        writeln!(&mut w, "let factor = {}_f32;", signal.factor)?;
+        writeln!(&mut w, "let offset = {}_f32;", signal.offset)?;
+        writeln!(&mut w, "(signal as f32) * factor + offset")?;
+    } else {
+        writeln!(&mut w, "let factor = {};", signal.factor)?;
+        let scaled_type = scaled_signal_to_rust_int(signal);
+
+        if scaled_type == signal_to_rust_uint(signal).replace('u', "i") {
+            // Can't do iNN::from(uNN) if they both fit in the same integer type,
+            // so cast first
+            writeln!(&mut w, "let signal = signal as {};", scaled_type)?;
+        }
+
+        if signal.offset >= 0.0 {
+            writeln!(
+                &mut w,
+                "{}::from(signal).saturating_mul(factor).saturating_add({})",
+                scaled_type, signal.offset,
+            )?;
+        } else {
+            writeln!(
+                &mut w,
+                "{}::from(signal).saturating_mul(factor).saturating_sub({})",
+                scaled_type,
+                signal.offset.abs(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn signal_to_payload(
+    mut w: impl Write,
+    config: &Config<'_>,
+    signal: &Signal,
+    msg: &Message,
+) -> Result<()> {
+    if signal.size == 1 {
+        // Map boolean to byte so we can pack it
+        writeln!(&mut w, "let value = value as u8;")?;
+    } else if signal_is_float_in_rust(signal) {
+        // Massage value into an int
+        writeln!(&mut w, "let factor = {}_f32;", signal.factor)?;
+        writeln!(&mut w, "let offset = {}_f32;", signal.offset)?;
+        writeln!(
+            &mut w,
+            "let value = ((value - offset) / factor) as {};",
+            signal_to_rust_int(signal)
+        )?;
+        writeln!(&mut w)?;
+    } else {
+        writeln!(&mut w, "let factor = {};", signal.factor)?;
+        if signal.offset >= 0.0 {
+            writeln!(&mut w, "let value = value.checked_sub({})", signal.offset)?;
+        } else {
+            writeln!(
+                &mut w,
+                "let value = value.checked_add({})",
+                signal.offset.abs()
+            )?;
+        }
+        writeln!(
+            &mut w,
+            "    .ok_or(CanError::ParameterOutOfRange {{ message_id: {}::MESSAGE_ID }})?;",
+            config.type_name(&msg.name)
+        )?;
+        writeln!(
+            &mut w,
+            "let value = (value / factor) as {};",
+            signal_to_rust_int(signal)
+        )?;
+        writeln!(&mut w)?;
+    }
+
+    if signal.value_type == ValueType::Signed {
+        writeln!(
+            &mut w,
+            "let value = {}::from_ne_bytes(value.to_ne_bytes());",
+            signal_to_rust_uint(signal)
+        )?;
+    };
+
+    match signal.byte_order {
+        ByteOrder::LittleEndian => {
+            let (start_bit, end_bit) = le_start_end_bit(signal, msg)?;
+            writeln!(
+                &mut w,
+                r#"self.raw.view_bits_mut::<Lsb0>()[{start_bit}..{end_bit}].store_le(value);"#,
+                start_bit = start_bit,
+                end_bit = end_bit,
+            )?;
+        }
+        ByteOrder::BigEndian => {
+            let (start_bit, end_bit) = be_start_end_bit(signal, msg)?;
+            writeln!(
+                &mut w,
+                r#"self.raw.view_bits_mut::<Msb0>()[{start_bit}..{end_bit}].store_be(value);"#,
+                start_bit = start_bit,
+                end_bit = end_bit,
+            )?;
+        }
+    };
+
+    writeln!(&mut w, "Ok(())")?;
+    Ok(())
+}
+
+/// Whether a signal can be read and written in a `const fn`, i.e. whether it can do
+/// without float arithmetic.
+fn signal_supports_const(signal: &Signal) -> bool {
+    !signal_is_float_in_rust(signal)
+}
+
+/// A part of a signal that is stored in a single payload byte.
+struct ByteChunk {
+    /// Index of the payload byte
+    byte: u64,
+    /// Position of the chunk's least significant bit in the payload byte
+    byte_shift: u64,
+    /// Position of the chunk's least significant bit in the signal value
+    value_shift: u64,
+    /// Number of bits in this chunk
+    width: u64,
+}
+
+impl ByteChunk {
+    /// Bits of the payload byte that belong to this chunk
+    fn byte_mask(&self) -> Result<u8> {
+        let mask = 1u16
+            .checked_shl(self.width as u32)
+            .and_then(|m| m.checked_sub(1))
+            .and_then(|m| m.checked_shl(self.byte_shift as u32))
+            .context("calculating byte mask")?;
+        u8::try_from(mask).context("byte mask exceeds a byte")
+    }
+}
+
+/// Split a signal into the payload bytes it spans, in the bit numbering used by `bitvec`
+/// (`Lsb0` for little endian and `Msb0` for big endian signals).
+fn signal_byte_chunks(signal: &Signal, msg: &Message) -> Result<Vec<ByteChunk>> {
+    let err = "calculating byte chunks";
+    ensure!(signal.size > 0, "signal {} has no bits", signal.name);
+
+    let (start_bit, end_bit) = match signal.byte_order {
+        ByteOrder::LittleEndian => le_start_end_bit(signal, msg)?,
+        ByteOrder::BigEndian => be_start_end_bit(signal, msg)?,
+    };
+    let first_byte = start_bit.checked_div(8).context(err)?;
+    let last_byte = end_bit
+        .checked_sub(1)
+        .and_then(|b| b.checked_div(8))
+        .context(err)?;
+
+    let mut chunks = Vec::new();
+    let mut consumed = 0u64;
+    for byte in first_byte..=last_byte {
+        let byte_start = byte.checked_mul(8).context(err)?;
+        let byte_end = byte_start.checked_add(8).context(err)?;
+        let lo = max(start_bit, byte_start)
+            .checked_sub(byte_start)
+            .context(err)?;
+        let hi = min(end_bit, byte_end)
+            .checked_sub(byte_start)
+            .context(err)?;
+        let width = hi.checked_sub(lo).context(err)?;
+
+        let chunk = match signal.byte_order {
+            // Lsb0: bit positions are bit significance, low bytes hold low bits
+            ByteOrder::LittleEndian => ByteChunk {
+                byte,
+                byte_shift: lo,
+                value_shift: consumed,
+                width,
+            },
+            // Msb0: bit positions count from the most significant bit, low bytes hold high bits
+            ByteOrder::BigEndian => ByteChunk {
+                byte,
+                byte_shift: 8u64.checked_sub(hi).context(err)?,
+                value_shift: signal
+                    .size
+                    .checked_sub(consumed)
+                    .and_then(|s| s.checked_sub(width))
+                    .context(err)?,
+                width,
+            },
+        };
+        consumed = consumed.checked_add(width).context(err)?;
+        chunks.push(chunk);
+    }
+
+    Ok(chunks)
+}
+
+/// Wrap a generated expression in parentheses unless it is a single term.
+fn parens(expr: &str) -> String {
+    if expr.contains(' ') {
+        format!("({})", expr)
+    } else {
+        expr.to_string()
+    }
+}
+
+/// `new` taking value table enums where signals have them, or `new_raw` taking raw values.
+fn render_new(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    dbc: &Database,
+    raw: bool,
+) -> Result<()> {
+    if raw {
+        writeln!(&mut w, "/// Construct new {} from raw values", msg.name)?;
+    } else {
+        writeln!(&mut w, "/// Construct new {} from values", msg.name)?;
+    }
+    let args: Vec<String> = msg
+        .signals
+        .iter()
+        .filter_map(|signal| {
+            if signal.multiplexer == Multiplexer::Plain
+                || signal.multiplexer == Multiplexer::Multiplexor
+            {
+                let typ = match value_enum_name(config, dbc, msg, signal) {
+                    Some(enum_type) if !raw => enum_type,
+                    _ => signal_to_rust_type(signal),
+                };
+                Some(format!("{}: {}", config.field_name(msg, signal), typ))
+            } else {
+                None
+            }
+        })
+        .collect();
+    render_allow_disallowed_names(&mut w, config, msg, msg.signals.iter())?;
+    writeln!(
+        &mut w,
+        "pub fn {}({}) -> Result<Self, CanError> {{",
+        if raw { "new_raw" } else { "new" },
+        args.join(", ")
+    )?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(
+            &mut w,
+            "let {}res = Self {{ raw: [0u8; {}] }};",
+            if msg.signals.is_empty() { "" } else { "mut " },
+            msg.size
+        )?;
+        for signal in msg.signals.iter() {
+            if signal.multiplexer == Multiplexer::Plain
+                || signal.multiplexer == Multiplexer::Multiplexor
+            {
+                let setter = if raw {
+                    setter_name(config, dbc, msg, signal)
+                } else {
+                    format!("set_{}", config.field_name(msg, signal))
+                };
+                writeln!(
+                    &mut w,
+                    "res.{}({})?;",
+                    setter,
+                    config.field_name(msg, signal)
+                )?;
+            }
+        }
+        writeln!(&mut w, "Ok(res)")?;
+    }
+    writeln!(&mut w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
+}
+
+/// Clippy's placeholder names, which signals like `Foo` turn into as parameters and bindings
+const DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
+
+/// Allow placeholder names on the next item if it binds one of `signals` by its field name.
+fn render_allow_disallowed_names<'a>(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    mut signals: impl Iterator<Item = &'a Signal>,
+) -> Result<()> {
+    if signals.any(|signal| DISALLOWED_NAMES.contains(&config.field_name(msg, signal).as_str())) {
+        writeln!(w, "#[allow(clippy::disallowed_names)]")?;
+    }
+    Ok(())
+}
+
+fn render_new_const(w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    let signals: Vec<&Signal> = msg
+        .signals
+        .iter()
+        .filter(|signal| {
+            signal.multiplexer == Multiplexer::Plain
+                || signal.multiplexer == Multiplexer::Multiplexor
+        })
+        .collect();
+    if signals.iter().any(|signal| !signal_supports_const(signal)) {
+        return Ok(());
+    }
+
+    let mut buf = Vec::new();
+    {
+        let mut w = &mut buf;
+        writeln!(
+            w,
+            "/// Construct new {} from values in a `const` context",
+            msg.name
+        )?;
+        writeln!(w, "///")?;
+        writeln!(w, "/// # Panics")?;
+        writeln!(w, "///")?;
+        writeln!(
+            w,
+            "/// Panics if a value is out of range. In a `const` item this is a compile error."
+        )?;
+        let args: Vec<String> = signals
+            .iter()
+            .map(|signal| {
+                format!(
+                    "{}: {}",
+                    config.field_name(msg, signal),
+                    signal_to_rust_type(signal)
+                )
+            })
+            .collect();
+        render_allow_disallowed_names(&mut w, config, msg, signals.iter().copied())?;
+        writeln!(w, "pub const fn new_const({}) -> Self {{", args.join(", "))?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(w, "let res = Self {{ raw: [0u8; {}] }};", msg.size)?;
+            for signal in &signals {
+                writeln!(
+                    w,
+                    "let res = match res.with_{field}({field}) {{ Ok(res) => res, Err(_) => panic!(\"{name} is out of range\") }};",
+                    field = config.field_name(msg, signal),
+                    name = signal.name,
+                )?;
+            }
+            writeln!(w, "res")?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+
+    config.impl_const_fns.fmt_cfg(w, |w| w.write_all(&buf))?;
+    Ok(())
+}
+
+fn render_const_signal(
+    mut w: impl Write,
+    config: &Config<'_>,
+    signal: &Signal,
+    msg: &Message,
+) -> Result<()> {
+    if matches!(config.impl_const_fns, FeatureConfig::Never) || !signal_supports_const(signal) {
+        return Ok(());
+    }
+
+    let mut buf = Vec::new();
+    {
+        let mut w = &mut buf;
+        writeln!(
+            w,
+            "/// Get raw value of {} in a `const` context",
+            signal.name
+        )?;
+        writeln!(w, "#[inline(always)]")?;
+        writeln!(
+            w,
+            "pub const fn {}_raw_const(&self) -> {} {{",
+            config.field_name(msg, signal),
+            signal_to_rust_type(signal)
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            signal_from_payload_const(&mut w, signal, msg).context("signal from payload")?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+    config
+        .impl_const_fns
+        .fmt_cfg(&mut w, |w| w.write_all(&buf))?;
+
+    let mut buf = Vec::new();
+    {
+        let mut w = &mut buf;
+        writeln!(w, "/// Set value of {} in a `const` context", signal.name)?;
+        writeln!(w, "#[inline(always)]")?;
+
+        // Like `set_…`, this is kept private for multiplexors.
+        let visibility = if signal.multiplexer == Multiplexer::Multiplexor {
+            ""
+        } else {
+            "pub "
+        };
+        writeln!(
+            w,
+            "{}const fn with_{}(self, value: {}) -> Result<Self, CanError> {{",
+            visibility,
+            config.field_name(msg, signal),
+            signal_to_rust_type(signal)
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            render_range_check(&mut w, config, signal, msg)?;
+            signal_to_payload_const(&mut w, config, signal, msg).context("signal to payload")?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+    config
+        .impl_const_fns
+        .fmt_cfg(&mut w, |w| w.write_all(&buf))?;
+
+    Ok(())
+}
+
+fn signal_from_payload_const(mut w: impl Write, signal: &Signal, msg: &Message) -> Result<()> {
+    let uint = signal_to_rust_uint(signal);
+
+    writeln!(&mut w, "let mut signal = 0{};", uint)?;
+    for chunk in signal_byte_chunks(signal, msg)? {
+        let mut part = format!("self.raw[{}]", chunk.byte);
+        if chunk.byte_shift != 0 {
+            part = format!("{} >> {}", part, chunk.byte_shift);
+        }
+        if chunk.width != 8 {
+            let mask = chunk.byte_mask()?.checked_shr(chunk.byte_shift as u32);
+            part = format!(
+                "{} & {:#04x}",
+                parens(&part),
+                mask.context("shifting mask")?
+            );
+        }
+        part = format!("{} as {}", parens(&part), uint);
+        if chunk.value_shift != 0 {
+            part = format!("{} << {}", parens(&part), chunk.value_shift);
+        }
+        writeln!(&mut w, "signal |= {};", part)?;
+    }
+
+    if signal.value_type == ValueType::Signed {
+        let int = signal_to_rust_int(signal);
+        let unused_bits = int
+            .trim_start_matches('i')
+            .parse::<u64>()?
+            .checked_sub(signal.size)
+            .context("signal is wider than its Rust type")?;
+        if unused_bits == 0 {
+            writeln!(&mut w, "let signal = signal as {};", int)?;
+        } else {
+            // Sign extend by shifting the sign bit into place and back
+            writeln!(
+                &mut w,
+                "let signal = ((signal << {bits}) as {int}) >> {bits};",
+                bits = unused_bits,
+                int = int
+            )?;
+        }
+    }
+    writeln!(&mut w)?;
+
+    if signal.size == 1 {
+        writeln!(&mut w, "signal == 1")?;
+    } else {
+        writeln!(&mut w, "let factor = {};", signal.factor)?;
+        let scaled_type = scaled_signal_to_rust_int(signal);
+
+        if signal.offset >= 0.0 {
+            writeln!(
+                &mut w,
+                "(signal as {}).saturating_mul(factor).saturating_add({})",
+                scaled_type, signal.offset,
+            )?;
+        } else {
+            writeln!(
+                &mut w,
+                "(signal as {}).saturating_mul(factor).saturating_sub({})",
+                scaled_type,
+                signal.offset.abs(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn signal_to_payload_const(
+    mut w: impl Write,
+    config: &Config<'_>,
+    signal: &Signal,
+    msg: &Message,
+) -> Result<()> {
+    if signal.size == 1 {
+        // Map boolean to byte so we can pack it
+        writeln!(&mut w, "let value = value as u8;")?;
+    } else {
+        writeln!(&mut w, "let factor = {};", signal.factor)?;
+        if signal.offset >= 0.0 {
+            writeln!(
+                &mut w,
+                "let value = match value.checked_sub({}) {{",
+                signal.offset
+            )?;
+        } else {
+            writeln!(
+                &mut w,
+                "let value = match value.checked_add({}) {{",
+                signal.offset.abs()
+            )?;
+        }
+        writeln!(&mut w, "    Some(value) => value,")?;
+        writeln!(
+            &mut w,
+            "    None => return Err(CanError::ParameterOutOfRange {{ message_id: {}::MESSAGE_ID }}),",
+            config.type_name(&msg.name)
+        )?;
+        writeln!(&mut w, "}};")?;
+        writeln!(
+            &mut w,
+            "let value = (value / factor) as {};",
+            signal_to_rust_int(signal)
+        )?;
+        writeln!(&mut w)?;
+    }
+
+    if signal.value_type == ValueType::Signed {
+        writeln!(
+            &mut w,
+            "let value = {}::from_ne_bytes(value.to_ne_bytes());",
+            signal_to_rust_uint(signal)
+        )?;
+    };
+
+    writeln!(&mut w, "let mut raw = self.raw;")?;
+    for chunk in signal_byte_chunks(signal, msg)? {
+        let mask = chunk.byte_mask()?;
+        let mut part = String::from("value");
+        if chunk.value_shift != 0 {
+            part = format!("{} >> {}", part, chunk.value_shift);
+        }
+        part = format!("{} as u8", parens(&part));
+        if chunk.byte_shift != 0 {
+            part = format!("{} << {}", parens(&part), chunk.byte_shift);
+        }
+        if mask == u8::MAX {
+            writeln!(&mut w, "raw[{}] = {};", chunk.byte, part)?;
+            continue;
+        }
+        writeln!(
+            &mut w,
+            "raw[{byte}] = (raw[{byte}] & {keep:#04x}) | ({part} & {mask:#04x});",
+            byte = chunk.byte,
+            keep = !mask,
+            part = parens(&part),
+            mask = mask,
+        )?;
+    }
+
+    writeln!(&mut w, "Ok(Self {{ raw }})")?;
+    Ok(())
+}
+
+/// Value table enum `type_name`, with the raw type of `signal`.
+fn write_enum(
+    mut w: impl Write,
+    config: &Config<'_>,
+    type_name: &str,
+    description: &str,
+    signal: &Signal,
+    variants: &[(f64, String)],
+) -> Result<()> {
+    let out_of_range = "CanError::UndefinedValue";
+    let signal_rust_type = signal_to_rust_type(signal);
+    let strict = enum_is_strict(config, signal, variants);
+    let variant_names = config.enum_variant_names(variants);
+
+    writeln!(w, "/// Defined values for {}", description)?;
+    writeln!(w, "#[derive(Clone, Copy, PartialEq)]")?;
+    config.fmt_comparison_derives(&mut w, signal_rust_type == "f32", true)?;
+    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
+    config
+        .impl_defmt
+        .fmt_attr(&mut w, "derive(defmt::Format)")?;
+    config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
+    config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
+    fmt_extra_attrs(&mut w, &config.enum_attributes)?;
+    writeln!(w, "pub enum {} {{", type_name)?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        for (variant, variant_name) in variants.iter().zip(&variant_names) {
+            if config.serde_representation == SerdeRepresentation::Signals {
+                config
+                    .impl_serde
+                    .fmt_attr(&mut w, format_args!("serde(rename = {:?})", variant.1))?;
+            }
+            writeln!(w, "{},", variant_name)?;
+        }
+        if !strict {
+            writeln!(w, "_Other({}),", signal_rust_type)?;
+        }
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    let match_on_raw_type = match signal_rust_type.as_str() {
+        "bool" => |x: f64| format!("{}", (x as i64) == 1),
+        "f32" => |x: f64| format!("{}_f32", x),
+        _ => |x: f64| format!("{}", x as i64),
+    };
+
+    writeln!(w, "impl {type_name} {{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(w, "/// All values defined in the DBC")?;
+        writeln!(w, "pub const VARIANTS: &'static [Self] = &[")?;
+        for variant_name in &variant_names {
+            writeln!(w, "    Self::{},", variant_name)?;
+        }
+        writeln!(w, "];")?;
+        writeln!(w)?;
+
+        writeln!(w, "/// Convert a raw signal value")?;
+        if strict {
+            writeln!(
+                w,
+                "pub fn from_raw(raw: {signal_rust_type}) -> Option<Self> {{"
+            )?;
+        } else {
+            writeln!(w, "pub fn from_raw(raw: {signal_rust_type}) -> Self {{")?;
+        }
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(w, "match raw {{")?;
+            for (variant, variant_name) in variants.iter().zip(&variant_names) {
+                let literal = match_on_raw_type(variant.0);
+                if strict {
+                    writeln!(w, "    {literal} => Some(Self::{variant_name}),")?;
+                } else {
+                    writeln!(w, "    {literal} => Self::{variant_name},")?;
+                }
+            }
+            if strict {
+                writeln!(w, "    _ => None,")?;
+            } else {
+                writeln!(w, "    x => Self::_Other(x),")?;
+            }
+            writeln!(w, "}}")?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+
+        writeln!(w, "/// Label of this value as written in the DBC")?;
+        if strict {
+            writeln!(w, "pub fn as_str(&self) -> &'static str {{")?;
+        } else {
+            writeln!(w, "///")?;
+            writeln!(w, "/// Returns `None` for values without a label.")?;
+            writeln!(w, "pub fn as_str(&self) -> Option<&'static str> {{")?;
+        }
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(w, "match self {{")?;
+            for (variant, variant_name) in variants.iter().zip(&variant_names) {
+                if strict {
+                    writeln!(w, "    Self::{variant_name} => {:?},", variant.1)?;
+                } else {
+                    writeln!(w, "    Self::{variant_name} => Some({:?}),", variant.1)?;
+                }
+            }
+            if !strict {
+                writeln!(w, "    Self::_Other(_) => None,")?;
+            }
+            writeln!(w, "}}")?;
+        }
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    if strict {
+        writeln!(
+            w,
+            "impl core::convert::TryFrom<{signal_rust_type}> for {type_name} {{"
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(w, "type Error = CanError;")?;
+            writeln!(w)?;
+            writeln!(
+                w,
+                "fn try_from(raw: {signal_rust_type}) -> Result<Self, Self::Error> {{"
+            )?;
+            writeln!(w, "    Self::from_raw(raw).ok_or({out_of_range})")?;
+            writeln!(w, "}}")?;
+        }
+    } else {
+        writeln!(w, "impl From<{signal_rust_type}> for {type_name} {{")?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(w, "fn from(raw: {signal_rust_type}) -> Self {{")?;
+            writeln!(w, "    Self::from_raw(raw)")?;
+            writeln!(w, "}}")?;
+        }
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl From<{type_name}> for {signal_rust_type} {{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(w, "fn from(val: {type_name}) -> {signal_rust_type} {{")?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+
+            writeln!(&mut w, "match val {{")?;
+            {
+                let mut w = PadAdapter::wrap(&mut w);
+                for (variant, variant_name) in variants.iter().zip(&variant_names) {
+                    let literal = match_on_raw_type(variant.0);
+                    writeln!(&mut w, "{}::{} => {},", type_name, variant_name, literal,)?;
+                }
+                if !strict {
+                    writeln!(&mut w, "{}::_Other(x) => x,", type_name,)?;
+                }
+            }
+            writeln!(w, "}}")?;
+        }
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl core::fmt::Display for {type_name} {{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(
+            w,
+            "fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+        )?;
+        if strict {
+            writeln!(w, "    f.write_str(self.as_str())")?;
+        } else {
+            writeln!(w, "    match self.as_str() {{")?;
+            writeln!(w, "        Some(label) => f.write_str(label),")?;
+            writeln!(
+                w,
+                "        None => write!(f, \"{{}}\", {signal_rust_type}::from(*self)),"
+            )?;
+            writeln!(w, "    }}")?;
+        }
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl core::str::FromStr for {type_name} {{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(w, "type Err = CanError;")?;
+        writeln!(w)?;
+        writeln!(w, "/// Parse a label as written in the DBC")?;
+        writeln!(w, "fn from_str(s: &str) -> Result<Self, Self::Err> {{")?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(w, "Self::VARIANTS")?;
+            writeln!(w, "    .iter()")?;
+            writeln!(
+                w,
+                "    .find(|v| v.as_str() == {})",
+                if strict { "s" } else { "Some(s)" }
+            )?;
+            writeln!(w, "    .copied()")?;
+            writeln!(w, "    .ok_or({out_of_range})")?;
+        }
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Whether a value table enum of this signal is generated without the `_Other` fallback,
+/// i.e. [Config::strict_enums] is set and the table covers every raw value of an unscaled,
+/// unsigned signal.
+fn enum_is_strict(config: &Config<'_>, signal: &Signal, variants: &[(f64, String)]) -> bool {
+    if !config.strict_enums
+        || signal.value_type != ValueType::Unsigned
+        || signal.size > 16
+        || signal.factor != 1.0
+        || signal.offset != 0.0
+    {
+        return false;
+    }
+    let values: BTreeSet<u64> = variants
+        .iter()
+        .filter(|v| v.0.fract() == 0.0 && v.0 >= 0.0)
+        .map(|v| v.0 as u64)
+        .collect();
+    (0..1u64 << signal.size).all(|raw| values.contains(&raw))
+}
+
+/// Determine the smallest rust integer that can fit the actual signal values,
+/// i.e. accounting for factor and offset.
+///
+/// NOTE: Factor and offset must be whole integers.
+fn scaled_signal_to_rust_int(signal: &Signal) -> String {
+    scaled_rust_int(
+        &signal.name,
+        signal.value_type,
+        signal.size,
+        signal.factor,
+        signal.offset,
+    )
+}
+
+fn scaled_rust_int(
+    name: &str,
+    value_type: ValueType,
+    size: u64,
+    factor: f64,
+    offset: f64,
+) -> String {
+    assert!(
+        factor.fract().abs() <= f64::EPSILON,
+        "Signal Factor ({}) should be an integer",
+        factor,
+    );
+    assert!(
+        offset.fract().abs() <= f64::EPSILON,
+        "Signal Offset ({}) should be an integer",
+        offset,
+    );
+
+    let err = format!("Signal {} could not be represented as a Rust integer", name);
+    signal_params_to_rust_int(value_type, size as u32, factor as i64, offset as i64).expect(&err)
+}
+
+/// Like [scaled_rust_int], but `None` instead of a panic if the scaled values don't fit into
+/// 128 bits, and instead of a wrong type if the factor or offset doesn't fit into an `i64`
+fn integer_rust_type(value_type: ValueType, size: u64, factor: f64, offset: f64) -> Option<String> {
+    // i64::MAX as f64 rounds up to 2^63, which is out of range
+    let fits_i64 = |x: f64| (-(2f64.powi(63))..2f64.powi(63)).contains(&x);
+    if !fits_i64(factor) || !fits_i64(offset) {
+        return None;
+    }
+    signal_params_to_rust_int(
+        value_type,
+        u32::try_from(size).ok()?,
+        factor as i64,
+        offset as i64,
+    )
+}
+
+/// Convert the relevant parameters of a `can_dbc::Signal` into a Rust type.
+fn signal_params_to_rust_int(
+    sign: ValueType,
+    signal_size: u32,
+    factor: i64,
+    offset: i64,
+) -> Option<String> {
+    if signal_size > 64 {
+        return None;
+    }
+    let range = get_range_of_values(sign, signal_size, factor, offset);
+    match range {
+        Some((low, high)) => Some(range_to_rust_int(low, high)),
+        _ => None,
+    }
+}
+
+/// Using the signal's parameters, find the range of values that it spans.
+fn get_range_of_values(
+    sign: ValueType,
+    signal_size: u32,
+    factor: i64,
+    offset: i64,
+) -> Option<(i128, i128)> {
+    if signal_size == 0 {
+        return None;
+    }
+    let low;
+    let high;
+    match sign {
+        ValueType::Signed => {
+            low = 1i128
+                .checked_shl(signal_size.saturating_sub(1))
+                .and_then(|n| n.checked_mul(-1));
+            high = 1i128
+                .checked_shl(signal_size.saturating_sub(1))
+                .and_then(|n| n.checked_sub(1));
+        }
+        ValueType::Unsigned => {
+            low = Some(0);
+            high = 1i128
+                .checked_shl(signal_size)
+                .and_then(|n| n.checked_sub(1));
+        }
+    }
+    let range1 = apply_factor_and_offset(low, factor, offset);
+    let range2 = apply_factor_and_offset(high, factor, offset);
+    match (range1, range2) {
+        (Some(a), Some(b)) => Some((min(a, b), max(a, b))),
+        _ => None,
+    }
+}
+
+fn apply_factor_and_offset(input: Option<i128>, factor: i64, offset: i64) -> Option<i128> {
+    input
+        .and_then(|n| n.checked_mul(factor.into()))
+        .and_then(|n| n.checked_add(offset.into()))
+}
+
+/// Determine the smallest Rust integer type that can fit the range of values
+/// Only values derived from 64 bit integers are supported, i.e. the range [-2^64-1, 2^64-1]
+fn range_to_rust_int(low: i128, high: i128) -> String {
+    let lower_bound: u8;
+    let upper_bound: u8;
+    let sign: &str;
+
+    if low < 0 {
+        // signed case
+        sign = "i";
+        lower_bound = match low {
+            n if n >= i8::MIN.into() => 8,
+            n if n >= i16::MIN.into() => 16,
+            n if n >= i32::MIN.into() => 32,
+            n if n >= i64::MIN.into() => 64,
+            _ => 128,
+        };
+        upper_bound = match high {
+            n if n <= i8::MAX.into() => 8,
+            n if n <= i16::MAX.into() => 16,
+            n if n <= i32::MAX.into() => 32,
+            n if n <= i64::MAX.into() => 64,
+            _ => 128,
+        };
+    } else {
+        sign = "u";
+        lower_bound = 8;
+        upper_bound = match high {
+            n if n <= u8::MAX.into() => 8,
+            n if n <= u16::MAX.into() => 16,
+            n if n <= u32::MAX.into() => 32,
+            n if n <= u64::MAX.into() => 64,
+            _ => 128,
+        };
+    }
+
+    let size = max(lower_bound, upper_bound);
+    format!("{sign}{size}")
+}
+
+/// Determine the smallest rust integer that can fit the raw signal values.
+fn signal_to_rust_int(signal: &Signal) -> String {
+    let sign = match signal.value_type {
+        ValueType::Signed => "i",
+        ValueType::Unsigned => "u",
+    };
+
+    let size = match signal.size {
+        n if n <= 8 => "8",
+        n if n <= 16 => "16",
+        n if n <= 32 => "32",
+        _ => "64",
+    };
+
+    format!("{sign}{size}")
+}
+
+/// Determine the smallest unsigned rust integer with no fewer bits than the signal.
+fn signal_to_rust_uint(signal: &Signal) -> String {
+    let size = match signal.size {
+        n if n <= 8 => "8",
+        n if n <= 16 => "16",
+        n if n <= 32 => "32",
+        _ => "64",
+    };
+
+    format!("u{}", size)
+}
+
+fn signal_is_float_in_rust(signal: &Signal) -> bool {
+    is_float_in_rust(signal.factor, signal.offset)
+}
+
+#[allow(clippy::float_cmp)]
+fn is_float_in_rust(factor: f64, offset: f64) -> bool {
+    offset.fract() != 0.0 || factor.fract() != 0.0
+}
+
+fn signal_to_rust_type(signal: &Signal) -> String {
+    rust_type(
+        &signal.name,
+        signal.value_type,
+        signal.size,
+        signal.factor,
+        signal.offset,
+    )
+}
+
+/// Rust type of a signal's physical value, shared by all backends and [ir::Signal::rust_type]
+fn rust_type(name: &str, value_type: ValueType, size: u64, factor: f64, offset: f64) -> String {
+    if size == 1 {
+        String::from("bool")
+    } else if is_float_in_rust(factor, offset) {
+        // If there is any scaling needed, go for float
+        String::from("f32")
+    } else {
+        scaled_rust_int(name, value_type, size, factor, offset)
+    }
+}
+
+/// Where the value table enum of a signal is defined.
+enum ValueEnum<'a> {
+    /// A named `VAL_TABLE_` with the same value descriptions
+    Table(&'a ValueTable),
+    /// The value descriptions of this or, with [Config::dedupe_value_tables], the first signal
+    /// with identical ones
+    Signal(&'a Message, &'a Signal),
+}
+
+/// Position of a value table enum's definition in the DBC, see [ValueEnum]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ValueEnumSource {
+    /// Index into the value tables
+    Table(usize),
+    /// Indices of the message and its signal
+    Signal(usize, usize),
+}
+
+/// All signals with value descriptions, in DBC order, with the positions of the message and
+/// signal. Multiplexors use their index enum instead.
+fn signals_with_values(
+    dbc: &Database,
+) -> impl Iterator<Item = (usize, usize, &Message, &Signal, &[(f64, String)])> {
+    dbc.messages.iter().enumerate().flat_map(|(m, msg)| {
+        msg.signals
+            .iter()
+            .enumerate()
+            .filter(|(_, signal)| {
+                !signal.values.is_empty() && signal.multiplexer != Multiplexer::Multiplexor
+            })
+            .map(move |(s, signal)| (m, s, msg, signal, signal.values.as_slice()))
+    })
+}
+
+/// Decide once per DBC which enum each signal with value descriptions uses.
+///
+/// A shared enum takes its raw type from the first signal using it, so only signals with the
+/// same raw type (and strictness) share it.
+fn resolve_value_enums(
+    config: &Config<'_>,
+    dbc: &Database,
+) -> BTreeMap<MessageId, BTreeMap<String, ValueEnumSource>> {
+    let key = |signal: &Signal, variants: &[(f64, String)]| {
+        (
+            signal_to_rust_type(signal),
+            enum_is_strict(config, signal, variants),
+        )
+    };
+    // First signal per set of value descriptions, bucketed by their text
+    let mut firsts: BTreeMap<String, Vec<_>> = BTreeMap::new();
+    let mut value_enums: BTreeMap<MessageId, BTreeMap<String, ValueEnumSource>> = BTreeMap::new();
+
+    for (m, s, msg, signal, variants) in signals_with_values(dbc) {
+        let bucket = firsts.entry(format!("{variants:?}")).or_default();
+        let (first_m, first_s, _) = match bucket.iter().find(|(_, _, v)| *v == variants) {
+            Some(first) => *first,
+            None => {
+                bucket.push((m, s, variants));
+                (m, s, variants)
+            }
+        };
+        let first_signal = &dbc.messages[first_m].signals[first_s];
+
+        let mut source = ValueEnumSource::Signal(m, s);
+        if key(first_signal, variants) == key(signal, variants) {
+            if let Some(table) = dbc
+                .value_tables
+                .iter()
+                .position(|t| t.values.as_slice() == variants)
+            {
+                source = ValueEnumSource::Table(table);
+            } else if config.dedupe_value_tables {
+                source = ValueEnumSource::Signal(first_m, first_s);
+            }
+        }
+        value_enums
+            .entry(msg.id)
+            .or_default()
+            .insert(signal.name.clone(), source);
+    }
+    value_enums
+}
+
+/// Value table enum of a signal, if any.
+fn value_enum<'a>(
+    config: &Config<'_>,
+    dbc: &'a Database,
+    msg: &Message,
+    signal: &Signal,
+) -> Option<ValueEnum<'a>> {
+    match config.value_enums.get(&msg.id)?.get(signal.name.as_str())? {
+        ValueEnumSource::Table(table) => dbc.value_tables.get(*table).map(ValueEnum::Table),
+        ValueEnumSource::Signal(m, s) => {
+            let msg = dbc.messages.get(*m)?;
+            Some(ValueEnum::Signal(msg, msg.signals.get(*s)?))
+        }
+    }
+}
+
+/// Type name of the value table enum of a signal, if any.
+fn value_enum_name(
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+    signal: &Signal,
+) -> Option<String> {
+    value_enum(config, dbc, msg, signal).map(|value_enum| match value_enum {
+        ValueEnum::Table(table) => config.value_table_name(&table.name),
+        ValueEnum::Signal(msg, signal) => config.enum_name(msg, signal),
+    })
+}
+
+/// Name of the setter taking the raw signal type, e.g. `set_three_raw` next to `set_three`
+/// taking the value table enum.
+fn setter_name(config: &Config<'_>, dbc: &Database, msg: &Message, signal: &Signal) -> String {
+    if value_enum_name(config, dbc, msg, signal).is_some() {
+        format!("set_{}_raw", config.field_name(msg, signal))
+    } else {
+        format!("set_{}", config.field_name(msg, signal))
+    }
+}
+
+/// DBC label of a multiplexor value, if the multiplexor has value descriptions.
+fn multiplexor_value_label(multiplexor: &Signal, switch_index: u64) -> Option<&str> {
+    multiplexor
+        .values
+        .iter()
+        .find(|v| v.0 == switch_index as f64)
+        .map(|v| v.1.as_str())
+}
+
+/// Index enum variant for a multiplexor value, named after its DBC label or `M{n}`.
+fn multiplexed_enum_variant_wrapper_name(
+    config: &Config<'_>,
+    msg: &Message,
+    multiplexor: &Signal,
+    switch_index: u64,
+) -> String {
+    let indexes = multiplexer_indexes(msg);
+    let idents = naming::disambiguate(
+        indexes
+            .iter()
+            .map(|index| match multiplexor_value_label(multiplexor, *index) {
+                Some(label) => config.mangled_enum_variant_name(label),
+                None => format!("M{}", index),
+            })
+            .collect(),
+        "",
+    );
+    indexes
+        .iter()
+        .position(|index| *index == switch_index)
+        .and_then(|i| idents.into_iter().nth(i))
+        .unwrap_or_else(|| format!("M{}", switch_index))
+}
+
+fn render_embedded_can_frame(
+    w: &mut impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+) -> Result<(), std::io::Error> {
+    config.impl_embedded_can_frame.fmt_cfg(w, |w| {
+        writeln!(
+            w,
+            "\
+impl embedded_can::Frame for {0} {{
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {{
+        if id.into() != Self::MESSAGE_ID {{
+            None
+        }} else {{
+            data.try_into().ok()
+        }}
+    }}
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {{
+        unimplemented!()
+    }}
+
+    fn is_extended(&self) -> bool {{
+        match self.id() {{
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }}
+    }}
+
+    fn is_remote_frame(&self) -> bool {{
+        false
+    }}
+
+    fn id(&self) -> Id {{
+        Self::MESSAGE_ID
+    }}
+
+    fn dlc(&self) -> usize {{
+        self.raw.len()
+    }}
 
-    writeln!(&mut w, "Ok(())")?;
-    Ok(())
+    fn data(&self) -> &[u8] {{
+        &self.raw
+    }}
+}}",
+            config.type_name(&msg.name)
+        )
+    })
 }
 
-fn write_enum(
+fn render_debug_impl(w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    let signals: Vec<&Signal> = msg
+        .signals
+        .iter()
+        .filter(|signal| {
+            matches!(
+                signal.multiplexer,
+                Multiplexer::Plain | Multiplexer::Multiplexor
+            )
+        })
+        .collect();
+    render_debug_impl_for(w, config, msg, &config.type_name(&msg.name), &signals)
+}
+
+/// Debug impl printing the given signals; a multiplexor prints the selected `…M{n}` struct.
+fn render_debug_impl_for(
     mut w: impl Write,
     config: &Config<'_>,
-    signal: &Signal,
     msg: &Message,
-    variants: &[ValDescription],
+    typ: &str,
+    signals: &[&Signal],
 ) -> Result<()> {
-    let type_name = enum_name(msg, signal);
-    let signal_rust_type = signal_to_rust_type(signal);
-
-    writeln!(w, "/// Defined values for {}", signal.name())?;
-    writeln!(w, "#[derive(Clone, Copy, PartialEq)]")?;
-    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
-    config
-        .impl_defmt
-        .fmt_attr(&mut w, "derive(defmt::Format)")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
-    writeln!(w, "pub enum {} {{", type_name)?;
-    {
-        let mut w = PadAdapter::wrap(&mut w);
-        for variant in variants {
-            writeln!(w, "{},", enum_variant_name(variant.b()))?;
-        }
-        writeln!(w, "_Other({}),", signal_rust_type)?;
+    match &config.impl_debug {
+        FeatureConfig::Always => {}
+        FeatureConfig::Gated(gate) => writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?,
+        FeatureConfig::Never => return Ok(()),
     }
-    writeln!(w, "}}")?;
-    writeln!(w)?;
 
-    writeln!(w, "impl From<{type_name}> for {signal_rust_type} {{")?;
-    {
-        let match_on_raw_type = match signal_to_rust_type(signal).as_str() {
-            "bool" => |x: f64| format!("{}", (x as i64) == 1),
-            "f32" => |x: f64| format!("{}_f32", x),
-            _ => |x: f64| format!("{}", x as i64),
-        };
+    let multiplexed = signals
+        .iter()
+        .any(|s| s.multiplexer == Multiplexer::Multiplexor);
 
+    writeln!(w, r##"impl core::fmt::Debug for {} {{"##, typ)?;
+    {
         let mut w = PadAdapter::wrap(&mut w);
-        writeln!(w, "fn from(val: {type_name}) -> {signal_rust_type} {{")?;
+        writeln!(
+            w,
+            "fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+        )?;
         {
             let mut w = PadAdapter::wrap(&mut w);
-
-            writeln!(&mut w, "match val {{")?;
-            {
+            writeln!(w, r#"if f.alternate() {{"#)?;
+            if multiplexed {
                 let mut w = PadAdapter::wrap(&mut w);
-                for variant in variants {
-                    let literal = match_on_raw_type(*variant.a());
-                    writeln!(
-                        &mut w,
-                        "{}::{} => {},",
-                        type_name,
-                        enum_variant_name(variant.b()),
-                        literal,
-                    )?;
+                writeln!(w, r#"let mut d = f.debug_struct("{}");"#, typ)?;
+                for signal in signals {
+                    let field_name = config.field_name(msg, signal);
+                    if signal.multiplexer == Multiplexer::Multiplexor {
+                        writeln!(w, "match self.{field_name}() {{")?;
+                        writeln!(
+                            w,
+                            r#"    Ok(multiplexor) => d.field("{field_name}", &multiplexor),"#
+                        )?;
+                        writeln!(
+                            w,
+                            r#"    Err(_) => d.field("{field_name}", &self.{field_name}_raw()),"#
+                        )?;
+                        writeln!(w, "}};")?;
+                    } else {
+                        writeln!(w, r#"d.field("{field_name}", &self.{field_name}());"#)?;
+                    }
+                }
+                writeln!(w, r#"d.finish()"#)?;
+            } else {
+                let mut w = PadAdapter::wrap(&mut w);
+                writeln!(w, r#"f.debug_struct("{}")"#, typ)?;
+                {
+                    let mut w = PadAdapter::wrap(&mut w);
+                    for signal in signals {
+                        writeln!(
+                            w,
+                            r#".field("{field_name}", &self.{field_name}())"#,
+                            field_name = config.field_name(msg, signal),
+                        )?;
+                    }
                 }
-                writeln!(&mut w, "{}::_Other(x) => x,", type_name,)?;
+                writeln!(w, r#".finish()"#)?;
+            }
+            writeln!(w, r#"}} else {{"#)?;
+            {
+                let mut w = PadAdapter::wrap(&mut w);
+                writeln!(w, r#"f.debug_tuple("{}").field(&self.raw).finish()"#, typ)?;
             }
             writeln!(w, "}}")?;
         }
@@ -1046,349 +3339,562 @@ fn write_enum(
     Ok(())
 }
 
-/// Determine the smallest rust integer that can fit the actual signal values,
-/// i.e. accounting for factor and offset.
-///
-/// NOTE: Factor and offset must be whole integers.
-fn scaled_signal_to_rust_int(signal: &Signal) -> String {
-    assert!(
-        signal.factor.fract().abs() <= f64::EPSILON,
-        "Signal Factor ({}) should be an integer",
-        signal.factor,
-    );
-    assert!(
-        signal.offset.fract().abs() <= f64::EPSILON,
-        "Signal Offset ({}) should be an integer",
-        signal.offset,
-    );
-
-    let err = format!(
-        "Signal {} could not be represented as a Rust integer",
-        &signal.name()
-    );
-    signal_params_to_rust_int(
-        *signal.value_type(),
-        signal.signal_size as u32,
-        signal.factor as i64,
-        signal.offset as i64,
-    )
-    .expect(&err)
+fn render_defmt_impl(w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    let signals: Vec<&Signal> = msg
+        .signals
+        .iter()
+        .filter(|signal| {
+            matches!(
+                signal.multiplexer,
+                Multiplexer::Plain | Multiplexer::Multiplexor
+            )
+        })
+        .collect();
+    render_defmt_impl_for(w, config, msg, &config.type_name(&msg.name), &signals)
 }
 
-/// Convert the relevant parameters of a `can_dbc::Signal` into a Rust type.
-fn signal_params_to_rust_int(
-    sign: can_dbc::ValueType,
-    signal_size: u32,
-    factor: i64,
-    offset: i64,
-) -> Option<String> {
-    if signal_size > 64 {
-        return None;
-    }
-    let range = get_range_of_values(sign, signal_size, factor, offset);
-    match range {
-        Some((low, high)) => Some(range_to_rust_int(low, high)),
-        _ => None,
+/// defmt impl printing the given signals; a multiplexor prints the selected `…M{n}` struct.
+fn render_defmt_impl_for(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    typ: &str,
+    signals: &[&Signal],
+) -> Result<()> {
+    match &config.impl_defmt {
+        FeatureConfig::Always => {}
+        FeatureConfig::Gated(gate) => writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?,
+        FeatureConfig::Never => return Ok(()),
     }
-}
 
-/// Using the signal's parameters, find the range of values that it spans.
-fn get_range_of_values(
-    sign: can_dbc::ValueType,
-    signal_size: u32,
-    factor: i64,
-    offset: i64,
-) -> Option<(i128, i128)> {
-    if signal_size == 0 {
-        return None;
-    }
-    let low;
-    let high;
-    match sign {
-        can_dbc::ValueType::Signed => {
-            low = 1i128
-                .checked_shl(signal_size.saturating_sub(1))
-                .and_then(|n| n.checked_mul(-1));
-            high = 1i128
-                .checked_shl(signal_size.saturating_sub(1))
-                .and_then(|n| n.checked_sub(1));
+    let multiplexor = signals
+        .iter()
+        .find(|s| s.multiplexer == Multiplexer::Multiplexor);
+
+    // `multiplexor_arg` is what gets printed for the multiplexor signal, if any
+    let write_call = |w: &mut dyn Write, multiplexor_arg: &str| -> Result<()> {
+        writeln!(w, r#"defmt::write!(f,"#)?;
+        {
+            let mut w = PadAdapter::wrap(&mut *w);
+            write!(w, r#""{} {{{{"#, typ)?;
+            for signal in signals {
+                write!(w, r#" {}={{:?}}"#, signal.name,)?;
+            }
+            writeln!(w, r#" }}}}","#)?;
+
+            for signal in signals {
+                if signal.multiplexer == Multiplexer::Multiplexor {
+                    writeln!(w, "{},", multiplexor_arg)?;
+                } else {
+                    writeln!(w, "self.{}(),", config.field_name(msg, signal))?;
+                }
+            }
         }
-        can_dbc::ValueType::Unsigned => {
-            low = Some(0);
-            high = 1i128
-                .checked_shl(signal_size)
-                .and_then(|n| n.checked_sub(1));
+        writeln!(w, r#");"#)?;
+        Ok(())
+    };
+
+    writeln!(w, r##"impl defmt::Format for {} {{"##, typ)?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(w, "fn format(&self, f: defmt::Formatter) {{")?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            if let Some(multiplexor) = multiplexor {
+                let field_name = config.field_name(msg, multiplexor);
+                writeln!(w, "match self.{field_name}() {{")?;
+                {
+                    let mut w = PadAdapter::wrap(&mut w);
+                    writeln!(w, "Ok(multiplexor) => {{")?;
+                    write_call(&mut PadAdapter::wrap(&mut w), "multiplexor")?;
+                    writeln!(w, "}}")?;
+                    writeln!(w, "Err(_) => {{")?;
+                    write_call(
+                        &mut PadAdapter::wrap(&mut w),
+                        &format!("self.{field_name}_raw()"),
+                    )?;
+                    writeln!(w, "}}")?;
+                }
+                writeln!(w, "}}")?;
+            } else {
+                write_call(&mut w, "")?;
+            }
+            writeln!(w, "}}")?;
         }
     }
-    let range1 = apply_factor_and_offset(low, factor, offset);
-    let range2 = apply_factor_and_offset(high, factor, offset);
-    match (range1, range2) {
-        (Some(a), Some(b)) => Some((min(a, b), max(a, b))),
-        _ => None,
-    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    Ok(())
 }
 
-fn apply_factor_and_offset(input: Option<i128>, factor: i64, offset: i64) -> Option<i128> {
-    input
-        .and_then(|n| n.checked_mul(factor.into()))
-        .and_then(|n| n.checked_add(offset.into()))
-}
+fn render_multiplexor_enums(
+    mut w: impl Write,
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+    tree: &MuxTree<'_>,
+) -> Result<()> {
+    let multiplexor_signal = tree.multiplexor;
+    let multiplexed_signals = &tree.groups;
 
-/// Determine the smallest Rust integer type that can fit the range of values
-/// Only values derived from 64 bit integers are supported, i.e. the range [-2^64-1, 2^64-1]
-fn range_to_rust_int(low: i128, high: i128) -> String {
-    let lower_bound: u8;
-    let upper_bound: u8;
-    let sign: &str;
+    writeln!(w, "/// Defined values for multiplexed signal {}", msg.name)?;
 
-    if low < 0 {
-        // signed case
-        sign = "i";
-        lower_bound = match low {
-            n if n >= i8::MIN.into() => 8,
-            n if n >= i16::MIN.into() => 16,
-            n if n >= i32::MIN.into() => 32,
-            n if n >= i64::MIN.into() => 64,
-            _ => 128,
-        };
-        upper_bound = match high {
-            n if n <= i8::MAX.into() => 8,
-            n if n <= i16::MAX.into() => 16,
-            n if n <= i32::MAX.into() => 32,
-            n if n <= i64::MAX.into() => 64,
-            _ => 128,
-        };
-    } else {
-        sign = "u";
-        lower_bound = 8;
-        upper_bound = match high {
-            n if n <= u8::MAX.into() => 8,
-            n if n <= u16::MAX.into() => 16,
-            n if n <= u32::MAX.into() => 32,
-            n if n <= u64::MAX.into() => 64,
-            _ => 128,
-        };
-    }
+    config.fmt_comparison_derives(&mut w, false, false)?;
+    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
+    config
+        .impl_defmt
+        .fmt_attr(&mut w, "derive(defmt::Format)")?;
+    config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
+    config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
+    fmt_extra_attrs(&mut w, &config.enum_attributes)?;
+    writeln!(
+        w,
+        "pub enum {} {{",
+        config.multiplex_enum_name(msg, multiplexor_signal)?
+    )?;
 
-    let size = max(lower_bound, upper_bound);
-    format!("{sign}{size}")
-}
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        for (switch_index, _multiplexed_signals) in multiplexed_signals.iter() {
+            if config.serde_representation == SerdeRepresentation::Signals {
+                if let Some(label) = multiplexor_value_label(multiplexor_signal, *switch_index) {
+                    config
+                        .impl_serde
+                        .fmt_attr(&mut w, format_args!("serde(rename = {:?})", label))?;
+                }
+            }
+            writeln!(
+                w,
+                "{multiplexed_wrapper_name}({multiplexed_name}),",
+                multiplexed_wrapper_name = multiplexed_enum_variant_wrapper_name(
+                    config,
+                    msg,
+                    multiplexor_signal,
+                    *switch_index
+                ),
+                multiplexed_name =
+                    config.multiplexed_enum_variant_name(msg, multiplexor_signal, *switch_index)?
+            )?;
+        }
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
 
-/// Determine the smallest rust integer that can fit the raw signal values.
-fn signal_to_rust_int(signal: &Signal) -> String {
-    let sign = match signal.value_type() {
-        can_dbc::ValueType::Signed => "i",
-        can_dbc::ValueType::Unsigned => "u",
-    };
+    for (switch_index, multiplexed_signals) in multiplexed_signals.iter() {
+        let struct_name =
+            config.multiplexed_enum_variant_name(msg, multiplexor_signal, *switch_index)?;
 
-    let size = match signal.signal_size() {
-        ..=8 => "8",
-        ..=16 => "16",
-        ..=32 => "32",
-        _ => "64",
-    };
+        config.fmt_comparison_derives(&mut w, false, false)?;
+        if config.serde_representation == SerdeRepresentation::Raw {
+            config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
+            config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
+        }
+        writeln!(w, r##"#[derive(Default)]"##)?;
+        fmt_extra_attrs(&mut w, &config.message_attributes)?;
+        writeln!(w, "pub struct {} {{", struct_name)?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            if config.serde_representation == SerdeRepresentation::Raw {
+                config
+                    .impl_serde
+                    .fmt_attr(&mut w, "serde(with = \"serde_bytes\")")?;
+            }
+            writeln!(w, "raw: [u8; {}],", msg.size)?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
 
-    format!("{sign}{size}")
-}
+        writeln!(w, "impl {} {{", struct_name)?;
 
-/// Determine the smallest unsigned rust integer with no fewer bits than the signal.
-fn signal_to_rust_uint(signal: &Signal) -> String {
-    let size = match *signal.signal_size() {
-        n if n <= 8 => "8",
-        n if n <= 16 => "16",
-        n if n <= 32 => "32",
-        _ => "64",
-    };
+        writeln!(
+            w,
+            "pub fn new() -> Self {{ Self {{ raw: [0u8; {}] }} }}",
+            msg.size
+        )?;
 
-    format!("u{}", size)
-}
+        for signal in multiplexed_signals {
+            render_signal(&mut w, config, signal, dbc, msg, Receiver::Owned)?;
+        }
 
-#[allow(clippy::float_cmp)]
-fn signal_is_float_in_rust(signal: &Signal) -> bool {
-    signal.offset.fract() != 0.0 || signal.factor.fract() != 0.0
-}
+        writeln!(w, "}}")?;
+        writeln!(w)?;
 
-fn signal_to_rust_type(signal: &Signal) -> String {
-    if signal.signal_size == 1 {
-        String::from("bool")
-    } else if signal_is_float_in_rust(signal) {
-        // If there is any scaling needed, go for float
-        String::from("f32")
-    } else {
-        scaled_signal_to_rust_int(signal)
-    }
-}
+        render_debug_impl_for(&mut w, config, msg, &struct_name, multiplexed_signals)?;
+        render_defmt_impl_for(&mut w, config, msg, &struct_name, multiplexed_signals)?;
+        render_arbitrary_multiplexed(&mut w, config, dbc, msg, &struct_name, multiplexed_signals)?;
 
-fn type_name(x: &str) -> String {
-    if keywords::is_keyword(x) || !x.starts_with(|c: char| c.is_ascii_alphabetic()) {
-        format!("X{}", x.to_pascal_case())
-    } else {
-        x.to_pascal_case()
+        render_serde_signals_multiplexed(
+            &mut w,
+            config,
+            dbc,
+            msg,
+            &struct_name,
+            multiplexed_signals,
+        )?;
     }
+
+    Ok(())
 }
 
-fn field_name(x: &str) -> String {
-    if keywords::is_keyword(x) || !x.starts_with(|c: char| c.is_ascii_alphabetic()) {
-        format!("x{}", x.to_snake_case())
-    } else {
-        x.to_snake_case()
+fn render_arbitrary(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    dbc: &Database,
+) -> Result<()> {
+    match &config.impl_arbitrary {
+        FeatureConfig::Always => {}
+        FeatureConfig::Gated(gate) => writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?,
+        FeatureConfig::Never => return Ok(()),
     }
-}
 
-fn enum_name(msg: &Message, signal: &Signal) -> String {
-    // this turns signal `_4DRIVE` into `4drive`
-    let signal_name = signal
-        .name()
-        .trim_start_matches(|c: char| c.is_ascii_punctuation());
+    let multiplexer_indexes: Vec<u64> = multiplexer_indexes(msg).into_iter().collect();
 
-    format!(
-        "{}{}",
-        enum_variant_name(msg.message_name()),
-        signal_name.to_pascal_case()
-    )
-}
+    writeln!(
+        w,
+        "impl<'a> Arbitrary<'a> for {typ} {{",
+        typ = config.type_name(&msg.name)
+    )?;
+    {
+        let filtered_signals: Vec<&Signal> = msg
+            .signals
+            .iter()
+            .filter(|signal| {
+                signal.multiplexer == Multiplexer::Plain
+                    || signal.multiplexer == Multiplexer::Multiplexor
+            })
+            .collect();
+        let mut w = PadAdapter::wrap(&mut w);
+        render_allow_disallowed_names(&mut w, config, msg, filtered_signals.iter().copied())?;
+        writeln!(
+            w,
+            "fn arbitrary({}u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {{",
+            if filtered_signals.is_empty() && multiplexer_indexes.is_empty() {
+                "_"
+            } else {
+                ""
+            },
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
 
-fn enum_variant_name(x: &str) -> String {
-    if keywords::is_keyword(x) || !x.starts_with(|c: char| c.is_ascii_alphabetic()) {
-        format!("X{}", x.to_pascal_case())
-    } else {
-        x.to_pascal_case()
+            for signal in filtered_signals.iter() {
+                writeln!(
+                    w,
+                    "let {field_name} = {arbitrary_value};",
+                    field_name = config.field_name(msg, signal),
+                    arbitrary_value = signal_to_arbitrary(signal),
+                )?;
+            }
+
+            let args: Vec<String> = filtered_signals
+                .iter()
+                .map(|signal| config.field_name(msg, signal))
+                .collect();
+            let new = if filtered_signals
+                .iter()
+                .any(|signal| value_enum_name(config, dbc, msg, signal).is_some())
+            {
+                "new_raw"
+            } else {
+                "new"
+            };
+
+            if multiplexer_indexes.is_empty() {
+                writeln!(
+                    w,
+                    "{typ}::{new}({args}).map_err(|_| arbitrary::Error::IncorrectFormat)",
+                    typ = config.type_name(&msg.name),
+                    args = args.join(",")
+                )?;
+            } else {
+                writeln!(
+                    w,
+                    "let mut msg = {typ}::{new}({args}).map_err(|_| arbitrary::Error::IncorrectFormat)?;",
+                    typ = config.type_name(&msg.name),
+                    args = args.join(",")
+                )?;
+                // Pick one of the defined multiplexor values and fill its signals
+                let multiplexor = msg
+                    .signals
+                    .iter()
+                    .find(|s| s.multiplexer == Multiplexer::Multiplexor)
+                    .context("multiplexed signals without multiplexor")?;
+                let last = multiplexer_indexes.len().saturating_sub(1);
+                writeln!(w, "match u.int_in_range(0..={last}_usize)? {{")?;
+                for (i, index) in multiplexer_indexes.iter().enumerate() {
+                    let pattern = if i == last {
+                        "_".to_string()
+                    } else {
+                        i.to_string()
+                    };
+                    writeln!(
+                        w,
+                        "    {pattern} => msg.set_{}(u.arbitrary()?),",
+                        multiplexed_enum_variant_wrapper_name(config, msg, multiplexor, *index)
+                            .to_snake_case()
+                    )?;
+                }
+                writeln!(w, "}}")?;
+                writeln!(w, ".map_err(|_| arbitrary::Error::IncorrectFormat)?;")?;
+                writeln!(w, "Ok(msg)")?;
+            }
+        }
+        writeln!(w, "}}")?;
     }
-}
+    writeln!(w, "}}")?;
+    writeln!(w)?;
 
-fn multiplexed_enum_variant_wrapper_name(switch_index: u64) -> String {
-    format!("M{}", switch_index)
+    Ok(())
 }
 
-fn multiplex_enum_name(msg: &Message, multiplexor: &Signal) -> Result<String> {
-    ensure!(
-        matches!(
-            multiplexor.multiplexer_indicator(),
-            MultiplexIndicator::Multiplexor
-        ),
-        "signal {:?} is not the multiplexor",
-        multiplexor
-    );
-    Ok(format!(
-        "{}{}Index",
-        msg.message_name().to_pascal_case(),
-        multiplexor.name().to_pascal_case()
-    ))
+fn render_arbitrary_multiplexed(
+    mut w: impl Write,
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+    struct_name: &str,
+    multiplexed_signals: &[&Signal],
+) -> Result<()> {
+    match &config.impl_arbitrary {
+        FeatureConfig::Always => {}
+        FeatureConfig::Gated(gate) => writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?,
+        FeatureConfig::Never => return Ok(()),
+    }
+
+    writeln!(w, "impl<'a> Arbitrary<'a> for {struct_name} {{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        writeln!(
+            w,
+            "fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {{"
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut w);
+            writeln!(w, "let mut msg = Self::new();")?;
+            for signal in multiplexed_signals {
+                writeln!(
+                    w,
+                    "msg.{setter}({arbitrary_value}).map_err(|_| arbitrary::Error::IncorrectFormat)?;",
+                    setter = setter_name(config, dbc, msg, signal),
+                    arbitrary_value = signal_to_arbitrary(signal),
+                )?;
+            }
+            writeln!(w, "Ok(msg)")?;
+        }
+        writeln!(w, "}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    Ok(())
 }
 
-fn multiplexed_enum_variant_name(
-    msg: &Message,
-    multiplexor: &Signal,
-    switch_index: u64,
-) -> Result<String> {
-    ensure!(
-        matches!(
-            multiplexor.multiplexer_indicator(),
-            MultiplexIndicator::Multiplexor
-        ),
-        "signal {:?} is not the multiplexor",
-        multiplexor
-    );
+fn render_view_accessors(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
+    let (view_ref, view_mut) = config.view_names(&msg.name);
+
+    config.impl_views.fmt_cfg(&mut w, |w| {
+        writeln!(w, "/// Borrow the payload as a [`{view_ref}`]")?;
+        writeln!(w, "pub fn view(&self) -> {view_ref}<'_> {{")?;
+        writeln!(w, "    {view_ref} {{ raw: &self.raw }}")?;
+        writeln!(w, "}}")?;
+        writeln!(w)
+    })?;
+    config.impl_views.fmt_cfg(&mut w, |w| {
+        writeln!(w, "/// Mutably borrow the payload as a [`{view_mut}`]")?;
+        writeln!(w, "pub fn view_mut(&mut self) -> {view_mut}<'_> {{")?;
+        writeln!(w, "    {view_mut} {{ raw: &mut self.raw }}")?;
+        writeln!(w, "}}")?;
+        writeln!(w)
+    })?;
 
-    Ok(format!(
-        "{}{}M{}",
-        msg.message_name().to_pascal_case(),
-        multiplexor.name().to_pascal_case(),
-        switch_index
-    ))
+    Ok(())
 }
 
-fn render_embedded_can_frame(
-    w: &mut impl Write,
+fn render_views(
+    mut w: impl Write,
     config: &Config<'_>,
     msg: &Message,
-) -> Result<(), std::io::Error> {
-    config.impl_embedded_can_frame.fmt_cfg(w, |w| {
-        writeln!(
-            w,
-            "\
-impl embedded_can::Frame for {0} {{
-    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {{
-        if id.into() != Self::MESSAGE_ID {{
-            None
-        }} else {{
-            data.try_into().ok()
-        }}
-    }}
+    dbc: &Database,
+) -> Result<()> {
+    if matches!(config.impl_views, FeatureConfig::Never) {
+        return Ok(());
+    }
 
-    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {{
-        unimplemented!()
-    }}
+    let typ = config.type_name(&msg.name);
+    let size = msg.size;
 
-    fn is_extended(&self) -> bool {{
-        match self.id() {{
-            Id::Standard(_) => false,
-            Id::Extended(_) => true,
-        }}
-    }}
+    let (view_ref, view_mut) = config.view_names(&msg.name);
 
-    fn is_remote_frame(&self) -> bool {{
-        false
-    }}
+    for (receiver, reference) in [(Receiver::Ref, "&'a"), (Receiver::Mut, "&'a mut")] {
+        let (view, description) = match receiver {
+            Receiver::Ref => (view_ref.clone(), "Borrowed"),
+            _ => (view_mut.clone(), "Mutably borrowed"),
+        };
 
-    fn id(&self) -> Id {{
-        Self::MESSAGE_ID
-    }}
+        let mut buf = Vec::new();
+        writeln!(buf, "/// {} view of a {} payload", description, msg.name)?;
+        writeln!(buf, "///")?;
+        writeln!(
+            buf,
+            "/// Accesses signals in place, without copying the payload."
+        )?;
+        if receiver == Receiver::Ref {
+            writeln!(buf, "#[derive(Clone, Copy)]")?;
+        }
+        writeln!(buf, "pub struct {view}<'a> {{")?;
+        writeln!(buf, "    raw: {reference} [u8; {size}],")?;
+        writeln!(buf, "}}")?;
+        writeln!(buf)?;
+        config.impl_views.fmt_cfg(&mut w, |w| w.write_all(&buf))?;
+
+        let mut buf = Vec::new();
+        writeln!(buf, "impl<'a> {view}<'a> {{")?;
+        {
+            let mut w = PadAdapter::wrap(&mut buf);
+            writeln!(w, "/// View a payload buffer as {}", msg.name)?;
+            writeln!(
+                w,
+                "pub fn from_raw(raw: {reference} [u8; {size}]) -> Self {{"
+            )?;
+            writeln!(w, "    Self {{ raw }}")?;
+            writeln!(w, "}}")?;
+            writeln!(w)?;
 
-    fn dlc(&self) -> usize {{
-        self.raw.len()
-    }}
+            writeln!(w, "/// Access message payload raw value")?;
+            writeln!(w, "pub fn raw(&self) -> &[u8; {size}] {{")?;
+            writeln!(w, "    self.raw")?;
+            writeln!(w, "}}")?;
+            writeln!(w)?;
+
+            if receiver == Receiver::Mut {
+                writeln!(w, "/// Mutably access message payload raw value")?;
+                writeln!(w, "pub fn raw_mut(&mut self) -> &mut [u8; {size}] {{")?;
+                writeln!(w, "    self.raw")?;
+                writeln!(w, "}}")?;
+                writeln!(w)?;
+            }
 
-    fn data(&self) -> &[u8] {{
-        &self.raw
-    }}
-}}",
-            type_name(msg.message_name())
-        )
-    })
+            render_signal_accessors(&mut w, config, msg, dbc, receiver)?;
+        }
+        writeln!(buf, "}}")?;
+        writeln!(buf)?;
+        config.impl_views.fmt_cfg(&mut w, |w| w.write_all(&buf))?;
+
+        let mut buf = Vec::new();
+        writeln!(
+            buf,
+            "impl<'a> core::convert::TryFrom<{reference} [u8]> for {view}<'a> {{"
+        )?;
+        {
+            let mut w = PadAdapter::wrap(&mut buf);
+            writeln!(w, "type Error = CanError;")?;
+            writeln!(w)?;
+            writeln!(w, "#[inline(always)]")?;
+            writeln!(
+                w,
+                "fn try_from(payload: {reference} [u8]) -> Result<Self, Self::Error> {{"
+            )?;
+            writeln!(
+                w,
+                "    let raw = payload.try_into().map_err(|_| CanError::InvalidPayloadSize)?;"
+            )?;
+            writeln!(w, "    Ok(Self {{ raw }})")?;
+            writeln!(w, "}}")?;
+        }
+        writeln!(buf, "}}")?;
+        writeln!(buf)?;
+        config.impl_views.fmt_cfg(&mut w, |w| w.write_all(&buf))?;
+
+        let mut buf = Vec::new();
+        writeln!(buf, "impl From<{view}<'_>> for {typ} {{")?;
+        writeln!(buf, "    fn from(view: {view}<'_>) -> Self {{")?;
+        writeln!(buf, "        Self {{ raw: *view.raw }}")?;
+        writeln!(buf, "    }}")?;
+        writeln!(buf, "}}")?;
+        writeln!(buf)?;
+        config.impl_views.fmt_cfg(&mut w, |w| w.write_all(&buf))?;
+    }
+
+    Ok(())
 }
 
-fn render_debug_impl(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
-    match &config.impl_debug {
-        FeatureConfig::Always => {}
-        FeatureConfig::Gated(gate) => writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?,
-        FeatureConfig::Never => return Ok(()),
+/// Type of a signal in the `Signals` serde representation, i.e. the return type of its getter.
+fn serde_signal_type(
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+    signal: &Signal,
+) -> Result<String> {
+    if signal.multiplexer == Multiplexer::Multiplexor {
+        config.multiplex_enum_name(msg, signal)
+    } else if let Some(enum_type) = value_enum_name(config, dbc, msg, signal) {
+        Ok(enum_type)
+    } else {
+        Ok(signal_to_rust_type(signal))
+    }
+}
+
+/// Helper struct with one field per signal, (de)serialized in place of a message.
+fn render_serde_signals_struct(
+    mut w: impl Write,
+    config: &Config<'_>,
+    dbc: &Database,
+    msg: &Message,
+    serde_name: &str,
+    signals: &[&Signal],
+) -> Result<()> {
+    writeln!(w, "#[derive(Serialize, Deserialize)]")?;
+    writeln!(w, "#[serde(rename = {:?})]", serde_name)?;
+    writeln!(w, "struct Signals {{")?;
+    {
+        let mut w = PadAdapter::wrap(&mut w);
+        for signal in signals {
+            writeln!(w, "#[serde(rename = {:?})]", signal.name)?;
+            writeln!(
+                w,
+                "{}: {},",
+                config.field_name(msg, signal),
+                serde_signal_type(config, dbc, msg, signal)?
+            )?;
+        }
     }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    Ok(())
+}
 
-    let typ = type_name(msg.message_name());
-    writeln!(w, r##"impl core::fmt::Debug for {} {{"##, typ)?;
+/// `impl Serialize` via the `Signals` helper struct, filled from the signal getters.
+fn render_serde_signals_serialize(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    typ: &str,
+    signals: &[&Signal],
+) -> Result<()> {
+    writeln!(w, "impl Serialize for {} {{", typ)?;
     {
         let mut w = PadAdapter::wrap(&mut w);
         writeln!(
             w,
-            "fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{"
+            "fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
         )?;
         {
             let mut w = PadAdapter::wrap(&mut w);
-            writeln!(w, r#"if f.alternate() {{"#)?;
-            {
-                let mut w = PadAdapter::wrap(&mut w);
-                writeln!(w, r#"f.debug_struct("{}")"#, typ)?;
-                {
-                    let mut w = PadAdapter::wrap(&mut w);
-                    for signal in msg.signals() {
-                        if *signal.multiplexer_indicator() == MultiplexIndicator::Plain {
-                            writeln!(
-                                w,
-                                r#".field("{field_name}", &self.{field_name}())"#,
-                                field_name = field_name(signal.name()),
-                            )?;
-                        }
-                    }
+            writeln!(w, "Signals {{")?;
+            for signal in signals {
+                if signal.multiplexer == Multiplexer::Multiplexor {
+                    writeln!(
+                        w,
+                        "    {0}: self.{0}().map_err(serde::ser::Error::custom)?,",
+                        config.field_name(msg, signal)
+                    )?;
+                } else {
+                    writeln!(w, "    {0}: self.{0}(),", config.field_name(msg, signal))?;
                 }
-                writeln!(w, r#".finish()"#)?;
-            }
-            writeln!(w, r#"}} else {{"#)?;
-            {
-                let mut w = PadAdapter::wrap(&mut w);
-                writeln!(w, r#"f.debug_tuple("{}").field(&self.raw).finish()"#, typ)?;
             }
             writeln!(w, "}}")?;
+            writeln!(w, ".serialize(serializer)")?;
         }
         writeln!(w, "}}")?;
     }
@@ -1397,201 +3903,180 @@ fn render_debug_impl(mut w: impl Write, config: &Config<'_>, msg: &Message) -> R
     Ok(())
 }
 
-fn render_defmt_impl(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
-    match &config.impl_defmt {
+fn render_serde_signals(
+    mut w: impl Write,
+    config: &Config<'_>,
+    msg: &Message,
+    dbc: &Database,
+) -> Result<()> {
+    if config.serde_representation != SerdeRepresentation::Signals {
+        return Ok(());
+    }
+    match &config.impl_serde {
         FeatureConfig::Always => {}
         FeatureConfig::Gated(gate) => writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?,
         FeatureConfig::Never => return Ok(()),
     }
 
-    let typ = type_name(msg.message_name());
-    writeln!(w, r##"impl defmt::Format for {} {{"##, typ)?;
+    let typ = config.type_name(&msg.name);
+    let signals: Vec<&Signal> = msg
+        .signals
+        .iter()
+        .filter(|signal| {
+            signal.multiplexer == Multiplexer::Plain
+                || signal.multiplexer == Multiplexer::Multiplexor
+        })
+        .collect();
+    let multiplexor = signals
+        .iter()
+        .find(|signal| signal.multiplexer == Multiplexer::Multiplexor);
+
+    writeln!(w, "const _: () = {{")?;
     {
         let mut w = PadAdapter::wrap(&mut w);
-        writeln!(w, "fn format(&self, f: defmt::Formatter) {{")?;
+        render_serde_signals_struct(&mut w, config, dbc, msg, &typ, &signals)?;
+        render_serde_signals_serialize(&mut w, config, msg, &typ, &signals)?;
+
+        writeln!(w, "impl<'de> Deserialize<'de> for {} {{", typ)?;
         {
             let mut w = PadAdapter::wrap(&mut w);
-            writeln!(w, r#"defmt::write!(f,"#)?;
+            writeln!(
+                w,
+                "fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+            )?;
             {
                 let mut w = PadAdapter::wrap(&mut w);
-                write!(w, r#""{} {{{{"#, typ)?;
-                {
-                    for signal in msg.signals() {
-                        if *signal.multiplexer_indicator() == MultiplexIndicator::Plain {
-                            write!(w, r#" {}={{:?}}"#, signal.name(),)?;
+                writeln!(
+                    w,
+                    "let {}signals = Signals::deserialize(deserializer)?;",
+                    if signals.is_empty() { "_" } else { "" },
+                )?;
+
+                let args: Vec<String> = signals
+                    .iter()
+                    .map(|signal| {
+                        if signal.multiplexer == Multiplexer::Multiplexor {
+                            config.field_name(msg, signal)
+                        } else {
+                            format!("signals.{}", config.field_name(msg, signal))
                         }
-                    }
-                }
-                writeln!(w, r#" }}}}","#)?;
+                    })
+                    .collect();
 
-                for signal in msg.signals() {
-                    if *signal.multiplexer_indicator() == MultiplexIndicator::Plain {
-                        writeln!(w, "self.{}(),", field_name(signal.name()))?;
+                if let Some(multiplexor) = multiplexor {
+                    let index_enum = config.multiplex_enum_name(msg, multiplexor)?;
+                    let multiplexor_field = config.field_name(msg, multiplexor);
+                    let indexes = multiplexer_indexes(msg);
+
+                    writeln!(
+                        w,
+                        "let {} = match &signals.{} {{",
+                        multiplexor_field, multiplexor_field
+                    )?;
+                    for index in &indexes {
+                        writeln!(
+                            w,
+                            "    {}::{}(_) => {},",
+                            index_enum,
+                            multiplexed_enum_variant_wrapper_name(config, msg, multiplexor, *index),
+                            index
+                        )?;
+                    }
+                    writeln!(w, "}};")?;
+                    writeln!(
+                        w,
+                        "let mut res = {}::new({}).map_err(serde::de::Error::custom)?;",
+                        typ,
+                        args.join(", ")
+                    )?;
+                    writeln!(w, "match signals.{} {{", multiplexor_field)?;
+                    for index in &indexes {
+                        writeln!(
+                            w,
+                            "    {}::{}(value) => res.set_{}(value),",
+                            index_enum,
+                            multiplexed_enum_variant_wrapper_name(config, msg, multiplexor, *index),
+                            multiplexed_enum_variant_wrapper_name(config, msg, multiplexor, *index)
+                                .to_snake_case(),
+                        )?;
                     }
+                    writeln!(w, "}}")?;
+                    writeln!(w, ".map_err(serde::de::Error::custom)?;")?;
+                    writeln!(w, "Ok(res)")?;
+                } else {
+                    writeln!(
+                        w,
+                        "{}::new({}).map_err(serde::de::Error::custom)",
+                        typ,
+                        args.join(", ")
+                    )?;
                 }
-                writeln!(w, r#");"#)?;
             }
             writeln!(w, "}}")?;
         }
+        writeln!(w, "}}")?;
     }
-    writeln!(w, "}}")?;
+    writeln!(w, "}};")?;
     writeln!(w)?;
+
     Ok(())
 }
 
-fn render_multiplexor_enums(
+fn render_serde_signals_multiplexed(
     mut w: impl Write,
     config: &Config<'_>,
-    dbc: &DBC,
+    dbc: &Database,
     msg: &Message,
-    multiplexor_signal: &Signal,
+    struct_name: &str,
+    signals: &[&Signal],
 ) -> Result<()> {
-    ensure!(
-        *multiplexor_signal.multiplexer_indicator() == MultiplexIndicator::Multiplexor,
-        "signal {} is not the multiplexor",
-        multiplexor_signal.name(),
-    );
-
-    let mut multiplexed_signals = BTreeMap::new();
-    for signal in msg.signals() {
-        if let MultiplexIndicator::MultiplexedSignal(switch_index) = signal.multiplexer_indicator()
-        {
-            multiplexed_signals
-                .entry(switch_index)
-                .and_modify(|v: &mut Vec<&Signal>| v.push(signal))
-                .or_insert_with(|| vec![signal]);
-        }
-    }
-
-    writeln!(
-        w,
-        "/// Defined values for multiplexed signal {}",
-        msg.message_name()
-    )?;
-
-    config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
-    config
-        .impl_defmt
-        .fmt_attr(&mut w, "derive(defmt::Format)")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
-    config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
-    writeln!(
-        w,
-        "pub enum {} {{",
-        multiplex_enum_name(msg, multiplexor_signal)?
-    )?;
-
-    {
-        let mut w = PadAdapter::wrap(&mut w);
-        for (switch_index, _multiplexed_signals) in multiplexed_signals.iter() {
-            writeln!(
-                w,
-                "{multiplexed_wrapper_name}({multiplexed_name}),",
-                multiplexed_wrapper_name = multiplexed_enum_variant_wrapper_name(**switch_index),
-                multiplexed_name =
-                    multiplexed_enum_variant_name(msg, multiplexor_signal, **switch_index)?
-            )?;
-        }
-    }
-    writeln!(w, "}}")?;
-    writeln!(w)?;
-
-    for (switch_index, multiplexed_signals) in multiplexed_signals.iter() {
-        let struct_name = multiplexed_enum_variant_name(msg, multiplexor_signal, **switch_index)?;
-
-        config.impl_debug.fmt_attr(&mut w, "derive(Debug)")?;
-        config
-            .impl_defmt
-            .fmt_attr(&mut w, "derive(defmt::Format)")?;
-        config.impl_serde.fmt_attr(&mut w, "derive(Serialize)")?;
-        config.impl_serde.fmt_attr(&mut w, "derive(Deserialize)")?;
-        writeln!(w, r##"#[derive(Default)]"##)?;
-        writeln!(
-            w,
-            "pub struct {} {{ raw: [u8; {}] }}",
-            struct_name,
-            msg.message_size()
-        )?;
-        writeln!(w)?;
-
-        writeln!(w, "impl {} {{", struct_name)?;
-
-        writeln!(
-            w,
-            "pub fn new() -> Self {{ Self {{ raw: [0u8; {}] }} }}",
-            msg.message_size()
-        )?;
-
-        for signal in multiplexed_signals {
-            render_signal(&mut w, config, signal, dbc, msg)?;
-        }
-
-        writeln!(w, "}}")?;
-        writeln!(w)?;
+    if config.serde_representation != SerdeRepresentation::Signals {
+        return Ok(());
     }
-
-    Ok(())
-}
-
-fn render_arbitrary(mut w: impl Write, config: &Config<'_>, msg: &Message) -> Result<()> {
-    match &config.impl_arbitrary {
+    match &config.impl_serde {
         FeatureConfig::Always => {}
         FeatureConfig::Gated(gate) => writeln!(w, r##"#[cfg(feature = {gate:?})]"##)?,
         FeatureConfig::Never => return Ok(()),
     }
 
-    writeln!(
-        w,
-        "impl<'a> Arbitrary<'a> for {typ} {{",
-        typ = type_name(msg.message_name())
-    )?;
+    writeln!(w, "const _: () = {{")?;
     {
-        let filtered_signals: Vec<&Signal> = msg
-            .signals()
-            .iter()
-            .filter(|signal| {
-                *signal.multiplexer_indicator() == MultiplexIndicator::Plain
-                    || *signal.multiplexer_indicator() == MultiplexIndicator::Multiplexor
-            })
-            .collect();
         let mut w = PadAdapter::wrap(&mut w);
-        writeln!(
-            w,
-            "fn arbitrary({}u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {{",
-            if filtered_signals.is_empty() { "_" } else { "" },
-        )?;
+        render_serde_signals_struct(&mut w, config, dbc, msg, struct_name, signals)?;
+        render_serde_signals_serialize(&mut w, config, msg, struct_name, signals)?;
+
+        writeln!(w, "impl<'de> Deserialize<'de> for {} {{", struct_name)?;
         {
             let mut w = PadAdapter::wrap(&mut w);
-
-            for signal in filtered_signals.iter() {
-                writeln!(
-                    w,
-                    "let {field_name} = {arbitrary_value};",
-                    field_name = field_name(signal.name()),
-                    arbitrary_value = signal_to_arbitrary(signal),
-                )?;
-            }
-
-            let args: Vec<String> = filtered_signals
-                .iter()
-                .map(|signal| field_name(signal.name()))
-                .collect();
-
             writeln!(
                 w,
-                "{typ}::new({args}).map_err(|_| arbitrary::Error::IncorrectFormat)",
-                typ = type_name(msg.message_name()),
-                args = args.join(",")
+                "fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
             )?;
+            {
+                let mut w = PadAdapter::wrap(&mut w);
+                writeln!(w, "let signals = Signals::deserialize(deserializer)?;")?;
+                writeln!(w, "let mut res = Self::new();")?;
+                for signal in signals {
+                    writeln!(
+                        w,
+                        "res.set_{0}(signals.{0}).map_err(serde::de::Error::custom)?;",
+                        config.field_name(msg, signal),
+                    )?;
+                }
+                writeln!(w, "Ok(res)")?;
+            }
+            writeln!(w, "}}")?;
         }
         writeln!(w, "}}")?;
     }
-    writeln!(w, "}}")?;
+    writeln!(w, "}};")?;
+    writeln!(w)?;
 
     Ok(())
 }
 
 fn render_error(mut w: impl Write, config: &Config<'_>) -> io::Result<()> {
+    fmt_extra_attrs(&mut w, &config.error_attributes)?;
     w.write_all(include_bytes!("./includes/errors.rs"))?;
 
     config.impl_error.fmt_cfg(w, |w| {
@@ -1599,9 +4084,14 @@ fn render_error(mut w: impl Write, config: &Config<'_>) -> io::Result<()> {
     })
 }
 
-fn render_arbitrary_helpers(mut w: impl Write, config: &Config<'_>) -> io::Result<()> {
+/// `UnstructuredFloatExt` trait, with the given visibility (e.g. `pub(super) `)
+fn render_arbitrary_helpers(
+    mut w: impl Write,
+    config: &Config<'_>,
+    visibility: &str,
+) -> io::Result<()> {
     config.impl_arbitrary.fmt_cfg(&mut w, |w| {
-        writeln!(w, "trait UnstructuredFloatExt {{")?;
+        writeln!(w, "{visibility}trait UnstructuredFloatExt {{")?;
         writeln!(w, "    fn float_in_range(&mut self, range: core::ops::RangeInclusive<f32>) -> arbitrary::Result<f32>;")?;
         writeln!(w, "}}")?;
         writeln!(w)
@@ -1635,30 +4125,212 @@ fn render_arbitrary_helpers(mut w: impl Write, config: &Config<'_>) -> io::Resul
 }
 
 fn signal_to_arbitrary(signal: &Signal) -> String {
-    if signal.signal_size == 1 {
+    if signal.size == 1 {
         "u.int_in_range(0..=1)? == 1".to_string()
     } else if signal_is_float_in_rust(signal) {
         format!(
             "u.float_in_range({min}_f32..={max}_f32)?",
-            min = signal.min(),
-            max = signal.max()
+            min = signal.min,
+            max = signal.max
         )
     } else {
         format!(
             "u.int_in_range({min}..={max})?",
-            min = signal.min(),
-            max = signal.max()
+            min = signal.min,
+            max = signal.max
         )
     }
 }
 
-fn get_relevant_messages(dbc: &DBC) -> impl Iterator<Item = &Message> {
-    dbc.messages().iter().filter(|m| !message_ignored(m))
+fn get_relevant_messages(dbc: &Database) -> impl Iterator<Item = &Message> {
+    dbc.messages.iter().filter(|m| !message_ignored(m))
 }
 
 fn message_ignored(message: &Message) -> bool {
     // DBC internal message containing signals unassigned to any real message
-    message.message_name() == "VECTOR__INDEPENDENT_SIG_MSG"
+    message.name == "VECTOR__INDEPENDENT_SIG_MSG"
+}
+
+fn fmt_extra_attrs(mut w: impl Write, attrs: &[ExtraAttribute<'_>]) -> io::Result<()> {
+    for attr in attrs {
+        attr.feature.fmt_attr(&mut w, attr.attr)?;
+    }
+    Ok(())
+}
+
+impl Config<'_> {
+    /// Derives for comparing and hashing a generated type.
+    ///
+    /// Types holding a float can't be `Eq` or `Hash`. Types that always derive `PartialEq`
+    /// only get the derives on top of that.
+    fn fmt_comparison_derives(
+        &self,
+        mut w: impl Write,
+        has_float: bool,
+        derives_partial_eq: bool,
+    ) -> io::Result<()> {
+        if !derives_partial_eq {
+            // `PartialOrd` requires `PartialEq`
+            match (self.impl_eq, self.impl_partial_ord) {
+                (FeatureConfig::Always, _) | (_, FeatureConfig::Always) => {
+                    writeln!(w, "#[derive(PartialEq)]")?
+                }
+                (FeatureConfig::Gated(eq), FeatureConfig::Gated(ord)) if eq != ord => writeln!(
+                    w,
+                    "#[cfg_attr(any(feature = {eq:?}, feature = {ord:?}), derive(PartialEq))]"
+                )?,
+                (FeatureConfig::Never, feature) | (feature, _) => {
+                    feature.fmt_attr(&mut w, "derive(PartialEq)")?
+                }
+            }
+        }
+        if !has_float {
+            self.impl_eq.fmt_attr(&mut w, "derive(Eq)")?;
+            self.impl_hash.fmt_attr(&mut w, "derive(Hash)")?;
+        }
+        self.impl_partial_ord.fmt_attr(&mut w, "derive(PartialOrd)")
+    }
+
+    /// Message struct name
+    fn type_name(&self, message: &str) -> String {
+        match self.type_names.get(message) {
+            Some(ident) => ident.clone(),
+            None => self.mangled_type_name(message),
+        }
+    }
+
+    /// Message struct name before resolving collisions
+    fn mangled_type_name(&self, message: &str) -> String {
+        match self.renames.messages.get(message) {
+            Some(ident) => ident.to_string(),
+            None => self.naming_strategy.type_name(message),
+        }
+    }
+
+    /// Signal accessor name, unique within the message
+    fn field_name(&self, msg: &Message, signal: &Signal) -> String {
+        let resolved = self.field_names.get(&msg.name).and_then(|fields| {
+            let i = msg.signals.iter().position(|s| s.name == signal.name)?;
+            fields.get(i)
+        });
+        if let Some(field) = resolved {
+            return field.clone();
+        }
+
+        let idents = naming::disambiguate(
+            msg.signals
+                .iter()
+                .map(|s| self.mangled_field_name(&s.name))
+                .collect(),
+            "_",
+        );
+        msg.signals
+            .iter()
+            .position(|s| s.name == signal.name)
+            .and_then(|i| idents.into_iter().nth(i))
+            .unwrap_or_else(|| self.mangled_field_name(&signal.name))
+    }
+
+    /// Signal accessor name before resolving collisions
+    fn mangled_field_name(&self, signal: &str) -> String {
+        match self.renames.signals.get(signal) {
+            Some(ident) => ident.to_string(),
+            None => self.naming_strategy.field_name(signal),
+        }
+    }
+
+    /// Value table enum variant names, unique within the table
+    fn enum_variant_names(&self, variants: &[(f64, String)]) -> Vec<String> {
+        naming::disambiguate(
+            variants
+                .iter()
+                .map(|v| self.mangled_enum_variant_name(&v.1))
+                .collect(),
+            "",
+        )
+    }
+
+    /// Value table enum variant name before resolving collisions
+    fn mangled_enum_variant_name(&self, label: &str) -> String {
+        match self.renames.value_labels.get(label) {
+            Some(ident) => ident.to_string(),
+            None => self.naming_strategy.enum_variant_name(label),
+        }
+    }
+
+    /// Value table enum name of a signal
+    fn enum_name(&self, msg: &Message, signal: &Signal) -> String {
+        let generated = GeneratedType::SignalEnum(msg.name.clone(), signal.name.clone());
+        match self.type_idents.get(&generated) {
+            Some(ident) => ident.clone(),
+            None => self
+                .naming_strategy
+                .enum_name(&self.type_name(&msg.name), &signal.name),
+        }
+    }
+
+    /// Enum name of a named value table
+    fn value_table_name(&self, table: &str) -> String {
+        match self
+            .type_idents
+            .get(&GeneratedType::ValueTable(table.to_string()))
+        {
+            Some(ident) => ident.clone(),
+            None => self.naming_strategy.value_table_name(table),
+        }
+    }
+
+    /// Names of the shared and exclusive views of a message
+    fn view_names(&self, message: &str) -> (String, String) {
+        let typ = self.type_name(message);
+        let view_ref = self
+            .type_idents
+            .get(&GeneratedType::Ref(message.to_string()));
+        let view_mut = self
+            .type_idents
+            .get(&GeneratedType::Mut(message.to_string()));
+        (
+            view_ref.cloned().unwrap_or_else(|| format!("{typ}Ref")),
+            view_mut.cloned().unwrap_or_else(|| format!("{typ}Mut")),
+        )
+    }
+
+    /// Multiplexor index enum name
+    fn multiplex_enum_name(&self, msg: &Message, multiplexor: &Signal) -> Result<String> {
+        ensure!(
+            matches!(multiplexor.multiplexer, Multiplexer::Multiplexor),
+            "signal {:?} is not the multiplexor",
+            multiplexor
+        );
+        let generated = GeneratedType::MultiplexEnum(msg.name.clone());
+        if let Some(ident) = self.type_idents.get(&generated) {
+            return Ok(ident.clone());
+        }
+        Ok(self
+            .naming_strategy
+            .multiplex_enum_name(&msg.name, &multiplexor.name))
+    }
+
+    /// Name of the struct holding the signals of one multiplexor value
+    fn multiplexed_enum_variant_name(
+        &self,
+        msg: &Message,
+        multiplexor: &Signal,
+        switch_index: u64,
+    ) -> Result<String> {
+        ensure!(
+            matches!(multiplexor.multiplexer, Multiplexer::Multiplexor),
+            "signal {:?} is not the multiplexor",
+            multiplexor
+        );
+        let generated = GeneratedType::MultiplexedStruct(msg.name.clone(), switch_index);
+        if let Some(ident) = self.type_idents.get(&generated) {
+            return Ok(ident.clone());
+        }
+        Ok(self
+            .naming_strategy
+            .multiplexed_struct_name(&msg.name, &multiplexor.name, switch_index))
+    }
 }
 
 impl FeatureConfig<'_> {
@@ -1694,8 +4366,11 @@ impl FeatureConfig<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{get_range_of_values, range_to_rust_int, signal_params_to_rust_int};
-    use can_dbc::ValueType::{Signed, Unsigned};
+    use crate::ir::ValueType::{Signed, Unsigned};
+    use crate::{
+        codegen, codegen_buses, get_range_of_values, range_to_rust_int, signal_params_to_rust_int,
+        BusesConfig, Config, FeatureConfig, InputFormat,
+    };
 
     #[test]
     fn test_range_of_values() {
@@ -1746,4 +4421,109 @@ mod tests {
             "This shouldn't be valid in a DBC, it's more than 64 bits"
         );
     }
+
+    #[test]
+    fn partial_ord_derives_partial_eq() {
+        let generate = |impl_eq, impl_partial_ord| {
+            generate_code(
+                Config::builder()
+                    .dbc_name("example.dbc")
+                    .dbc_content(include_bytes!("../testing/dbc-examples/example.dbc"))
+                    .impl_eq(impl_eq)
+                    .impl_partial_ord(impl_partial_ord)
+                    .build(),
+            )
+        };
+
+        let code = generate(FeatureConfig::Never, FeatureConfig::Always);
+        assert!(code.contains("#[derive(PartialEq)]\n#[derive(PartialOrd)]\n"));
+
+        let code = generate(FeatureConfig::Gated("eq"), FeatureConfig::Gated("ord"));
+        assert!(code
+            .contains("#[cfg_attr(any(feature = \"eq\", feature = \"ord\"), derive(PartialEq))]"));
+    }
+
+    #[test]
+    fn repr_transparent_only_for_casts() {
+        let generate = |impl_zerocopy, impl_bytemuck| {
+            generate_code(
+                Config::builder()
+                    .dbc_name("example.dbc")
+                    .dbc_content(include_bytes!("../testing/dbc-examples/example.dbc"))
+                    .impl_zerocopy(impl_zerocopy)
+                    .impl_bytemuck(impl_bytemuck)
+                    .build(),
+            )
+        };
+
+        let code = generate(FeatureConfig::Never, FeatureConfig::Never);
+        assert!(!code.contains("repr(transparent)"));
+
+        let code = generate(FeatureConfig::Gated("zerocopy"), FeatureConfig::Always);
+        assert!(code.contains("#[repr(transparent)]\npub struct Foo {"));
+
+        let code = generate(FeatureConfig::Never, FeatureConfig::Gated("bytemuck"));
+        assert!(code
+            .contains("#[cfg_attr(feature = \"bytemuck\", repr(transparent))]\npub struct Foo {"));
+
+        let code = generate(
+            FeatureConfig::Gated("zerocopy"),
+            FeatureConfig::Gated("bytemuck"),
+        );
+        assert!(code.contains(
+            "#[cfg_attr(any(feature = \"zerocopy\", feature = \"bytemuck\"), repr(transparent))]"
+        ));
+    }
+
+    #[test]
+    fn buses_share_options() {
+        let dbc = include_bytes!("../testing/dbc-examples/example.dbc");
+        let config = |impl_debug, impl_eq| {
+            Config::builder()
+                .dbc_name("example.dbc")
+                .dbc_content(dbc)
+                .impl_debug(impl_debug)
+                .impl_eq(impl_eq)
+                .build()
+        };
+        let generate = |bus_enum, body| {
+            let buses = BusesConfig::builder()
+                .buses(vec![
+                    (
+                        "chassis",
+                        config(FeatureConfig::Always, FeatureConfig::Always),
+                    ),
+                    ("body", body),
+                ])
+                .bus_enum(bus_enum)
+                .build();
+            codegen_buses(buses, &mut Vec::new())
+        };
+
+        generate(false, config(FeatureConfig::Never, FeatureConfig::Never)).unwrap();
+        let err = generate(true, config(FeatureConfig::Never, FeatureConfig::Never)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "bus `body` needs the same `impl_debug`, `impl_eq` as the other buses"
+        );
+        generate(true, config(FeatureConfig::Always, FeatureConfig::Always)).unwrap();
+    }
+
+    /// Rust code generated for `config`
+    pub(crate) fn generate_code(config: Config<'_>) -> String {
+        let mut out = Vec::new();
+        codegen(config, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// Rust code generated from `content`, reading `bus` of files describing several
+    pub(crate) fn generate(content: &[u8], input_format: InputFormat, bus: Option<&str>) -> String {
+        let config = Config::builder()
+            .dbc_name("example")
+            .dbc_content(content)
+            .input_format(input_format)
+            .bus(bus)
+            .build();
+        generate_code(config)
+    }
 }
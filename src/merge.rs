@@ -0,0 +1,467 @@
+use crate::dbc_file::{DbcFile, FileMessage, FileValueDescription};
+use crate::ldf::LinDescription;
+use anyhow::{anyhow, Result};
+use can_dbc::{AttributeDefinition, AttributeValuedForObjectType, Comment, DBC};
+
+/// How to resolve definitions that differ between merged DBC files, see [merge_dbc].
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MergeConflicts {
+    /// Fail, listing every conflict.
+    #[default]
+    Error,
+
+    /// Keep the definition from the earliest file.
+    FirstWins,
+
+    /// Use the definition from the latest file.
+    LastWins,
+}
+
+/// DBC text combined from several files by [merge_dbc].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MergedDbc {
+    /// The merged DBC file
+    pub dbc_content: String,
+
+    /// Every conflict that was resolved by `FirstWins` or `LastWins`
+    pub conflicts: Vec<String>,
+}
+
+/// Combine a base DBC file with patch files adding or overriding definitions.
+///
+/// `fragments` are pairs of a file name (for messages only) and the file contents, in order.
+/// Messages are matched by ID and their signals by name, all other definitions (value
+/// descriptions, comments, attributes, …) by the object they belong to. Definitions that are
+/// identical in several files are not conflicts. The merged file is canonical like
+/// [normalize_dbc](crate::normalize_dbc) output.
+///
+/// ```
+/// use dbc_codegen::{merge_dbc, MergeConflicts};
+///
+/// let base = include_bytes!("../testing/dbc-examples/example.dbc");
+/// let patch = br#"VERSION ""
+///
+/// NS_ :
+///
+/// BS_:
+///
+/// BU_: Lorem
+///
+/// BO_ 2000 Extra: 1 Lorem
+///  SG_ Flag : 0|1@1+ (1,0) [0|1] "" Vector__XXX
+/// "#;
+///
+/// let merged = merge_dbc(&[("example.dbc", base), ("extra.dbc", patch)], MergeConflicts::Error)
+///     .unwrap();
+/// assert!(merged.dbc_content.contains("BO_ 2000 Extra: 1 Lorem"));
+/// ```
+pub fn merge_dbc(fragments: &[(&str, &[u8])], conflicts: MergeConflicts) -> Result<MergedDbc> {
+    let mut files = Vec::new();
+    for (name, content) in fragments {
+        let dbc = DBC::from_slice(content).map_err(|_| anyhow!("Could not parse `{name}`"))?;
+        files.push((*name, DbcFile::from(&dbc), None));
+    }
+    let (mut merged, _, conflicts) = merge_files(&files, conflicts)?;
+    merged.sort();
+    Ok(MergedDbc {
+        dbc_content: merged.to_dbc_string(),
+        conflicts,
+    })
+}
+
+/// Merge as [merge_dbc] does, keeping definitions in the order of the files, along with the
+/// LIN descriptions of LDF files, and return the resolved conflicts
+pub(crate) fn merge_files(
+    fragments: &[(&str, DbcFile, Option<LinDescription>)],
+    conflicts: MergeConflicts,
+) -> Result<(DbcFile, Option<LinDescription>, Vec<String>)> {
+    let mut merger = Merger {
+        rule: conflicts,
+        conflicts: Vec::new(),
+    };
+    let mut merged = DbcFile::default();
+    let mut merged_lin = None;
+
+    for (name, file, lin) in fragments {
+        merger.add(&mut merged, name, file);
+        if let Some(lin) = lin {
+            merger.add_lin(&mut merged_lin, name, lin);
+        }
+    }
+
+    if conflicts == MergeConflicts::Error && !merger.conflicts.is_empty() {
+        return Err(anyhow!(
+            "conflicting definitions in merged DBC files:\n- {}",
+            merger.conflicts.join("\n- ")
+        ));
+    }
+
+    Ok((merged, merged_lin, merger.conflicts))
+}
+
+struct Merger {
+    rule: MergeConflicts,
+    conflicts: Vec<String>,
+}
+
+impl Merger {
+    fn add(&mut self, merged: &mut DbcFile, source: &str, file: &DbcFile) {
+        if !file.version.is_empty() {
+            if merged.version.is_empty() {
+                merged.version.clone_from(&file.version);
+            } else if merged.version != file.version {
+                self.conflict(source, format!("version {:?}", file.version));
+                if self.rule == MergeConflicts::LastWins {
+                    merged.version.clone_from(&file.version);
+                }
+            }
+        }
+        for symbol in &file.new_symbols {
+            if !merged.new_symbols.contains(symbol) {
+                merged.new_symbols.push(symbol.clone());
+            }
+        }
+        for node in &file.nodes {
+            if !merged.nodes.contains(node) {
+                merged.nodes.push(node.clone());
+            }
+        }
+
+        self.merge(
+            &mut merged.value_tables,
+            &file.value_tables,
+            source,
+            |t| t.name.clone(),
+            |t| format!("value table `{}`", t.name),
+        );
+        for msg in &file.messages {
+            self.add_message(&mut merged.messages, source, msg);
+        }
+        self.merge(
+            &mut merged.message_transmitters,
+            &file.message_transmitters,
+            source,
+            |t| t.message_id().raw(),
+            |t| format!("transmitters of message {}", t.message_id().raw()),
+        );
+        self.merge(
+            &mut merged.environment_variables,
+            &file.environment_variables,
+            source,
+            |e| e.env_var_name().clone(),
+            |e| format!("environment variable `{}`", e.env_var_name()),
+        );
+        self.merge(
+            &mut merged.environment_variable_data,
+            &file.environment_variable_data,
+            source,
+            |e| e.env_var_name().clone(),
+            |e| format!("environment variable data `{}`", e.env_var_name()),
+        );
+        self.merge(
+            &mut merged.signal_types,
+            &file.signal_types,
+            source,
+            |t| t.signal_type_name().clone(),
+            |t| format!("signal type `{}`", t.signal_type_name()),
+        );
+        self.merge(
+            &mut merged.comments,
+            &file.comments,
+            source,
+            comment_object,
+            |c| format!("comment on {}", comment_object(c)),
+        );
+        self.merge(
+            &mut merged.attribute_definitions,
+            &file.attribute_definitions,
+            source,
+            attribute_definition_key,
+            |d| format!("attribute definition {}", attribute_definition_key(d)),
+        );
+        self.merge(
+            &mut merged.attribute_defaults,
+            &file.attribute_defaults,
+            source,
+            |d| d.attribute_name().clone(),
+            |d| format!("default of attribute `{}`", d.attribute_name()),
+        );
+        self.merge(
+            &mut merged.attribute_values,
+            &file.attribute_values,
+            source,
+            |v| {
+                (
+                    v.attribute_name().clone(),
+                    attribute_object(v.attribute_value()),
+                )
+            },
+            |v| {
+                format!(
+                    "attribute `{}` of {}",
+                    v.attribute_name(),
+                    attribute_object(v.attribute_value())
+                )
+            },
+        );
+        self.merge(
+            &mut merged.value_descriptions,
+            &file.value_descriptions,
+            source,
+            value_description_object,
+            |d| format!("value descriptions of {}", value_description_object(d)),
+        );
+        self.merge(
+            &mut merged.signal_type_refs,
+            &file.signal_type_refs,
+            source,
+            |r| (r.message_id().raw(), r.signal_name().clone()),
+            |r| {
+                format!(
+                    "signal type of `{}` in message {}",
+                    r.signal_name(),
+                    r.message_id().raw()
+                )
+            },
+        );
+        self.merge(
+            &mut merged.signal_groups,
+            &file.signal_groups,
+            source,
+            |g| (g.message_id().raw(), g.signal_group_name().clone()),
+            |g| {
+                format!(
+                    "signal group `{}` of message {}",
+                    g.signal_group_name(),
+                    g.message_id().raw()
+                )
+            },
+        );
+        self.merge(
+            &mut merged.extended_multiplex,
+            &file.extended_multiplex,
+            source,
+            |m| (m.message_id().raw(), m.signal_name().clone()),
+            |m| {
+                format!(
+                    "extended multiplexing of `{}` in message {}",
+                    m.signal_name(),
+                    m.message_id().raw()
+                )
+            },
+        );
+    }
+
+    /// Frames and schedule tables are matched by name
+    fn add_lin(&mut self, merged: &mut Option<LinDescription>, source: &str, lin: &LinDescription) {
+        let Some(merged) = merged else {
+            *merged = Some(lin.clone());
+            return;
+        };
+        if let Some(speed) = lin.speed {
+            match merged.speed {
+                None => merged.speed = Some(speed),
+                Some(existing) if existing == speed => {}
+                Some(_) => {
+                    self.conflict(source, format!("LIN speed of {speed} bit/s"));
+                    if self.rule == MergeConflicts::LastWins {
+                        merged.speed = Some(speed);
+                    }
+                }
+            }
+        }
+        self.merge(
+            &mut merged.frames,
+            &lin.frames,
+            source,
+            |f| f.name.clone(),
+            |f| format!("LIN frame `{}`", f.name),
+        );
+        self.merge(
+            &mut merged.schedule_tables,
+            &lin.schedule_tables,
+            source,
+            |t| t.name.clone(),
+            |t| format!("schedule table `{}`", t.name),
+        );
+    }
+
+    /// Messages are matched by ID, then their signals by name
+    fn add_message(&mut self, merged: &mut Vec<FileMessage>, source: &str, msg: &FileMessage) {
+        let id = msg.id.raw();
+        let Some(existing) = merged.iter_mut().find(|m| m.id.raw() == id) else {
+            merged.push(msg.clone());
+            return;
+        };
+
+        if (&existing.name, existing.size, &existing.transmitter)
+            != (&msg.name, msg.size, &msg.transmitter)
+        {
+            self.conflict(source, format!("message {id} `{}`", msg.name));
+            if self.rule == MergeConflicts::LastWins {
+                existing.name.clone_from(&msg.name);
+                existing.size = msg.size;
+                existing.transmitter = msg.transmitter.clone();
+            }
+        }
+        self.merge(
+            &mut existing.signals,
+            &msg.signals,
+            source,
+            |s| s.name.clone(),
+            |s| format!("signal `{}` of message {id}", s.name),
+        );
+    }
+
+    /// Add `items` to `merged`; items with the same key are definitions of the same object
+    fn merge<T: Clone + PartialEq, K: PartialEq>(
+        &mut self,
+        merged: &mut Vec<T>,
+        items: &[T],
+        source: &str,
+        key: impl Fn(&T) -> K,
+        describe: impl Fn(&T) -> String,
+    ) {
+        for item in items {
+            match merged.iter_mut().find(|m| key(m) == key(item)) {
+                None => merged.push(item.clone()),
+                Some(existing) if existing == item => {}
+                Some(existing) => {
+                    self.conflict(source, describe(item));
+                    if self.rule == MergeConflicts::LastWins {
+                        *existing = item.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    fn conflict(&mut self, source: &str, what: String) {
+        self.conflicts.push(format!(
+            "{what} in `{source}` differs from an earlier definition"
+        ));
+    }
+}
+
+fn comment_object(comment: &Comment) -> String {
+    match comment {
+        Comment::Node { node_name, .. } => format!("node `{node_name}`"),
+        Comment::Message { message_id, .. } => format!("message {}", message_id.raw()),
+        Comment::Signal {
+            message_id,
+            signal_name,
+            ..
+        } => format!("signal `{signal_name}` of message {}", message_id.raw()),
+        Comment::EnvVar { env_var_name, .. } => format!("environment variable `{env_var_name}`"),
+        // Plain comments can't be overridden, only added
+        Comment::Plain { comment } => format!("network {comment:?}"),
+    }
+}
+
+/// Object kind and attribute name, e.g. `BO_ "GenMsgCycleTime"`
+fn attribute_definition_key(definition: &AttributeDefinition) -> String {
+    let (object, rest) = match definition {
+        AttributeDefinition::Message(rest) => ("BO_ ", rest),
+        AttributeDefinition::Node(rest) => ("BU_ ", rest),
+        AttributeDefinition::Signal(rest) => ("SG_ ", rest),
+        AttributeDefinition::EnvironmentVariable(rest) => ("EV_ ", rest),
+        AttributeDefinition::Plain(rest) => ("", rest),
+    };
+    let name = rest.split_whitespace().next().unwrap_or_default();
+    format!("{object}{name}")
+}
+
+fn attribute_object(value: &AttributeValuedForObjectType) -> String {
+    match value {
+        AttributeValuedForObjectType::RawAttributeValue(_) => "the network".to_string(),
+        AttributeValuedForObjectType::NetworkNodeAttributeValue(node, _) => {
+            format!("node `{node}`")
+        }
+        AttributeValuedForObjectType::MessageDefinitionAttributeValue(id, _) => {
+            format!("message {}", id.raw())
+        }
+        AttributeValuedForObjectType::SignalAttributeValue(id, signal, _) => {
+            format!("signal `{signal}` of message {}", id.raw())
+        }
+        AttributeValuedForObjectType::EnvVariableAttributeValue(env, _) => {
+            format!("environment variable `{env}`")
+        }
+    }
+}
+
+fn value_description_object(description: &FileValueDescription) -> String {
+    match description {
+        FileValueDescription::Signal {
+            message_id,
+            signal_name,
+            ..
+        } => format!("signal `{signal_name}` of message {}", message_id.raw()),
+        FileValueDescription::EnvironmentVariable { env_var_name, .. } => {
+            format!("environment variable `{env_var_name}`")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::generate_code;
+    use crate::{codegen, Config, MergeConflicts};
+
+    #[test]
+    fn merge_identical_patch() {
+        let dbc = include_bytes!("../testing/dbc-examples/example.dbc");
+        let generate = |patches: Vec<&'static [u8]>| {
+            generate_code(
+                Config::builder()
+                    .dbc_name("example.dbc")
+                    .dbc_content(dbc)
+                    .dbc_patches(patches)
+                    .build(),
+            )
+        };
+
+        // Merging an identical file must not change anything
+        assert_eq!(generate(vec![]), generate(vec![dbc]));
+    }
+
+    #[test]
+    fn merge_conflicts() {
+        fn config(rule: MergeConflicts, on_merge_conflict: &dyn Fn(&str)) -> Config<'_> {
+            Config::builder()
+                .dbc_name("base.dbc")
+                .dbc_content(include_bytes!("../testing/dbc-examples/merge-base.dbc"))
+                .dbc_patches([&include_bytes!("../testing/dbc-examples/merge-patch.dbc")[..]])
+                .merge_conflicts(rule)
+                .on_merge_conflict(on_merge_conflict)
+                .build()
+        }
+        let resolved = std::cell::RefCell::new(Vec::new());
+        let on_merge_conflict = |conflict: &str| resolved.borrow_mut().push(conflict.to_string());
+
+        let err = format!(
+            "{:#}",
+            codegen(
+                config(MergeConflicts::Error, &on_merge_conflict),
+                Vec::new()
+            )
+            .unwrap_err()
+        );
+        assert!(err.contains("signal `Speed` of message 256 in `patch 1`"));
+        assert!(err.contains("value descriptions of signal `Speed` of message 256 in `patch 1`"));
+
+        assert!(resolved.borrow().is_empty());
+
+        let out = generate_code(config(MergeConflicts::FirstWins, &on_merge_conflict));
+        assert_eq!(resolved.borrow().len(), 2);
+        assert!(resolved.borrow()[0].contains("signal `Speed` of message 256 in `patch 1`"));
+        assert!(out.contains("pub fn speed_raw(&self) -> u8"));
+        assert!(out.contains("pub fn slip(&self) -> u8"));
+        assert!(out.contains("pub struct Brakes {"));
+        assert!(out.contains("Stop,"));
+
+        let out = generate_code(config(MergeConflicts::LastWins, &|_| {}));
+        assert!(out.contains("pub fn speed_raw(&self) -> f32"));
+        assert!(out.contains("Standstill,"));
+    }
+}
@@ -0,0 +1,312 @@
+use crate::keywords;
+use heck::{ToPascalCase, ToSnakeCase};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Turns DBC names into Rust identifiers.
+///
+/// All methods have defaults matching the built-in conversions, so an implementation only
+/// needs to override what it wants to change:
+///
+/// ```
+/// use dbc_codegen::NamingStrategy;
+///
+/// /// Keep message names as they are in the DBC
+/// struct Verbatim;
+///
+/// impl NamingStrategy for Verbatim {
+///     fn type_name(&self, message: &str) -> String {
+///         message.to_string()
+///     }
+/// }
+/// ```
+///
+/// Names returned here must be valid identifiers, keywords are not escaped afterwards.
+pub trait NamingStrategy {
+    /// Message struct and `Messages` variant, e.g. `Bar`.
+    fn type_name(&self, message: &str) -> String {
+        if keywords::is_keyword(message) || !message.starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            format!("X{}", message.to_pascal_case())
+        } else {
+            message.to_pascal_case()
+        }
+    }
+
+    /// Signal getters, setters and constants, e.g. `three`.
+    fn field_name(&self, signal: &str) -> String {
+        if keywords::is_keyword(signal) || !signal.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            format!("x{}", signal.to_snake_case())
+        } else {
+            signal.to_snake_case()
+        }
+    }
+
+    /// Value table enum of a signal, e.g. `BarThree`.
+    ///
+    /// `message_type` is the message struct name.
+    fn enum_name(&self, message_type: &str, signal: &str) -> String {
+        // this turns signal `_4DRIVE` into `4drive`
+        let signal = signal.trim_start_matches(|c: char| c.is_ascii_punctuation());
+        format!("{}{}", message_type, signal.to_pascal_case())
+    }
+
+    /// Enum of a named `VAL_TABLE_`.
+    fn value_table_name(&self, table: &str) -> String {
+        self.enum_variant_name(table)
+    }
+
+    /// Value table enum variant from a value label, e.g. `Onest`.
+    fn enum_variant_name(&self, label: &str) -> String {
+        if keywords::is_keyword(label) || !label.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            format!("X{}", label.to_pascal_case())
+        } else {
+            label.to_pascal_case()
+        }
+    }
+
+    /// Multiplexor index enum, e.g. `MultiplexTestMultiplexorIndex`.
+    ///
+    /// `message` is the DBC message name, which is used without the `X` prefix of
+    /// [type_name](NamingStrategy::type_name).
+    fn multiplex_enum_name(&self, message: &str, multiplexor: &str) -> String {
+        format!(
+            "{}{}Index",
+            message.to_pascal_case(),
+            multiplexor.to_pascal_case()
+        )
+    }
+
+    /// Struct holding the signals of one multiplexor value, e.g. `MultiplexTestMultiplexorM0`.
+    ///
+    /// `message` is the DBC message name, like in
+    /// [multiplex_enum_name](NamingStrategy::multiplex_enum_name).
+    fn multiplexed_struct_name(
+        &self,
+        message: &str,
+        multiplexor: &str,
+        switch_index: u64,
+    ) -> String {
+        format!(
+            "{}{}M{}",
+            message.to_pascal_case(),
+            multiplexor.to_pascal_case(),
+            switch_index
+        )
+    }
+}
+
+/// The built-in [NamingStrategy]: `PascalCase` types and variants, `snake_case` fields,
+/// prefixed with `X`/`x` if they are keywords or don't start with a letter.
+#[derive(Default, Clone, Copy)]
+pub struct DefaultNamingStrategy;
+
+impl NamingStrategy for DefaultNamingStrategy {}
+
+/// Explicit renames from DBC names to Rust identifiers, taking precedence over the
+/// [NamingStrategy].
+///
+/// ```
+/// use dbc_codegen::Renames;
+///
+/// let renames = Renames::default()
+///     .message("ABS_WhlSpd", "WheelSpeeds")
+///     .signal("ABS_WhlSpd_FL", "front_left")
+///     .value_label("ON", "Enabled");
+/// ```
+#[derive(Default, Clone)]
+pub struct Renames<'a> {
+    /// Message name → message struct name
+    pub messages: BTreeMap<&'a str, &'a str>,
+    /// Signal name → field name, for signals of that name in all messages
+    pub signals: BTreeMap<&'a str, &'a str>,
+    /// Value label → enum variant name, in all value tables
+    pub value_labels: BTreeMap<&'a str, &'a str>,
+}
+
+impl<'a> Renames<'a> {
+    /// Rename a message
+    pub fn message(mut self, dbc_name: &'a str, ident: &'a str) -> Self {
+        self.messages.insert(dbc_name, ident);
+        self
+    }
+
+    /// Rename a signal
+    pub fn signal(mut self, dbc_name: &'a str, ident: &'a str) -> Self {
+        self.signals.insert(dbc_name, ident);
+        self
+    }
+
+    /// Rename a value label
+    pub fn value_label(mut self, dbc_name: &'a str, ident: &'a str) -> Self {
+        self.value_labels.insert(dbc_name, ident);
+        self
+    }
+}
+
+/// What to do when different DBC names turn into the same Rust identifier, see
+/// [Config::name_collisions](crate::Config::name_collisions).
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NameCollisions {
+    /// Fail code generation, listing every collision.
+    #[default]
+    Error,
+
+    /// Keep the first identifier and append `2`, `3`, … to the following ones, in DBC order,
+    /// e.g. `speed_fl` and `speed_fl_2`, or `On` and `On2`. Message structs keep their names
+    /// over generated enums and views, e.g. `BarThree` and `BarThree2` for the values of
+    /// `Bar.Three`.
+    Suffix,
+}
+
+/// Makes repeated identifiers unique by appending `separator` and a counter, skipping
+/// identifiers that are already taken.
+pub(crate) fn disambiguate(idents: Vec<String>, separator: &str) -> Vec<String> {
+    let mut taken: BTreeSet<String> = idents.iter().cloned().collect();
+    let mut seen = BTreeSet::new();
+    idents
+        .into_iter()
+        .map(|ident| {
+            if seen.insert(ident.clone()) {
+                return ident;
+            }
+            let mut counter = 2_u32;
+            loop {
+                let candidate = format!("{ident}{separator}{counter}");
+                if taken.insert(candidate.clone()) {
+                    return candidate;
+                }
+                counter = counter.saturating_add(1);
+            }
+        })
+        .collect()
+}
+
+/// Claims `ident` in `taken`. With [NameCollisions::Suffix], an identifier that is already
+/// taken gets `separator` and the first free counter appended instead.
+pub(crate) fn claim(
+    taken: &mut BTreeSet<String>,
+    ident: String,
+    separator: &str,
+    collisions: NameCollisions,
+) -> String {
+    if collisions == NameCollisions::Error || !taken.contains(&ident) {
+        taken.insert(ident.clone());
+        return ident;
+    }
+    let mut counter = 2_u32;
+    loop {
+        let candidate = format!("{ident}{separator}{counter}");
+        if taken.insert(candidate.clone()) {
+            return candidate;
+        }
+        counter = counter.saturating_add(1);
+    }
+}
+
+/// Groups of DBC names sharing an identifier, e.g. ``"`Speed_FL`, `SpeedFL` -> `speed_fl`"``.
+pub(crate) fn collisions<'n>(names: impl IntoIterator<Item = (&'n str, String)>) -> Vec<String> {
+    described_collisions(
+        names
+            .into_iter()
+            .map(|(name, ident)| (format!("`{name}`"), ident)),
+    )
+}
+
+/// Like [collisions], for items described in prose, e.g. ``"message `BarThree`"``.
+pub(crate) fn described_collisions(
+    items: impl IntoIterator<Item = (String, String)>,
+) -> Vec<String> {
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    let mut positions = BTreeMap::new();
+    for (item, ident) in items {
+        match positions
+            .get(&ident)
+            .and_then(|i: &usize| groups.get_mut(*i))
+        {
+            Some((_, items)) => items.push(item),
+            None => {
+                positions.insert(ident.clone(), groups.len());
+                groups.push((ident, vec![item]));
+            }
+        }
+    }
+    groups
+        .into_iter()
+        .filter(|(_, items)| items.len() > 1)
+        .map(|(ident, items)| format!("{} -> `{ident}`", items.join(", ")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::generate_code;
+    use crate::{codegen, Config, DefaultNamingStrategy, NameCollisions, NamingStrategy, Renames};
+
+    #[test]
+    fn test_naming_strategy_and_renames() {
+        struct Prefixed;
+        impl NamingStrategy for Prefixed {
+            fn type_name(&self, message: &str) -> String {
+                format!("Can{}", DefaultNamingStrategy.type_name(message))
+            }
+        }
+
+        let config = Config::builder()
+            .dbc_name("example.dbc")
+            .dbc_content(include_bytes!("../testing/dbc-examples/example.dbc"))
+            .naming_strategy(&Prefixed)
+            .renames(
+                Renames::default()
+                    .message("Foo", "Supply")
+                    .signal("Voltage", "volts")
+                    .value_label("ONEST", "Fourth"),
+            )
+            .build();
+        let out = generate_code(config);
+
+        assert!(out.contains("pub struct Supply {"));
+        assert!(out.contains("pub fn volts(&self) -> f32"));
+        assert!(out.contains("pub struct CanBar {"));
+        assert!(out.contains("pub enum CanBarThree {"));
+        assert!(out.contains("CanBarThree::Fourth"));
+        assert!(out.contains("pub enum MultiplexTestMultiplexorIndex {"));
+    }
+
+    #[test]
+    fn name_collisions_error() {
+        let config = Config::builder()
+            .dbc_name("colliding.dbc")
+            .dbc_content(include_bytes!("../testing/dbc-examples/colliding.dbc"))
+            .build();
+        let err = format!("{:#}", codegen(config, Vec::new()).unwrap_err());
+
+        assert!(err.contains("messages `Wheels`, `wheels` -> `Wheels`"));
+        assert!(err.contains("signals of `Wheels`: `Speed_FL`, `SpeedFL` -> `speed_fl`"));
+        assert!(err.contains("values of `Light` in `Wheels`: `On`, `ON` -> `On`"));
+        assert!(err.contains(
+            "types: message `WheelsLight`, values of `Light` in `Wheels` -> `WheelsLight`"
+        ));
+        assert!(err.contains("members of `Wheels`: signal `Gear`, signal `Gear_raw` -> `gear_raw`"));
+    }
+
+    #[test]
+    fn name_collisions_suffix() {
+        let config = Config::builder()
+            .dbc_name("colliding.dbc")
+            .dbc_content(include_bytes!("../testing/dbc-examples/colliding.dbc"))
+            .name_collisions(NameCollisions::Suffix)
+            .build();
+        let out = generate_code(config);
+
+        assert!(out.contains("pub struct Wheels {"));
+        assert!(out.contains("pub struct Wheels2 {"));
+        assert!(out.contains("pub fn speed_fl(&self) -> u8"));
+        assert!(out.contains("pub fn speed_fl_2(&self) -> u8"));
+        assert!(out.contains("    On,\n"));
+        assert!(out.contains("    On2,\n"));
+        assert!(out.contains("pub struct WheelsLight {"));
+        assert!(out.contains("pub enum WheelsLight2 {"));
+        assert!(out.contains("pub fn gear_raw(&self) -> u8"));
+        assert!(out.contains("pub fn gear_raw_2(&self) -> u8"));
+    }
+}
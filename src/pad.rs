@@ -2,7 +2,7 @@ use std::io::{Result as IoResult, Write};
 
 // adopted from libcore's `fmt/builders.rs`
 
-/// Indents all lines written to this by four spaces
+/// Indents all non-empty lines written to this by four spaces
 pub struct PadAdapter<'a> {
     buf: &'a mut (dyn Write + 'a),
     on_newline: bool,
@@ -21,7 +21,8 @@ impl Write for PadAdapter<'_> {
     fn write(&mut self, mut s: &[u8]) -> IoResult<usize> {
         let len = s.len();
         while !s.is_empty() {
-            if self.on_newline {
+            // Empty lines stay empty instead of ending in whitespace
+            if self.on_newline && s[0] != b'\n' {
                 self.buf.write_all(b"    ")?;
             }
 
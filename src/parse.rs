@@ -0,0 +1,71 @@
+//! Helpers shared by the readers of KCD, ARXML, SYM and LDF files.
+
+use anyhow::{anyhow, Result};
+use roxmltree::Node;
+
+/// Decimal, or hexadecimal with a `0x` prefix
+pub(crate) fn parse_u64(value: &str) -> Result<u64> {
+    let value = value.trim();
+    let parsed = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|_| anyhow!("invalid number `{value}`"))
+}
+
+pub(crate) fn parse_u32(value: &str) -> Result<u32> {
+    u32::try_from(parse_u64(value)?).map_err(|_| anyhow!("number `{value}` out of range"))
+}
+
+/// Decimal, or an integer with a `0x` prefix
+pub(crate) fn parse_f64(value: &str) -> Result<f64> {
+    let value = value.trim();
+    if value.starts_with("0x") || value.starts_with("0X") {
+        return parse_u64(value).map(|v| v as f64);
+    }
+    value
+        .parse()
+        .map_err(|_| anyhow!("invalid number `{value}`"))
+}
+
+/// Compare tag names regardless of the namespace
+pub(crate) fn is(node: &Node, tag: &str) -> bool {
+    node.is_element() && node.tag_name().name() == tag
+}
+
+/// Child elements named `tag`
+pub(crate) fn elements<'a, 'input: 'a>(
+    parent: Node<'a, 'input>,
+    tag: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    parent.children().filter(move |child| is(child, tag))
+}
+
+/// First child element named `tag`
+pub(crate) fn child<'a, 'input>(node: Node<'a, 'input>, tag: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|c| is(c, tag))
+}
+
+pub(crate) fn descendants<'a, 'input: 'a>(
+    node: Node<'a, 'input>,
+    tag: &'a str,
+) -> impl Iterator<Item = Node<'a, 'input>> + 'a {
+    node.descendants().filter(move |n| is(n, tag))
+}
+
+/// Trimmed text of the first child element named `tag`
+pub(crate) fn text<'a>(node: Node<'a, '_>, tag: &str) -> Option<&'a str> {
+    child(node, tag).and_then(|c| c.text()).map(str::trim)
+}
+
+pub(crate) fn required_text<'a>(node: Node<'a, '_>, tag: &str) -> Result<&'a str> {
+    text(node, tag).ok_or_else(|| anyhow!("`{}` without `{tag}`", node.tag_name().name()))
+}
+
+pub(crate) fn attribute<'a>(node: Node<'a, '_>, name: &str) -> Result<&'a str> {
+    node.attribute(name)
+        .ok_or_else(|| anyhow!("`{}` without `{name}`", node.tag_name().name()))
+}
@@ -0,0 +1,528 @@
+//! Reading PCAN symbol (SYM) files.
+//!
+//! Enums become named value tables, so signals using the same enum share its generated type.
+//! A message defined in several blocks with `Mux=` lines is multiplexed: signals defined in
+//! every block are not multiplexed, all others belong to the multiplexor value of their block.
+//! If all blocks use the same multiplexor name, it names the multiplexor signal, otherwise the
+//! multiplexor is called `Multiplexor` and the names become its value descriptions.
+//!
+//! Messages without a length, or with different lengths per block, get the largest length
+//! their signals and blocks require. Motorola (`-m`) start bits count from the most
+//! significant bit of each byte.
+
+use crate::dbc_file::{
+    raw_range, DbcFile, FileMessage, FileSignal, FileValueDescription, FileValueTable,
+};
+use crate::parse::{parse_f64, parse_u32, parse_u64};
+use anyhow::{anyhow, bail, Context, Result};
+use can_dbc::{
+    ByteOrder, Comment, MessageId, MultiplexIndicator, SignalExtendedValueType, Transmitter,
+    ValueType,
+};
+use std::borrow::Cow;
+
+/// Read all messages of a SYM file
+pub(crate) fn parse_sym(content: &[u8]) -> Result<DbcFile> {
+    let text = std::str::from_utf8(content).context("SYM file is not valid UTF-8")?;
+    let sections = sections(text);
+
+    let mut file = DbcFile::default();
+    for line in sections.enums {
+        file.value_tables
+            .push(parse_enum(&line).with_context(|| format!("in SYM enum `{line}`"))?);
+    }
+
+    let mut definitions = Vec::new();
+    for (line, comment) in sections.signals {
+        let tokens = tokenize(&line);
+        let Some(name) = tokens.first() else {
+            continue;
+        };
+        definitions.push((name.clone(), tokens, comment));
+    }
+
+    // Blocks of the same message, in order of their first appearance
+    let mut messages: Vec<(String, Vec<Block>)> = Vec::new();
+    for block in sections.blocks {
+        match messages.iter_mut().find(|(name, _)| *name == block.name) {
+            Some((_, blocks)) => blocks.push(block),
+            None => messages.push((block.name.clone(), vec![block])),
+        }
+    }
+
+    let reader = Reader {
+        definitions: &definitions,
+    };
+    for (name, blocks) in &messages {
+        let msg = reader
+            .message(&mut file, name, blocks)
+            .with_context(|| format!("in SYM message `{name}`"))?;
+        file.messages.push(msg);
+    }
+
+    Ok(file)
+}
+
+#[derive(Default)]
+struct Sections {
+    /// Complete `enum` definitions, joined if they span several lines
+    enums: Vec<String>,
+    /// `Sig=` definitions with their comment
+    signals: Vec<(String, Option<String>)>,
+    blocks: Vec<Block>,
+}
+
+/// A `[Name]` block with its `Key=Value` lines, values with their comment
+struct Block {
+    name: String,
+    entries: Vec<(String, String, Option<String>)>,
+}
+
+impl Block {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(k, _, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v, _)| v.as_str())
+    }
+}
+
+fn sections(text: &str) -> Sections {
+    let mut sections = Sections::default();
+    let mut section = String::new();
+    let mut pending_enum = String::new();
+
+    for line in text.lines() {
+        let (line, comment) = split_comment(line);
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('{').and_then(|l| l.strip_suffix('}')) {
+            section = name.to_ascii_uppercase();
+            continue;
+        }
+
+        match section.as_str() {
+            "ENUMS" => {
+                pending_enum.push_str(line);
+                if line.ends_with(')') {
+                    sections.enums.push(std::mem::take(&mut pending_enum));
+                }
+            }
+            "SIGNALS" => {
+                if let Some(definition) = line.strip_prefix("Sig=") {
+                    sections.signals.push((definition.to_string(), comment));
+                }
+            }
+            "SEND" | "RECEIVE" | "SENDRECEIVE" => {
+                if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                    sections.blocks.push(Block {
+                        name: name.to_string(),
+                        entries: Vec::new(),
+                    });
+                } else if let (Some(block), Some((key, value))) =
+                    (sections.blocks.last_mut(), line.split_once('='))
+                {
+                    block
+                        .entries
+                        .push((key.trim().to_string(), value.trim().to_string(), comment));
+                }
+            }
+            // Header lines like `FormatVersion=6.0` and unknown sections
+            _ => {}
+        }
+    }
+
+    sections
+}
+
+struct Reader<'a> {
+    /// `Sig=` definitions by name, as tokens
+    definitions: &'a [(String, Vec<String>, Option<String>)],
+}
+
+impl Reader<'_> {
+    fn message(&self, file: &mut DbcFile, name: &str, blocks: &[Block]) -> Result<FileMessage> {
+        let raw_id = blocks
+            .iter()
+            .find_map(|b| b.get("ID"))
+            .ok_or_else(|| anyhow!("message without `ID`"))?;
+        if raw_id.contains('-') {
+            bail!("ID ranges are not supported");
+        }
+        let raw_id = parse_u32(&number(raw_id))?;
+        let extended = blocks
+            .iter()
+            .find_map(|b| b.get("Type"))
+            .map_or(false, |t| t.to_ascii_lowercase().contains("extended"));
+        let id = if extended {
+            MessageId::Extended(raw_id)
+        } else {
+            MessageId::Standard(
+                u16::try_from(raw_id).map_err(|_| anyhow!("standard ID {raw_id} out of range"))?,
+            )
+        };
+
+        // Signals of each block
+        let mut block_signals = Vec::new();
+        for block in blocks {
+            let mut signals = Vec::new();
+            for (key, value, comment) in &block.entries {
+                let signal = match key.as_str() {
+                    "Var" => self.signal(file, id, &tokenize(value), comment.as_deref())?,
+                    "Sig" => {
+                        let tokens = tokenize(value);
+                        let (Some(name), Some(start)) = (tokens.first(), tokens.get(1)) else {
+                            bail!("invalid `Sig={value}`");
+                        };
+                        let (_, definition, definition_comment) = self
+                            .definitions
+                            .iter()
+                            .find(|(n, _, _)| n == name)
+                            .ok_or_else(|| anyhow!("unknown signal `{name}`"))?;
+                        // `Sig=Name type length …` is used like `Var=Name type start,length …`
+                        let mut tokens = definition.clone();
+                        if let Some(length) = tokens.get_mut(2) {
+                            *length = format!("{start},{length}");
+                        }
+                        tokens.extend(tokens_flags(&tokenize(value)));
+                        let comment = comment.as_ref().or(definition_comment.as_ref());
+                        self.signal(file, id, &tokens, comment.map(String::as_str))?
+                    }
+                    _ => continue,
+                };
+                signals.push(signal);
+            }
+            block_signals.push(signals);
+        }
+
+        let muxes = blocks
+            .iter()
+            .map(|b| b.get("Mux").map(tokenize))
+            .collect::<Option<Vec<_>>>();
+        let signals = match muxes {
+            Some(muxes) if !muxes.is_empty() => {
+                self.multiplexed_signals(file, id, &muxes, block_signals)?
+            }
+            _ => block_signals.into_iter().flatten().collect(),
+        };
+
+        let mut size = 0;
+        for block in blocks {
+            if let Some(length) = block.get("DLC").or_else(|| block.get("Len")) {
+                size = size.max(parse_u64(&number(length))?);
+            }
+        }
+        for signal in &signals {
+            let end = match signal.byte_order {
+                ByteOrder::LittleEndian => signal.start_bit,
+                ByteOrder::BigEndian => signal.start_bit ^ 7,
+            };
+            size = size.max(end.saturating_add(signal.size).div_ceil(8));
+        }
+
+        Ok(FileMessage {
+            id,
+            name: name.to_string(),
+            size,
+            transmitter: Transmitter::VectorXXX,
+            signals,
+        })
+    }
+
+    fn multiplexed_signals(
+        &self,
+        file: &mut DbcFile,
+        message_id: MessageId,
+        muxes: &[Vec<String>],
+        block_signals: Vec<Vec<FileSignal>>,
+    ) -> Result<Vec<FileSignal>> {
+        // `Mux=Name start,length value [-m]`
+        let mut values = Vec::new();
+        for tokens in muxes {
+            let (Some(name), Some(value)) = (tokens.first(), tokens.get(2)) else {
+                bail!("invalid `Mux={}`", tokens.join(" "));
+            };
+            values.push((name.clone(), parse_u64(&number(value))?));
+        }
+        let Some((first_name, _)) = values.first() else {
+            bail!("message without blocks");
+        };
+        let same_name = values.iter().all(|(name, _)| name == first_name);
+        let multiplexor_name = if same_name {
+            first_name.clone()
+        } else {
+            file.value_descriptions.push(FileValueDescription::Signal {
+                message_id,
+                signal_name: "Multiplexor".to_string(),
+                values: values
+                    .iter()
+                    .map(|(name, value)| (*value as f64, name.clone()))
+                    .collect(),
+            });
+            "Multiplexor".to_string()
+        };
+
+        let mut mux_tokens = vec![multiplexor_name, "unsigned".to_string()];
+        mux_tokens.extend(muxes[0].iter().skip(1).take(1).cloned());
+        mux_tokens.extend(tokens_flags(&muxes[0]));
+        let multiplexor = self.signal(file, message_id, &mux_tokens, None)?;
+        let mut signals = vec![FileSignal {
+            multiplexer: MultiplexIndicator::Multiplexor,
+            ..multiplexor
+        }];
+
+        // Signals defined identically in all blocks are not multiplexed
+        let shared: Vec<FileSignal> = match block_signals.split_first() {
+            Some((first, rest)) if !rest.is_empty() => first
+                .iter()
+                .filter(|s| rest.iter().all(|block| block.contains(s)))
+                .cloned()
+                .collect(),
+            _ => Vec::new(),
+        };
+        signals.extend(shared.iter().cloned());
+        for ((_, value), block) in values.iter().zip(block_signals) {
+            for signal in block {
+                if !shared.contains(&signal) {
+                    signals.push(FileSignal {
+                        multiplexer: MultiplexIndicator::MultiplexedSignal(*value),
+                        ..signal
+                    });
+                }
+            }
+        }
+        Ok(signals)
+    }
+
+    /// `Name type start,length [flags]`
+    fn signal(
+        &self,
+        file: &mut DbcFile,
+        message_id: MessageId,
+        tokens: &[String],
+        comment: Option<&str>,
+    ) -> Result<FileSignal> {
+        let (Some(name), Some(typ), Some(position)) =
+            (tokens.first(), tokens.get(1), tokens.get(2))
+        else {
+            bail!("invalid signal `{}`", tokens.join(" "));
+        };
+        let (start, size) = match position.split_once(',') {
+            Some((start, size)) => (parse_u64(&number(start))?, parse_u64(&number(size))?),
+            None => (parse_u64(&number(position))?, 1),
+        };
+
+        let (value_type, extended_value_type) = match typ.as_str() {
+            "signed" => (ValueType::Signed, None),
+            "float" => (
+                ValueType::Signed,
+                Some(SignalExtendedValueType::IEEEfloat32Bit),
+            ),
+            "double" => (
+                ValueType::Signed,
+                Some(SignalExtendedValueType::IEEEdouble64bit),
+            ),
+            "unsigned" | "bit" | "char" | "string" | "raw" => (ValueType::Unsigned, None),
+            other => bail!("unknown type `{other}` of signal `{name}`"),
+        };
+
+        let mut byte_order = ByteOrder::LittleEndian;
+        let mut unit = String::new();
+        let mut factor = 1.0;
+        let mut offset = 0.0;
+        let mut min = None;
+        let mut max = None;
+        let mut enum_name = None;
+        for flag in tokens.iter().skip(3) {
+            if flag == "-m" {
+                byte_order = ByteOrder::BigEndian;
+            } else if let Some(value) = flag.strip_prefix("/u:") {
+                unit = value.to_string();
+            } else if let Some(value) = flag.strip_prefix("/f:") {
+                factor = parse_f64(value)?;
+            } else if let Some(value) = flag.strip_prefix("/o:") {
+                offset = parse_f64(value)?;
+            } else if let Some(value) = flag.strip_prefix("/min:") {
+                min = Some(parse_f64(value)?);
+            } else if let Some(value) = flag.strip_prefix("/max:") {
+                max = Some(parse_f64(value)?);
+            } else if let Some(value) = flag.strip_prefix("/e:") {
+                enum_name = Some(value);
+            }
+            // Display options like `-h`, `/p:` or `/ln:` don't matter for code generation
+        }
+
+        let (raw_min, raw_max) = if extended_value_type.is_some() {
+            (0.0, 0.0)
+        } else {
+            raw_range(value_type, size, factor, offset)
+        };
+        let start_bit = match byte_order {
+            ByteOrder::LittleEndian => start,
+            ByteOrder::BigEndian => start ^ 7,
+        };
+
+        if let Some(enum_name) = enum_name {
+            let table = file
+                .value_tables
+                .iter()
+                .find(|t| t.name == enum_name)
+                .ok_or_else(|| anyhow!("unknown enum `{enum_name}` of signal `{name}`"))?;
+            let description = FileValueDescription::Signal {
+                message_id,
+                signal_name: name.clone(),
+                values: table.values.clone(),
+            };
+            if !file.value_descriptions.contains(&description) {
+                file.value_descriptions.push(description);
+            }
+        }
+        if let Some(comment) = comment {
+            let comment = Comment::Signal {
+                message_id,
+                signal_name: name.clone(),
+                comment: comment.to_string(),
+            };
+            if !file.comments.contains(&comment) {
+                file.comments.push(comment);
+            }
+        }
+
+        Ok(FileSignal {
+            name: name.clone(),
+            multiplexer: MultiplexIndicator::Plain,
+            start_bit,
+            size,
+            byte_order,
+            value_type,
+            factor,
+            offset,
+            min: min.unwrap_or(raw_min),
+            max: max.unwrap_or(raw_max),
+            unit,
+            receivers: Vec::new(),
+            extended_value_type,
+        })
+    }
+}
+
+/// `enum Name(0="Off", 1="On")`
+fn parse_enum(line: &str) -> Result<FileValueTable> {
+    let line = line
+        .strip_prefix("enum")
+        .ok_or_else(|| anyhow!("expected `enum`"))?;
+    let (name, rest) = line
+        .split_once('(')
+        .ok_or_else(|| anyhow!("expected `(`"))?;
+    let body = rest
+        .strip_suffix(')')
+        .ok_or_else(|| anyhow!("expected `)`"))?;
+
+    let mut values = Vec::new();
+    let mut entry = String::new();
+    let mut quoted = false;
+    for c in body.chars().chain(std::iter::once(',')) {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                entry.push(c);
+            }
+            ',' if !quoted => {
+                let item = std::mem::take(&mut entry);
+                let item = item.trim();
+                if item.is_empty() {
+                    continue;
+                }
+                let (value, label) = item
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("expected `value=\"label\"` in `{item}`"))?;
+                let label = label.trim().trim_matches('"');
+                values.push((parse_u64(&number(value))? as f64, label.to_string()));
+            }
+            _ => entry.push(c),
+        }
+    }
+
+    Ok(FileValueTable {
+        name: name.trim().to_string(),
+        values,
+    })
+}
+
+/// Split a line into its definition and `//` comment
+fn split_comment(line: &str) -> (&str, Option<String>) {
+    let mut quoted = false;
+    let mut chars = line.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '/' if !quoted && chars.peek().map(|(_, c)| *c) == Some('/') => {
+                let comment = line[i..].trim_start_matches('/').trim();
+                let comment = (!comment.is_empty()).then(|| comment.replace('"', "'"));
+                return (line[..i].trim(), comment);
+            }
+            _ => {}
+        }
+    }
+    (line.trim(), None)
+}
+
+/// Whitespace separated tokens; quotes group words and are removed, e.g. `/u:"km/h"`
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            }
+            c => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Flags following the name and position, e.g. `-m` or `/u:V`
+fn tokens_flags(tokens: &[String]) -> impl Iterator<Item = String> + '_ {
+    tokens
+        .iter()
+        .filter(|t| t.starts_with('-') || t.starts_with('/'))
+        .cloned()
+}
+
+/// Decimal, or hexadecimal with a `h` suffix like `1A3h` turned into `0x1A3` for
+/// [parse_u64] and [parse_u32]
+fn number(value: &str) -> Cow<'_, str> {
+    match value.trim().strip_suffix(['h', 'H']) {
+        Some(hex) => Cow::Owned(format!("0x{hex}")),
+        None => Cow::Borrowed(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::generate;
+    use crate::InputFormat;
+
+    #[test]
+    fn sym_matches_dbc() {
+        let dbc = include_bytes!("../testing/dbc-examples/example-sym.dbc");
+        let sym = generate(
+            include_bytes!("../testing/dbc-examples/example.sym"),
+            InputFormat::Sym,
+            None,
+        );
+        assert_eq!(generate(dbc, InputFormat::Dbc, None), sym);
+        // Signals using the same enum share its type
+        assert!(sym.contains("pub fn xtype(&self) -> OnOff {"));
+        assert!(sym.contains("pub fn ok(&self) -> OnOff {"));
+    }
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use dbc_codegen::Config;
+use std::fs;
+
+fn main() -> Result<()> {
+    let dbc_file = fs::read("../dbc-examples/example.dbc")?;
+    println!("cargo:rerun-if-changed=../dbc-examples/example.dbc");
+    println!("cargo:rerun-if-changed=../../src");
+
+    let config = Config::builder()
+        .dbc_name("example.dbc")
+        .dbc_content(&dbc_file)
+        .build();
+    dbc_codegen::codegen_c(config, "src")?;
+
+    cc::Build::new()
+        .file("src/example.c")
+        .flag_if_supported("-std=c99")
+        .flag_if_supported("-Wconversion")
+        .extra_warnings(true)
+        .warnings_into_errors(true)
+        .compile("example");
+
+    Ok(())
+}
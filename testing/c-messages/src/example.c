@@ -0,0 +1,1144 @@
+/* Generated code! */
+
+#include <string.h>
+
+#include "example.h"
+
+int example_foo_pack(uint8_t *dst_p, const struct example_foo_t *src_p, size_t size)
+{
+    if (size < 4u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 4);
+
+    dst_p[2] |= (uint8_t)((uint16_t)src_p->voltage & 0xffu);
+    dst_p[3] |= (uint8_t)(((uint16_t)src_p->voltage >> 8) & 0xffu);
+    dst_p[0] |= (uint8_t)((uint16_t)src_p->current & 0xffu);
+    dst_p[1] |= (uint8_t)(((uint16_t)src_p->current >> 8) & 0xffu);
+
+    return (4);
+}
+
+int example_foo_unpack(struct example_foo_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 4u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)(src_p[2] & 0xffu);
+        raw |= (uint16_t)((uint16_t)(src_p[3] & 0xffu) << 8);
+        dst_p->voltage = (uint16_t)raw;
+    }
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)(src_p[0] & 0xffu);
+        raw |= (uint16_t)((uint16_t)(src_p[1] & 0xffu) << 8);
+        dst_p->current = (int16_t)raw;
+    }
+
+    return (0);
+}
+
+uint16_t example_foo_voltage_encode(float value)
+{
+    return (uint16_t)((value - 0.0f) / 0.000976562f);
+}
+
+float example_foo_voltage_decode(uint16_t value)
+{
+    return ((float)value * 0.000976562f + 0.0f);
+}
+
+bool example_foo_voltage_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 63.9990234375f));
+}
+
+int16_t example_foo_current_encode(float value)
+{
+    return (int16_t)((value - 0.0f) / 0.0625f);
+}
+
+float example_foo_current_decode(int16_t value)
+{
+    return ((float)value * 0.0625f + 0.0f);
+}
+
+bool example_foo_current_is_in_range(float value)
+{
+    return ((value >= -2048.0f) && (value <= 2047.9375f));
+}
+
+int example_bar_pack(uint8_t *dst_p, const struct example_bar_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[1] |= (uint8_t)(((uint8_t)src_p->one << 6) & 0xc0u);
+    dst_p[0] |= (uint8_t)((uint8_t)src_p->two & 0xffu);
+    dst_p[1] |= (uint8_t)(((uint8_t)src_p->three << 3) & 0x38u);
+    dst_p[1] |= (uint8_t)(((uint8_t)src_p->four << 1) & 0x06u);
+    dst_p[3] |= (uint8_t)(((uint8_t)src_p->xtype << 6) & 0x40u);
+
+    return (8);
+}
+
+int example_bar_unpack(struct example_bar_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[1] & 0xc0u) >> 6);
+        dst_p->one = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[0] & 0xffu);
+        dst_p->two = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[1] & 0x38u) >> 3);
+        dst_p->three = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[1] & 0x06u) >> 1);
+        dst_p->four = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[3] & 0x40u) >> 6);
+        dst_p->xtype = (uint8_t)raw;
+    }
+
+    return (0);
+}
+
+uint8_t example_bar_one_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_bar_one_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_bar_one_is_in_range(uint8_t value)
+{
+    return (value <= 3);
+}
+
+uint8_t example_bar_two_encode(float value)
+{
+    return (uint8_t)((value - 0.0f) / 0.39f);
+}
+
+float example_bar_two_decode(uint8_t value)
+{
+    return ((float)value * 0.39f + 0.0f);
+}
+
+bool example_bar_two_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 100.0f));
+}
+
+uint8_t example_bar_three_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_bar_three_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_bar_three_is_in_range(uint8_t value)
+{
+    return (value <= 7);
+}
+
+uint8_t example_bar_four_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_bar_four_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_bar_four_is_in_range(uint8_t value)
+{
+    return (value <= 3);
+}
+
+uint8_t example_bar_xtype_encode(bool value)
+{
+    return (uint8_t)(value ? 1u : 0u);
+}
+
+bool example_bar_xtype_decode(uint8_t value)
+{
+    return (value == 1u);
+}
+
+bool example_bar_xtype_is_in_range(bool value)
+{
+    (void)value;
+
+    return (true);
+}
+
+int example_x4wd_pack(uint8_t *dst_p, const struct example_x4wd_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[1] |= (uint8_t)(((uint8_t)src_p->x4drive << 3) & 0x38u);
+
+    return (8);
+}
+
+int example_x4wd_unpack(struct example_x4wd_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[1] & 0x38u) >> 3);
+        dst_p->x4drive = (uint8_t)raw;
+    }
+
+    return (0);
+}
+
+uint8_t example_x4wd_x4drive_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_x4wd_x4drive_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_x4wd_x4drive_is_in_range(uint8_t value)
+{
+    return (value <= 7);
+}
+
+int example_amet_pack(uint8_t *dst_p, const struct example_amet_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[1] |= (uint8_t)(((uint8_t)src_p->one << 6) & 0xc0u);
+    dst_p[0] |= (uint8_t)((uint8_t)src_p->two & 0xffu);
+    dst_p[2] |= (uint8_t)(((uint8_t)src_p->three << 2) & 0x1cu);
+    dst_p[3] |= (uint8_t)(((uint8_t)src_p->four << 5) & 0x60u);
+    dst_p[5] |= (uint8_t)((uint8_t)src_p->five & 0x01u);
+
+    return (8);
+}
+
+int example_amet_unpack(struct example_amet_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[1] & 0xc0u) >> 6);
+        dst_p->one = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[0] & 0xffu);
+        dst_p->two = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[2] & 0x1cu) >> 2);
+        dst_p->three = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[3] & 0x60u) >> 5);
+        dst_p->four = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[5] & 0x01u);
+        dst_p->five = (uint8_t)raw;
+    }
+
+    return (0);
+}
+
+uint8_t example_amet_one_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_amet_one_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_amet_one_is_in_range(uint8_t value)
+{
+    return (value <= 3);
+}
+
+uint8_t example_amet_two_encode(float value)
+{
+    return (uint8_t)((value - 0.0f) / 0.39f);
+}
+
+float example_amet_two_decode(uint8_t value)
+{
+    return ((float)value * 0.39f + 0.0f);
+}
+
+bool example_amet_two_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 100.0f));
+}
+
+uint8_t example_amet_three_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_amet_three_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_amet_three_is_in_range(uint8_t value)
+{
+    return (value <= 7);
+}
+
+uint8_t example_amet_four_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_amet_four_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_amet_four_is_in_range(uint8_t value)
+{
+    return (value <= 3);
+}
+
+uint8_t example_amet_five_encode(bool value)
+{
+    return (uint8_t)(value ? 1u : 0u);
+}
+
+bool example_amet_five_decode(uint8_t value)
+{
+    return (value == 1u);
+}
+
+bool example_amet_five_is_in_range(bool value)
+{
+    (void)value;
+
+    return (true);
+}
+
+int example_dolor_pack(uint8_t *dst_p, const struct example_dolor_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[0] |= (uint8_t)(((uint16_t)src_p->one_float >> 11) & 0x01u);
+    dst_p[1] |= (uint8_t)(((uint16_t)src_p->one_float >> 3) & 0xffu);
+    dst_p[2] |= (uint8_t)(((uint16_t)src_p->one_float << 5) & 0xe0u);
+
+    return (8);
+}
+
+int example_dolor_unpack(struct example_dolor_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)((uint16_t)(src_p[0] & 0x01u) << 11);
+        raw |= (uint16_t)((uint16_t)(src_p[1] & 0xffu) << 3);
+        raw |= (uint16_t)((src_p[2] & 0xe0u) >> 5);
+        dst_p->one_float = (uint16_t)raw;
+    }
+
+    return (0);
+}
+
+uint16_t example_dolor_one_float_encode(float value)
+{
+    return (uint16_t)((value - 0.0f) / 0.5f);
+}
+
+float example_dolor_one_float_decode(uint16_t value)
+{
+    return ((float)value * 0.5f + 0.0f);
+}
+
+bool example_dolor_one_float_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 130.0f));
+}
+
+int example_multiplex_test_pack(uint8_t *dst_p, const struct example_multiplex_test_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[0] |= (uint8_t)((uint8_t)src_p->multiplexor & 0x0fu);
+    dst_p[0] |= (uint8_t)(((uint8_t)src_p->unmultiplexed_signal << 4) & 0xf0u);
+    dst_p[1] |= (uint8_t)(((uint8_t)src_p->unmultiplexed_signal >> 4) & 0x0fu);
+
+    switch (src_p->multiplexor) {
+    case 0:
+        dst_p[1] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_zero_a << 4) & 0xf0u);
+        dst_p[2] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_zero_a >> 4) & 0x0fu);
+        dst_p[2] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_zero_b << 4) & 0xf0u);
+        dst_p[3] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_zero_b >> 4) & 0x0fu);
+        break;
+
+    case 1:
+        dst_p[1] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_one_a << 4) & 0xf0u);
+        dst_p[2] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_one_a >> 4) & 0x0fu);
+        dst_p[2] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_one_b << 4) & 0xf0u);
+        dst_p[3] |= (uint8_t)(((uint8_t)src_p->multiplexed_signal_one_b >> 4) & 0x0fu);
+        break;
+
+    default:
+        break;
+    }
+
+    return (8);
+}
+
+int example_multiplex_test_unpack(struct example_multiplex_test_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[0] & 0x0fu);
+        dst_p->multiplexor = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[0] & 0xf0u) >> 4);
+        raw |= (uint8_t)((uint8_t)(src_p[1] & 0x0fu) << 4);
+        dst_p->unmultiplexed_signal = (uint8_t)raw;
+    }
+
+    switch (dst_p->multiplexor) {
+    case 0:
+        {
+            uint8_t raw = 0u;
+            raw |= (uint8_t)((src_p[1] & 0xf0u) >> 4);
+            raw |= (uint8_t)((uint8_t)(src_p[2] & 0x0fu) << 4);
+            dst_p->multiplexed_signal_zero_a = (uint8_t)raw;
+        }
+        {
+            uint8_t raw = 0u;
+            raw |= (uint8_t)((src_p[2] & 0xf0u) >> 4);
+            raw |= (uint8_t)((uint8_t)(src_p[3] & 0x0fu) << 4);
+            dst_p->multiplexed_signal_zero_b = (uint8_t)raw;
+        }
+        break;
+
+    case 1:
+        {
+            uint8_t raw = 0u;
+            raw |= (uint8_t)((src_p[1] & 0xf0u) >> 4);
+            raw |= (uint8_t)((uint8_t)(src_p[2] & 0x0fu) << 4);
+            dst_p->multiplexed_signal_one_a = (uint8_t)raw;
+        }
+        {
+            uint8_t raw = 0u;
+            raw |= (uint8_t)((src_p[2] & 0xf0u) >> 4);
+            raw |= (uint8_t)((uint8_t)(src_p[3] & 0x0fu) << 4);
+            dst_p->multiplexed_signal_one_b = (uint8_t)raw;
+        }
+        break;
+
+    default:
+        break;
+    }
+
+    return (0);
+}
+
+uint8_t example_multiplex_test_multiplexor_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_multiplex_test_multiplexor_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_multiplex_test_multiplexor_is_in_range(uint8_t value)
+{
+    return (value <= 2);
+}
+
+uint8_t example_multiplex_test_unmultiplexed_signal_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_multiplex_test_unmultiplexed_signal_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_multiplex_test_unmultiplexed_signal_is_in_range(uint8_t value)
+{
+    return (value <= 4);
+}
+
+uint8_t example_multiplex_test_multiplexed_signal_zero_a_encode(float value)
+{
+    return (uint8_t)((value - 0.0f) / 0.1f);
+}
+
+float example_multiplex_test_multiplexed_signal_zero_a_decode(uint8_t value)
+{
+    return ((float)value * 0.1f + 0.0f);
+}
+
+bool example_multiplex_test_multiplexed_signal_zero_a_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 3.0f));
+}
+
+uint8_t example_multiplex_test_multiplexed_signal_zero_b_encode(float value)
+{
+    return (uint8_t)((value - 0.0f) / 0.1f);
+}
+
+float example_multiplex_test_multiplexed_signal_zero_b_decode(uint8_t value)
+{
+    return ((float)value * 0.1f + 0.0f);
+}
+
+bool example_multiplex_test_multiplexed_signal_zero_b_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 3.0f));
+}
+
+uint8_t example_multiplex_test_multiplexed_signal_one_a_encode(float value)
+{
+    return (uint8_t)((value - 0.0f) / 0.1f);
+}
+
+float example_multiplex_test_multiplexed_signal_one_a_decode(uint8_t value)
+{
+    return ((float)value * 0.1f + 0.0f);
+}
+
+bool example_multiplex_test_multiplexed_signal_one_a_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 6.0f));
+}
+
+uint8_t example_multiplex_test_multiplexed_signal_one_b_encode(float value)
+{
+    return (uint8_t)((value - 0.0f) / 0.1f);
+}
+
+float example_multiplex_test_multiplexed_signal_one_b_decode(uint8_t value)
+{
+    return ((float)value * 0.1f + 0.0f);
+}
+
+bool example_multiplex_test_multiplexed_signal_one_b_is_in_range(float value)
+{
+    return ((value >= 0.0f) && (value <= 6.0f));
+}
+
+int example_named_multiplex_test_pack(uint8_t *dst_p, const struct example_named_multiplex_test_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[0] |= (uint8_t)((uint8_t)src_p->kind & 0x0fu);
+
+    switch (src_p->kind) {
+    case 0:
+        dst_p[1] |= (uint8_t)((uint8_t)src_p->celsius & 0xffu);
+        break;
+
+    case 1:
+        dst_p[1] |= (uint8_t)((uint16_t)src_p->kilopascal & 0xffu);
+        dst_p[2] |= (uint8_t)(((uint16_t)src_p->kilopascal >> 8) & 0xffu);
+        break;
+
+    default:
+        break;
+    }
+
+    return (8);
+}
+
+int example_named_multiplex_test_unpack(struct example_named_multiplex_test_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[0] & 0x0fu);
+        dst_p->kind = (uint8_t)raw;
+    }
+
+    switch (dst_p->kind) {
+    case 0:
+        {
+            uint8_t raw = 0u;
+            raw |= (uint8_t)(src_p[1] & 0xffu);
+            dst_p->celsius = (int8_t)raw;
+        }
+        break;
+
+    case 1:
+        {
+            uint16_t raw = 0u;
+            raw |= (uint16_t)(src_p[1] & 0xffu);
+            raw |= (uint16_t)((uint16_t)(src_p[2] & 0xffu) << 8);
+            dst_p->kilopascal = (uint16_t)raw;
+        }
+        break;
+
+    default:
+        break;
+    }
+
+    return (0);
+}
+
+uint8_t example_named_multiplex_test_kind_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_named_multiplex_test_kind_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_named_multiplex_test_kind_is_in_range(uint8_t value)
+{
+    return (value <= 2);
+}
+
+int8_t example_named_multiplex_test_celsius_encode(int8_t value)
+{
+    return (int8_t)((value + 0) / 1);
+}
+
+int8_t example_named_multiplex_test_celsius_decode(int8_t value)
+{
+    return (int8_t)((int8_t)value * 1 + 0);
+}
+
+bool example_named_multiplex_test_celsius_is_in_range(int8_t value)
+{
+    return ((value >= -40) && (value <= 100));
+}
+
+uint16_t example_named_multiplex_test_kilopascal_encode(uint16_t value)
+{
+    return (uint16_t)((value + 0) / 1);
+}
+
+uint16_t example_named_multiplex_test_kilopascal_decode(uint16_t value)
+{
+    return (uint16_t)((uint16_t)value * 1 + 0);
+}
+
+bool example_named_multiplex_test_kilopascal_is_in_range(uint16_t value)
+{
+    return (value <= 1000);
+}
+
+int example_integer_factor_offset_pack(uint8_t *dst_p, const struct example_integer_factor_offset_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[0] |= (uint8_t)((uint8_t)src_p->byte_with_offset & 0xffu);
+    dst_p[1] |= (uint8_t)((uint8_t)src_p->byte_with_factor & 0xffu);
+    dst_p[2] |= (uint8_t)((uint8_t)src_p->byte_with_both & 0xffu);
+    dst_p[3] |= (uint8_t)((uint8_t)src_p->byte_with_negative_offset & 0xffu);
+    dst_p[4] |= (uint8_t)((uint8_t)src_p->byte_with_negative_min & 0xffu);
+
+    return (8);
+}
+
+int example_integer_factor_offset_unpack(struct example_integer_factor_offset_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[0] & 0xffu);
+        dst_p->byte_with_offset = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[1] & 0xffu);
+        dst_p->byte_with_factor = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[2] & 0xffu);
+        dst_p->byte_with_both = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[3] & 0xffu);
+        dst_p->byte_with_negative_offset = (uint8_t)raw;
+    }
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)(src_p[4] & 0xffu);
+        dst_p->byte_with_negative_min = (uint8_t)raw;
+    }
+
+    return (0);
+}
+
+uint8_t example_integer_factor_offset_byte_with_offset_encode(uint16_t value)
+{
+    return (uint8_t)((value - 1) / 1);
+}
+
+uint16_t example_integer_factor_offset_byte_with_offset_decode(uint8_t value)
+{
+    return (uint16_t)((uint16_t)value * 1 + 1);
+}
+
+bool example_integer_factor_offset_byte_with_offset_is_in_range(uint16_t value)
+{
+    return ((value >= 1) && (value <= 256));
+}
+
+uint8_t example_integer_factor_offset_byte_with_factor_encode(uint16_t value)
+{
+    return (uint8_t)((value + 0) / 4);
+}
+
+uint16_t example_integer_factor_offset_byte_with_factor_decode(uint8_t value)
+{
+    return (uint16_t)((uint16_t)value * 4 + 0);
+}
+
+bool example_integer_factor_offset_byte_with_factor_is_in_range(uint16_t value)
+{
+    return (value <= 1020);
+}
+
+uint8_t example_integer_factor_offset_byte_with_both_encode(uint16_t value)
+{
+    return (uint8_t)((value - 16) / 2);
+}
+
+uint16_t example_integer_factor_offset_byte_with_both_decode(uint8_t value)
+{
+    return (uint16_t)((uint16_t)value * 2 + 16);
+}
+
+bool example_integer_factor_offset_byte_with_both_is_in_range(uint16_t value)
+{
+    return ((value >= 16) && (value <= 526));
+}
+
+uint8_t example_integer_factor_offset_byte_with_negative_offset_encode(int16_t value)
+{
+    return (uint8_t)((value + 1) / 1);
+}
+
+int16_t example_integer_factor_offset_byte_with_negative_offset_decode(uint8_t value)
+{
+    return (int16_t)((int16_t)value * 1 - 1);
+}
+
+bool example_integer_factor_offset_byte_with_negative_offset_is_in_range(int16_t value)
+{
+    return ((value >= 0) && (value <= 255));
+}
+
+uint8_t example_integer_factor_offset_byte_with_negative_min_encode(int16_t value)
+{
+    return (uint8_t)((value + 1) / 1);
+}
+
+int16_t example_integer_factor_offset_byte_with_negative_min_decode(uint8_t value)
+{
+    return (int16_t)((int16_t)value * 1 - 1);
+}
+
+bool example_integer_factor_offset_byte_with_negative_min_is_in_range(int16_t value)
+{
+    return ((value >= -127) && (value <= 127));
+}
+
+int example_negative_factor_test_pack(uint8_t *dst_p, const struct example_negative_factor_test_t *src_p, size_t size)
+{
+    if (size < 4u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 4);
+
+    dst_p[0] |= (uint8_t)((uint16_t)src_p->unsigned_negative_factor_signal & 0xffu);
+    dst_p[1] |= (uint8_t)(((uint16_t)src_p->unsigned_negative_factor_signal >> 8) & 0xffu);
+    dst_p[2] |= (uint8_t)((uint16_t)src_p->width_more_than_min_max & 0xffu);
+    dst_p[3] |= (uint8_t)(((uint16_t)src_p->width_more_than_min_max >> 8) & 0x03u);
+
+    return (4);
+}
+
+int example_negative_factor_test_unpack(struct example_negative_factor_test_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 4u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)(src_p[0] & 0xffu);
+        raw |= (uint16_t)((uint16_t)(src_p[1] & 0xffu) << 8);
+        dst_p->unsigned_negative_factor_signal = (uint16_t)raw;
+    }
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)(src_p[2] & 0xffu);
+        raw |= (uint16_t)((uint16_t)(src_p[3] & 0x03u) << 8);
+        if ((raw & 0x200u) != 0u) {
+            raw |= 0xfc00u;
+        }
+        dst_p->width_more_than_min_max = (int16_t)raw;
+    }
+
+    return (0);
+}
+
+uint16_t example_negative_factor_test_unsigned_negative_factor_signal_encode(int32_t value)
+{
+    return (uint16_t)((value + 0) / -1);
+}
+
+int32_t example_negative_factor_test_unsigned_negative_factor_signal_decode(uint16_t value)
+{
+    return (int32_t)((int32_t)value * -1 + 0);
+}
+
+bool example_negative_factor_test_unsigned_negative_factor_signal_is_in_range(int32_t value)
+{
+    return ((value >= -65535) && (value <= 0));
+}
+
+int16_t example_negative_factor_test_width_more_than_min_max_encode(int16_t value)
+{
+    return (int16_t)((value + 0) / 1);
+}
+
+int16_t example_negative_factor_test_width_more_than_min_max_decode(int16_t value)
+{
+    return (int16_t)((int16_t)value * 1 + 0);
+}
+
+bool example_negative_factor_test_width_more_than_min_max_is_in_range(int16_t value)
+{
+    return ((value >= -2) && (value <= 2));
+}
+
+int example_larger_ints_with_offsets_pack(uint8_t *dst_p, const struct example_larger_ints_with_offsets_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[0] |= (uint8_t)((uint16_t)src_p->twelve & 0xffu);
+    dst_p[1] |= (uint8_t)(((uint16_t)src_p->twelve >> 8) & 0x0fu);
+    dst_p[1] |= (uint8_t)(((uint16_t)src_p->sixteen << 4) & 0xf0u);
+    dst_p[2] |= (uint8_t)(((uint16_t)src_p->sixteen >> 4) & 0xffu);
+    dst_p[3] |= (uint8_t)(((uint16_t)src_p->sixteen >> 12) & 0x0fu);
+
+    return (8);
+}
+
+int example_larger_ints_with_offsets_unpack(struct example_larger_ints_with_offsets_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)(src_p[0] & 0xffu);
+        raw |= (uint16_t)((uint16_t)(src_p[1] & 0x0fu) << 8);
+        dst_p->twelve = (uint16_t)raw;
+    }
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)((src_p[1] & 0xf0u) >> 4);
+        raw |= (uint16_t)((uint16_t)(src_p[2] & 0xffu) << 4);
+        raw |= (uint16_t)((uint16_t)(src_p[3] & 0x0fu) << 12);
+        dst_p->sixteen = (uint16_t)raw;
+    }
+
+    return (0);
+}
+
+uint16_t example_larger_ints_with_offsets_twelve_encode(int16_t value)
+{
+    return (uint16_t)((value + 1000) / 1);
+}
+
+int16_t example_larger_ints_with_offsets_twelve_decode(uint16_t value)
+{
+    return (int16_t)((int16_t)value * 1 - 1000);
+}
+
+bool example_larger_ints_with_offsets_twelve_is_in_range(int16_t value)
+{
+    return ((value >= -1000) && (value <= 3000));
+}
+
+uint16_t example_larger_ints_with_offsets_sixteen_encode(int32_t value)
+{
+    return (uint16_t)((value + 1000) / 1);
+}
+
+int32_t example_larger_ints_with_offsets_sixteen_decode(uint16_t value)
+{
+    return (int32_t)((int32_t)value * 1 - 1000);
+}
+
+bool example_larger_ints_with_offsets_sixteen_is_in_range(int32_t value)
+{
+    return ((value >= -1000) && (value <= 64535));
+}
+
+int example_msg_without_signals_pack(uint8_t *dst_p, const struct example_msg_without_signals_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+    (void)src_p;
+
+    return (8);
+}
+
+int example_msg_without_signals_unpack(struct example_msg_without_signals_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    (void)dst_p;
+    (void)src_p;
+
+    return (0);
+}
+
+int example_truncated_be_signal_pack(uint8_t *dst_p, const struct example_truncated_be_signal_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[0] |= (uint8_t)(((uint16_t)src_p->foo >> 11) & 0x01u);
+    dst_p[1] |= (uint8_t)(((uint16_t)src_p->foo >> 3) & 0xffu);
+    dst_p[2] |= (uint8_t)(((uint16_t)src_p->foo << 5) & 0xe0u);
+
+    return (8);
+}
+
+int example_truncated_be_signal_unpack(struct example_truncated_be_signal_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)((uint16_t)(src_p[0] & 0x01u) << 11);
+        raw |= (uint16_t)((uint16_t)(src_p[1] & 0xffu) << 3);
+        raw |= (uint16_t)((src_p[2] & 0xe0u) >> 5);
+        if ((raw & 0x800u) != 0u) {
+            raw |= 0xf000u;
+        }
+        dst_p->foo = (int16_t)raw;
+    }
+
+    return (0);
+}
+
+int16_t example_truncated_be_signal_foo_encode(int16_t value)
+{
+    return (int16_t)((value + 0) / 1);
+}
+
+int16_t example_truncated_be_signal_foo_decode(int16_t value)
+{
+    return (int16_t)((int16_t)value * 1 + 0);
+}
+
+bool example_truncated_be_signal_foo_is_in_range(int16_t value)
+{
+    return ((value >= -100) && (value <= 100));
+}
+
+int example_truncated_le_signal_pack(uint8_t *dst_p, const struct example_truncated_le_signal_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[0] |= (uint8_t)((uint16_t)src_p->foo & 0xffu);
+    dst_p[1] |= (uint8_t)(((uint16_t)src_p->foo >> 8) & 0x0fu);
+
+    return (8);
+}
+
+int example_truncated_le_signal_unpack(struct example_truncated_le_signal_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint16_t raw = 0u;
+        raw |= (uint16_t)(src_p[0] & 0xffu);
+        raw |= (uint16_t)((uint16_t)(src_p[1] & 0x0fu) << 8);
+        if ((raw & 0x800u) != 0u) {
+            raw |= 0xf000u;
+        }
+        dst_p->foo = (int16_t)raw;
+    }
+
+    return (0);
+}
+
+int16_t example_truncated_le_signal_foo_encode(int16_t value)
+{
+    return (int16_t)((value + 0) / 1);
+}
+
+int16_t example_truncated_le_signal_foo_decode(int16_t value)
+{
+    return (int16_t)((int16_t)value * 1 + 0);
+}
+
+bool example_truncated_le_signal_foo_is_in_range(int16_t value)
+{
+    return ((value >= -100) && (value <= 100));
+}
+
+int example_msg_extended_id_pack(uint8_t *dst_p, const struct example_msg_extended_id_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    memset(&dst_p[0], 0, 8);
+
+    dst_p[1] |= (uint8_t)(((uint8_t)src_p->dummy << 6) & 0xc0u);
+
+    return (8);
+}
+
+int example_msg_extended_id_unpack(struct example_msg_extended_id_t *dst_p, const uint8_t *src_p, size_t size)
+{
+    if (size < 8u) {
+        return (-EINVAL);
+    }
+
+    {
+        uint8_t raw = 0u;
+        raw |= (uint8_t)((src_p[1] & 0xc0u) >> 6);
+        dst_p->dummy = (uint8_t)raw;
+    }
+
+    return (0);
+}
+
+uint8_t example_msg_extended_id_dummy_encode(uint8_t value)
+{
+    return (uint8_t)((value + 0) / 1);
+}
+
+uint8_t example_msg_extended_id_dummy_decode(uint8_t value)
+{
+    return (uint8_t)((uint8_t)value * 1 + 0);
+}
+
+bool example_msg_extended_id_dummy_is_in_range(uint8_t value)
+{
+    return (value <= 3);
+}
+
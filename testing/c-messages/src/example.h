@@ -0,0 +1,1418 @@
+/* Generated code! */
+
+#ifndef EXAMPLE_H
+#define EXAMPLE_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#include <stdint.h>
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifndef EINVAL
+#    define EINVAL 22
+#endif
+
+/* Frame ids. */
+#define EXAMPLE_FOO_FRAME_ID (0x100u)
+#define EXAMPLE_BAR_FRAME_ID (0x200u)
+#define EXAMPLE_X4WD_FRAME_ID (0x300u)
+#define EXAMPLE_AMET_FRAME_ID (0x400u)
+#define EXAMPLE_DOLOR_FRAME_ID (0x404u)
+#define EXAMPLE_MULTIPLEX_TEST_FRAME_ID (0xc8u)
+#define EXAMPLE_NAMED_MULTIPLEX_TEST_FRAME_ID (0xc9u)
+#define EXAMPLE_INTEGER_FACTOR_OFFSET_FRAME_ID (0x539u)
+#define EXAMPLE_NEGATIVE_FACTOR_TEST_FRAME_ID (0x540u)
+#define EXAMPLE_LARGER_INTS_WITH_OFFSETS_FRAME_ID (0x53au)
+#define EXAMPLE_MSG_WITHOUT_SIGNALS_FRAME_ID (0x201u)
+#define EXAMPLE_TRUNCATED_BE_SIGNAL_FRAME_ID (0x2329u)
+#define EXAMPLE_TRUNCATED_LE_SIGNAL_FRAME_ID (0x232au)
+#define EXAMPLE_MSG_EXTENDED_ID_FRAME_ID (0x1234u)
+
+/* Frame lengths in bytes. */
+#define EXAMPLE_FOO_LENGTH (4u)
+#define EXAMPLE_BAR_LENGTH (8u)
+#define EXAMPLE_X4WD_LENGTH (8u)
+#define EXAMPLE_AMET_LENGTH (8u)
+#define EXAMPLE_DOLOR_LENGTH (8u)
+#define EXAMPLE_MULTIPLEX_TEST_LENGTH (8u)
+#define EXAMPLE_NAMED_MULTIPLEX_TEST_LENGTH (8u)
+#define EXAMPLE_INTEGER_FACTOR_OFFSET_LENGTH (8u)
+#define EXAMPLE_NEGATIVE_FACTOR_TEST_LENGTH (4u)
+#define EXAMPLE_LARGER_INTS_WITH_OFFSETS_LENGTH (8u)
+#define EXAMPLE_MSG_WITHOUT_SIGNALS_LENGTH (8u)
+#define EXAMPLE_TRUNCATED_BE_SIGNAL_LENGTH (8u)
+#define EXAMPLE_TRUNCATED_LE_SIGNAL_LENGTH (8u)
+#define EXAMPLE_MSG_EXTENDED_ID_LENGTH (8u)
+
+/* Extended or standard frame types. */
+#define EXAMPLE_FOO_IS_EXTENDED (0)
+#define EXAMPLE_BAR_IS_EXTENDED (0)
+#define EXAMPLE_X4WD_IS_EXTENDED (0)
+#define EXAMPLE_AMET_IS_EXTENDED (0)
+#define EXAMPLE_DOLOR_IS_EXTENDED (0)
+#define EXAMPLE_MULTIPLEX_TEST_IS_EXTENDED (0)
+#define EXAMPLE_NAMED_MULTIPLEX_TEST_IS_EXTENDED (0)
+#define EXAMPLE_INTEGER_FACTOR_OFFSET_IS_EXTENDED (0)
+#define EXAMPLE_NEGATIVE_FACTOR_TEST_IS_EXTENDED (0)
+#define EXAMPLE_LARGER_INTS_WITH_OFFSETS_IS_EXTENDED (0)
+#define EXAMPLE_MSG_WITHOUT_SIGNALS_IS_EXTENDED (0)
+#define EXAMPLE_TRUNCATED_BE_SIGNAL_IS_EXTENDED (0)
+#define EXAMPLE_TRUNCATED_LE_SIGNAL_IS_EXTENDED (0)
+#define EXAMPLE_MSG_EXTENDED_ID_IS_EXTENDED (1)
+
+/* Signal choices. */
+#define EXAMPLE_BAR_THREE_OFF_CHOICE (0u)
+#define EXAMPLE_BAR_THREE_ON_CHOICE (1u)
+#define EXAMPLE_BAR_THREE_ONER_CHOICE (2u)
+#define EXAMPLE_BAR_THREE_ONEST_CHOICE (3u)
+#define EXAMPLE_BAR_FOUR_OFF_CHOICE (0u)
+#define EXAMPLE_BAR_FOUR_ON_CHOICE (1u)
+#define EXAMPLE_BAR_FOUR_ONER_CHOICE (2u)
+#define EXAMPLE_BAR_FOUR_ONEST_CHOICE (3u)
+#define EXAMPLE_BAR_XTYPE_X0OFF_CHOICE (0u)
+#define EXAMPLE_BAR_XTYPE_X1ON_CHOICE (1u)
+#define EXAMPLE_X4WD_X4DRIVE_OFF_CHOICE (0u)
+#define EXAMPLE_X4WD_X4DRIVE_X2WD_CHOICE (1u)
+#define EXAMPLE_X4WD_X4DRIVE_X4WD_CHOICE (2u)
+#define EXAMPLE_X4WD_X4DRIVE_ALL_CHOICE (3u)
+#define EXAMPLE_AMET_FOUR_OFF_CHOICE (0u)
+#define EXAMPLE_AMET_FOUR_ON_CHOICE (1u)
+#define EXAMPLE_AMET_FOUR_ONER_CHOICE (2u)
+#define EXAMPLE_AMET_FOUR_ONEST_CHOICE (3u)
+#define EXAMPLE_AMET_FIVE_ON_CHOICE (1u)
+#define EXAMPLE_AMET_FIVE_OFF_CHOICE (0u)
+#define EXAMPLE_DOLOR_ONE_FLOAT_DOLOR_CHOICE (3u)
+#define EXAMPLE_DOLOR_ONE_FLOAT_OTHER_CHOICE (5u)
+#define EXAMPLE_NAMED_MULTIPLEX_TEST_KIND_TEMPERATURE_CHOICE (0u)
+#define EXAMPLE_NAMED_MULTIPLEX_TEST_KIND_PRESSURE_CHOICE (1u)
+#define EXAMPLE_NAMED_MULTIPLEX_TEST_KIND_UNUSED_CHOICE (2u)
+
+/**
+ * Signals in message Foo.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_foo_t {
+    /**
+     * Voltage
+     *
+     * - Min: 0
+     * - Max: 63.9990234375
+     * - Unit: "V"
+     * - Factor: 0.000976562
+     * - Offset: 0
+     */
+    uint16_t voltage;
+
+    /**
+     * Current
+     *
+     * - Min: -2048
+     * - Max: 2047.9375
+     * - Unit: "A"
+     * - Factor: 0.0625
+     * - Offset: 0
+     */
+    int16_t current;
+};
+
+/**
+ * Signals in message Bar.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_bar_t {
+    /**
+     * One
+     *
+     * - Min: 0
+     * - Max: 3
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t one;
+
+    /**
+     * Two
+     *
+     * - Min: 0
+     * - Max: 100
+     * - Unit: "%"
+     * - Factor: 0.39
+     * - Offset: 0
+     */
+    uint8_t two;
+
+    /**
+     * Three
+     *
+     * - Min: 0
+     * - Max: 7
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t three;
+
+    /**
+     * Four
+     *
+     * - Min: 0
+     * - Max: 3
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t four;
+
+    /**
+     * Type
+     *
+     * - Min: 0
+     * - Max: 1
+     * - Unit: "boolean"
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t xtype;
+};
+
+/**
+ * Signals in message _4WD.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_x4wd_t {
+    /**
+     * _4DRIVE
+     *
+     * - Min: 0
+     * - Max: 7
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t x4drive;
+};
+
+/**
+ * Signals in message Amet.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_amet_t {
+    /**
+     * One
+     *
+     * - Min: 0
+     * - Max: 3
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t one;
+
+    /**
+     * Two
+     *
+     * - Min: 0
+     * - Max: 100
+     * - Unit: "%"
+     * - Factor: 0.39
+     * - Offset: 0
+     */
+    uint8_t two;
+
+    /**
+     * Three
+     *
+     * - Min: 0
+     * - Max: 7
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t three;
+
+    /**
+     * Four
+     *
+     * - Min: 0
+     * - Max: 3
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t four;
+
+    /**
+     * Five
+     *
+     * - Min: 0
+     * - Max: 1
+     * - Unit: "boolean"
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t five;
+};
+
+/**
+ * Signals in message Dolor.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_dolor_t {
+    /**
+     * OneFloat
+     *
+     * - Min: 0
+     * - Max: 130
+     * - Unit: ""
+     * - Factor: 0.5
+     * - Offset: 0
+     */
+    uint16_t one_float;
+};
+
+/**
+ * Signals in message MultiplexTest.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_multiplex_test_t {
+    /**
+     * Multiplexor
+     *
+     * - Min: 0
+     * - Max: 2
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t multiplexor;
+
+    /**
+     * UnmultiplexedSignal
+     *
+     * - Min: 0
+     * - Max: 4
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t unmultiplexed_signal;
+
+    /**
+     * MultiplexedSignalZeroA
+     *
+     * - Min: 0
+     * - Max: 3
+     * - Unit: ""
+     * - Factor: 0.1
+     * - Offset: 0
+     */
+    uint8_t multiplexed_signal_zero_a;
+
+    /**
+     * MultiplexedSignalZeroB
+     *
+     * - Min: 0
+     * - Max: 3
+     * - Unit: ""
+     * - Factor: 0.1
+     * - Offset: 0
+     */
+    uint8_t multiplexed_signal_zero_b;
+
+    /**
+     * MultiplexedSignalOneA
+     *
+     * - Min: 0
+     * - Max: 6
+     * - Unit: ""
+     * - Factor: 0.1
+     * - Offset: 0
+     */
+    uint8_t multiplexed_signal_one_a;
+
+    /**
+     * MultiplexedSignalOneB
+     *
+     * - Min: 0
+     * - Max: 6
+     * - Unit: ""
+     * - Factor: 0.1
+     * - Offset: 0
+     */
+    uint8_t multiplexed_signal_one_b;
+};
+
+/**
+ * Signals in message NamedMultiplexTest.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_named_multiplex_test_t {
+    /**
+     * Kind
+     *
+     * - Min: 0
+     * - Max: 2
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t kind;
+
+    /**
+     * Celsius
+     *
+     * - Min: -40
+     * - Max: 100
+     * - Unit: "degC"
+     * - Factor: 1
+     * - Offset: 0
+     */
+    int8_t celsius;
+
+    /**
+     * Kilopascal
+     *
+     * - Min: 0
+     * - Max: 1000
+     * - Unit: "kPa"
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint16_t kilopascal;
+};
+
+/**
+ * Signals in message IntegerFactorOffset.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_integer_factor_offset_t {
+    /**
+     * ByteWithOffset
+     *
+     * - Min: 1
+     * - Max: 256
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 1
+     */
+    uint8_t byte_with_offset;
+
+    /**
+     * ByteWithFactor
+     *
+     * - Min: 0
+     * - Max: 1020
+     * - Unit: ""
+     * - Factor: 4
+     * - Offset: 0
+     */
+    uint8_t byte_with_factor;
+
+    /**
+     * ByteWithBoth
+     *
+     * - Min: 16
+     * - Max: 526
+     * - Unit: ""
+     * - Factor: 2
+     * - Offset: 16
+     */
+    uint8_t byte_with_both;
+
+    /**
+     * ByteWithNegativeOffset
+     *
+     * - Min: 0
+     * - Max: 255
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: -1
+     */
+    uint8_t byte_with_negative_offset;
+
+    /**
+     * ByteWithNegativeMin
+     *
+     * - Min: -127
+     * - Max: 127
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: -1
+     */
+    uint8_t byte_with_negative_min;
+};
+
+/**
+ * Signals in message NegativeFactorTest.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_negative_factor_test_t {
+    /**
+     * UnsignedNegativeFactorSignal
+     *
+     * - Min: -65535
+     * - Max: 0
+     * - Unit: ""
+     * - Factor: -1
+     * - Offset: 0
+     */
+    uint16_t unsigned_negative_factor_signal;
+
+    /**
+     * WidthMoreThanMinMax
+     *
+     * - Min: -2
+     * - Max: 2
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    int16_t width_more_than_min_max;
+};
+
+/**
+ * Signals in message LargerIntsWithOffsets.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_larger_ints_with_offsets_t {
+    /**
+     * Twelve
+     *
+     * - Min: -1000
+     * - Max: 3000
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: -1000
+     */
+    uint16_t twelve;
+
+    /**
+     * Sixteen
+     *
+     * - Min: -1000
+     * - Max: 64535
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: -1000
+     */
+    uint16_t sixteen;
+};
+
+/**
+ * Signals in message MsgWithoutSignals.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_msg_without_signals_t {
+    /**
+     * Dummy signal in empty message.
+     */
+    uint8_t dummy;
+};
+
+/**
+ * Signals in message TruncatedBeSignal.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_truncated_be_signal_t {
+    /**
+     * Foo
+     *
+     * - Min: -100
+     * - Max: 100
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    int16_t foo;
+};
+
+/**
+ * Signals in message TruncatedLeSignal.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_truncated_le_signal_t {
+    /**
+     * Foo
+     *
+     * - Min: -100
+     * - Max: 100
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    int16_t foo;
+};
+
+/**
+ * Signals in message MsgExtendedId.
+ *
+ * All signal values are as on the CAN bus.
+ */
+struct example_msg_extended_id_t {
+    /**
+     * Dummy
+     *
+     * - Min: 0
+     * - Max: 3
+     * - Unit: ""
+     * - Factor: 1
+     * - Offset: 0
+     */
+    uint8_t dummy;
+};
+
+/**
+ * Pack message Foo.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_foo_pack(uint8_t *dst_p, const struct example_foo_t *src_p, size_t size);
+
+/**
+ * Unpack message Foo.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_foo_unpack(struct example_foo_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint16_t example_foo_voltage_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_foo_voltage_decode(uint16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_foo_voltage_is_in_range(float value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+int16_t example_foo_current_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_foo_current_decode(int16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_foo_current_is_in_range(float value);
+
+/**
+ * Pack message Bar.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_bar_pack(uint8_t *dst_p, const struct example_bar_t *src_p, size_t size);
+
+/**
+ * Unpack message Bar.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_bar_unpack(struct example_bar_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_one_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_one_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_bar_one_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_two_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_bar_two_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_bar_two_is_in_range(float value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_three_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_three_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_bar_three_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_four_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_four_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_bar_four_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_bar_xtype_encode(bool value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+bool example_bar_xtype_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_bar_xtype_is_in_range(bool value);
+
+/**
+ * Pack message _4WD.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_x4wd_pack(uint8_t *dst_p, const struct example_x4wd_t *src_p, size_t size);
+
+/**
+ * Unpack message _4WD.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_x4wd_unpack(struct example_x4wd_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_x4wd_x4drive_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_x4wd_x4drive_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_x4wd_x4drive_is_in_range(uint8_t value);
+
+/**
+ * Pack message Amet.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_amet_pack(uint8_t *dst_p, const struct example_amet_t *src_p, size_t size);
+
+/**
+ * Unpack message Amet.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_amet_unpack(struct example_amet_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_one_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_one_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_amet_one_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_two_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_amet_two_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_amet_two_is_in_range(float value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_three_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_three_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_amet_three_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_four_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_four_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_amet_four_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_amet_five_encode(bool value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+bool example_amet_five_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_amet_five_is_in_range(bool value);
+
+/**
+ * Pack message Dolor.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_dolor_pack(uint8_t *dst_p, const struct example_dolor_t *src_p, size_t size);
+
+/**
+ * Unpack message Dolor.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_dolor_unpack(struct example_dolor_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint16_t example_dolor_one_float_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_dolor_one_float_decode(uint16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_dolor_one_float_is_in_range(float value);
+
+/**
+ * Pack message MultiplexTest.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_multiplex_test_pack(uint8_t *dst_p, const struct example_multiplex_test_t *src_p, size_t size);
+
+/**
+ * Unpack message MultiplexTest.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_multiplex_test_unpack(struct example_multiplex_test_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_multiplexor_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_multiplexor_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_multiplex_test_multiplexor_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_unmultiplexed_signal_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_unmultiplexed_signal_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_multiplex_test_unmultiplexed_signal_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_multiplexed_signal_zero_a_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_multiplex_test_multiplexed_signal_zero_a_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_multiplex_test_multiplexed_signal_zero_a_is_in_range(float value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_multiplexed_signal_zero_b_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_multiplex_test_multiplexed_signal_zero_b_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_multiplex_test_multiplexed_signal_zero_b_is_in_range(float value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_multiplexed_signal_one_a_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_multiplex_test_multiplexed_signal_one_a_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_multiplex_test_multiplexed_signal_one_a_is_in_range(float value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_multiplex_test_multiplexed_signal_one_b_encode(float value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+float example_multiplex_test_multiplexed_signal_one_b_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_multiplex_test_multiplexed_signal_one_b_is_in_range(float value);
+
+/**
+ * Pack message NamedMultiplexTest.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_named_multiplex_test_pack(uint8_t *dst_p, const struct example_named_multiplex_test_t *src_p, size_t size);
+
+/**
+ * Unpack message NamedMultiplexTest.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_named_multiplex_test_unpack(struct example_named_multiplex_test_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_named_multiplex_test_kind_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_named_multiplex_test_kind_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_named_multiplex_test_kind_is_in_range(uint8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+int8_t example_named_multiplex_test_celsius_encode(int8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int8_t example_named_multiplex_test_celsius_decode(int8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_named_multiplex_test_celsius_is_in_range(int8_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint16_t example_named_multiplex_test_kilopascal_encode(uint16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint16_t example_named_multiplex_test_kilopascal_decode(uint16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_named_multiplex_test_kilopascal_is_in_range(uint16_t value);
+
+/**
+ * Pack message IntegerFactorOffset.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_integer_factor_offset_pack(uint8_t *dst_p, const struct example_integer_factor_offset_t *src_p, size_t size);
+
+/**
+ * Unpack message IntegerFactorOffset.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_integer_factor_offset_unpack(struct example_integer_factor_offset_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_integer_factor_offset_byte_with_offset_encode(uint16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint16_t example_integer_factor_offset_byte_with_offset_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_integer_factor_offset_byte_with_offset_is_in_range(uint16_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_integer_factor_offset_byte_with_factor_encode(uint16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint16_t example_integer_factor_offset_byte_with_factor_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_integer_factor_offset_byte_with_factor_is_in_range(uint16_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_integer_factor_offset_byte_with_both_encode(uint16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint16_t example_integer_factor_offset_byte_with_both_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_integer_factor_offset_byte_with_both_is_in_range(uint16_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_integer_factor_offset_byte_with_negative_offset_encode(int16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int16_t example_integer_factor_offset_byte_with_negative_offset_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_integer_factor_offset_byte_with_negative_offset_is_in_range(int16_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_integer_factor_offset_byte_with_negative_min_encode(int16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int16_t example_integer_factor_offset_byte_with_negative_min_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_integer_factor_offset_byte_with_negative_min_is_in_range(int16_t value);
+
+/**
+ * Pack message NegativeFactorTest.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_negative_factor_test_pack(uint8_t *dst_p, const struct example_negative_factor_test_t *src_p, size_t size);
+
+/**
+ * Unpack message NegativeFactorTest.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_negative_factor_test_unpack(struct example_negative_factor_test_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint16_t example_negative_factor_test_unsigned_negative_factor_signal_encode(int32_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int32_t example_negative_factor_test_unsigned_negative_factor_signal_decode(uint16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_negative_factor_test_unsigned_negative_factor_signal_is_in_range(int32_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+int16_t example_negative_factor_test_width_more_than_min_max_encode(int16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int16_t example_negative_factor_test_width_more_than_min_max_decode(int16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_negative_factor_test_width_more_than_min_max_is_in_range(int16_t value);
+
+/**
+ * Pack message LargerIntsWithOffsets.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_larger_ints_with_offsets_pack(uint8_t *dst_p, const struct example_larger_ints_with_offsets_t *src_p, size_t size);
+
+/**
+ * Unpack message LargerIntsWithOffsets.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_larger_ints_with_offsets_unpack(struct example_larger_ints_with_offsets_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint16_t example_larger_ints_with_offsets_twelve_encode(int16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int16_t example_larger_ints_with_offsets_twelve_decode(uint16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_larger_ints_with_offsets_twelve_is_in_range(int16_t value);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint16_t example_larger_ints_with_offsets_sixteen_encode(int32_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int32_t example_larger_ints_with_offsets_sixteen_decode(uint16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_larger_ints_with_offsets_sixteen_is_in_range(int32_t value);
+
+/**
+ * Pack message MsgWithoutSignals.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_msg_without_signals_pack(uint8_t *dst_p, const struct example_msg_without_signals_t *src_p, size_t size);
+
+/**
+ * Unpack message MsgWithoutSignals.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_msg_without_signals_unpack(struct example_msg_without_signals_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Pack message TruncatedBeSignal.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_truncated_be_signal_pack(uint8_t *dst_p, const struct example_truncated_be_signal_t *src_p, size_t size);
+
+/**
+ * Unpack message TruncatedBeSignal.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_truncated_be_signal_unpack(struct example_truncated_be_signal_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+int16_t example_truncated_be_signal_foo_encode(int16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int16_t example_truncated_be_signal_foo_decode(int16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_truncated_be_signal_foo_is_in_range(int16_t value);
+
+/**
+ * Pack message TruncatedLeSignal.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_truncated_le_signal_pack(uint8_t *dst_p, const struct example_truncated_le_signal_t *src_p, size_t size);
+
+/**
+ * Unpack message TruncatedLeSignal.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_truncated_le_signal_unpack(struct example_truncated_le_signal_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+int16_t example_truncated_le_signal_foo_encode(int16_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+int16_t example_truncated_le_signal_foo_decode(int16_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_truncated_le_signal_foo_is_in_range(int16_t value);
+
+/**
+ * Pack message MsgExtendedId.
+ *
+ * @param[out] dst_p Buffer to pack the message into.
+ * @param[in] src_p Data to pack.
+ * @param[in] size Size of dst_p.
+ *
+ * @return Size of packed data, or negative error code.
+ */
+int example_msg_extended_id_pack(uint8_t *dst_p, const struct example_msg_extended_id_t *src_p, size_t size);
+
+/**
+ * Unpack message MsgExtendedId.
+ *
+ * @param[out] dst_p Object to unpack the message into.
+ * @param[in] src_p Message to unpack.
+ * @param[in] size Size of src_p.
+ *
+ * @return zero(0) or negative error code.
+ */
+int example_msg_extended_id_unpack(struct example_msg_extended_id_t *dst_p, const uint8_t *src_p, size_t size);
+
+/**
+ * Encode given signal by applying scaling and offset.
+ */
+uint8_t example_msg_extended_id_dummy_encode(uint8_t value);
+
+/**
+ * Decode given signal by applying scaling and offset.
+ */
+uint8_t example_msg_extended_id_dummy_decode(uint8_t value);
+
+/**
+ * Check that given signal is in allowed range.
+ */
+bool example_msg_extended_id_dummy_is_in_range(uint8_t value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,261 @@
+//! C code generated from `example.dbc`, checked against the Rust code in `can-messages`.
+
+#![allow(non_camel_case_types)]
+
+use std::os::raw::c_int;
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct example_foo_t {
+    pub voltage: u16,
+    pub current: i16,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct example_bar_t {
+    pub one: u8,
+    pub two: u8,
+    pub three: u8,
+    pub four: u8,
+    pub xtype: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct example_multiplex_test_t {
+    pub multiplexor: u8,
+    pub unmultiplexed_signal: u8,
+    pub multiplexed_signal_zero_a: u8,
+    pub multiplexed_signal_zero_b: u8,
+    pub multiplexed_signal_one_a: u8,
+    pub multiplexed_signal_one_b: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct example_integer_factor_offset_t {
+    pub byte_with_offset: u8,
+    pub byte_with_factor: u8,
+    pub byte_with_both: u8,
+    pub byte_with_negative_offset: u8,
+    pub byte_with_negative_min: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct example_truncated_be_signal_t {
+    pub foo: i16,
+}
+
+extern "C" {
+    pub fn example_foo_pack(dst_p: *mut u8, src_p: *const example_foo_t, size: usize) -> c_int;
+    pub fn example_foo_unpack(dst_p: *mut example_foo_t, src_p: *const u8, size: usize) -> c_int;
+    pub fn example_foo_voltage_encode(value: f32) -> u16;
+    pub fn example_foo_voltage_decode(value: u16) -> f32;
+    pub fn example_foo_current_encode(value: f32) -> i16;
+    pub fn example_foo_current_decode(value: i16) -> f32;
+    pub fn example_foo_current_is_in_range(value: f32) -> bool;
+
+    pub fn example_bar_pack(dst_p: *mut u8, src_p: *const example_bar_t, size: usize) -> c_int;
+    pub fn example_bar_unpack(dst_p: *mut example_bar_t, src_p: *const u8, size: usize) -> c_int;
+    pub fn example_bar_two_encode(value: f32) -> u8;
+    pub fn example_bar_two_decode(value: u8) -> f32;
+    pub fn example_bar_xtype_encode(value: bool) -> u8;
+    pub fn example_bar_xtype_decode(value: u8) -> bool;
+
+    pub fn example_multiplex_test_pack(
+        dst_p: *mut u8,
+        src_p: *const example_multiplex_test_t,
+        size: usize,
+    ) -> c_int;
+    pub fn example_multiplex_test_unpack(
+        dst_p: *mut example_multiplex_test_t,
+        src_p: *const u8,
+        size: usize,
+    ) -> c_int;
+    pub fn example_multiplex_test_multiplexed_signal_one_a_encode(value: f32) -> u8;
+    pub fn example_multiplex_test_multiplexed_signal_one_b_encode(value: f32) -> u8;
+
+    pub fn example_integer_factor_offset_pack(
+        dst_p: *mut u8,
+        src_p: *const example_integer_factor_offset_t,
+        size: usize,
+    ) -> c_int;
+    pub fn example_integer_factor_offset_byte_with_both_encode(value: u16) -> u8;
+    pub fn example_integer_factor_offset_byte_with_both_decode(value: u8) -> u16;
+    pub fn example_integer_factor_offset_byte_with_negative_offset_encode(value: i16) -> u8;
+    pub fn example_integer_factor_offset_byte_with_negative_offset_decode(value: u8) -> i16;
+
+    pub fn example_truncated_be_signal_unpack(
+        dst_p: *mut example_truncated_be_signal_t,
+        src_p: *const u8,
+        size: usize,
+    ) -> c_int;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use can_messages::{
+        Bar, BarFour, BarThree, BarType, Foo, IntegerFactorOffset, MultiplexTest,
+        MultiplexTestMultiplexorIndex, MultiplexTestMultiplexorM1, TruncatedBeSignal,
+    };
+
+    #[test]
+    fn pack_little_endian_signed() {
+        let rust = Foo::new(12.5, -3.0 * 0.0625).unwrap();
+
+        let foo = unsafe {
+            example_foo_t {
+                voltage: example_foo_voltage_encode(12.5),
+                current: example_foo_current_encode(-3.0 * 0.0625),
+            }
+        };
+        let mut buffer = [0u8; 4];
+        assert_eq!(
+            unsafe { example_foo_pack(buffer.as_mut_ptr(), &foo, buffer.len()) },
+            4
+        );
+        assert_eq!(rust.raw(), &buffer);
+    }
+
+    #[test]
+    fn unpack_little_endian_signed() {
+        let rust = Foo::new(12.5, -2048.0).unwrap();
+
+        let mut foo = example_foo_t::default();
+        assert_eq!(
+            unsafe { example_foo_unpack(&mut foo, rust.raw().as_ptr(), 4) },
+            0
+        );
+        assert_eq!(
+            unsafe { example_foo_voltage_decode(foo.voltage) },
+            rust.voltage()
+        );
+        assert_eq!(
+            unsafe { example_foo_current_decode(foo.current) },
+            rust.current()
+        );
+        assert!(unsafe { example_foo_current_is_in_range(rust.current()) });
+        assert!(!unsafe { example_foo_current_is_in_range(-2049.0) });
+    }
+
+    #[test]
+    fn payload_too_short() {
+        let foo = example_foo_t::default();
+        let mut buffer = [0u8; 3];
+        assert_eq!(
+            unsafe { example_foo_pack(buffer.as_mut_ptr(), &foo, buffer.len()) },
+            -22
+        );
+    }
+
+    #[test]
+    fn big_endian() {
+        let rust = Bar::new(3, 2.0, BarThree::Oner, BarFour::On, BarType::X1on).unwrap();
+
+        let bar = unsafe {
+            example_bar_t {
+                one: 3,
+                two: example_bar_two_encode(2.0),
+                three: 2,
+                four: 1,
+                xtype: example_bar_xtype_encode(true),
+            }
+        };
+        let mut buffer = [0u8; 8];
+        unsafe { example_bar_pack(buffer.as_mut_ptr(), &bar, buffer.len()) };
+        assert_eq!(rust.raw(), &buffer);
+
+        let mut unpacked = example_bar_t::default();
+        unsafe { example_bar_unpack(&mut unpacked, rust.raw().as_ptr(), 8) };
+        assert_eq!(unpacked, bar);
+        assert_eq!(unsafe { example_bar_two_decode(unpacked.two) }, rust.two());
+        assert!(unsafe { example_bar_xtype_decode(unpacked.xtype) });
+    }
+
+    #[test]
+    fn multiplexed() {
+        let mut rust = MultiplexTest::new(1, 3).unwrap();
+        let mut m1 = MultiplexTestMultiplexorM1::new();
+        m1.set_multiplexed_signal_one_a(1.2).unwrap();
+        m1.set_multiplexed_signal_one_b(5.0).unwrap();
+        rust.set_m1(m1).unwrap();
+
+        let multiplex_test =
+            unsafe {
+                example_multiplex_test_t {
+                    multiplexor: 1,
+                    unmultiplexed_signal: 3,
+                    // Signals of other multiplexor values are not packed
+                    multiplexed_signal_zero_a: 0xff,
+                    multiplexed_signal_zero_b: 0xff,
+                    multiplexed_signal_one_a:
+                        example_multiplex_test_multiplexed_signal_one_a_encode(1.2),
+                    multiplexed_signal_one_b:
+                        example_multiplex_test_multiplexed_signal_one_b_encode(5.0),
+                }
+            };
+        let mut buffer = [0u8; 8];
+        unsafe { example_multiplex_test_pack(buffer.as_mut_ptr(), &multiplex_test, 8) };
+        assert_eq!(rust.raw(), &buffer);
+
+        let mut unpacked = example_multiplex_test_t::default();
+        unsafe { example_multiplex_test_unpack(&mut unpacked, buffer.as_ptr(), 8) };
+        assert_eq!(
+            unpacked,
+            example_multiplex_test_t {
+                multiplexed_signal_zero_a: 0,
+                multiplexed_signal_zero_b: 0,
+                ..multiplex_test
+            }
+        );
+        assert!(matches!(
+            MultiplexTest::try_from(&buffer[..]).unwrap().multiplexor(),
+            Ok(MultiplexTestMultiplexorIndex::M1(_))
+        ));
+    }
+
+    #[test]
+    fn integer_factor_and_offset() {
+        let rust = IntegerFactorOffset::new(10, 20, 30, 40, 50).unwrap();
+
+        let message = unsafe {
+            example_integer_factor_offset_t {
+                byte_with_offset: 9,
+                byte_with_factor: 5,
+                byte_with_both: example_integer_factor_offset_byte_with_both_encode(30),
+                byte_with_negative_offset:
+                    example_integer_factor_offset_byte_with_negative_offset_encode(40),
+                byte_with_negative_min: 51,
+            }
+        };
+        let mut buffer = [0u8; 8];
+        unsafe { example_integer_factor_offset_pack(buffer.as_mut_ptr(), &message, 8) };
+        assert_eq!(rust.raw(), &buffer);
+
+        assert_eq!(
+            unsafe { example_integer_factor_offset_byte_with_both_decode(message.byte_with_both) },
+            rust.byte_with_both()
+        );
+        assert_eq!(
+            unsafe {
+                example_integer_factor_offset_byte_with_negative_offset_decode(
+                    message.byte_with_negative_offset,
+                )
+            },
+            rust.byte_with_negative_offset()
+        );
+    }
+
+    #[test]
+    fn sign_extension() {
+        let rust = TruncatedBeSignal::new(-100).unwrap();
+
+        let mut message = example_truncated_be_signal_t::default();
+        unsafe { example_truncated_be_signal_unpack(&mut message, rust.raw().as_ptr(), 8) };
+        assert_eq!(message.foo, -100);
+        assert_eq!(message.foo, rust.foo());
+    }
+}
@@ -1,5 +1,8 @@
 use anyhow::Result;
-use dbc_codegen::{Config, FeatureConfig};
+use dbc_codegen::{
+    BusesConfig, Config, ExtraAttribute, FeatureConfig, InputFormat, ModuleSplit,
+    SerdeRepresentation,
+};
 use std::{
     fs::{self, File},
     io::{BufWriter, Write},
@@ -11,6 +14,7 @@ fn main() -> Result<()> {
     let dbc_file = fs::read("../dbc-examples/example.dbc")?;
     let mut out = BufWriter::new(File::create(out_file)?);
     println!("cargo:rerun-if-changed=../dbc-examples/example.dbc");
+    println!("cargo:rerun-if-changed=../dbc-examples/example.ldf");
     println!("cargo:rerun-if-changed=../../src");
     println!("cargo:rerun-if-changed=../can-embedded/src");
 
@@ -20,9 +24,30 @@ fn main() -> Result<()> {
         .debug_prints(true)
         .impl_debug(FeatureConfig::Always)
         .impl_defmt(FeatureConfig::Always)
+        .impl_eq(FeatureConfig::Always)
+        .impl_hash(FeatureConfig::Always)
+        .impl_partial_ord(FeatureConfig::Always)
         .impl_error(FeatureConfig::Gated("std"))
         .impl_arbitrary(FeatureConfig::Gated("arb"))
+        .impl_serde(FeatureConfig::Gated("serde"))
+        .serde_representation(SerdeRepresentation::Signals)
         .check_ranges(FeatureConfig::Always)
+        .impl_const_fns(FeatureConfig::Always)
+        .strict_enums(true)
+        .dedupe_value_tables(true)
+        .impl_views(FeatureConfig::Always)
+        .impl_zerocopy(FeatureConfig::Gated("zerocopy"))
+        .impl_bytemuck(FeatureConfig::Gated("bytemuck"))
+        .message_attributes([ExtraAttribute::always("derive(Ord)")])
+        .enum_attributes([ExtraAttribute::gated(
+            "derive(zerocopy::KnownLayout)",
+            "zerocopy",
+        )])
+        .messages_enum_attributes([ExtraAttribute::gated(
+            "derive(zerocopy::KnownLayout)",
+            "zerocopy",
+        )])
+        .error_attributes([ExtraAttribute::always("derive(Hash)")])
         .build();
 
     dbc_codegen::codegen(config, &mut out)?;
@@ -38,5 +63,80 @@ fn main() -> Result<()> {
 
     fs::copy("src/messages.rs", "../can-embedded/src/messages.rs")?;
 
+    // The same messages, split into one file per transmitting node
+    let split_dir = "src/split";
+    fs::create_dir_all(split_dir)?;
+    let config = Config::builder()
+        .dbc_name("example.dbc")
+        .dbc_content(&dbc_file)
+        .impl_debug(FeatureConfig::Always)
+        .impl_eq(FeatureConfig::Always)
+        .impl_arbitrary(FeatureConfig::Gated("arb"))
+        .impl_error(FeatureConfig::Gated("std"))
+        .strict_enums(true)
+        .dedupe_value_tables(true)
+        .module_split(ModuleSplit::Transmitter)
+        .build();
+
+    dbc_codegen::codegen_to_dir(config, split_dir)?;
+
+    Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .arg(format!("{split_dir}/mod.rs"))
+        .output()
+        .expect("failed to execute rustfmt");
+
+    // The same DBC as two buses sharing `CanError`
+    let buses_file = "src/buses.rs";
+    let bus_config = || {
+        Config::builder()
+            .dbc_name("example.dbc")
+            .dbc_content(&dbc_file)
+            .impl_debug(FeatureConfig::Always)
+            .impl_eq(FeatureConfig::Always)
+            .impl_arbitrary(FeatureConfig::Gated("arb"))
+            .impl_error(FeatureConfig::Gated("std"))
+            .build()
+    };
+    let buses = BusesConfig::builder()
+        .buses(vec![("chassis", bus_config()), ("body", bus_config())])
+        .bus_enum(true)
+        .build();
+    let mut out = BufWriter::new(File::create(buses_file)?);
+    dbc_codegen::codegen_buses(buses, &mut out)?;
+    out.flush()?;
+    drop(out);
+
+    Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .arg(buses_file)
+        .output()
+        .expect("failed to execute rustfmt");
+
+    // Frames and schedule tables of a LIN sub-bus
+    let lin_file = "src/lin.rs";
+    let ldf_file = fs::read("../dbc-examples/example.ldf")?;
+    let config = Config::builder()
+        .dbc_name("example.ldf")
+        .dbc_content(&ldf_file)
+        .input_format(InputFormat::Ldf)
+        .impl_debug(FeatureConfig::Always)
+        .impl_eq(FeatureConfig::Always)
+        .impl_error(FeatureConfig::Gated("std"))
+        .build();
+    let mut out = BufWriter::new(File::create(lin_file)?);
+    dbc_codegen::codegen(config, &mut out)?;
+    out.flush()?;
+    drop(out);
+
+    Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .arg(lin_file)
+        .output()
+        .expect("failed to execute rustfmt");
+
     Ok(())
 }
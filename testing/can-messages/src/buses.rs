@@ -0,0 +1,8239 @@
+// Generated code!
+#![allow(unused_comparisons, unreachable_patterns, unused_imports)]
+#![allow(clippy::let_and_return, clippy::eq_op)]
+#![allow(clippy::useless_conversion, clippy::unnecessary_cast)]
+#![allow(
+    clippy::excessive_precision,
+    clippy::manual_range_contains,
+    clippy::absurd_extreme_comparisons,
+    clippy::too_many_arguments
+)]
+#![deny(clippy::arithmetic_side_effects)]
+
+//! Message definitions of several buses
+
+pub use common::CanError;
+
+/// Error type and helpers shared by all buses
+pub mod common {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum CanError {
+        UnknownMessageId(embedded_can::Id),
+        /// Signal parameter is not within the range
+        /// defined in the dbc
+        ParameterOutOfRange {
+            /// dbc message id
+            message_id: embedded_can::Id,
+        },
+        InvalidPayloadSize,
+        /// Multiplexor value not defined in the dbc
+        InvalidMultiplexor {
+            /// dbc message id
+            message_id: embedded_can::Id,
+            /// Multiplexor value not defined in the dbc
+            multiplexor: u16,
+        },
+        /// Value or label not defined in the value table
+        UndefinedValue,
+    }
+
+    impl core::fmt::Display for CanError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{:?}", self)
+        }
+    }
+    #[cfg(feature = "std")]
+    impl std::error::Error for CanError {}
+    #[cfg(feature = "arb")]
+    pub(super) trait UnstructuredFloatExt {
+        fn float_in_range(
+            &mut self,
+            range: core::ops::RangeInclusive<f32>,
+        ) -> arbitrary::Result<f32>;
+    }
+
+    #[cfg(feature = "arb")]
+    impl UnstructuredFloatExt for arbitrary::Unstructured<'_> {
+        fn float_in_range(
+            &mut self,
+            range: core::ops::RangeInclusive<f32>,
+        ) -> arbitrary::Result<f32> {
+            let min = range.start();
+            let max = range.end();
+            let steps = u32::MAX;
+            let factor = (max - min) / (steps as f32);
+            let random_int: u32 = self.int_in_range(0..=steps)?;
+            let random = min + factor * (random_int as f32);
+            Ok(random)
+        }
+    }
+}
+
+pub mod chassis {
+    //! Message definitions from file `"example.dbc"`
+    //!
+    //! - Version: `Version("43")`
+
+    #[cfg(feature = "arb")]
+    use arbitrary::{Arbitrary, Unstructured};
+    use bitvec::prelude::*;
+    use core::ops::BitOr;
+    use embedded_can::{ExtendedId, Id, StandardId};
+
+    use super::common::*;
+
+    /// All messages
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum Messages {
+        /// Foo
+        Foo(Foo),
+        /// Bar
+        Bar(Bar),
+        /// _4WD
+        X4wd(X4wd),
+        /// Amet
+        Amet(Amet),
+        /// Dolor
+        Dolor(Dolor),
+        /// MultiplexTest
+        MultiplexTest(MultiplexTest),
+        /// NamedMultiplexTest
+        NamedMultiplexTest(NamedMultiplexTest),
+        /// IntegerFactorOffset
+        IntegerFactorOffset(IntegerFactorOffset),
+        /// NegativeFactorTest
+        NegativeFactorTest(NegativeFactorTest),
+        /// LargerIntsWithOffsets
+        LargerIntsWithOffsets(LargerIntsWithOffsets),
+        /// MsgWithoutSignals
+        MsgWithoutSignals(MsgWithoutSignals),
+        /// TruncatedBeSignal
+        TruncatedBeSignal(TruncatedBeSignal),
+        /// TruncatedLeSignal
+        TruncatedLeSignal(TruncatedLeSignal),
+        /// MsgExtendedId
+        MsgExtendedId(MsgExtendedId),
+    }
+
+    impl Messages {
+        /// Read message from CAN frame
+        #[inline(never)]
+        pub fn from_can_message(id: Id, payload: &[u8]) -> Result<Self, CanError> {
+            let res = match id {
+                Foo::MESSAGE_ID => Messages::Foo(Foo::try_from(payload)?),
+                Bar::MESSAGE_ID => Messages::Bar(Bar::try_from(payload)?),
+                X4wd::MESSAGE_ID => Messages::X4wd(X4wd::try_from(payload)?),
+                Amet::MESSAGE_ID => Messages::Amet(Amet::try_from(payload)?),
+                Dolor::MESSAGE_ID => Messages::Dolor(Dolor::try_from(payload)?),
+                MultiplexTest::MESSAGE_ID => {
+                    Messages::MultiplexTest(MultiplexTest::try_from(payload)?)
+                }
+                NamedMultiplexTest::MESSAGE_ID => {
+                    Messages::NamedMultiplexTest(NamedMultiplexTest::try_from(payload)?)
+                }
+                IntegerFactorOffset::MESSAGE_ID => {
+                    Messages::IntegerFactorOffset(IntegerFactorOffset::try_from(payload)?)
+                }
+                NegativeFactorTest::MESSAGE_ID => {
+                    Messages::NegativeFactorTest(NegativeFactorTest::try_from(payload)?)
+                }
+                LargerIntsWithOffsets::MESSAGE_ID => {
+                    Messages::LargerIntsWithOffsets(LargerIntsWithOffsets::try_from(payload)?)
+                }
+                MsgWithoutSignals::MESSAGE_ID => {
+                    Messages::MsgWithoutSignals(MsgWithoutSignals::try_from(payload)?)
+                }
+                TruncatedBeSignal::MESSAGE_ID => {
+                    Messages::TruncatedBeSignal(TruncatedBeSignal::try_from(payload)?)
+                }
+                TruncatedLeSignal::MESSAGE_ID => {
+                    Messages::TruncatedLeSignal(TruncatedLeSignal::try_from(payload)?)
+                }
+                MsgExtendedId::MESSAGE_ID => {
+                    Messages::MsgExtendedId(MsgExtendedId::try_from(payload)?)
+                }
+                id => return Err(CanError::UnknownMessageId(id)),
+            };
+            Ok(res)
+        }
+    }
+
+    /// Foo
+    ///
+    /// - Standard ID: 256 (0x100)
+    /// - Size: 4 bytes
+    /// - Transmitter: Lorem
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Foo {
+        raw: [u8; 4],
+    }
+
+    impl Foo {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x100) });
+
+        pub const VOLTAGE_MIN: f32 = 0_f32;
+        pub const VOLTAGE_MAX: f32 = 63.9990234375_f32;
+        pub const CURRENT_MIN: f32 = -2048_f32;
+        pub const CURRENT_MAX: f32 = 2047.9375_f32;
+
+        /// Construct new Foo from values
+        pub fn new(voltage: f32, current: f32) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 4] };
+            res.set_voltage(voltage)?;
+            res.set_current(current)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 4] {
+            &self.raw
+        }
+
+        /// Voltage
+        ///
+        /// - Min: 0
+        /// - Max: 63.9990234375
+        /// - Unit: "V"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn voltage(&self) -> f32 {
+            self.voltage_raw()
+        }
+
+        /// Get raw value of Voltage
+        ///
+        /// - Start bit: 16
+        /// - Signal size: 16 bits
+        /// - Factor: 0.000976562
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn voltage_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[16..32].load_le::<u16>();
+
+            let factor = 0.000976562_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Voltage
+        #[inline(always)]
+        pub fn set_voltage(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 63.9990234375_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Foo::MESSAGE_ID,
+                });
+            }
+            let factor = 0.000976562_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[16..32].store_le(value);
+            Ok(())
+        }
+
+        /// Current
+        ///
+        /// - Min: -2048
+        /// - Max: 2047.9375
+        /// - Unit: "A"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn current(&self) -> f32 {
+            self.current_raw()
+        }
+
+        /// Get raw value of Current
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 16 bits
+        /// - Factor: 0.0625
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn current_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<i16>();
+
+            let factor = 0.0625_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Current
+        #[inline(always)]
+        pub fn set_current(&mut self, value: f32) -> Result<(), CanError> {
+            if value < -2048_f32 || 2047.9375_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Foo::MESSAGE_ID,
+                });
+            }
+            let factor = 0.0625_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Foo {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 4 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 4];
+            raw.copy_from_slice(&payload[..4]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Foo {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Foo {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Foo")
+                    .field("voltage", &self.voltage())
+                    .field("current", &self.current())
+                    .finish()
+            } else {
+                f.debug_tuple("Foo").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Foo {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let voltage = u.float_in_range(0_f32..=63.9990234375_f32)?;
+            let current = u.float_in_range(-2048_f32..=2047.9375_f32)?;
+            Foo::new(voltage, current).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Bar
+    ///
+    /// - Standard ID: 512 (0x200)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Bar {
+        raw: [u8; 8],
+    }
+
+    impl Bar {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x200) });
+
+        pub const ONE_MIN: u8 = 0_u8;
+        pub const ONE_MAX: u8 = 3_u8;
+        pub const TWO_MIN: f32 = 0_f32;
+        pub const TWO_MAX: f32 = 100_f32;
+        pub const THREE_MIN: u8 = 0_u8;
+        pub const THREE_MAX: u8 = 7_u8;
+        pub const FOUR_MIN: u8 = 0_u8;
+        pub const FOUR_MAX: u8 = 3_u8;
+
+        /// Construct new Bar from values
+        pub fn new(
+            one: u8,
+            two: f32,
+            three: BarThree,
+            four: BarFour,
+            xtype: BarType,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three(three)?;
+            res.set_four(four)?;
+            res.set_xtype(xtype)?;
+            Ok(res)
+        }
+
+        /// Construct new Bar from raw values
+        pub fn new_raw(
+            one: u8,
+            two: f32,
+            three: u8,
+            four: u8,
+            xtype: bool,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three_raw(three)?;
+            res.set_four_raw(four)?;
+            res.set_xtype_raw(xtype)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// One
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn one(&self) -> u8 {
+            self.one_raw()
+        }
+
+        /// Get raw value of One
+        ///
+        /// - Start bit: 15
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn one_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of One
+        #[inline(always)]
+        pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+            Ok(())
+        }
+
+        /// Two
+        ///
+        /// - Min: 0
+        /// - Max: 100
+        /// - Unit: "%"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn two(&self) -> f32 {
+            self.two_raw()
+        }
+
+        /// Get raw value of Two
+        ///
+        /// - Start bit: 7
+        /// - Signal size: 8 bits
+        /// - Factor: 0.39
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn two_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Two
+        #[inline(always)]
+        pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 100_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
+            Ok(())
+        }
+
+        /// Three
+        ///
+        /// - Min: 0
+        /// - Max: 7
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn three(&self) -> BarThree {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            match signal {
+                0 => BarThree::Off,
+                1 => BarThree::On,
+                2 => BarThree::Oner,
+                3 => BarThree::Onest,
+                _ => BarThree::_Other(self.three_raw()),
+            }
+        }
+
+        /// Get raw value of Three
+        ///
+        /// - Start bit: 13
+        /// - Signal size: 3 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn three_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Three
+        #[inline(always)]
+        pub fn set_three(&mut self, value: BarThree) -> Result<(), CanError> {
+            self.set_three_raw(value.into())
+        }
+
+        /// Set raw value of Three
+        #[inline(always)]
+        pub fn set_three_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
+            Ok(())
+        }
+
+        /// Four
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn four(&self) -> BarFour {
+            let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+            match signal {
+                0 => BarFour::Off,
+                1 => BarFour::On,
+                2 => BarFour::Oner,
+                3 => BarFour::Onest,
+                _ => BarFour::_Other(self.four_raw()),
+            }
+        }
+
+        /// Get raw value of Four
+        ///
+        /// - Start bit: 10
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn four_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Four
+        #[inline(always)]
+        pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+            self.set_four_raw(value.into())
+        }
+
+        /// Set raw value of Four
+        #[inline(always)]
+        pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[13..15].store_be(value);
+            Ok(())
+        }
+
+        /// Type
+        ///
+        /// - Min: 0
+        /// - Max: 1
+        /// - Unit: "boolean"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn xtype(&self) -> BarType {
+            let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
+
+            match signal {
+                0 => BarType::X0off,
+                1 => BarType::X1on,
+                _ => BarType::_Other(self.xtype_raw()),
+            }
+        }
+
+        /// Get raw value of Type
+        ///
+        /// - Start bit: 30
+        /// - Signal size: 1 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn xtype_raw(&self) -> bool {
+            let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
+
+            signal == 1
+        }
+
+        /// Set value of Type
+        #[inline(always)]
+        pub fn set_xtype(&mut self, value: BarType) -> Result<(), CanError> {
+            self.set_xtype_raw(value.into())
+        }
+
+        /// Set raw value of Type
+        #[inline(always)]
+        pub fn set_xtype_raw(&mut self, value: bool) -> Result<(), CanError> {
+            let value = value as u8;
+            self.raw.view_bits_mut::<Msb0>()[25..26].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Bar {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Bar {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Bar {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Bar")
+                    .field("one", &self.one())
+                    .field("two", &self.two())
+                    .field("three", &self.three())
+                    .field("four", &self.four())
+                    .field("xtype", &self.xtype())
+                    .finish()
+            } else {
+                f.debug_tuple("Bar").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Bar {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let one = u.int_in_range(0..=3)?;
+            let two = u.float_in_range(0_f32..=100_f32)?;
+            let three = u.int_in_range(0..=7)?;
+            let four = u.int_in_range(0..=3)?;
+            let xtype = u.int_in_range(0..=1)? == 1;
+            Bar::new_raw(one, two, three, four, xtype)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for Three
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BarThree {
+        Off,
+        On,
+        Oner,
+        Onest,
+        _Other(u8),
+    }
+
+    impl BarThree {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::On,
+                2 => Self::Oner,
+                3 => Self::Onest,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("OFF"),
+                Self::On => Some("ON"),
+                Self::Oner => Some("ONER"),
+                Self::Onest => Some("ONEST"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for BarThree {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<BarThree> for u8 {
+        fn from(val: BarThree) -> u8 {
+            match val {
+                BarThree::Off => 0,
+                BarThree::On => 1,
+                BarThree::Oner => 2,
+                BarThree::Onest => 3,
+                BarThree::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for BarThree {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for BarThree {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Defined values for Four
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BarFour {
+        Off,
+        On,
+        Oner,
+        Onest,
+        _Other(u8),
+    }
+
+    impl BarFour {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::On,
+                2 => Self::Oner,
+                3 => Self::Onest,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("Off"),
+                Self::On => Some("On"),
+                Self::Oner => Some("Oner"),
+                Self::Onest => Some("Onest"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for BarFour {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<BarFour> for u8 {
+        fn from(val: BarFour) -> u8 {
+            match val {
+                BarFour::Off => 0,
+                BarFour::On => 1,
+                BarFour::Oner => 2,
+                BarFour::Onest => 3,
+                BarFour::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for BarFour {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for BarFour {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Defined values for Type
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BarType {
+        X0off,
+        X1on,
+        _Other(bool),
+    }
+
+    impl BarType {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::X0off, Self::X1on];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: bool) -> Self {
+            match raw {
+                false => Self::X0off,
+                true => Self::X1on,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::X0off => Some("0Off"),
+                Self::X1on => Some("1On"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<bool> for BarType {
+        fn from(raw: bool) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<BarType> for bool {
+        fn from(val: BarType) -> bool {
+            match val {
+                BarType::X0off => false,
+                BarType::X1on => true,
+                BarType::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for BarType {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", bool::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for BarType {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// _4WD
+    ///
+    /// - Standard ID: 768 (0x300)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct X4wd {
+        raw: [u8; 8],
+    }
+
+    impl X4wd {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x300) });
+
+        pub const X4DRIVE_MIN: u8 = 0_u8;
+        pub const X4DRIVE_MAX: u8 = 7_u8;
+
+        /// Construct new _4WD from values
+        pub fn new(x4drive: X4wd4drive) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_x4drive(x4drive)?;
+            Ok(res)
+        }
+
+        /// Construct new _4WD from raw values
+        pub fn new_raw(x4drive: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_x4drive_raw(x4drive)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// _4DRIVE
+        ///
+        /// - Min: 0
+        /// - Max: 7
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn x4drive(&self) -> X4wd4drive {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            match signal {
+                0 => X4wd4drive::Off,
+                1 => X4wd4drive::X2wd,
+                2 => X4wd4drive::X4wd,
+                3 => X4wd4drive::All,
+                _ => X4wd4drive::_Other(self.x4drive_raw()),
+            }
+        }
+
+        /// Get raw value of _4DRIVE
+        ///
+        /// - Start bit: 13
+        /// - Signal size: 3 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn x4drive_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of _4DRIVE
+        #[inline(always)]
+        pub fn set_x4drive(&mut self, value: X4wd4drive) -> Result<(), CanError> {
+            self.set_x4drive_raw(value.into())
+        }
+
+        /// Set raw value of _4DRIVE
+        #[inline(always)]
+        pub fn set_x4drive_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: X4wd::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: X4wd::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for X4wd {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for X4wd {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for X4wd {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("X4wd")
+                    .field("x4drive", &self.x4drive())
+                    .finish()
+            } else {
+                f.debug_tuple("X4wd").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for X4wd {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let x4drive = u.int_in_range(0..=7)?;
+            X4wd::new_raw(x4drive).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for _4DRIVE
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum X4wd4drive {
+        Off,
+        X2wd,
+        X4wd,
+        All,
+        _Other(u8),
+    }
+
+    impl X4wd4drive {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::X2wd, Self::X4wd, Self::All];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::X2wd,
+                2 => Self::X4wd,
+                3 => Self::All,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("OFF"),
+                Self::X2wd => Some("2WD"),
+                Self::X4wd => Some("4WD"),
+                Self::All => Some("ALL"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for X4wd4drive {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<X4wd4drive> for u8 {
+        fn from(val: X4wd4drive) -> u8 {
+            match val {
+                X4wd4drive::Off => 0,
+                X4wd4drive::X2wd => 1,
+                X4wd4drive::X4wd => 2,
+                X4wd4drive::All => 3,
+                X4wd4drive::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for X4wd4drive {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for X4wd4drive {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Amet
+    ///
+    /// - Standard ID: 1024 (0x400)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Amet {
+        raw: [u8; 8],
+    }
+
+    impl Amet {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x400) });
+
+        pub const ONE_MIN: u8 = 0_u8;
+        pub const ONE_MAX: u8 = 3_u8;
+        pub const TWO_MIN: f32 = 0_f32;
+        pub const TWO_MAX: f32 = 100_f32;
+        pub const THREE_MIN: u8 = 0_u8;
+        pub const THREE_MAX: u8 = 7_u8;
+        pub const FOUR_MIN: u8 = 0_u8;
+        pub const FOUR_MAX: u8 = 3_u8;
+
+        /// Construct new Amet from values
+        pub fn new(
+            one: u8,
+            two: f32,
+            three: u8,
+            four: AmetFour,
+            five: OnOff,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three(three)?;
+            res.set_four(four)?;
+            res.set_five(five)?;
+            Ok(res)
+        }
+
+        /// Construct new Amet from raw values
+        pub fn new_raw(
+            one: u8,
+            two: f32,
+            three: u8,
+            four: u8,
+            five: bool,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three(three)?;
+            res.set_four_raw(four)?;
+            res.set_five_raw(five)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// One
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn one(&self) -> u8 {
+            self.one_raw()
+        }
+
+        /// Get raw value of One
+        ///
+        /// - Start bit: 15
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn one_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of One
+        #[inline(always)]
+        pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+            Ok(())
+        }
+
+        /// Two
+        ///
+        /// - Min: 0
+        /// - Max: 100
+        /// - Unit: "%"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn two(&self) -> f32 {
+            self.two_raw()
+        }
+
+        /// Get raw value of Two
+        ///
+        /// - Start bit: 7
+        /// - Signal size: 8 bits
+        /// - Factor: 0.39
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn two_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Two
+        #[inline(always)]
+        pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 100_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
+            Ok(())
+        }
+
+        /// Three
+        ///
+        /// - Min: 0
+        /// - Max: 7
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn three(&self) -> u8 {
+            self.three_raw()
+        }
+
+        /// Get raw value of Three
+        ///
+        /// - Start bit: 20
+        /// - Signal size: 3 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn three_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[19..22].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Three
+        #[inline(always)]
+        pub fn set_three(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[19..22].store_be(value);
+            Ok(())
+        }
+
+        /// Four
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn four(&self) -> AmetFour {
+            let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+            match signal {
+                0 => AmetFour::Off,
+                1 => AmetFour::On,
+                2 => AmetFour::Oner,
+                3 => AmetFour::Onest,
+                _ => AmetFour::_Other(self.four_raw()),
+            }
+        }
+
+        /// Get raw value of Four
+        ///
+        /// - Start bit: 30
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn four_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Four
+        #[inline(always)]
+        pub fn set_four(&mut self, value: AmetFour) -> Result<(), CanError> {
+            self.set_four_raw(value.into())
+        }
+
+        /// Set raw value of Four
+        #[inline(always)]
+        pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[25..27].store_be(value);
+            Ok(())
+        }
+
+        /// Five
+        ///
+        /// - Min: 0
+        /// - Max: 1
+        /// - Unit: "boolean"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn five(&self) -> OnOff {
+            let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+            match signal {
+                1 => OnOff::On,
+                0 => OnOff::Off,
+                _ => OnOff::_Other(self.five_raw()),
+            }
+        }
+
+        /// Get raw value of Five
+        ///
+        /// - Start bit: 40
+        /// - Signal size: 1 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn five_raw(&self) -> bool {
+            let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+            signal == 1
+        }
+
+        /// Set value of Five
+        #[inline(always)]
+        pub fn set_five(&mut self, value: OnOff) -> Result<(), CanError> {
+            self.set_five_raw(value.into())
+        }
+
+        /// Set raw value of Five
+        #[inline(always)]
+        pub fn set_five_raw(&mut self, value: bool) -> Result<(), CanError> {
+            let value = value as u8;
+            self.raw.view_bits_mut::<Msb0>()[47..48].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Amet {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Amet {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Amet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Amet")
+                    .field("one", &self.one())
+                    .field("two", &self.two())
+                    .field("three", &self.three())
+                    .field("four", &self.four())
+                    .field("five", &self.five())
+                    .finish()
+            } else {
+                f.debug_tuple("Amet").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Amet {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let one = u.int_in_range(0..=3)?;
+            let two = u.float_in_range(0_f32..=100_f32)?;
+            let three = u.int_in_range(0..=7)?;
+            let four = u.int_in_range(0..=3)?;
+            let five = u.int_in_range(0..=1)? == 1;
+            Amet::new_raw(one, two, three, four, five)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for Four
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum AmetFour {
+        Off,
+        On,
+        Oner,
+        Onest,
+        _Other(u8),
+    }
+
+    impl AmetFour {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::On,
+                2 => Self::Oner,
+                3 => Self::Onest,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("Off"),
+                Self::On => Some("On"),
+                Self::Oner => Some("Oner"),
+                Self::Onest => Some("Onest"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for AmetFour {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<AmetFour> for u8 {
+        fn from(val: AmetFour) -> u8 {
+            match val {
+                AmetFour::Off => 0,
+                AmetFour::On => 1,
+                AmetFour::Oner => 2,
+                AmetFour::Onest => 3,
+                AmetFour::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for AmetFour {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for AmetFour {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Dolor
+    ///
+    /// - Standard ID: 1028 (0x404)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Dolor {
+        raw: [u8; 8],
+    }
+
+    impl Dolor {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x404) });
+
+        pub const ONE_FLOAT_MIN: f32 = 0_f32;
+        pub const ONE_FLOAT_MAX: f32 = 130_f32;
+
+        /// Construct new Dolor from values
+        pub fn new(one_float: DolorOneFloat) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one_float(one_float)?;
+            Ok(res)
+        }
+
+        /// Construct new Dolor from raw values
+        pub fn new_raw(one_float: f32) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one_float_raw(one_float)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// OneFloat
+        ///
+        /// - Min: 0
+        /// - Max: 130
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn one_float(&self) -> DolorOneFloat {
+            let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+            match signal {
+                3 => DolorOneFloat::Dolor,
+                5 => DolorOneFloat::Other,
+                _ => DolorOneFloat::_Other(self.one_float_raw()),
+            }
+        }
+
+        /// Get raw value of OneFloat
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 0.5
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn one_float_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+            let factor = 0.5_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of OneFloat
+        #[inline(always)]
+        pub fn set_one_float(&mut self, value: DolorOneFloat) -> Result<(), CanError> {
+            self.set_one_float_raw(value.into())
+        }
+
+        /// Set raw value of OneFloat
+        #[inline(always)]
+        pub fn set_one_float_raw(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 130_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Dolor::MESSAGE_ID,
+                });
+            }
+            let factor = 0.5_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u16;
+
+            self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Dolor {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Dolor {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Dolor {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Dolor")
+                    .field("one_float", &self.one_float())
+                    .finish()
+            } else {
+                f.debug_tuple("Dolor").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Dolor {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let one_float = u.float_in_range(0_f32..=130_f32)?;
+            Dolor::new_raw(one_float).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for OneFloat
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum DolorOneFloat {
+        Dolor,
+        Other,
+        _Other(f32),
+    }
+
+    impl DolorOneFloat {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Dolor, Self::Other];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: f32) -> Self {
+            match raw {
+                3_f32 => Self::Dolor,
+                5_f32 => Self::Other,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Dolor => Some("Dolor"),
+                Self::Other => Some("Other"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<f32> for DolorOneFloat {
+        fn from(raw: f32) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<DolorOneFloat> for f32 {
+        fn from(val: DolorOneFloat) -> f32 {
+            match val {
+                DolorOneFloat::Dolor => 3_f32,
+                DolorOneFloat::Other => 5_f32,
+                DolorOneFloat::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for DolorOneFloat {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", f32::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for DolorOneFloat {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// MultiplexTest
+    ///
+    /// - Standard ID: 200 (0xc8)
+    /// - Size: 8 bytes
+    /// - Transmitter: SENSOR
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct MultiplexTest {
+        raw: [u8; 8],
+    }
+
+    impl MultiplexTest {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0xc8) });
+
+        pub const MULTIPLEXOR_MIN: u8 = 0_u8;
+        pub const MULTIPLEXOR_MAX: u8 = 2_u8;
+        pub const UNMULTIPLEXED_SIGNAL_MIN: u8 = 0_u8;
+        pub const UNMULTIPLEXED_SIGNAL_MAX: u8 = 4_u8;
+        pub const MULTIPLEXED_SIGNAL_ZERO_A_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ZERO_A_MAX: f32 = 3_f32;
+        pub const MULTIPLEXED_SIGNAL_ZERO_B_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ZERO_B_MAX: f32 = 3_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_A_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_A_MAX: f32 = 6_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_B_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_B_MAX: f32 = 6_f32;
+
+        /// Construct new MultiplexTest from values
+        pub fn new(multiplexor: u8, unmultiplexed_signal: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_multiplexor(multiplexor)?;
+            res.set_unmultiplexed_signal(unmultiplexed_signal)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Get raw value of Multiplexor
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 4 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexor_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        pub fn multiplexor(&self) -> Result<MultiplexTestMultiplexorIndex, CanError> {
+            match self.multiplexor_raw() {
+                0 => Ok(MultiplexTestMultiplexorIndex::M0(
+                    MultiplexTestMultiplexorM0 { raw: self.raw },
+                )),
+                1 => Ok(MultiplexTestMultiplexorIndex::M1(
+                    MultiplexTestMultiplexorM1 { raw: self.raw },
+                )),
+                multiplexor => Err(CanError::InvalidMultiplexor {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                    multiplexor: multiplexor.into(),
+                }),
+            }
+        }
+        /// Set value of Multiplexor
+        #[inline(always)]
+        fn set_multiplexor(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 2_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+            Ok(())
+        }
+
+        /// Set value of Multiplexor
+        #[inline(always)]
+        pub fn set_m0(&mut self, value: MultiplexTestMultiplexorM0) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_multiplexor(0)?;
+            Ok(())
+        }
+
+        /// Set value of Multiplexor
+        #[inline(always)]
+        pub fn set_m1(&mut self, value: MultiplexTestMultiplexorM1) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_multiplexor(1)?;
+            Ok(())
+        }
+
+        /// UnmultiplexedSignal
+        ///
+        /// - Min: 0
+        /// - Max: 4
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn unmultiplexed_signal(&self) -> u8 {
+            self.unmultiplexed_signal_raw()
+        }
+
+        /// Get raw value of UnmultiplexedSignal
+        ///
+        /// - Start bit: 4
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn unmultiplexed_signal_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Lsb0>()[4..12].load_le::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of UnmultiplexedSignal
+        #[inline(always)]
+        pub fn set_unmultiplexed_signal(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 4_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[4..12].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for MultiplexTest {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for MultiplexTest {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for MultiplexTest {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                let mut d = f.debug_struct("MultiplexTest");
+                match self.multiplexor() {
+                    Ok(multiplexor) => d.field("multiplexor", &multiplexor),
+                    Err(_) => d.field("multiplexor", &self.multiplexor_raw()),
+                };
+                d.field("unmultiplexed_signal", &self.unmultiplexed_signal());
+                d.finish()
+            } else {
+                f.debug_tuple("MultiplexTest").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MultiplexTest {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let multiplexor = u.int_in_range(0..=2)?;
+            let unmultiplexed_signal = u.int_in_range(0..=4)?;
+            let mut msg = MultiplexTest::new(multiplexor, unmultiplexed_signal)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            match u.int_in_range(0..=1_usize)? {
+                0 => msg.set_m0(u.arbitrary()?),
+                _ => msg.set_m1(u.arbitrary()?),
+            }
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// Defined values for multiplexed signal MultiplexTest
+    #[derive(PartialEq, Eq, Debug)]
+    pub enum MultiplexTestMultiplexorIndex {
+        M0(MultiplexTestMultiplexorM0),
+        M1(MultiplexTestMultiplexorM1),
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct MultiplexTestMultiplexorM0 {
+        raw: [u8; 8],
+    }
+
+    impl MultiplexTestMultiplexorM0 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// MultiplexedSignalZeroA
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_a(&self) -> f32 {
+            self.multiplexed_signal_zero_a_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalZeroA
+        ///
+        /// - Start bit: 12
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_a_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalZeroA
+        #[inline(always)]
+        pub fn set_multiplexed_signal_zero_a(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 3_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+            Ok(())
+        }
+
+        /// MultiplexedSignalZeroB
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_b(&self) -> f32 {
+            self.multiplexed_signal_zero_b_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalZeroB
+        ///
+        /// - Start bit: 20
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_b_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalZeroB
+        #[inline(always)]
+        pub fn set_multiplexed_signal_zero_b(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 3_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for MultiplexTestMultiplexorM0 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MultiplexTestMultiplexorM0")
+                    .field(
+                        "multiplexed_signal_zero_a",
+                        &self.multiplexed_signal_zero_a(),
+                    )
+                    .field(
+                        "multiplexed_signal_zero_b",
+                        &self.multiplexed_signal_zero_b(),
+                    )
+                    .finish()
+            } else {
+                f.debug_tuple("MultiplexTestMultiplexorM0")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM0 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_multiplexed_signal_zero_a(u.float_in_range(0_f32..=3_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            msg.set_multiplexed_signal_zero_b(u.float_in_range(0_f32..=3_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct MultiplexTestMultiplexorM1 {
+        raw: [u8; 8],
+    }
+
+    impl MultiplexTestMultiplexorM1 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// MultiplexedSignalOneA
+        ///
+        /// - Min: 0
+        /// - Max: 6
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_one_a(&self) -> f32 {
+            self.multiplexed_signal_one_a_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalOneA
+        ///
+        /// - Start bit: 12
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_one_a_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalOneA
+        #[inline(always)]
+        pub fn set_multiplexed_signal_one_a(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 6_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+            Ok(())
+        }
+
+        /// MultiplexedSignalOneB
+        ///
+        /// - Min: 0
+        /// - Max: 6
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_one_b(&self) -> f32 {
+            self.multiplexed_signal_one_b_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalOneB
+        ///
+        /// - Start bit: 20
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_one_b_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalOneB
+        #[inline(always)]
+        pub fn set_multiplexed_signal_one_b(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 6_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for MultiplexTestMultiplexorM1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MultiplexTestMultiplexorM1")
+                    .field("multiplexed_signal_one_a", &self.multiplexed_signal_one_a())
+                    .field("multiplexed_signal_one_b", &self.multiplexed_signal_one_b())
+                    .finish()
+            } else {
+                f.debug_tuple("MultiplexTestMultiplexorM1")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM1 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_multiplexed_signal_one_a(u.float_in_range(0_f32..=6_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            msg.set_multiplexed_signal_one_b(u.float_in_range(0_f32..=6_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// NamedMultiplexTest
+    ///
+    /// - Standard ID: 201 (0xc9)
+    /// - Size: 8 bytes
+    /// - Transmitter: SENSOR
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct NamedMultiplexTest {
+        raw: [u8; 8],
+    }
+
+    impl NamedMultiplexTest {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0xc9) });
+
+        pub const KIND_MIN: u8 = 0_u8;
+        pub const KIND_MAX: u8 = 2_u8;
+        pub const CELSIUS_MIN: i8 = -40_i8;
+        pub const CELSIUS_MAX: i8 = 100_i8;
+        pub const KILOPASCAL_MIN: u16 = 0_u16;
+        pub const KILOPASCAL_MAX: u16 = 1000_u16;
+
+        /// Construct new NamedMultiplexTest from values
+        pub fn new(kind: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_kind(kind)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Get raw value of Kind
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 4 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn kind_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        pub fn kind(&self) -> Result<NamedMultiplexTestKindIndex, CanError> {
+            match self.kind_raw() {
+                0 => Ok(NamedMultiplexTestKindIndex::Temperature(
+                    NamedMultiplexTestKindM0 { raw: self.raw },
+                )),
+                1 => Ok(NamedMultiplexTestKindIndex::Pressure(
+                    NamedMultiplexTestKindM1 { raw: self.raw },
+                )),
+                multiplexor => Err(CanError::InvalidMultiplexor {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                    multiplexor: multiplexor.into(),
+                }),
+            }
+        }
+        /// Set value of Kind
+        #[inline(always)]
+        fn set_kind(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 2_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+            Ok(())
+        }
+
+        /// Set value of Kind
+        #[inline(always)]
+        pub fn set_temperature(&mut self, value: NamedMultiplexTestKindM0) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_kind(0)?;
+            Ok(())
+        }
+
+        /// Set value of Kind
+        #[inline(always)]
+        pub fn set_pressure(&mut self, value: NamedMultiplexTestKindM1) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_kind(1)?;
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for NamedMultiplexTest {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for NamedMultiplexTest {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for NamedMultiplexTest {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                let mut d = f.debug_struct("NamedMultiplexTest");
+                match self.kind() {
+                    Ok(multiplexor) => d.field("kind", &multiplexor),
+                    Err(_) => d.field("kind", &self.kind_raw()),
+                };
+                d.finish()
+            } else {
+                f.debug_tuple("NamedMultiplexTest")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NamedMultiplexTest {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let kind = u.int_in_range(0..=2)?;
+            let mut msg =
+                NamedMultiplexTest::new(kind).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            match u.int_in_range(0..=1_usize)? {
+                0 => msg.set_temperature(u.arbitrary()?),
+                _ => msg.set_pressure(u.arbitrary()?),
+            }
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// Defined values for multiplexed signal NamedMultiplexTest
+    #[derive(PartialEq, Eq, Debug)]
+    pub enum NamedMultiplexTestKindIndex {
+        Temperature(NamedMultiplexTestKindM0),
+        Pressure(NamedMultiplexTestKindM1),
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct NamedMultiplexTestKindM0 {
+        raw: [u8; 8],
+    }
+
+    impl NamedMultiplexTestKindM0 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// Celsius
+        ///
+        /// - Min: -40
+        /// - Max: 100
+        /// - Unit: "degC"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn celsius(&self) -> i8 {
+            self.celsius_raw()
+        }
+
+        /// Get raw value of Celsius
+        ///
+        /// - Start bit: 8
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn celsius_raw(&self) -> i8 {
+            let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<i8>();
+
+            let factor = 1;
+            let signal = signal as i8;
+            i8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Celsius
+        #[inline(always)]
+        pub fn set_celsius(&mut self, value: i8) -> Result<(), CanError> {
+            if value < -40_i8 || 100_i8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i8;
+
+            let value = u8::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for NamedMultiplexTestKindM0 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("NamedMultiplexTestKindM0")
+                    .field("celsius", &self.celsius())
+                    .finish()
+            } else {
+                f.debug_tuple("NamedMultiplexTestKindM0")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM0 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_celsius(u.int_in_range(-40..=100)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct NamedMultiplexTestKindM1 {
+        raw: [u8; 8],
+    }
+
+    impl NamedMultiplexTestKindM1 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// Kilopascal
+        ///
+        /// - Min: 0
+        /// - Max: 1000
+        /// - Unit: "kPa"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn kilopascal(&self) -> u16 {
+            self.kilopascal_raw()
+        }
+
+        /// Get raw value of Kilopascal
+        ///
+        /// - Start bit: 8
+        /// - Signal size: 16 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn kilopascal_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[8..24].load_le::<u16>();
+
+            let factor = 1;
+            u16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Kilopascal
+        #[inline(always)]
+        pub fn set_kilopascal(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 0_u16 || 1000_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for NamedMultiplexTestKindM1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("NamedMultiplexTestKindM1")
+                    .field("kilopascal", &self.kilopascal())
+                    .finish()
+            } else {
+                f.debug_tuple("NamedMultiplexTestKindM1")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM1 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_kilopascal(u.int_in_range(0..=1000)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// IntegerFactorOffset
+    ///
+    /// - Standard ID: 1337 (0x539)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct IntegerFactorOffset {
+        raw: [u8; 8],
+    }
+
+    impl IntegerFactorOffset {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x539) });
+
+        pub const BYTE_WITH_OFFSET_MIN: u16 = 1_u16;
+        pub const BYTE_WITH_OFFSET_MAX: u16 = 256_u16;
+        pub const BYTE_WITH_FACTOR_MIN: u16 = 0_u16;
+        pub const BYTE_WITH_FACTOR_MAX: u16 = 1020_u16;
+        pub const BYTE_WITH_BOTH_MIN: u16 = 16_u16;
+        pub const BYTE_WITH_BOTH_MAX: u16 = 526_u16;
+        pub const BYTE_WITH_NEGATIVE_OFFSET_MIN: i16 = 0_i16;
+        pub const BYTE_WITH_NEGATIVE_OFFSET_MAX: i16 = 255_i16;
+        pub const BYTE_WITH_NEGATIVE_MIN_MIN: i16 = -127_i16;
+        pub const BYTE_WITH_NEGATIVE_MIN_MAX: i16 = 127_i16;
+
+        /// Construct new IntegerFactorOffset from values
+        pub fn new(
+            byte_with_offset: u16,
+            byte_with_factor: u16,
+            byte_with_both: u16,
+            byte_with_negative_offset: i16,
+            byte_with_negative_min: i16,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_byte_with_offset(byte_with_offset)?;
+            res.set_byte_with_factor(byte_with_factor)?;
+            res.set_byte_with_both(byte_with_both)?;
+            res.set_byte_with_negative_offset(byte_with_negative_offset)?;
+            res.set_byte_with_negative_min(byte_with_negative_min)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// ByteWithOffset
+        ///
+        /// - Min: 1
+        /// - Max: 256
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_offset(&self) -> u16 {
+            self.byte_with_offset_raw()
+        }
+
+        /// Get raw value of ByteWithOffset
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: 1
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_offset_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+            let factor = 1;
+            u16::from(signal).saturating_mul(factor).saturating_add(1)
+        }
+
+        /// Set value of ByteWithOffset
+        #[inline(always)]
+        pub fn set_byte_with_offset(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 1_u16 || 256_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(1).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithFactor
+        ///
+        /// - Min: 0
+        /// - Max: 1020
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_factor(&self) -> u16 {
+            self.byte_with_factor_raw()
+        }
+
+        /// Get raw value of ByteWithFactor
+        ///
+        /// - Start bit: 8
+        /// - Signal size: 8 bits
+        /// - Factor: 4
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_factor_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+            let factor = 4;
+            u16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of ByteWithFactor
+        #[inline(always)]
+        pub fn set_byte_with_factor(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 0_u16 || 1020_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 4;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithBoth
+        ///
+        /// - Min: 16
+        /// - Max: 526
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_both(&self) -> u16 {
+            self.byte_with_both_raw()
+        }
+
+        /// Get raw value of ByteWithBoth
+        ///
+        /// - Start bit: 16
+        /// - Signal size: 8 bits
+        /// - Factor: 2
+        /// - Offset: 16
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_both_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+            let factor = 2;
+            u16::from(signal).saturating_mul(factor).saturating_add(16)
+        }
+
+        /// Set value of ByteWithBoth
+        #[inline(always)]
+        pub fn set_byte_with_both(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 16_u16 || 526_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 2;
+            let value = value.checked_sub(16).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithNegativeOffset
+        ///
+        /// - Min: 0
+        /// - Max: 255
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_negative_offset(&self) -> i16 {
+            self.byte_with_negative_offset_raw()
+        }
+
+        /// Get raw value of ByteWithNegativeOffset
+        ///
+        /// - Start bit: 24
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: -1
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_negative_offset_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+            let factor = 1;
+            i16::from(signal).saturating_mul(factor).saturating_sub(1)
+        }
+
+        /// Set value of ByteWithNegativeOffset
+        #[inline(always)]
+        pub fn set_byte_with_negative_offset(&mut self, value: i16) -> Result<(), CanError> {
+            if value < 0_i16 || 255_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithNegativeMin
+        ///
+        /// - Min: -127
+        /// - Max: 127
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_negative_min(&self) -> i16 {
+            self.byte_with_negative_min_raw()
+        }
+
+        /// Get raw value of ByteWithNegativeMin
+        ///
+        /// - Start bit: 32
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: -1
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_negative_min_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+            let factor = 1;
+            i16::from(signal).saturating_mul(factor).saturating_sub(1)
+        }
+
+        /// Set value of ByteWithNegativeMin
+        #[inline(always)]
+        pub fn set_byte_with_negative_min(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -127_i16 || 127_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for IntegerFactorOffset {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for IntegerFactorOffset {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for IntegerFactorOffset {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("IntegerFactorOffset")
+                    .field("byte_with_offset", &self.byte_with_offset())
+                    .field("byte_with_factor", &self.byte_with_factor())
+                    .field("byte_with_both", &self.byte_with_both())
+                    .field(
+                        "byte_with_negative_offset",
+                        &self.byte_with_negative_offset(),
+                    )
+                    .field("byte_with_negative_min", &self.byte_with_negative_min())
+                    .finish()
+            } else {
+                f.debug_tuple("IntegerFactorOffset")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for IntegerFactorOffset {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let byte_with_offset = u.int_in_range(1..=256)?;
+            let byte_with_factor = u.int_in_range(0..=1020)?;
+            let byte_with_both = u.int_in_range(16..=526)?;
+            let byte_with_negative_offset = u.int_in_range(0..=255)?;
+            let byte_with_negative_min = u.int_in_range(-127..=127)?;
+            IntegerFactorOffset::new(
+                byte_with_offset,
+                byte_with_factor,
+                byte_with_both,
+                byte_with_negative_offset,
+                byte_with_negative_min,
+            )
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// NegativeFactorTest
+    ///
+    /// - Standard ID: 1344 (0x540)
+    /// - Size: 4 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct NegativeFactorTest {
+        raw: [u8; 4],
+    }
+
+    impl NegativeFactorTest {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x540) });
+
+        pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MIN: i32 = -65535_i32;
+        pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MAX: i32 = 0_i32;
+        pub const WIDTH_MORE_THAN_MIN_MAX_MIN: i16 = -2_i16;
+        pub const WIDTH_MORE_THAN_MIN_MAX_MAX: i16 = 2_i16;
+
+        /// Construct new NegativeFactorTest from values
+        pub fn new(
+            unsigned_negative_factor_signal: i32,
+            width_more_than_min_max: i16,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 4] };
+            res.set_unsigned_negative_factor_signal(unsigned_negative_factor_signal)?;
+            res.set_width_more_than_min_max(width_more_than_min_max)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 4] {
+            &self.raw
+        }
+
+        /// UnsignedNegativeFactorSignal
+        ///
+        /// - Min: -65535
+        /// - Max: 0
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn unsigned_negative_factor_signal(&self) -> i32 {
+            self.unsigned_negative_factor_signal_raw()
+        }
+
+        /// Get raw value of UnsignedNegativeFactorSignal
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 16 bits
+        /// - Factor: -1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn unsigned_negative_factor_signal_raw(&self) -> i32 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<u16>();
+
+            let factor = -1;
+            i32::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of UnsignedNegativeFactorSignal
+        #[inline(always)]
+        pub fn set_unsigned_negative_factor_signal(&mut self, value: i32) -> Result<(), CanError> {
+            if value < -65535_i32 || 0_i32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NegativeFactorTest::MESSAGE_ID,
+                });
+            }
+            let factor = -1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+            Ok(())
+        }
+
+        /// WidthMoreThanMinMax
+        ///
+        /// - Min: -2
+        /// - Max: 2
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn width_more_than_min_max(&self) -> i16 {
+            self.width_more_than_min_max_raw()
+        }
+
+        /// Get raw value of WidthMoreThanMinMax
+        ///
+        /// - Start bit: 16
+        /// - Signal size: 10 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn width_more_than_min_max_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[16..26].load_le::<i16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of WidthMoreThanMinMax
+        #[inline(always)]
+        pub fn set_width_more_than_min_max(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -2_i16 || 2_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NegativeFactorTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[16..26].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for NegativeFactorTest {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 4 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 4];
+            raw.copy_from_slice(&payload[..4]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for NegativeFactorTest {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for NegativeFactorTest {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("NegativeFactorTest")
+                    .field(
+                        "unsigned_negative_factor_signal",
+                        &self.unsigned_negative_factor_signal(),
+                    )
+                    .field("width_more_than_min_max", &self.width_more_than_min_max())
+                    .finish()
+            } else {
+                f.debug_tuple("NegativeFactorTest")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NegativeFactorTest {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let unsigned_negative_factor_signal = u.int_in_range(-65535..=0)?;
+            let width_more_than_min_max = u.int_in_range(-2..=2)?;
+            NegativeFactorTest::new(unsigned_negative_factor_signal, width_more_than_min_max)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// LargerIntsWithOffsets
+    ///
+    /// - Standard ID: 1338 (0x53a)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct LargerIntsWithOffsets {
+        raw: [u8; 8],
+    }
+
+    impl LargerIntsWithOffsets {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x53a) });
+
+        pub const TWELVE_MIN: i16 = -1000_i16;
+        pub const TWELVE_MAX: i16 = 3000_i16;
+        pub const SIXTEEN_MIN: i32 = -1000_i32;
+        pub const SIXTEEN_MAX: i32 = 64535_i32;
+
+        /// Construct new LargerIntsWithOffsets from values
+        pub fn new(twelve: i16, sixteen: i32) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_twelve(twelve)?;
+            res.set_sixteen(sixteen)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Twelve
+        ///
+        /// - Min: -1000
+        /// - Max: 3000
+        /// - Unit: ""
+        /// - Receivers: XXX
+        #[inline(always)]
+        pub fn twelve(&self) -> i16 {
+            self.twelve_raw()
+        }
+
+        /// Get raw value of Twelve
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 1
+        /// - Offset: -1000
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn twelve_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<u16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal)
+                .saturating_mul(factor)
+                .saturating_sub(1000)
+        }
+
+        /// Set value of Twelve
+        #[inline(always)]
+        pub fn set_twelve(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -1000_i16 || 3000_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value
+                .checked_add(1000)
+                .ok_or(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+            Ok(())
+        }
+
+        /// Sixteen
+        ///
+        /// - Min: -1000
+        /// - Max: 64535
+        /// - Unit: ""
+        /// - Receivers: XXX
+        #[inline(always)]
+        pub fn sixteen(&self) -> i32 {
+            self.sixteen_raw()
+        }
+
+        /// Get raw value of Sixteen
+        ///
+        /// - Start bit: 12
+        /// - Signal size: 16 bits
+        /// - Factor: 1
+        /// - Offset: -1000
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn sixteen_raw(&self) -> i32 {
+            let signal = self.raw.view_bits::<Lsb0>()[12..28].load_le::<u16>();
+
+            let factor = 1;
+            i32::from(signal)
+                .saturating_mul(factor)
+                .saturating_sub(1000)
+        }
+
+        /// Set value of Sixteen
+        #[inline(always)]
+        pub fn set_sixteen(&mut self, value: i32) -> Result<(), CanError> {
+            if value < -1000_i32 || 64535_i32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value
+                .checked_add(1000)
+                .ok_or(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[12..28].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for LargerIntsWithOffsets {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for LargerIntsWithOffsets {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for LargerIntsWithOffsets {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("LargerIntsWithOffsets")
+                    .field("twelve", &self.twelve())
+                    .field("sixteen", &self.sixteen())
+                    .finish()
+            } else {
+                f.debug_tuple("LargerIntsWithOffsets")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for LargerIntsWithOffsets {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let twelve = u.int_in_range(-1000..=3000)?;
+            let sixteen = u.int_in_range(-1000..=64535)?;
+            LargerIntsWithOffsets::new(twelve, sixteen)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// MsgWithoutSignals
+    ///
+    /// - Standard ID: 513 (0x201)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct MsgWithoutSignals {
+        raw: [u8; 8],
+    }
+
+    impl MsgWithoutSignals {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x201) });
+
+        /// Construct new MsgWithoutSignals from values
+        pub fn new() -> Result<Self, CanError> {
+            let res = Self { raw: [0u8; 8] };
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for MsgWithoutSignals {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for MsgWithoutSignals {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for MsgWithoutSignals {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MsgWithoutSignals").finish()
+            } else {
+                f.debug_tuple("MsgWithoutSignals").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MsgWithoutSignals {
+        fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            MsgWithoutSignals::new().map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// TruncatedBeSignal
+    ///
+    /// - Standard ID: 9001 (0x2329)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct TruncatedBeSignal {
+        raw: [u8; 8],
+    }
+
+    impl TruncatedBeSignal {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x2329) });
+
+        pub const FOO_MIN: i16 = -100_i16;
+        pub const FOO_MAX: i16 = 100_i16;
+
+        /// Construct new TruncatedBeSignal from values
+        #[allow(clippy::disallowed_names)]
+        pub fn new(foo: i16) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_foo(foo)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Foo
+        ///
+        /// - Min: -100
+        /// - Max: 100
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn foo(&self) -> i16 {
+            self.foo_raw()
+        }
+
+        /// Get raw value of Foo
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn foo_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<i16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Foo
+        #[inline(always)]
+        pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -100_i16 || 100_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: TruncatedBeSignal::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: TruncatedBeSignal::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for TruncatedBeSignal {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for TruncatedBeSignal {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for TruncatedBeSignal {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("TruncatedBeSignal")
+                    .field("foo", &self.foo())
+                    .finish()
+            } else {
+                f.debug_tuple("TruncatedBeSignal").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for TruncatedBeSignal {
+        #[allow(clippy::disallowed_names)]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let foo = u.int_in_range(-100..=100)?;
+            TruncatedBeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// TruncatedLeSignal
+    ///
+    /// - Standard ID: 9002 (0x232a)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct TruncatedLeSignal {
+        raw: [u8; 8],
+    }
+
+    impl TruncatedLeSignal {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x232a) });
+
+        pub const FOO_MIN: i16 = -100_i16;
+        pub const FOO_MAX: i16 = 100_i16;
+
+        /// Construct new TruncatedLeSignal from values
+        #[allow(clippy::disallowed_names)]
+        pub fn new(foo: i16) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_foo(foo)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Foo
+        ///
+        /// - Min: -100
+        /// - Max: 100
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn foo(&self) -> i16 {
+            self.foo_raw()
+        }
+
+        /// Get raw value of Foo
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn foo_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<i16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Foo
+        #[inline(always)]
+        pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -100_i16 || 100_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: TruncatedLeSignal::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: TruncatedLeSignal::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for TruncatedLeSignal {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for TruncatedLeSignal {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for TruncatedLeSignal {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("TruncatedLeSignal")
+                    .field("foo", &self.foo())
+                    .finish()
+            } else {
+                f.debug_tuple("TruncatedLeSignal").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for TruncatedLeSignal {
+        #[allow(clippy::disallowed_names)]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let foo = u.int_in_range(-100..=100)?;
+            TruncatedLeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// MsgExtendedId
+    ///
+    /// - Extended ID: 4660 (0x1234)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct MsgExtendedId {
+        raw: [u8; 8],
+    }
+
+    impl MsgExtendedId {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Extended(unsafe { ExtendedId::new_unchecked(0x1234) });
+
+        pub const DUMMY_MIN: u8 = 0_u8;
+        pub const DUMMY_MAX: u8 = 3_u8;
+
+        /// Construct new MsgExtendedId from values
+        pub fn new(dummy: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_dummy(dummy)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Dummy
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: XXX
+        #[inline(always)]
+        pub fn dummy(&self) -> u8 {
+            self.dummy_raw()
+        }
+
+        /// Get raw value of Dummy
+        ///
+        /// - Start bit: 15
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn dummy_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Dummy
+        #[inline(always)]
+        pub fn set_dummy(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MsgExtendedId::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: MsgExtendedId::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for MsgExtendedId {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for MsgExtendedId {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for MsgExtendedId {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MsgExtendedId")
+                    .field("dummy", &self.dummy())
+                    .finish()
+            } else {
+                f.debug_tuple("MsgExtendedId").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MsgExtendedId {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let dummy = u.int_in_range(0..=3)?;
+            MsgExtendedId::new(dummy).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for value table OnOff
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum OnOff {
+        On,
+        Off,
+        _Other(bool),
+    }
+
+    impl OnOff {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::On, Self::Off];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: bool) -> Self {
+            match raw {
+                true => Self::On,
+                false => Self::Off,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::On => Some("On"),
+                Self::Off => Some("Off"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<bool> for OnOff {
+        fn from(raw: bool) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<OnOff> for bool {
+        fn from(val: OnOff) -> bool {
+            match val {
+                OnOff::On => true,
+                OnOff::Off => false,
+                OnOff::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for OnOff {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", bool::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for OnOff {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+}
+
+pub mod body {
+    //! Message definitions from file `"example.dbc"`
+    //!
+    //! - Version: `Version("43")`
+
+    #[cfg(feature = "arb")]
+    use arbitrary::{Arbitrary, Unstructured};
+    use bitvec::prelude::*;
+    use core::ops::BitOr;
+    use embedded_can::{ExtendedId, Id, StandardId};
+
+    use super::common::*;
+
+    /// All messages
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum Messages {
+        /// Foo
+        Foo(Foo),
+        /// Bar
+        Bar(Bar),
+        /// _4WD
+        X4wd(X4wd),
+        /// Amet
+        Amet(Amet),
+        /// Dolor
+        Dolor(Dolor),
+        /// MultiplexTest
+        MultiplexTest(MultiplexTest),
+        /// NamedMultiplexTest
+        NamedMultiplexTest(NamedMultiplexTest),
+        /// IntegerFactorOffset
+        IntegerFactorOffset(IntegerFactorOffset),
+        /// NegativeFactorTest
+        NegativeFactorTest(NegativeFactorTest),
+        /// LargerIntsWithOffsets
+        LargerIntsWithOffsets(LargerIntsWithOffsets),
+        /// MsgWithoutSignals
+        MsgWithoutSignals(MsgWithoutSignals),
+        /// TruncatedBeSignal
+        TruncatedBeSignal(TruncatedBeSignal),
+        /// TruncatedLeSignal
+        TruncatedLeSignal(TruncatedLeSignal),
+        /// MsgExtendedId
+        MsgExtendedId(MsgExtendedId),
+    }
+
+    impl Messages {
+        /// Read message from CAN frame
+        #[inline(never)]
+        pub fn from_can_message(id: Id, payload: &[u8]) -> Result<Self, CanError> {
+            let res = match id {
+                Foo::MESSAGE_ID => Messages::Foo(Foo::try_from(payload)?),
+                Bar::MESSAGE_ID => Messages::Bar(Bar::try_from(payload)?),
+                X4wd::MESSAGE_ID => Messages::X4wd(X4wd::try_from(payload)?),
+                Amet::MESSAGE_ID => Messages::Amet(Amet::try_from(payload)?),
+                Dolor::MESSAGE_ID => Messages::Dolor(Dolor::try_from(payload)?),
+                MultiplexTest::MESSAGE_ID => {
+                    Messages::MultiplexTest(MultiplexTest::try_from(payload)?)
+                }
+                NamedMultiplexTest::MESSAGE_ID => {
+                    Messages::NamedMultiplexTest(NamedMultiplexTest::try_from(payload)?)
+                }
+                IntegerFactorOffset::MESSAGE_ID => {
+                    Messages::IntegerFactorOffset(IntegerFactorOffset::try_from(payload)?)
+                }
+                NegativeFactorTest::MESSAGE_ID => {
+                    Messages::NegativeFactorTest(NegativeFactorTest::try_from(payload)?)
+                }
+                LargerIntsWithOffsets::MESSAGE_ID => {
+                    Messages::LargerIntsWithOffsets(LargerIntsWithOffsets::try_from(payload)?)
+                }
+                MsgWithoutSignals::MESSAGE_ID => {
+                    Messages::MsgWithoutSignals(MsgWithoutSignals::try_from(payload)?)
+                }
+                TruncatedBeSignal::MESSAGE_ID => {
+                    Messages::TruncatedBeSignal(TruncatedBeSignal::try_from(payload)?)
+                }
+                TruncatedLeSignal::MESSAGE_ID => {
+                    Messages::TruncatedLeSignal(TruncatedLeSignal::try_from(payload)?)
+                }
+                MsgExtendedId::MESSAGE_ID => {
+                    Messages::MsgExtendedId(MsgExtendedId::try_from(payload)?)
+                }
+                id => return Err(CanError::UnknownMessageId(id)),
+            };
+            Ok(res)
+        }
+    }
+
+    /// Foo
+    ///
+    /// - Standard ID: 256 (0x100)
+    /// - Size: 4 bytes
+    /// - Transmitter: Lorem
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Foo {
+        raw: [u8; 4],
+    }
+
+    impl Foo {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x100) });
+
+        pub const VOLTAGE_MIN: f32 = 0_f32;
+        pub const VOLTAGE_MAX: f32 = 63.9990234375_f32;
+        pub const CURRENT_MIN: f32 = -2048_f32;
+        pub const CURRENT_MAX: f32 = 2047.9375_f32;
+
+        /// Construct new Foo from values
+        pub fn new(voltage: f32, current: f32) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 4] };
+            res.set_voltage(voltage)?;
+            res.set_current(current)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 4] {
+            &self.raw
+        }
+
+        /// Voltage
+        ///
+        /// - Min: 0
+        /// - Max: 63.9990234375
+        /// - Unit: "V"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn voltage(&self) -> f32 {
+            self.voltage_raw()
+        }
+
+        /// Get raw value of Voltage
+        ///
+        /// - Start bit: 16
+        /// - Signal size: 16 bits
+        /// - Factor: 0.000976562
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn voltage_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[16..32].load_le::<u16>();
+
+            let factor = 0.000976562_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Voltage
+        #[inline(always)]
+        pub fn set_voltage(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 63.9990234375_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Foo::MESSAGE_ID,
+                });
+            }
+            let factor = 0.000976562_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[16..32].store_le(value);
+            Ok(())
+        }
+
+        /// Current
+        ///
+        /// - Min: -2048
+        /// - Max: 2047.9375
+        /// - Unit: "A"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn current(&self) -> f32 {
+            self.current_raw()
+        }
+
+        /// Get raw value of Current
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 16 bits
+        /// - Factor: 0.0625
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn current_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<i16>();
+
+            let factor = 0.0625_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Current
+        #[inline(always)]
+        pub fn set_current(&mut self, value: f32) -> Result<(), CanError> {
+            if value < -2048_f32 || 2047.9375_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Foo::MESSAGE_ID,
+                });
+            }
+            let factor = 0.0625_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Foo {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 4 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 4];
+            raw.copy_from_slice(&payload[..4]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Foo {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Foo {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Foo")
+                    .field("voltage", &self.voltage())
+                    .field("current", &self.current())
+                    .finish()
+            } else {
+                f.debug_tuple("Foo").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Foo {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let voltage = u.float_in_range(0_f32..=63.9990234375_f32)?;
+            let current = u.float_in_range(-2048_f32..=2047.9375_f32)?;
+            Foo::new(voltage, current).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Bar
+    ///
+    /// - Standard ID: 512 (0x200)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Bar {
+        raw: [u8; 8],
+    }
+
+    impl Bar {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x200) });
+
+        pub const ONE_MIN: u8 = 0_u8;
+        pub const ONE_MAX: u8 = 3_u8;
+        pub const TWO_MIN: f32 = 0_f32;
+        pub const TWO_MAX: f32 = 100_f32;
+        pub const THREE_MIN: u8 = 0_u8;
+        pub const THREE_MAX: u8 = 7_u8;
+        pub const FOUR_MIN: u8 = 0_u8;
+        pub const FOUR_MAX: u8 = 3_u8;
+
+        /// Construct new Bar from values
+        pub fn new(
+            one: u8,
+            two: f32,
+            three: BarThree,
+            four: BarFour,
+            xtype: BarType,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three(three)?;
+            res.set_four(four)?;
+            res.set_xtype(xtype)?;
+            Ok(res)
+        }
+
+        /// Construct new Bar from raw values
+        pub fn new_raw(
+            one: u8,
+            two: f32,
+            three: u8,
+            four: u8,
+            xtype: bool,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three_raw(three)?;
+            res.set_four_raw(four)?;
+            res.set_xtype_raw(xtype)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// One
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn one(&self) -> u8 {
+            self.one_raw()
+        }
+
+        /// Get raw value of One
+        ///
+        /// - Start bit: 15
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn one_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of One
+        #[inline(always)]
+        pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+            Ok(())
+        }
+
+        /// Two
+        ///
+        /// - Min: 0
+        /// - Max: 100
+        /// - Unit: "%"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn two(&self) -> f32 {
+            self.two_raw()
+        }
+
+        /// Get raw value of Two
+        ///
+        /// - Start bit: 7
+        /// - Signal size: 8 bits
+        /// - Factor: 0.39
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn two_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Two
+        #[inline(always)]
+        pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 100_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
+            Ok(())
+        }
+
+        /// Three
+        ///
+        /// - Min: 0
+        /// - Max: 7
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn three(&self) -> BarThree {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            match signal {
+                0 => BarThree::Off,
+                1 => BarThree::On,
+                2 => BarThree::Oner,
+                3 => BarThree::Onest,
+                _ => BarThree::_Other(self.three_raw()),
+            }
+        }
+
+        /// Get raw value of Three
+        ///
+        /// - Start bit: 13
+        /// - Signal size: 3 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn three_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Three
+        #[inline(always)]
+        pub fn set_three(&mut self, value: BarThree) -> Result<(), CanError> {
+            self.set_three_raw(value.into())
+        }
+
+        /// Set raw value of Three
+        #[inline(always)]
+        pub fn set_three_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
+            Ok(())
+        }
+
+        /// Four
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn four(&self) -> BarFour {
+            let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+            match signal {
+                0 => BarFour::Off,
+                1 => BarFour::On,
+                2 => BarFour::Oner,
+                3 => BarFour::Onest,
+                _ => BarFour::_Other(self.four_raw()),
+            }
+        }
+
+        /// Get raw value of Four
+        ///
+        /// - Start bit: 10
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn four_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Four
+        #[inline(always)]
+        pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+            self.set_four_raw(value.into())
+        }
+
+        /// Set raw value of Four
+        #[inline(always)]
+        pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[13..15].store_be(value);
+            Ok(())
+        }
+
+        /// Type
+        ///
+        /// - Min: 0
+        /// - Max: 1
+        /// - Unit: "boolean"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn xtype(&self) -> BarType {
+            let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
+
+            match signal {
+                0 => BarType::X0off,
+                1 => BarType::X1on,
+                _ => BarType::_Other(self.xtype_raw()),
+            }
+        }
+
+        /// Get raw value of Type
+        ///
+        /// - Start bit: 30
+        /// - Signal size: 1 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn xtype_raw(&self) -> bool {
+            let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
+
+            signal == 1
+        }
+
+        /// Set value of Type
+        #[inline(always)]
+        pub fn set_xtype(&mut self, value: BarType) -> Result<(), CanError> {
+            self.set_xtype_raw(value.into())
+        }
+
+        /// Set raw value of Type
+        #[inline(always)]
+        pub fn set_xtype_raw(&mut self, value: bool) -> Result<(), CanError> {
+            let value = value as u8;
+            self.raw.view_bits_mut::<Msb0>()[25..26].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Bar {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Bar {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Bar {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Bar")
+                    .field("one", &self.one())
+                    .field("two", &self.two())
+                    .field("three", &self.three())
+                    .field("four", &self.four())
+                    .field("xtype", &self.xtype())
+                    .finish()
+            } else {
+                f.debug_tuple("Bar").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Bar {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let one = u.int_in_range(0..=3)?;
+            let two = u.float_in_range(0_f32..=100_f32)?;
+            let three = u.int_in_range(0..=7)?;
+            let four = u.int_in_range(0..=3)?;
+            let xtype = u.int_in_range(0..=1)? == 1;
+            Bar::new_raw(one, two, three, four, xtype)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for Three
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BarThree {
+        Off,
+        On,
+        Oner,
+        Onest,
+        _Other(u8),
+    }
+
+    impl BarThree {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::On,
+                2 => Self::Oner,
+                3 => Self::Onest,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("OFF"),
+                Self::On => Some("ON"),
+                Self::Oner => Some("ONER"),
+                Self::Onest => Some("ONEST"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for BarThree {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<BarThree> for u8 {
+        fn from(val: BarThree) -> u8 {
+            match val {
+                BarThree::Off => 0,
+                BarThree::On => 1,
+                BarThree::Oner => 2,
+                BarThree::Onest => 3,
+                BarThree::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for BarThree {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for BarThree {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Defined values for Four
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BarFour {
+        Off,
+        On,
+        Oner,
+        Onest,
+        _Other(u8),
+    }
+
+    impl BarFour {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::On,
+                2 => Self::Oner,
+                3 => Self::Onest,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("Off"),
+                Self::On => Some("On"),
+                Self::Oner => Some("Oner"),
+                Self::Onest => Some("Onest"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for BarFour {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<BarFour> for u8 {
+        fn from(val: BarFour) -> u8 {
+            match val {
+                BarFour::Off => 0,
+                BarFour::On => 1,
+                BarFour::Oner => 2,
+                BarFour::Onest => 3,
+                BarFour::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for BarFour {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for BarFour {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Defined values for Type
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum BarType {
+        X0off,
+        X1on,
+        _Other(bool),
+    }
+
+    impl BarType {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::X0off, Self::X1on];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: bool) -> Self {
+            match raw {
+                false => Self::X0off,
+                true => Self::X1on,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::X0off => Some("0Off"),
+                Self::X1on => Some("1On"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<bool> for BarType {
+        fn from(raw: bool) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<BarType> for bool {
+        fn from(val: BarType) -> bool {
+            match val {
+                BarType::X0off => false,
+                BarType::X1on => true,
+                BarType::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for BarType {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", bool::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for BarType {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// _4WD
+    ///
+    /// - Standard ID: 768 (0x300)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct X4wd {
+        raw: [u8; 8],
+    }
+
+    impl X4wd {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x300) });
+
+        pub const X4DRIVE_MIN: u8 = 0_u8;
+        pub const X4DRIVE_MAX: u8 = 7_u8;
+
+        /// Construct new _4WD from values
+        pub fn new(x4drive: X4wd4drive) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_x4drive(x4drive)?;
+            Ok(res)
+        }
+
+        /// Construct new _4WD from raw values
+        pub fn new_raw(x4drive: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_x4drive_raw(x4drive)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// _4DRIVE
+        ///
+        /// - Min: 0
+        /// - Max: 7
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn x4drive(&self) -> X4wd4drive {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            match signal {
+                0 => X4wd4drive::Off,
+                1 => X4wd4drive::X2wd,
+                2 => X4wd4drive::X4wd,
+                3 => X4wd4drive::All,
+                _ => X4wd4drive::_Other(self.x4drive_raw()),
+            }
+        }
+
+        /// Get raw value of _4DRIVE
+        ///
+        /// - Start bit: 13
+        /// - Signal size: 3 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn x4drive_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of _4DRIVE
+        #[inline(always)]
+        pub fn set_x4drive(&mut self, value: X4wd4drive) -> Result<(), CanError> {
+            self.set_x4drive_raw(value.into())
+        }
+
+        /// Set raw value of _4DRIVE
+        #[inline(always)]
+        pub fn set_x4drive_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: X4wd::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: X4wd::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for X4wd {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for X4wd {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for X4wd {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("X4wd")
+                    .field("x4drive", &self.x4drive())
+                    .finish()
+            } else {
+                f.debug_tuple("X4wd").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for X4wd {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let x4drive = u.int_in_range(0..=7)?;
+            X4wd::new_raw(x4drive).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for _4DRIVE
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum X4wd4drive {
+        Off,
+        X2wd,
+        X4wd,
+        All,
+        _Other(u8),
+    }
+
+    impl X4wd4drive {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::X2wd, Self::X4wd, Self::All];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::X2wd,
+                2 => Self::X4wd,
+                3 => Self::All,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("OFF"),
+                Self::X2wd => Some("2WD"),
+                Self::X4wd => Some("4WD"),
+                Self::All => Some("ALL"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for X4wd4drive {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<X4wd4drive> for u8 {
+        fn from(val: X4wd4drive) -> u8 {
+            match val {
+                X4wd4drive::Off => 0,
+                X4wd4drive::X2wd => 1,
+                X4wd4drive::X4wd => 2,
+                X4wd4drive::All => 3,
+                X4wd4drive::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for X4wd4drive {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for X4wd4drive {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Amet
+    ///
+    /// - Standard ID: 1024 (0x400)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Amet {
+        raw: [u8; 8],
+    }
+
+    impl Amet {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x400) });
+
+        pub const ONE_MIN: u8 = 0_u8;
+        pub const ONE_MAX: u8 = 3_u8;
+        pub const TWO_MIN: f32 = 0_f32;
+        pub const TWO_MAX: f32 = 100_f32;
+        pub const THREE_MIN: u8 = 0_u8;
+        pub const THREE_MAX: u8 = 7_u8;
+        pub const FOUR_MIN: u8 = 0_u8;
+        pub const FOUR_MAX: u8 = 3_u8;
+
+        /// Construct new Amet from values
+        pub fn new(
+            one: u8,
+            two: f32,
+            three: u8,
+            four: AmetFour,
+            five: OnOff,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three(three)?;
+            res.set_four(four)?;
+            res.set_five(five)?;
+            Ok(res)
+        }
+
+        /// Construct new Amet from raw values
+        pub fn new_raw(
+            one: u8,
+            two: f32,
+            three: u8,
+            four: u8,
+            five: bool,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one(one)?;
+            res.set_two(two)?;
+            res.set_three(three)?;
+            res.set_four_raw(four)?;
+            res.set_five_raw(five)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// One
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn one(&self) -> u8 {
+            self.one_raw()
+        }
+
+        /// Get raw value of One
+        ///
+        /// - Start bit: 15
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn one_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of One
+        #[inline(always)]
+        pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+            Ok(())
+        }
+
+        /// Two
+        ///
+        /// - Min: 0
+        /// - Max: 100
+        /// - Unit: "%"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn two(&self) -> f32 {
+            self.two_raw()
+        }
+
+        /// Get raw value of Two
+        ///
+        /// - Start bit: 7
+        /// - Signal size: 8 bits
+        /// - Factor: 0.39
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn two_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of Two
+        #[inline(always)]
+        pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 100_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 0.39_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
+            Ok(())
+        }
+
+        /// Three
+        ///
+        /// - Min: 0
+        /// - Max: 7
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn three(&self) -> u8 {
+            self.three_raw()
+        }
+
+        /// Get raw value of Three
+        ///
+        /// - Start bit: 20
+        /// - Signal size: 3 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn three_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[19..22].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Three
+        #[inline(always)]
+        pub fn set_three(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 7_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[19..22].store_be(value);
+            Ok(())
+        }
+
+        /// Four
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn four(&self) -> AmetFour {
+            let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+            match signal {
+                0 => AmetFour::Off,
+                1 => AmetFour::On,
+                2 => AmetFour::Oner,
+                3 => AmetFour::Onest,
+                _ => AmetFour::_Other(self.four_raw()),
+            }
+        }
+
+        /// Get raw value of Four
+        ///
+        /// - Start bit: 30
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn four_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Four
+        #[inline(always)]
+        pub fn set_four(&mut self, value: AmetFour) -> Result<(), CanError> {
+            self.set_four_raw(value.into())
+        }
+
+        /// Set raw value of Four
+        #[inline(always)]
+        pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[25..27].store_be(value);
+            Ok(())
+        }
+
+        /// Five
+        ///
+        /// - Min: 0
+        /// - Max: 1
+        /// - Unit: "boolean"
+        /// - Receivers: Dolor
+        #[inline(always)]
+        pub fn five(&self) -> OnOff {
+            let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+            match signal {
+                1 => OnOff::On,
+                0 => OnOff::Off,
+                _ => OnOff::_Other(self.five_raw()),
+            }
+        }
+
+        /// Get raw value of Five
+        ///
+        /// - Start bit: 40
+        /// - Signal size: 1 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn five_raw(&self) -> bool {
+            let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+            signal == 1
+        }
+
+        /// Set value of Five
+        #[inline(always)]
+        pub fn set_five(&mut self, value: OnOff) -> Result<(), CanError> {
+            self.set_five_raw(value.into())
+        }
+
+        /// Set raw value of Five
+        #[inline(always)]
+        pub fn set_five_raw(&mut self, value: bool) -> Result<(), CanError> {
+            let value = value as u8;
+            self.raw.view_bits_mut::<Msb0>()[47..48].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Amet {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Amet {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Amet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Amet")
+                    .field("one", &self.one())
+                    .field("two", &self.two())
+                    .field("three", &self.three())
+                    .field("four", &self.four())
+                    .field("five", &self.five())
+                    .finish()
+            } else {
+                f.debug_tuple("Amet").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Amet {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let one = u.int_in_range(0..=3)?;
+            let two = u.float_in_range(0_f32..=100_f32)?;
+            let three = u.int_in_range(0..=7)?;
+            let four = u.int_in_range(0..=3)?;
+            let five = u.int_in_range(0..=1)? == 1;
+            Amet::new_raw(one, two, three, four, five)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for Four
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum AmetFour {
+        Off,
+        On,
+        Oner,
+        Onest,
+        _Other(u8),
+    }
+
+    impl AmetFour {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: u8) -> Self {
+            match raw {
+                0 => Self::Off,
+                1 => Self::On,
+                2 => Self::Oner,
+                3 => Self::Onest,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Off => Some("Off"),
+                Self::On => Some("On"),
+                Self::Oner => Some("Oner"),
+                Self::Onest => Some("Onest"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<u8> for AmetFour {
+        fn from(raw: u8) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<AmetFour> for u8 {
+        fn from(val: AmetFour) -> u8 {
+            match val {
+                AmetFour::Off => 0,
+                AmetFour::On => 1,
+                AmetFour::Oner => 2,
+                AmetFour::Onest => 3,
+                AmetFour::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for AmetFour {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", u8::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for AmetFour {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// Dolor
+    ///
+    /// - Standard ID: 1028 (0x404)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Dolor {
+        raw: [u8; 8],
+    }
+
+    impl Dolor {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x404) });
+
+        pub const ONE_FLOAT_MIN: f32 = 0_f32;
+        pub const ONE_FLOAT_MAX: f32 = 130_f32;
+
+        /// Construct new Dolor from values
+        pub fn new(one_float: DolorOneFloat) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one_float(one_float)?;
+            Ok(res)
+        }
+
+        /// Construct new Dolor from raw values
+        pub fn new_raw(one_float: f32) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_one_float_raw(one_float)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// OneFloat
+        ///
+        /// - Min: 0
+        /// - Max: 130
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn one_float(&self) -> DolorOneFloat {
+            let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+            match signal {
+                3 => DolorOneFloat::Dolor,
+                5 => DolorOneFloat::Other,
+                _ => DolorOneFloat::_Other(self.one_float_raw()),
+            }
+        }
+
+        /// Get raw value of OneFloat
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 0.5
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn one_float_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+            let factor = 0.5_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of OneFloat
+        #[inline(always)]
+        pub fn set_one_float(&mut self, value: DolorOneFloat) -> Result<(), CanError> {
+            self.set_one_float_raw(value.into())
+        }
+
+        /// Set raw value of OneFloat
+        #[inline(always)]
+        pub fn set_one_float_raw(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 130_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Dolor::MESSAGE_ID,
+                });
+            }
+            let factor = 0.5_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u16;
+
+            self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for Dolor {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for Dolor {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for Dolor {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("Dolor")
+                    .field("one_float", &self.one_float())
+                    .finish()
+            } else {
+                f.debug_tuple("Dolor").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for Dolor {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let one_float = u.float_in_range(0_f32..=130_f32)?;
+            Dolor::new_raw(one_float).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for OneFloat
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub enum DolorOneFloat {
+        Dolor,
+        Other,
+        _Other(f32),
+    }
+
+    impl DolorOneFloat {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::Dolor, Self::Other];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: f32) -> Self {
+            match raw {
+                3_f32 => Self::Dolor,
+                5_f32 => Self::Other,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::Dolor => Some("Dolor"),
+                Self::Other => Some("Other"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<f32> for DolorOneFloat {
+        fn from(raw: f32) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<DolorOneFloat> for f32 {
+        fn from(val: DolorOneFloat) -> f32 {
+            match val {
+                DolorOneFloat::Dolor => 3_f32,
+                DolorOneFloat::Other => 5_f32,
+                DolorOneFloat::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for DolorOneFloat {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", f32::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for DolorOneFloat {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+
+    /// MultiplexTest
+    ///
+    /// - Standard ID: 200 (0xc8)
+    /// - Size: 8 bytes
+    /// - Transmitter: SENSOR
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct MultiplexTest {
+        raw: [u8; 8],
+    }
+
+    impl MultiplexTest {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0xc8) });
+
+        pub const MULTIPLEXOR_MIN: u8 = 0_u8;
+        pub const MULTIPLEXOR_MAX: u8 = 2_u8;
+        pub const UNMULTIPLEXED_SIGNAL_MIN: u8 = 0_u8;
+        pub const UNMULTIPLEXED_SIGNAL_MAX: u8 = 4_u8;
+        pub const MULTIPLEXED_SIGNAL_ZERO_A_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ZERO_A_MAX: f32 = 3_f32;
+        pub const MULTIPLEXED_SIGNAL_ZERO_B_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ZERO_B_MAX: f32 = 3_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_A_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_A_MAX: f32 = 6_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_B_MIN: f32 = 0_f32;
+        pub const MULTIPLEXED_SIGNAL_ONE_B_MAX: f32 = 6_f32;
+
+        /// Construct new MultiplexTest from values
+        pub fn new(multiplexor: u8, unmultiplexed_signal: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_multiplexor(multiplexor)?;
+            res.set_unmultiplexed_signal(unmultiplexed_signal)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Get raw value of Multiplexor
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 4 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexor_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        pub fn multiplexor(&self) -> Result<MultiplexTestMultiplexorIndex, CanError> {
+            match self.multiplexor_raw() {
+                0 => Ok(MultiplexTestMultiplexorIndex::M0(
+                    MultiplexTestMultiplexorM0 { raw: self.raw },
+                )),
+                1 => Ok(MultiplexTestMultiplexorIndex::M1(
+                    MultiplexTestMultiplexorM1 { raw: self.raw },
+                )),
+                multiplexor => Err(CanError::InvalidMultiplexor {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                    multiplexor: multiplexor.into(),
+                }),
+            }
+        }
+        /// Set value of Multiplexor
+        #[inline(always)]
+        fn set_multiplexor(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 2_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+            Ok(())
+        }
+
+        /// Set value of Multiplexor
+        #[inline(always)]
+        pub fn set_m0(&mut self, value: MultiplexTestMultiplexorM0) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_multiplexor(0)?;
+            Ok(())
+        }
+
+        /// Set value of Multiplexor
+        #[inline(always)]
+        pub fn set_m1(&mut self, value: MultiplexTestMultiplexorM1) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_multiplexor(1)?;
+            Ok(())
+        }
+
+        /// UnmultiplexedSignal
+        ///
+        /// - Min: 0
+        /// - Max: 4
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn unmultiplexed_signal(&self) -> u8 {
+            self.unmultiplexed_signal_raw()
+        }
+
+        /// Get raw value of UnmultiplexedSignal
+        ///
+        /// - Start bit: 4
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn unmultiplexed_signal_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Lsb0>()[4..12].load_le::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of UnmultiplexedSignal
+        #[inline(always)]
+        pub fn set_unmultiplexed_signal(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 4_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[4..12].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for MultiplexTest {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for MultiplexTest {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for MultiplexTest {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                let mut d = f.debug_struct("MultiplexTest");
+                match self.multiplexor() {
+                    Ok(multiplexor) => d.field("multiplexor", &multiplexor),
+                    Err(_) => d.field("multiplexor", &self.multiplexor_raw()),
+                };
+                d.field("unmultiplexed_signal", &self.unmultiplexed_signal());
+                d.finish()
+            } else {
+                f.debug_tuple("MultiplexTest").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MultiplexTest {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let multiplexor = u.int_in_range(0..=2)?;
+            let unmultiplexed_signal = u.int_in_range(0..=4)?;
+            let mut msg = MultiplexTest::new(multiplexor, unmultiplexed_signal)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            match u.int_in_range(0..=1_usize)? {
+                0 => msg.set_m0(u.arbitrary()?),
+                _ => msg.set_m1(u.arbitrary()?),
+            }
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// Defined values for multiplexed signal MultiplexTest
+    #[derive(PartialEq, Eq, Debug)]
+    pub enum MultiplexTestMultiplexorIndex {
+        M0(MultiplexTestMultiplexorM0),
+        M1(MultiplexTestMultiplexorM1),
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct MultiplexTestMultiplexorM0 {
+        raw: [u8; 8],
+    }
+
+    impl MultiplexTestMultiplexorM0 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// MultiplexedSignalZeroA
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_a(&self) -> f32 {
+            self.multiplexed_signal_zero_a_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalZeroA
+        ///
+        /// - Start bit: 12
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_a_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalZeroA
+        #[inline(always)]
+        pub fn set_multiplexed_signal_zero_a(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 3_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+            Ok(())
+        }
+
+        /// MultiplexedSignalZeroB
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_b(&self) -> f32 {
+            self.multiplexed_signal_zero_b_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalZeroB
+        ///
+        /// - Start bit: 20
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_zero_b_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalZeroB
+        #[inline(always)]
+        pub fn set_multiplexed_signal_zero_b(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 3_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for MultiplexTestMultiplexorM0 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MultiplexTestMultiplexorM0")
+                    .field(
+                        "multiplexed_signal_zero_a",
+                        &self.multiplexed_signal_zero_a(),
+                    )
+                    .field(
+                        "multiplexed_signal_zero_b",
+                        &self.multiplexed_signal_zero_b(),
+                    )
+                    .finish()
+            } else {
+                f.debug_tuple("MultiplexTestMultiplexorM0")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM0 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_multiplexed_signal_zero_a(u.float_in_range(0_f32..=3_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            msg.set_multiplexed_signal_zero_b(u.float_in_range(0_f32..=3_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct MultiplexTestMultiplexorM1 {
+        raw: [u8; 8],
+    }
+
+    impl MultiplexTestMultiplexorM1 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// MultiplexedSignalOneA
+        ///
+        /// - Min: 0
+        /// - Max: 6
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_one_a(&self) -> f32 {
+            self.multiplexed_signal_one_a_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalOneA
+        ///
+        /// - Start bit: 12
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_one_a_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalOneA
+        #[inline(always)]
+        pub fn set_multiplexed_signal_one_a(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 6_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+            Ok(())
+        }
+
+        /// MultiplexedSignalOneB
+        ///
+        /// - Min: 0
+        /// - Max: 6
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn multiplexed_signal_one_b(&self) -> f32 {
+            self.multiplexed_signal_one_b_raw()
+        }
+
+        /// Get raw value of MultiplexedSignalOneB
+        ///
+        /// - Start bit: 20
+        /// - Signal size: 8 bits
+        /// - Factor: 0.1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn multiplexed_signal_one_b_raw(&self) -> f32 {
+            let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            (signal as f32) * factor + offset
+        }
+
+        /// Set value of MultiplexedSignalOneB
+        #[inline(always)]
+        pub fn set_multiplexed_signal_one_b(&mut self, value: f32) -> Result<(), CanError> {
+            if value < 0_f32 || 6_f32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 0.1_f32;
+            let offset = 0_f32;
+            let value = ((value - offset) / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for MultiplexTestMultiplexorM1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MultiplexTestMultiplexorM1")
+                    .field("multiplexed_signal_one_a", &self.multiplexed_signal_one_a())
+                    .field("multiplexed_signal_one_b", &self.multiplexed_signal_one_b())
+                    .finish()
+            } else {
+                f.debug_tuple("MultiplexTestMultiplexorM1")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM1 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_multiplexed_signal_one_a(u.float_in_range(0_f32..=6_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            msg.set_multiplexed_signal_one_b(u.float_in_range(0_f32..=6_f32)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// NamedMultiplexTest
+    ///
+    /// - Standard ID: 201 (0xc9)
+    /// - Size: 8 bytes
+    /// - Transmitter: SENSOR
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct NamedMultiplexTest {
+        raw: [u8; 8],
+    }
+
+    impl NamedMultiplexTest {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0xc9) });
+
+        pub const KIND_MIN: u8 = 0_u8;
+        pub const KIND_MAX: u8 = 2_u8;
+        pub const CELSIUS_MIN: i8 = -40_i8;
+        pub const CELSIUS_MAX: i8 = 100_i8;
+        pub const KILOPASCAL_MIN: u16 = 0_u16;
+        pub const KILOPASCAL_MAX: u16 = 1000_u16;
+
+        /// Construct new NamedMultiplexTest from values
+        pub fn new(kind: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_kind(kind)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Get raw value of Kind
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 4 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn kind_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        pub fn kind(&self) -> Result<NamedMultiplexTestKindIndex, CanError> {
+            match self.kind_raw() {
+                0 => Ok(NamedMultiplexTestKindIndex::Temperature(
+                    NamedMultiplexTestKindM0 { raw: self.raw },
+                )),
+                1 => Ok(NamedMultiplexTestKindIndex::Pressure(
+                    NamedMultiplexTestKindM1 { raw: self.raw },
+                )),
+                multiplexor => Err(CanError::InvalidMultiplexor {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                    multiplexor: multiplexor.into(),
+                }),
+            }
+        }
+        /// Set value of Kind
+        #[inline(always)]
+        fn set_kind(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 2_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+            Ok(())
+        }
+
+        /// Set value of Kind
+        #[inline(always)]
+        pub fn set_temperature(&mut self, value: NamedMultiplexTestKindM0) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_kind(0)?;
+            Ok(())
+        }
+
+        /// Set value of Kind
+        #[inline(always)]
+        pub fn set_pressure(&mut self, value: NamedMultiplexTestKindM1) -> Result<(), CanError> {
+            let b0 = BitArray::<_, LocalBits>::new(self.raw);
+            let b1 = BitArray::<_, LocalBits>::new(value.raw);
+            self.raw = b0.bitor(b1).into_inner();
+            self.set_kind(1)?;
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for NamedMultiplexTest {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for NamedMultiplexTest {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for NamedMultiplexTest {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                let mut d = f.debug_struct("NamedMultiplexTest");
+                match self.kind() {
+                    Ok(multiplexor) => d.field("kind", &multiplexor),
+                    Err(_) => d.field("kind", &self.kind_raw()),
+                };
+                d.finish()
+            } else {
+                f.debug_tuple("NamedMultiplexTest")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NamedMultiplexTest {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let kind = u.int_in_range(0..=2)?;
+            let mut msg =
+                NamedMultiplexTest::new(kind).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            match u.int_in_range(0..=1_usize)? {
+                0 => msg.set_temperature(u.arbitrary()?),
+                _ => msg.set_pressure(u.arbitrary()?),
+            }
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// Defined values for multiplexed signal NamedMultiplexTest
+    #[derive(PartialEq, Eq, Debug)]
+    pub enum NamedMultiplexTestKindIndex {
+        Temperature(NamedMultiplexTestKindM0),
+        Pressure(NamedMultiplexTestKindM1),
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct NamedMultiplexTestKindM0 {
+        raw: [u8; 8],
+    }
+
+    impl NamedMultiplexTestKindM0 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// Celsius
+        ///
+        /// - Min: -40
+        /// - Max: 100
+        /// - Unit: "degC"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn celsius(&self) -> i8 {
+            self.celsius_raw()
+        }
+
+        /// Get raw value of Celsius
+        ///
+        /// - Start bit: 8
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn celsius_raw(&self) -> i8 {
+            let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<i8>();
+
+            let factor = 1;
+            let signal = signal as i8;
+            i8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Celsius
+        #[inline(always)]
+        pub fn set_celsius(&mut self, value: i8) -> Result<(), CanError> {
+            if value < -40_i8 || 100_i8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i8;
+
+            let value = u8::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for NamedMultiplexTestKindM0 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("NamedMultiplexTestKindM0")
+                    .field("celsius", &self.celsius())
+                    .finish()
+            } else {
+                f.debug_tuple("NamedMultiplexTestKindM0")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM0 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_celsius(u.int_in_range(-40..=100)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    #[derive(PartialEq, Eq, Default)]
+    pub struct NamedMultiplexTestKindM1 {
+        raw: [u8; 8],
+    }
+
+    impl NamedMultiplexTestKindM1 {
+        pub fn new() -> Self {
+            Self { raw: [0u8; 8] }
+        }
+        /// Kilopascal
+        ///
+        /// - Min: 0
+        /// - Max: 1000
+        /// - Unit: "kPa"
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn kilopascal(&self) -> u16 {
+            self.kilopascal_raw()
+        }
+
+        /// Get raw value of Kilopascal
+        ///
+        /// - Start bit: 8
+        /// - Signal size: 16 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn kilopascal_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[8..24].load_le::<u16>();
+
+            let factor = 1;
+            u16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Kilopascal
+        #[inline(always)]
+        pub fn set_kilopascal(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 0_u16 || 1000_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::fmt::Debug for NamedMultiplexTestKindM1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("NamedMultiplexTestKindM1")
+                    .field("kilopascal", &self.kilopascal())
+                    .finish()
+            } else {
+                f.debug_tuple("NamedMultiplexTestKindM1")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM1 {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let mut msg = Self::new();
+            msg.set_kilopascal(u.int_in_range(0..=1000)?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+            Ok(msg)
+        }
+    }
+
+    /// IntegerFactorOffset
+    ///
+    /// - Standard ID: 1337 (0x539)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct IntegerFactorOffset {
+        raw: [u8; 8],
+    }
+
+    impl IntegerFactorOffset {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x539) });
+
+        pub const BYTE_WITH_OFFSET_MIN: u16 = 1_u16;
+        pub const BYTE_WITH_OFFSET_MAX: u16 = 256_u16;
+        pub const BYTE_WITH_FACTOR_MIN: u16 = 0_u16;
+        pub const BYTE_WITH_FACTOR_MAX: u16 = 1020_u16;
+        pub const BYTE_WITH_BOTH_MIN: u16 = 16_u16;
+        pub const BYTE_WITH_BOTH_MAX: u16 = 526_u16;
+        pub const BYTE_WITH_NEGATIVE_OFFSET_MIN: i16 = 0_i16;
+        pub const BYTE_WITH_NEGATIVE_OFFSET_MAX: i16 = 255_i16;
+        pub const BYTE_WITH_NEGATIVE_MIN_MIN: i16 = -127_i16;
+        pub const BYTE_WITH_NEGATIVE_MIN_MAX: i16 = 127_i16;
+
+        /// Construct new IntegerFactorOffset from values
+        pub fn new(
+            byte_with_offset: u16,
+            byte_with_factor: u16,
+            byte_with_both: u16,
+            byte_with_negative_offset: i16,
+            byte_with_negative_min: i16,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_byte_with_offset(byte_with_offset)?;
+            res.set_byte_with_factor(byte_with_factor)?;
+            res.set_byte_with_both(byte_with_both)?;
+            res.set_byte_with_negative_offset(byte_with_negative_offset)?;
+            res.set_byte_with_negative_min(byte_with_negative_min)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// ByteWithOffset
+        ///
+        /// - Min: 1
+        /// - Max: 256
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_offset(&self) -> u16 {
+            self.byte_with_offset_raw()
+        }
+
+        /// Get raw value of ByteWithOffset
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: 1
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_offset_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+            let factor = 1;
+            u16::from(signal).saturating_mul(factor).saturating_add(1)
+        }
+
+        /// Set value of ByteWithOffset
+        #[inline(always)]
+        pub fn set_byte_with_offset(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 1_u16 || 256_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(1).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithFactor
+        ///
+        /// - Min: 0
+        /// - Max: 1020
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_factor(&self) -> u16 {
+            self.byte_with_factor_raw()
+        }
+
+        /// Get raw value of ByteWithFactor
+        ///
+        /// - Start bit: 8
+        /// - Signal size: 8 bits
+        /// - Factor: 4
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_factor_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+            let factor = 4;
+            u16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of ByteWithFactor
+        #[inline(always)]
+        pub fn set_byte_with_factor(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 0_u16 || 1020_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 4;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithBoth
+        ///
+        /// - Min: 16
+        /// - Max: 526
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_both(&self) -> u16 {
+            self.byte_with_both_raw()
+        }
+
+        /// Get raw value of ByteWithBoth
+        ///
+        /// - Start bit: 16
+        /// - Signal size: 8 bits
+        /// - Factor: 2
+        /// - Offset: 16
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_both_raw(&self) -> u16 {
+            let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+            let factor = 2;
+            u16::from(signal).saturating_mul(factor).saturating_add(16)
+        }
+
+        /// Set value of ByteWithBoth
+        #[inline(always)]
+        pub fn set_byte_with_both(&mut self, value: u16) -> Result<(), CanError> {
+            if value < 16_u16 || 526_u16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 2;
+            let value = value.checked_sub(16).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithNegativeOffset
+        ///
+        /// - Min: 0
+        /// - Max: 255
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_negative_offset(&self) -> i16 {
+            self.byte_with_negative_offset_raw()
+        }
+
+        /// Get raw value of ByteWithNegativeOffset
+        ///
+        /// - Start bit: 24
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: -1
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_negative_offset_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+            let factor = 1;
+            i16::from(signal).saturating_mul(factor).saturating_sub(1)
+        }
+
+        /// Set value of ByteWithNegativeOffset
+        #[inline(always)]
+        pub fn set_byte_with_negative_offset(&mut self, value: i16) -> Result<(), CanError> {
+            if value < 0_i16 || 255_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+            Ok(())
+        }
+
+        /// ByteWithNegativeMin
+        ///
+        /// - Min: -127
+        /// - Max: 127
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn byte_with_negative_min(&self) -> i16 {
+            self.byte_with_negative_min_raw()
+        }
+
+        /// Get raw value of ByteWithNegativeMin
+        ///
+        /// - Start bit: 32
+        /// - Signal size: 8 bits
+        /// - Factor: 1
+        /// - Offset: -1
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn byte_with_negative_min_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+            let factor = 1;
+            i16::from(signal).saturating_mul(factor).saturating_sub(1)
+        }
+
+        /// Set value of ByteWithNegativeMin
+        #[inline(always)]
+        pub fn set_byte_with_negative_min(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -127_i16 || 127_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for IntegerFactorOffset {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for IntegerFactorOffset {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for IntegerFactorOffset {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("IntegerFactorOffset")
+                    .field("byte_with_offset", &self.byte_with_offset())
+                    .field("byte_with_factor", &self.byte_with_factor())
+                    .field("byte_with_both", &self.byte_with_both())
+                    .field(
+                        "byte_with_negative_offset",
+                        &self.byte_with_negative_offset(),
+                    )
+                    .field("byte_with_negative_min", &self.byte_with_negative_min())
+                    .finish()
+            } else {
+                f.debug_tuple("IntegerFactorOffset")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for IntegerFactorOffset {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let byte_with_offset = u.int_in_range(1..=256)?;
+            let byte_with_factor = u.int_in_range(0..=1020)?;
+            let byte_with_both = u.int_in_range(16..=526)?;
+            let byte_with_negative_offset = u.int_in_range(0..=255)?;
+            let byte_with_negative_min = u.int_in_range(-127..=127)?;
+            IntegerFactorOffset::new(
+                byte_with_offset,
+                byte_with_factor,
+                byte_with_both,
+                byte_with_negative_offset,
+                byte_with_negative_min,
+            )
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// NegativeFactorTest
+    ///
+    /// - Standard ID: 1344 (0x540)
+    /// - Size: 4 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct NegativeFactorTest {
+        raw: [u8; 4],
+    }
+
+    impl NegativeFactorTest {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x540) });
+
+        pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MIN: i32 = -65535_i32;
+        pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MAX: i32 = 0_i32;
+        pub const WIDTH_MORE_THAN_MIN_MAX_MIN: i16 = -2_i16;
+        pub const WIDTH_MORE_THAN_MIN_MAX_MAX: i16 = 2_i16;
+
+        /// Construct new NegativeFactorTest from values
+        pub fn new(
+            unsigned_negative_factor_signal: i32,
+            width_more_than_min_max: i16,
+        ) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 4] };
+            res.set_unsigned_negative_factor_signal(unsigned_negative_factor_signal)?;
+            res.set_width_more_than_min_max(width_more_than_min_max)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 4] {
+            &self.raw
+        }
+
+        /// UnsignedNegativeFactorSignal
+        ///
+        /// - Min: -65535
+        /// - Max: 0
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn unsigned_negative_factor_signal(&self) -> i32 {
+            self.unsigned_negative_factor_signal_raw()
+        }
+
+        /// Get raw value of UnsignedNegativeFactorSignal
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 16 bits
+        /// - Factor: -1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn unsigned_negative_factor_signal_raw(&self) -> i32 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<u16>();
+
+            let factor = -1;
+            i32::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of UnsignedNegativeFactorSignal
+        #[inline(always)]
+        pub fn set_unsigned_negative_factor_signal(&mut self, value: i32) -> Result<(), CanError> {
+            if value < -65535_i32 || 0_i32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NegativeFactorTest::MESSAGE_ID,
+                });
+            }
+            let factor = -1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+            Ok(())
+        }
+
+        /// WidthMoreThanMinMax
+        ///
+        /// - Min: -2
+        /// - Max: 2
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn width_more_than_min_max(&self) -> i16 {
+            self.width_more_than_min_max_raw()
+        }
+
+        /// Get raw value of WidthMoreThanMinMax
+        ///
+        /// - Start bit: 16
+        /// - Signal size: 10 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn width_more_than_min_max_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[16..26].load_le::<i16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of WidthMoreThanMinMax
+        #[inline(always)]
+        pub fn set_width_more_than_min_max(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -2_i16 || 2_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NegativeFactorTest::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[16..26].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for NegativeFactorTest {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 4 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 4];
+            raw.copy_from_slice(&payload[..4]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for NegativeFactorTest {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for NegativeFactorTest {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("NegativeFactorTest")
+                    .field(
+                        "unsigned_negative_factor_signal",
+                        &self.unsigned_negative_factor_signal(),
+                    )
+                    .field("width_more_than_min_max", &self.width_more_than_min_max())
+                    .finish()
+            } else {
+                f.debug_tuple("NegativeFactorTest")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for NegativeFactorTest {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let unsigned_negative_factor_signal = u.int_in_range(-65535..=0)?;
+            let width_more_than_min_max = u.int_in_range(-2..=2)?;
+            NegativeFactorTest::new(unsigned_negative_factor_signal, width_more_than_min_max)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// LargerIntsWithOffsets
+    ///
+    /// - Standard ID: 1338 (0x53a)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct LargerIntsWithOffsets {
+        raw: [u8; 8],
+    }
+
+    impl LargerIntsWithOffsets {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x53a) });
+
+        pub const TWELVE_MIN: i16 = -1000_i16;
+        pub const TWELVE_MAX: i16 = 3000_i16;
+        pub const SIXTEEN_MIN: i32 = -1000_i32;
+        pub const SIXTEEN_MAX: i32 = 64535_i32;
+
+        /// Construct new LargerIntsWithOffsets from values
+        pub fn new(twelve: i16, sixteen: i32) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_twelve(twelve)?;
+            res.set_sixteen(sixteen)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Twelve
+        ///
+        /// - Min: -1000
+        /// - Max: 3000
+        /// - Unit: ""
+        /// - Receivers: XXX
+        #[inline(always)]
+        pub fn twelve(&self) -> i16 {
+            self.twelve_raw()
+        }
+
+        /// Get raw value of Twelve
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 1
+        /// - Offset: -1000
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn twelve_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<u16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal)
+                .saturating_mul(factor)
+                .saturating_sub(1000)
+        }
+
+        /// Set value of Twelve
+        #[inline(always)]
+        pub fn set_twelve(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -1000_i16 || 3000_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value
+                .checked_add(1000)
+                .ok_or(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+            Ok(())
+        }
+
+        /// Sixteen
+        ///
+        /// - Min: -1000
+        /// - Max: 64535
+        /// - Unit: ""
+        /// - Receivers: XXX
+        #[inline(always)]
+        pub fn sixteen(&self) -> i32 {
+            self.sixteen_raw()
+        }
+
+        /// Get raw value of Sixteen
+        ///
+        /// - Start bit: 12
+        /// - Signal size: 16 bits
+        /// - Factor: 1
+        /// - Offset: -1000
+        /// - Byte order: LittleEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn sixteen_raw(&self) -> i32 {
+            let signal = self.raw.view_bits::<Lsb0>()[12..28].load_le::<u16>();
+
+            let factor = 1;
+            i32::from(signal)
+                .saturating_mul(factor)
+                .saturating_sub(1000)
+        }
+
+        /// Set value of Sixteen
+        #[inline(always)]
+        pub fn set_sixteen(&mut self, value: i32) -> Result<(), CanError> {
+            if value < -1000_i32 || 64535_i32 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value
+                .checked_add(1000)
+                .ok_or(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                })?;
+            let value = (value / factor) as u16;
+
+            self.raw.view_bits_mut::<Lsb0>()[12..28].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for LargerIntsWithOffsets {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for LargerIntsWithOffsets {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for LargerIntsWithOffsets {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("LargerIntsWithOffsets")
+                    .field("twelve", &self.twelve())
+                    .field("sixteen", &self.sixteen())
+                    .finish()
+            } else {
+                f.debug_tuple("LargerIntsWithOffsets")
+                    .field(&self.raw)
+                    .finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for LargerIntsWithOffsets {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let twelve = u.int_in_range(-1000..=3000)?;
+            let sixteen = u.int_in_range(-1000..=64535)?;
+            LargerIntsWithOffsets::new(twelve, sixteen)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// MsgWithoutSignals
+    ///
+    /// - Standard ID: 513 (0x201)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct MsgWithoutSignals {
+        raw: [u8; 8],
+    }
+
+    impl MsgWithoutSignals {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x201) });
+
+        /// Construct new MsgWithoutSignals from values
+        pub fn new() -> Result<Self, CanError> {
+            let res = Self { raw: [0u8; 8] };
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for MsgWithoutSignals {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for MsgWithoutSignals {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for MsgWithoutSignals {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MsgWithoutSignals").finish()
+            } else {
+                f.debug_tuple("MsgWithoutSignals").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MsgWithoutSignals {
+        fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            MsgWithoutSignals::new().map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// TruncatedBeSignal
+    ///
+    /// - Standard ID: 9001 (0x2329)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct TruncatedBeSignal {
+        raw: [u8; 8],
+    }
+
+    impl TruncatedBeSignal {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x2329) });
+
+        pub const FOO_MIN: i16 = -100_i16;
+        pub const FOO_MAX: i16 = 100_i16;
+
+        /// Construct new TruncatedBeSignal from values
+        #[allow(clippy::disallowed_names)]
+        pub fn new(foo: i16) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_foo(foo)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Foo
+        ///
+        /// - Min: -100
+        /// - Max: 100
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn foo(&self) -> i16 {
+            self.foo_raw()
+        }
+
+        /// Get raw value of Foo
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn foo_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<i16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Foo
+        #[inline(always)]
+        pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -100_i16 || 100_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: TruncatedBeSignal::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: TruncatedBeSignal::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for TruncatedBeSignal {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for TruncatedBeSignal {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for TruncatedBeSignal {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("TruncatedBeSignal")
+                    .field("foo", &self.foo())
+                    .finish()
+            } else {
+                f.debug_tuple("TruncatedBeSignal").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for TruncatedBeSignal {
+        #[allow(clippy::disallowed_names)]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let foo = u.int_in_range(-100..=100)?;
+            TruncatedBeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// TruncatedLeSignal
+    ///
+    /// - Standard ID: 9002 (0x232a)
+    /// - Size: 8 bytes
+    /// - Transmitter: Ipsum
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct TruncatedLeSignal {
+        raw: [u8; 8],
+    }
+
+    impl TruncatedLeSignal {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Standard(unsafe { StandardId::new_unchecked(0x232a) });
+
+        pub const FOO_MIN: i16 = -100_i16;
+        pub const FOO_MAX: i16 = 100_i16;
+
+        /// Construct new TruncatedLeSignal from values
+        #[allow(clippy::disallowed_names)]
+        pub fn new(foo: i16) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_foo(foo)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Foo
+        ///
+        /// - Min: -100
+        /// - Max: 100
+        /// - Unit: ""
+        /// - Receivers: Vector__XXX
+        #[inline(always)]
+        pub fn foo(&self) -> i16 {
+            self.foo_raw()
+        }
+
+        /// Get raw value of Foo
+        ///
+        /// - Start bit: 0
+        /// - Signal size: 12 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: LittleEndian
+        /// - Value type: Signed
+        #[inline(always)]
+        pub fn foo_raw(&self) -> i16 {
+            let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<i16>();
+
+            let factor = 1;
+            let signal = signal as i16;
+            i16::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Foo
+        #[inline(always)]
+        pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+            if value < -100_i16 || 100_i16 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: TruncatedLeSignal::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: TruncatedLeSignal::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as i16;
+
+            let value = u16::from_ne_bytes(value.to_ne_bytes());
+            self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for TruncatedLeSignal {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for TruncatedLeSignal {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for TruncatedLeSignal {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("TruncatedLeSignal")
+                    .field("foo", &self.foo())
+                    .finish()
+            } else {
+                f.debug_tuple("TruncatedLeSignal").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for TruncatedLeSignal {
+        #[allow(clippy::disallowed_names)]
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let foo = u.int_in_range(-100..=100)?;
+            TruncatedLeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// MsgExtendedId
+    ///
+    /// - Extended ID: 4660 (0x1234)
+    /// - Size: 8 bytes
+    /// - Transmitter: Sit
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct MsgExtendedId {
+        raw: [u8; 8],
+    }
+
+    impl MsgExtendedId {
+        pub const MESSAGE_ID: embedded_can::Id =
+            Id::Extended(unsafe { ExtendedId::new_unchecked(0x1234) });
+
+        pub const DUMMY_MIN: u8 = 0_u8;
+        pub const DUMMY_MAX: u8 = 3_u8;
+
+        /// Construct new MsgExtendedId from values
+        pub fn new(dummy: u8) -> Result<Self, CanError> {
+            let mut res = Self { raw: [0u8; 8] };
+            res.set_dummy(dummy)?;
+            Ok(res)
+        }
+
+        /// Access message payload raw value
+        pub const fn raw(&self) -> &[u8; 8] {
+            &self.raw
+        }
+
+        /// Dummy
+        ///
+        /// - Min: 0
+        /// - Max: 3
+        /// - Unit: ""
+        /// - Receivers: XXX
+        #[inline(always)]
+        pub fn dummy(&self) -> u8 {
+            self.dummy_raw()
+        }
+
+        /// Get raw value of Dummy
+        ///
+        /// - Start bit: 15
+        /// - Signal size: 2 bits
+        /// - Factor: 1
+        /// - Offset: 0
+        /// - Byte order: BigEndian
+        /// - Value type: Unsigned
+        #[inline(always)]
+        pub fn dummy_raw(&self) -> u8 {
+            let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+            let factor = 1;
+            u8::from(signal).saturating_mul(factor).saturating_add(0)
+        }
+
+        /// Set value of Dummy
+        #[inline(always)]
+        pub fn set_dummy(&mut self, value: u8) -> Result<(), CanError> {
+            if value < 0_u8 || 3_u8 < value {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MsgExtendedId::MESSAGE_ID,
+                });
+            }
+            let factor = 1;
+            let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+                message_id: MsgExtendedId::MESSAGE_ID,
+            })?;
+            let value = (value / factor) as u8;
+
+            self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+            Ok(())
+        }
+    }
+
+    impl core::convert::TryFrom<&[u8]> for MsgExtendedId {
+        type Error = CanError;
+
+        #[inline(always)]
+        fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+            if payload.len() != 8 {
+                return Err(CanError::InvalidPayloadSize);
+            }
+            let mut raw = [0u8; 8];
+            raw.copy_from_slice(&payload[..8]);
+            Ok(Self { raw })
+        }
+    }
+
+    impl embedded_can::Frame for MsgExtendedId {
+        fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+            if id.into() != Self::MESSAGE_ID {
+                None
+            } else {
+                data.try_into().ok()
+            }
+        }
+
+        fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+            unimplemented!()
+        }
+
+        fn is_extended(&self) -> bool {
+            match self.id() {
+                Id::Standard(_) => false,
+                Id::Extended(_) => true,
+            }
+        }
+
+        fn is_remote_frame(&self) -> bool {
+            false
+        }
+
+        fn id(&self) -> Id {
+            Self::MESSAGE_ID
+        }
+
+        fn dlc(&self) -> usize {
+            self.raw.len()
+        }
+
+        fn data(&self) -> &[u8] {
+            &self.raw
+        }
+    }
+    impl core::fmt::Debug for MsgExtendedId {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            if f.alternate() {
+                f.debug_struct("MsgExtendedId")
+                    .field("dummy", &self.dummy())
+                    .finish()
+            } else {
+                f.debug_tuple("MsgExtendedId").field(&self.raw).finish()
+            }
+        }
+    }
+
+    #[cfg(feature = "arb")]
+    impl<'a> Arbitrary<'a> for MsgExtendedId {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+            let dummy = u.int_in_range(0..=3)?;
+            MsgExtendedId::new(dummy).map_err(|_| arbitrary::Error::IncorrectFormat)
+        }
+    }
+
+    /// Defined values for value table OnOff
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum OnOff {
+        On,
+        Off,
+        _Other(bool),
+    }
+
+    impl OnOff {
+        /// All values defined in the DBC
+        pub const VARIANTS: &'static [Self] = &[Self::On, Self::Off];
+
+        /// Convert a raw signal value
+        pub fn from_raw(raw: bool) -> Self {
+            match raw {
+                true => Self::On,
+                false => Self::Off,
+                x => Self::_Other(x),
+            }
+        }
+
+        /// Label of this value as written in the DBC
+        ///
+        /// Returns `None` for values without a label.
+        pub fn as_str(&self) -> Option<&'static str> {
+            match self {
+                Self::On => Some("On"),
+                Self::Off => Some("Off"),
+                Self::_Other(_) => None,
+            }
+        }
+    }
+
+    impl From<bool> for OnOff {
+        fn from(raw: bool) -> Self {
+            Self::from_raw(raw)
+        }
+    }
+
+    impl From<OnOff> for bool {
+        fn from(val: OnOff) -> bool {
+            match val {
+                OnOff::On => true,
+                OnOff::Off => false,
+                OnOff::_Other(x) => x,
+            }
+        }
+    }
+
+    impl core::fmt::Display for OnOff {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self.as_str() {
+                Some(label) => f.write_str(label),
+                None => write!(f, "{}", bool::from(*self)),
+            }
+        }
+    }
+
+    impl core::str::FromStr for OnOff {
+        type Err = CanError;
+
+        /// Parse a label as written in the DBC
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::VARIANTS
+                .iter()
+                .find(|v| v.as_str() == Some(s))
+                .copied()
+                .ok_or(CanError::UndefinedValue)
+        }
+    }
+}
+
+/// Messages of all buses
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Bus {
+    /// Messages of `chassis`
+    Chassis(chassis::Messages),
+    /// Messages of `body`
+    Body(body::Messages),
+}
@@ -1,2 +1,6 @@
 mod messages;
 pub use messages::*;
+
+pub mod buses;
+pub mod lin;
+pub mod split;
@@ -0,0 +1,1669 @@
+// Generated code!
+#![allow(unused_comparisons, unreachable_patterns, unused_imports)]
+#![allow(clippy::let_and_return, clippy::eq_op)]
+#![allow(clippy::useless_conversion, clippy::unnecessary_cast)]
+#![allow(
+    clippy::excessive_precision,
+    clippy::manual_range_contains,
+    clippy::absurd_extreme_comparisons,
+    clippy::too_many_arguments
+)]
+#![deny(clippy::arithmetic_side_effects)]
+
+//! Message definitions from file `"example.ldf"`
+//!
+//! - Version: `Version("")`
+
+use bitvec::prelude::*;
+use core::ops::BitOr;
+use embedded_can::{ExtendedId, Id, StandardId};
+
+/// All messages
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Messages {
+    /// SeatControl
+    SeatControl(SeatControl),
+    /// MirrorStatus
+    MirrorStatus(MirrorStatus),
+    /// MasterReq
+    MasterReq(MasterReq),
+    /// SlaveResp
+    SlaveResp(SlaveResp),
+}
+
+impl Messages {
+    /// Read message from CAN frame
+    #[inline(never)]
+    pub fn from_can_message(id: Id, payload: &[u8]) -> Result<Self, CanError> {
+        let res = match id {
+            SeatControl::MESSAGE_ID => Messages::SeatControl(SeatControl::try_from(payload)?),
+            MirrorStatus::MESSAGE_ID => Messages::MirrorStatus(MirrorStatus::try_from(payload)?),
+            MasterReq::MESSAGE_ID => Messages::MasterReq(MasterReq::try_from(payload)?),
+            SlaveResp::MESSAGE_ID => Messages::SlaveResp(SlaveResp::try_from(payload)?),
+            id => return Err(CanError::UnknownMessageId(id)),
+        };
+        Ok(res)
+    }
+}
+
+/// SeatControl
+///
+/// - Standard ID: 16 (0x10)
+/// - Size: 2 bytes
+/// - Transmitter: Gateway
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SeatControl {
+    raw: [u8; 2],
+}
+
+impl SeatControl {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x10) });
+
+    pub const SEAT_POSITION_MIN: f32 = 0_f32;
+    pub const SEAT_POSITION_MAX: f32 = 100_f32;
+    pub const SEAT_HEATING_MIN: u8 = 0_u8;
+    pub const SEAT_HEATING_MAX: u8 = 3_u8;
+
+    /// Construct new SeatControl from values
+    pub fn new(seat_position: f32, seat_heating: SeatControlSeatHeating) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 2] };
+        res.set_seat_position(seat_position)?;
+        res.set_seat_heating(seat_heating)?;
+        Ok(res)
+    }
+
+    /// Construct new SeatControl from raw values
+    pub fn new_raw(seat_position: f32, seat_heating: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 2] };
+        res.set_seat_position(seat_position)?;
+        res.set_seat_heating_raw(seat_heating)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 2] {
+        &self.raw
+    }
+
+    /// SeatPosition
+    ///
+    /// - Min: 0
+    /// - Max: 100
+    /// - Unit: "%"
+    /// - Receivers: Seat
+    #[inline(always)]
+    pub fn seat_position(&self) -> f32 {
+        self.seat_position_raw()
+    }
+
+    /// Get raw value of SeatPosition
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 8 bits
+    /// - Factor: 0.5
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn seat_position_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of SeatPosition
+    #[inline(always)]
+    pub fn set_seat_position(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 100_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SeatControl::MESSAGE_ID,
+            });
+        }
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+        Ok(())
+    }
+
+    /// SeatHeating
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Seat
+    #[inline(always)]
+    pub fn seat_heating(&self) -> SeatControlSeatHeating {
+        let signal = self.raw.view_bits::<Lsb0>()[8..10].load_le::<u8>();
+
+        match signal {
+            0 => SeatControlSeatHeating::Off,
+            1 => SeatControlSeatHeating::Low,
+            2 => SeatControlSeatHeating::High,
+            _ => SeatControlSeatHeating::_Other(self.seat_heating_raw()),
+        }
+    }
+
+    /// Get raw value of SeatHeating
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn seat_heating_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..10].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SeatHeating
+    #[inline(always)]
+    pub fn set_seat_heating(&mut self, value: SeatControlSeatHeating) -> Result<(), CanError> {
+        self.set_seat_heating_raw(value.into())
+    }
+
+    /// Set raw value of SeatHeating
+    #[inline(always)]
+    pub fn set_seat_heating_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SeatControl::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SeatControl::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..10].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for SeatControl {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 2 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 2];
+        raw.copy_from_slice(&payload[..2]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for SeatControl {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for SeatControl {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("SeatControl")
+                .field("seat_position", &self.seat_position())
+                .field("seat_heating", &self.seat_heating())
+                .finish()
+        } else {
+            f.debug_tuple("SeatControl").field(&self.raw).finish()
+        }
+    }
+}
+
+/// Defined values for SeatHeating
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SeatControlSeatHeating {
+    Off,
+    Low,
+    High,
+    _Other(u8),
+}
+
+impl SeatControlSeatHeating {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Off, Self::Low, Self::High];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Off,
+            1 => Self::Low,
+            2 => Self::High,
+            x => Self::_Other(x),
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    ///
+    /// Returns `None` for values without a label.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Off => Some("Off"),
+            Self::Low => Some("Low"),
+            Self::High => Some("High"),
+            Self::_Other(_) => None,
+        }
+    }
+}
+
+impl From<u8> for SeatControlSeatHeating {
+    fn from(raw: u8) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<SeatControlSeatHeating> for u8 {
+    fn from(val: SeatControlSeatHeating) -> u8 {
+        match val {
+            SeatControlSeatHeating::Off => 0,
+            SeatControlSeatHeating::Low => 1,
+            SeatControlSeatHeating::High => 2,
+            SeatControlSeatHeating::_Other(x) => x,
+        }
+    }
+}
+
+impl core::fmt::Display for SeatControlSeatHeating {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "{}", u8::from(*self)),
+        }
+    }
+}
+
+impl core::str::FromStr for SeatControlSeatHeating {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == Some(s))
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// MirrorStatus
+///
+/// - Standard ID: 33 (0x21)
+/// - Size: 6 bytes
+/// - Transmitter: Mirror
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MirrorStatus {
+    raw: [u8; 6],
+}
+
+impl MirrorStatus {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x21) });
+
+    pub const MIRROR_ANGLE_MIN: f32 = -50_f32;
+    pub const MIRROR_ANGLE_MAX: f32 = 50_f32;
+    pub const MIRROR_SERIAL_MIN: u32 = 0_u32;
+    pub const MIRROR_SERIAL_MAX: u32 = 16777215_u32;
+
+    /// Construct new MirrorStatus from values
+    pub fn new(
+        mirror_angle: f32,
+        mirror_folded: bool,
+        mirror_serial: u32,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 6] };
+        res.set_mirror_angle(mirror_angle)?;
+        res.set_mirror_folded(mirror_folded)?;
+        res.set_mirror_serial(mirror_serial)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 6] {
+        &self.raw
+    }
+
+    /// MirrorAngle
+    ///
+    /// - Min: -50
+    /// - Max: 50
+    /// - Unit: "deg"
+    /// - Receivers: Gateway
+    #[inline(always)]
+    pub fn mirror_angle(&self) -> f32 {
+        self.mirror_angle_raw()
+    }
+
+    /// Get raw value of MirrorAngle
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 10 bits
+    /// - Factor: 0.1
+    /// - Offset: -50
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn mirror_angle_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..10].load_le::<u16>();
+
+        let factor = 0.1_f32;
+        let offset = -50_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MirrorAngle
+    #[inline(always)]
+    pub fn set_mirror_angle(&mut self, value: f32) -> Result<(), CanError> {
+        if value < -50_f32 || 50_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MirrorStatus::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = -50_f32;
+        let value = ((value - offset) / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..10].store_le(value);
+        Ok(())
+    }
+
+    /// MirrorFolded
+    ///
+    /// - Min: 0
+    /// - Max: 1
+    /// - Unit: ""
+    /// - Receivers: Gateway
+    #[inline(always)]
+    pub fn mirror_folded(&self) -> bool {
+        self.mirror_folded_raw()
+    }
+
+    /// Get raw value of MirrorFolded
+    ///
+    /// - Start bit: 10
+    /// - Signal size: 1 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn mirror_folded_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Lsb0>()[10..11].load_le::<u8>();
+
+        signal == 1
+    }
+
+    /// Set value of MirrorFolded
+    #[inline(always)]
+    pub fn set_mirror_folded(&mut self, value: bool) -> Result<(), CanError> {
+        let value = value as u8;
+        self.raw.view_bits_mut::<Lsb0>()[10..11].store_le(value);
+        Ok(())
+    }
+
+    /// MirrorSerial
+    ///
+    /// - Min: 0
+    /// - Max: 16777215
+    /// - Unit: ""
+    /// - Receivers: Gateway
+    #[inline(always)]
+    pub fn mirror_serial(&self) -> u32 {
+        self.mirror_serial_raw()
+    }
+
+    /// Get raw value of MirrorSerial
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 24 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn mirror_serial_raw(&self) -> u32 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..40].load_le::<u32>();
+
+        let factor = 1;
+        u32::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MirrorSerial
+    #[inline(always)]
+    pub fn set_mirror_serial(&mut self, value: u32) -> Result<(), CanError> {
+        if value < 0_u32 || 16777215_u32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MirrorStatus::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MirrorStatus::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u32;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..40].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for MirrorStatus {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 6 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 6];
+        raw.copy_from_slice(&payload[..6]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for MirrorStatus {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for MirrorStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MirrorStatus")
+                .field("mirror_angle", &self.mirror_angle())
+                .field("mirror_folded", &self.mirror_folded())
+                .field("mirror_serial", &self.mirror_serial())
+                .finish()
+        } else {
+            f.debug_tuple("MirrorStatus").field(&self.raw).finish()
+        }
+    }
+}
+
+/// MasterReq
+///
+/// - Standard ID: 60 (0x3c)
+/// - Size: 8 bytes
+/// - Transmitter: Gateway
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MasterReq {
+    raw: [u8; 8],
+}
+
+impl MasterReq {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x3c) });
+
+    pub const MASTER_REQ_B0_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B0_MAX: u8 = 255_u8;
+    pub const MASTER_REQ_B1_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B1_MAX: u8 = 255_u8;
+    pub const MASTER_REQ_B2_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B2_MAX: u8 = 255_u8;
+    pub const MASTER_REQ_B3_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B3_MAX: u8 = 255_u8;
+    pub const MASTER_REQ_B4_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B4_MAX: u8 = 255_u8;
+    pub const MASTER_REQ_B5_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B5_MAX: u8 = 255_u8;
+    pub const MASTER_REQ_B6_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B6_MAX: u8 = 255_u8;
+    pub const MASTER_REQ_B7_MIN: u8 = 0_u8;
+    pub const MASTER_REQ_B7_MAX: u8 = 255_u8;
+
+    /// Construct new MasterReq from values
+    pub fn new(
+        master_req_b0: u8,
+        master_req_b1: u8,
+        master_req_b2: u8,
+        master_req_b3: u8,
+        master_req_b4: u8,
+        master_req_b5: u8,
+        master_req_b6: u8,
+        master_req_b7: u8,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_master_req_b0(master_req_b0)?;
+        res.set_master_req_b1(master_req_b1)?;
+        res.set_master_req_b2(master_req_b2)?;
+        res.set_master_req_b3(master_req_b3)?;
+        res.set_master_req_b4(master_req_b4)?;
+        res.set_master_req_b5(master_req_b5)?;
+        res.set_master_req_b6(master_req_b6)?;
+        res.set_master_req_b7(master_req_b7)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// MasterReqB0
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b0(&self) -> u8 {
+        self.master_req_b0_raw()
+    }
+
+    /// Get raw value of MasterReqB0
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b0_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB0
+    #[inline(always)]
+    pub fn set_master_req_b0(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+        Ok(())
+    }
+
+    /// MasterReqB1
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b1(&self) -> u8 {
+        self.master_req_b1_raw()
+    }
+
+    /// Get raw value of MasterReqB1
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b1_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB1
+    #[inline(always)]
+    pub fn set_master_req_b1(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        Ok(())
+    }
+
+    /// MasterReqB2
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b2(&self) -> u8 {
+        self.master_req_b2_raw()
+    }
+
+    /// Get raw value of MasterReqB2
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b2_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB2
+    #[inline(always)]
+    pub fn set_master_req_b2(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        Ok(())
+    }
+
+    /// MasterReqB3
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b3(&self) -> u8 {
+        self.master_req_b3_raw()
+    }
+
+    /// Get raw value of MasterReqB3
+    ///
+    /// - Start bit: 24
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b3_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB3
+    #[inline(always)]
+    pub fn set_master_req_b3(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        Ok(())
+    }
+
+    /// MasterReqB4
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b4(&self) -> u8 {
+        self.master_req_b4_raw()
+    }
+
+    /// Get raw value of MasterReqB4
+    ///
+    /// - Start bit: 32
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b4_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB4
+    #[inline(always)]
+    pub fn set_master_req_b4(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+        Ok(())
+    }
+
+    /// MasterReqB5
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b5(&self) -> u8 {
+        self.master_req_b5_raw()
+    }
+
+    /// Get raw value of MasterReqB5
+    ///
+    /// - Start bit: 40
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b5_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[40..48].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB5
+    #[inline(always)]
+    pub fn set_master_req_b5(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[40..48].store_le(value);
+        Ok(())
+    }
+
+    /// MasterReqB6
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b6(&self) -> u8 {
+        self.master_req_b6_raw()
+    }
+
+    /// Get raw value of MasterReqB6
+    ///
+    /// - Start bit: 48
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b6_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[48..56].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB6
+    #[inline(always)]
+    pub fn set_master_req_b6(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[48..56].store_le(value);
+        Ok(())
+    }
+
+    /// MasterReqB7
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn master_req_b7(&self) -> u8 {
+        self.master_req_b7_raw()
+    }
+
+    /// Get raw value of MasterReqB7
+    ///
+    /// - Start bit: 56
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn master_req_b7_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[56..64].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of MasterReqB7
+    #[inline(always)]
+    pub fn set_master_req_b7(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MasterReq::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MasterReq::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[56..64].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for MasterReq {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for MasterReq {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for MasterReq {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MasterReq")
+                .field("master_req_b0", &self.master_req_b0())
+                .field("master_req_b1", &self.master_req_b1())
+                .field("master_req_b2", &self.master_req_b2())
+                .field("master_req_b3", &self.master_req_b3())
+                .field("master_req_b4", &self.master_req_b4())
+                .field("master_req_b5", &self.master_req_b5())
+                .field("master_req_b6", &self.master_req_b6())
+                .field("master_req_b7", &self.master_req_b7())
+                .finish()
+        } else {
+            f.debug_tuple("MasterReq").field(&self.raw).finish()
+        }
+    }
+}
+
+/// SlaveResp
+///
+/// - Standard ID: 61 (0x3d)
+/// - Size: 8 bytes
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SlaveResp {
+    raw: [u8; 8],
+}
+
+impl SlaveResp {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x3d) });
+
+    pub const SLAVE_RESP_B0_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B0_MAX: u8 = 255_u8;
+    pub const SLAVE_RESP_B1_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B1_MAX: u8 = 255_u8;
+    pub const SLAVE_RESP_B2_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B2_MAX: u8 = 255_u8;
+    pub const SLAVE_RESP_B3_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B3_MAX: u8 = 255_u8;
+    pub const SLAVE_RESP_B4_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B4_MAX: u8 = 255_u8;
+    pub const SLAVE_RESP_B5_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B5_MAX: u8 = 255_u8;
+    pub const SLAVE_RESP_B6_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B6_MAX: u8 = 255_u8;
+    pub const SLAVE_RESP_B7_MIN: u8 = 0_u8;
+    pub const SLAVE_RESP_B7_MAX: u8 = 255_u8;
+
+    /// Construct new SlaveResp from values
+    pub fn new(
+        slave_resp_b0: u8,
+        slave_resp_b1: u8,
+        slave_resp_b2: u8,
+        slave_resp_b3: u8,
+        slave_resp_b4: u8,
+        slave_resp_b5: u8,
+        slave_resp_b6: u8,
+        slave_resp_b7: u8,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_slave_resp_b0(slave_resp_b0)?;
+        res.set_slave_resp_b1(slave_resp_b1)?;
+        res.set_slave_resp_b2(slave_resp_b2)?;
+        res.set_slave_resp_b3(slave_resp_b3)?;
+        res.set_slave_resp_b4(slave_resp_b4)?;
+        res.set_slave_resp_b5(slave_resp_b5)?;
+        res.set_slave_resp_b6(slave_resp_b6)?;
+        res.set_slave_resp_b7(slave_resp_b7)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// SlaveRespB0
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b0(&self) -> u8 {
+        self.slave_resp_b0_raw()
+    }
+
+    /// Get raw value of SlaveRespB0
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b0_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB0
+    #[inline(always)]
+    pub fn set_slave_resp_b0(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+        Ok(())
+    }
+
+    /// SlaveRespB1
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b1(&self) -> u8 {
+        self.slave_resp_b1_raw()
+    }
+
+    /// Get raw value of SlaveRespB1
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b1_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB1
+    #[inline(always)]
+    pub fn set_slave_resp_b1(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        Ok(())
+    }
+
+    /// SlaveRespB2
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b2(&self) -> u8 {
+        self.slave_resp_b2_raw()
+    }
+
+    /// Get raw value of SlaveRespB2
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b2_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB2
+    #[inline(always)]
+    pub fn set_slave_resp_b2(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        Ok(())
+    }
+
+    /// SlaveRespB3
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b3(&self) -> u8 {
+        self.slave_resp_b3_raw()
+    }
+
+    /// Get raw value of SlaveRespB3
+    ///
+    /// - Start bit: 24
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b3_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB3
+    #[inline(always)]
+    pub fn set_slave_resp_b3(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        Ok(())
+    }
+
+    /// SlaveRespB4
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b4(&self) -> u8 {
+        self.slave_resp_b4_raw()
+    }
+
+    /// Get raw value of SlaveRespB4
+    ///
+    /// - Start bit: 32
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b4_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB4
+    #[inline(always)]
+    pub fn set_slave_resp_b4(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+        Ok(())
+    }
+
+    /// SlaveRespB5
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b5(&self) -> u8 {
+        self.slave_resp_b5_raw()
+    }
+
+    /// Get raw value of SlaveRespB5
+    ///
+    /// - Start bit: 40
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b5_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[40..48].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB5
+    #[inline(always)]
+    pub fn set_slave_resp_b5(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[40..48].store_le(value);
+        Ok(())
+    }
+
+    /// SlaveRespB6
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b6(&self) -> u8 {
+        self.slave_resp_b6_raw()
+    }
+
+    /// Get raw value of SlaveRespB6
+    ///
+    /// - Start bit: 48
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b6_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[48..56].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB6
+    #[inline(always)]
+    pub fn set_slave_resp_b6(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[48..56].store_le(value);
+        Ok(())
+    }
+
+    /// SlaveRespB7
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn slave_resp_b7(&self) -> u8 {
+        self.slave_resp_b7_raw()
+    }
+
+    /// Get raw value of SlaveRespB7
+    ///
+    /// - Start bit: 56
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn slave_resp_b7_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[56..64].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of SlaveRespB7
+    #[inline(always)]
+    pub fn set_slave_resp_b7(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 255_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: SlaveResp::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: SlaveResp::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[56..64].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for SlaveResp {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for SlaveResp {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for SlaveResp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("SlaveResp")
+                .field("slave_resp_b0", &self.slave_resp_b0())
+                .field("slave_resp_b1", &self.slave_resp_b1())
+                .field("slave_resp_b2", &self.slave_resp_b2())
+                .field("slave_resp_b3", &self.slave_resp_b3())
+                .field("slave_resp_b4", &self.slave_resp_b4())
+                .field("slave_resp_b5", &self.slave_resp_b5())
+                .field("slave_resp_b6", &self.slave_resp_b6())
+                .field("slave_resp_b7", &self.slave_resp_b7())
+                .finish()
+        } else {
+            f.debug_tuple("SlaveResp").field(&self.raw).finish()
+        }
+    }
+}
+
+/// Checksum model of a LIN frame
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LinChecksum {
+    /// Sum over the data bytes (LIN 1.x and diagnostic frames)
+    Classic,
+    /// Sum over the protected identifier and the data bytes (LIN 2.x)
+    Enhanced,
+}
+
+/// Slot of a LIN schedule table
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LinScheduleEntry {
+    /// ID of the frame header sent in this slot, the first of `frame_ids` for sporadic frames
+    pub frame_id: u8,
+    /// Unconditional frames that may be sent in this slot, in priority order
+    pub frame_ids: &'static [u8],
+    /// Time until the next slot
+    pub delay: core::time::Duration,
+}
+
+/// LIN bus speed in bit/s
+pub const LIN_SPEED: u32 = 19200;
+
+/// Schedule table `Normal`
+pub const SCHEDULE_NORMAL: &[LinScheduleEntry] = &[
+    LinScheduleEntry {
+        frame_id: 0x10,
+        frame_ids: &[0x10],
+        delay: core::time::Duration::from_micros(10000),
+    },
+    LinScheduleEntry {
+        frame_id: 0x21,
+        frame_ids: &[0x21],
+        delay: core::time::Duration::from_micros(15000),
+    },
+];
+
+/// Schedule table `Events`
+pub const SCHEDULE_EVENTS: &[LinScheduleEntry] = &[
+    LinScheduleEntry {
+        frame_id: 0x10,
+        frame_ids: &[0x10],
+        delay: core::time::Duration::from_micros(20000),
+    },
+    LinScheduleEntry {
+        frame_id: 0x3a,
+        frame_ids: &[0x21],
+        delay: core::time::Duration::from_micros(20000),
+    },
+];
+
+/// Schedule table `Configuration`
+pub const SCHEDULE_CONFIGURATION: &[LinScheduleEntry] = &[
+    LinScheduleEntry {
+        frame_id: 0x3c,
+        frame_ids: &[0x3c],
+        delay: core::time::Duration::from_micros(20000),
+    },
+    LinScheduleEntry {
+        frame_id: 0x3c,
+        frame_ids: &[0x3c],
+        delay: core::time::Duration::from_micros(10000),
+    },
+    LinScheduleEntry {
+        frame_id: 0x3d,
+        frame_ids: &[0x3d],
+        delay: core::time::Duration::from_micros(10000),
+    },
+];
+
+impl SeatControl {
+    /// LIN frame ID
+    pub const LIN_FRAME_ID: u8 = 0x10;
+    /// LIN frame ID with its parity bits
+    pub const LIN_PROTECTED_ID: u8 = 0x50;
+    /// Checksum model of this frame
+    pub const LIN_CHECKSUM: LinChecksum = LinChecksum::Enhanced;
+}
+
+impl MirrorStatus {
+    /// LIN frame ID
+    pub const LIN_FRAME_ID: u8 = 0x21;
+    /// LIN frame ID with its parity bits
+    pub const LIN_PROTECTED_ID: u8 = 0x61;
+    /// Checksum model of this frame
+    pub const LIN_CHECKSUM: LinChecksum = LinChecksum::Enhanced;
+}
+
+impl MasterReq {
+    /// LIN frame ID
+    pub const LIN_FRAME_ID: u8 = 0x3c;
+    /// LIN frame ID with its parity bits
+    pub const LIN_PROTECTED_ID: u8 = 0x3c;
+    /// Checksum model of this frame
+    pub const LIN_CHECKSUM: LinChecksum = LinChecksum::Classic;
+}
+
+impl SlaveResp {
+    /// LIN frame ID
+    pub const LIN_FRAME_ID: u8 = 0x3d;
+    /// LIN frame ID with its parity bits
+    pub const LIN_PROTECTED_ID: u8 = 0x7d;
+    /// Checksum model of this frame
+    pub const LIN_CHECKSUM: LinChecksum = LinChecksum::Classic;
+}
+
+/// This is just to make testing easier
+#[allow(dead_code)]
+fn main() {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CanError {
+    UnknownMessageId(embedded_can::Id),
+    /// Signal parameter is not within the range
+    /// defined in the dbc
+    ParameterOutOfRange {
+        /// dbc message id
+        message_id: embedded_can::Id,
+    },
+    InvalidPayloadSize,
+    /// Multiplexor value not defined in the dbc
+    InvalidMultiplexor {
+        /// dbc message id
+        message_id: embedded_can::Id,
+        /// Multiplexor value not defined in the dbc
+        multiplexor: u16,
+    },
+    /// Value or label not defined in the value table
+    UndefinedValue,
+}
+
+impl core::fmt::Display for CanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for CanError {}
@@ -19,9 +19,14 @@ use arbitrary::{Arbitrary, Unstructured};
 use bitvec::prelude::*;
 use core::ops::BitOr;
 use embedded_can::{ExtendedId, Id, StandardId};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// All messages
-#[derive(Clone, Debug, defmt::Format)]
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
 pub enum Messages {
     /// Foo
     Foo(Foo),
@@ -35,6 +40,8 @@ pub enum Messages {
     Dolor(Dolor),
     /// MultiplexTest
     MultiplexTest(MultiplexTest),
+    /// NamedMultiplexTest
+    NamedMultiplexTest(NamedMultiplexTest),
     /// IntegerFactorOffset
     IntegerFactorOffset(IntegerFactorOffset),
     /// NegativeFactorTest
@@ -62,6 +69,9 @@ impl Messages {
             Amet::MESSAGE_ID => Messages::Amet(Amet::try_from(payload)?),
             Dolor::MESSAGE_ID => Messages::Dolor(Dolor::try_from(payload)?),
             MultiplexTest::MESSAGE_ID => Messages::MultiplexTest(MultiplexTest::try_from(payload)?),
+            NamedMultiplexTest::MESSAGE_ID => {
+                Messages::NamedMultiplexTest(NamedMultiplexTest::try_from(payload)?)
+            }
             IntegerFactorOffset::MESSAGE_ID => {
                 Messages::IntegerFactorOffset(IntegerFactorOffset::try_from(payload)?)
             }
@@ -92,7 +102,19 @@ impl Messages {
 /// - Standard ID: 256 (0x100)
 /// - Size: 4 bytes
 /// - Transmitter: Lorem
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
 pub struct Foo {
     raw: [u8; 4],
 }
@@ -115,10 +137,20 @@ impl Foo {
     }
 
     /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 4] {
+    pub const fn raw(&self) -> &[u8; 4] {
         &self.raw
     }
 
+    /// Borrow the payload as a [`FooRef`]
+    pub fn view(&self) -> FooRef<'_> {
+        FooRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`FooMut`]
+    pub fn view_mut(&mut self) -> FooMut<'_> {
+        FooMut { raw: &mut self.raw }
+    }
+
     /// Voltage
     ///
     /// - Min: 0
@@ -292,12 +324,278 @@ impl<'a> Arbitrary<'a> for Foo {
     }
 }
 
+/// Borrowed view of a Foo payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct FooRef<'a> {
+    raw: &'a [u8; 4],
+}
+
+impl<'a> FooRef<'a> {
+    /// View a payload buffer as Foo
+    pub fn from_raw(raw: &'a [u8; 4]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 4] {
+        self.raw
+    }
+
+    /// Voltage
+    ///
+    /// - Min: 0
+    /// - Max: 63.9990234375
+    /// - Unit: "V"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn voltage(&self) -> f32 {
+        self.voltage_raw()
+    }
+
+    /// Get raw value of Voltage
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 16 bits
+    /// - Factor: 0.000976562
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn voltage_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..32].load_le::<u16>();
+
+        let factor = 0.000976562_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Current
+    ///
+    /// - Min: -2048
+    /// - Max: 2047.9375
+    /// - Unit: "A"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn current(&self) -> f32 {
+        self.current_raw()
+    }
+
+    /// Get raw value of Current
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 16 bits
+    /// - Factor: 0.0625
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn current_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<i16>();
+
+        let factor = 0.0625_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for FooRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<FooRef<'_>> for Foo {
+    fn from(view: FooRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a Foo payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct FooMut<'a> {
+    raw: &'a mut [u8; 4],
+}
+
+impl<'a> FooMut<'a> {
+    /// View a payload buffer as Foo
+    pub fn from_raw(raw: &'a mut [u8; 4]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 4] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 4] {
+        self.raw
+    }
+
+    /// Voltage
+    ///
+    /// - Min: 0
+    /// - Max: 63.9990234375
+    /// - Unit: "V"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn voltage(&self) -> f32 {
+        self.voltage_raw()
+    }
+
+    /// Get raw value of Voltage
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 16 bits
+    /// - Factor: 0.000976562
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn voltage_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..32].load_le::<u16>();
+
+        let factor = 0.000976562_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of Voltage
+    #[inline(always)]
+    pub fn set_voltage(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 63.9990234375_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Foo::MESSAGE_ID,
+            });
+        }
+        let factor = 0.000976562_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..32].store_le(value);
+        Ok(())
+    }
+
+    /// Current
+    ///
+    /// - Min: -2048
+    /// - Max: 2047.9375
+    /// - Unit: "A"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn current(&self) -> f32 {
+        self.current_raw()
+    }
+
+    /// Get raw value of Current
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 16 bits
+    /// - Factor: 0.0625
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn current_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<i16>();
+
+        let factor = 0.0625_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of Current
+    #[inline(always)]
+    pub fn set_current(&mut self, value: f32) -> Result<(), CanError> {
+        if value < -2048_f32 || 2047.9375_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Foo::MESSAGE_ID,
+            });
+        }
+        let factor = 0.0625_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for FooMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<FooMut<'_>> for Foo {
+    fn from(view: FooMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Foo")]
+    struct Signals {
+        #[serde(rename = "Voltage")]
+        voltage: f32,
+        #[serde(rename = "Current")]
+        current: f32,
+    }
+
+    impl Serialize for Foo {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                voltage: self.voltage(),
+                current: self.current(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Foo {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            Foo::new(signals.voltage, signals.current).map_err(serde::de::Error::custom)
+        }
+    }
+};
+
 /// Bar
 ///
 /// - Standard ID: 512 (0x200)
 /// - Size: 8 bytes
 /// - Transmitter: Ipsum
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
 pub struct Bar {
     raw: [u8; 8],
 }
@@ -316,7 +614,13 @@ impl Bar {
     pub const FOUR_MAX: u8 = 3_u8;
 
     /// Construct new Bar from values
-    pub fn new(one: u8, two: f32, three: u8, four: u8, xtype: bool) -> Result<Self, CanError> {
+    pub fn new(
+        one: u8,
+        two: f32,
+        three: BarThree,
+        four: BarFour,
+        xtype: BarType,
+    ) -> Result<Self, CanError> {
         let mut res = Self { raw: [0u8; 8] };
         res.set_one(one)?;
         res.set_two(two)?;
@@ -326,11 +630,32 @@ impl Bar {
         Ok(res)
     }
 
+    /// Construct new Bar from raw values
+    pub fn new_raw(one: u8, two: f32, three: u8, four: u8, xtype: bool) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one(one)?;
+        res.set_two(two)?;
+        res.set_three_raw(three)?;
+        res.set_four_raw(four)?;
+        res.set_xtype_raw(xtype)?;
+        Ok(res)
+    }
+
     /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 8] {
+    pub const fn raw(&self) -> &[u8; 8] {
         &self.raw
     }
 
+    /// Borrow the payload as a [`BarRef`]
+    pub fn view(&self) -> BarRef<'_> {
+        BarRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`BarMut`]
+    pub fn view_mut(&mut self) -> BarMut<'_> {
+        BarMut { raw: &mut self.raw }
+    }
+
     /// One
     ///
     /// - Min: 0
@@ -376,6 +701,40 @@ impl Bar {
         Ok(())
     }
 
+    /// Get raw value of One in a `const` context
+    #[inline(always)]
+    pub const fn one_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[1] >> 6) & 0x03) as u8;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of One in a `const` context
+    #[inline(always)]
+    pub const fn with_one(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[1] = (raw[1] & 0x3f) | (((value as u8) << 6) & 0xc0);
+        Ok(Self { raw })
+    }
+
     /// Two
     ///
     /// - Min: 0
@@ -457,7 +816,13 @@ impl Bar {
 
     /// Set value of Three
     #[inline(always)]
-    pub fn set_three(&mut self, value: u8) -> Result<(), CanError> {
+    pub fn set_three(&mut self, value: BarThree) -> Result<(), CanError> {
+        self.set_three_raw(value.into())
+    }
+
+    /// Set raw value of Three
+    #[inline(always)]
+    pub fn set_three_raw(&mut self, value: u8) -> Result<(), CanError> {
         if value < 0_u8 || 7_u8 < value {
             return Err(CanError::ParameterOutOfRange {
                 message_id: Bar::MESSAGE_ID,
@@ -473,26 +838,59 @@ impl Bar {
         Ok(())
     }
 
-    /// Four
-    ///
-    /// - Min: 0
-    /// - Max: 3
-    /// - Unit: ""
-    /// - Receivers: Dolor
+    /// Get raw value of Three in a `const` context
     #[inline(always)]
-    pub fn four(&self) -> BarFour {
-        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+    pub const fn three_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[1] >> 3) & 0x07) as u8;
 
-        match signal {
-            0 => BarFour::Off,
-            1 => BarFour::On,
-            2 => BarFour::Oner,
-            3 => BarFour::Onest,
-            _ => BarFour::_Other(self.four_raw()),
-        }
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Get raw value of Four
+    /// Set value of Three in a `const` context
+    #[inline(always)]
+    pub const fn with_three(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 7_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[1] = (raw[1] & 0xc7) | (((value as u8) << 3) & 0x38);
+        Ok(Self { raw })
+    }
+
+    /// Four
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
+    }
+
+    /// Get raw value of Four
     ///
     /// - Start bit: 10
     /// - Signal size: 2 bits
@@ -510,7 +908,13 @@ impl Bar {
 
     /// Set value of Four
     #[inline(always)]
-    pub fn set_four(&mut self, value: u8) -> Result<(), CanError> {
+    pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+        self.set_four_raw(value.into())
+    }
+
+    /// Set raw value of Four
+    #[inline(always)]
+    pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
         if value < 0_u8 || 3_u8 < value {
             return Err(CanError::ParameterOutOfRange {
                 message_id: Bar::MESSAGE_ID,
@@ -526,6 +930,40 @@ impl Bar {
         Ok(())
     }
 
+    /// Get raw value of Four in a `const` context
+    #[inline(always)]
+    pub const fn four_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[1] >> 1) & 0x03) as u8;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Four in a `const` context
+    #[inline(always)]
+    pub const fn with_four(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Bar::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[1] = (raw[1] & 0xf9) | (((value as u8) << 1) & 0x06);
+        Ok(Self { raw })
+    }
+
     /// Type
     ///
     /// - Min: 0
@@ -538,8 +976,7 @@ impl Bar {
 
         match signal {
             0 => BarType::X0off,
-            1 => BarType::X1on,
-            _ => BarType::_Other(self.xtype_raw()),
+            _ => BarType::X1on,
         }
     }
 
@@ -560,11 +997,35 @@ impl Bar {
 
     /// Set value of Type
     #[inline(always)]
-    pub fn set_xtype(&mut self, value: bool) -> Result<(), CanError> {
+    pub fn set_xtype(&mut self, value: BarType) -> Result<(), CanError> {
+        self.set_xtype_raw(value.into())
+    }
+
+    /// Set raw value of Type
+    #[inline(always)]
+    pub fn set_xtype_raw(&mut self, value: bool) -> Result<(), CanError> {
         let value = value as u8;
         self.raw.view_bits_mut::<Msb0>()[25..26].store_be(value);
         Ok(())
     }
+
+    /// Get raw value of Type in a `const` context
+    #[inline(always)]
+    pub const fn xtype_raw_const(&self) -> bool {
+        let mut signal = 0u8;
+        signal |= ((self.raw[3] >> 6) & 0x01) as u8;
+
+        signal == 1
+    }
+
+    /// Set value of Type in a `const` context
+    #[inline(always)]
+    pub const fn with_xtype(self, value: bool) -> Result<Self, CanError> {
+        let value = value as u8;
+        let mut raw = self.raw;
+        raw[3] = (raw[3] & 0xbf) | (((value as u8) << 6) & 0x40);
+        Ok(Self { raw })
+    }
 }
 
 impl core::convert::TryFrom<&[u8]> for Bar {
@@ -655,120 +1116,104 @@ impl<'a> Arbitrary<'a> for Bar {
         let three = u.int_in_range(0..=7)?;
         let four = u.int_in_range(0..=3)?;
         let xtype = u.int_in_range(0..=1)? == 1;
-        Bar::new(one, two, three, four, xtype).map_err(|_| arbitrary::Error::IncorrectFormat)
-    }
-}
-/// Defined values for Three
-#[derive(Clone, Copy, PartialEq, Debug, defmt::Format)]
-pub enum BarThree {
-    Off,
-    On,
-    Oner,
-    Onest,
-    _Other(u8),
-}
-
-impl From<BarThree> for u8 {
-    fn from(val: BarThree) -> u8 {
-        match val {
-            BarThree::Off => 0,
-            BarThree::On => 1,
-            BarThree::Oner => 2,
-            BarThree::Onest => 3,
-            BarThree::_Other(x) => x,
-        }
+        Bar::new_raw(one, two, three, four, xtype).map_err(|_| arbitrary::Error::IncorrectFormat)
     }
 }
 
-/// Defined values for Four
-#[derive(Clone, Copy, PartialEq, Debug, defmt::Format)]
-pub enum BarFour {
-    Off,
-    On,
-    Oner,
-    Onest,
-    _Other(u8),
+/// Borrowed view of a Bar payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct BarRef<'a> {
+    raw: &'a [u8; 8],
 }
 
-impl From<BarFour> for u8 {
-    fn from(val: BarFour) -> u8 {
-        match val {
-            BarFour::Off => 0,
-            BarFour::On => 1,
-            BarFour::Oner => 2,
-            BarFour::Onest => 3,
-            BarFour::_Other(x) => x,
-        }
+impl<'a> BarRef<'a> {
+    /// View a payload buffer as Bar
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
     }
-}
-
-/// Defined values for Type
-#[derive(Clone, Copy, PartialEq, Debug, defmt::Format)]
-pub enum BarType {
-    X0off,
-    X1on,
-    _Other(bool),
-}
 
-impl From<BarType> for bool {
-    fn from(val: BarType) -> bool {
-        match val {
-            BarType::X0off => false,
-            BarType::X1on => true,
-            BarType::_Other(x) => x,
-        }
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
     }
-}
 
-/// _4WD
-///
-/// - Standard ID: 768 (0x300)
-/// - Size: 8 bytes
-/// - Transmitter: Ipsum
-#[derive(Clone, Copy)]
-pub struct X4wd {
-    raw: [u8; 8],
-}
+    /// One
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn one(&self) -> u8 {
+        self.one_raw()
+    }
 
-impl X4wd {
-    pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x300) });
+    /// Get raw value of One
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
 
-    pub const X4DRIVE_MIN: u8 = 0_u8;
-    pub const X4DRIVE_MAX: u8 = 7_u8;
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
 
-    /// Construct new _4WD from values
-    pub fn new(x4drive: u8) -> Result<Self, CanError> {
-        let mut res = Self { raw: [0u8; 8] };
-        res.set_x4drive(x4drive)?;
-        Ok(res)
+    /// Two
+    ///
+    /// - Min: 0
+    /// - Max: 100
+    /// - Unit: "%"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn two(&self) -> f32 {
+        self.two_raw()
     }
 
-    /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 8] {
-        &self.raw
+    /// Get raw value of Two
+    ///
+    /// - Start bit: 7
+    /// - Signal size: 8 bits
+    /// - Factor: 0.39
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn two_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
     }
 
-    /// _4DRIVE
+    /// Three
     ///
     /// - Min: 0
     /// - Max: 7
     /// - Unit: ""
     /// - Receivers: Dolor
     #[inline(always)]
-    pub fn x4drive(&self) -> X4wd4drive {
+    pub fn three(&self) -> BarThree {
         let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
 
         match signal {
-            0 => X4wd4drive::Off,
-            1 => X4wd4drive::X2wd,
-            2 => X4wd4drive::X4wd,
-            3 => X4wd4drive::All,
-            _ => X4wd4drive::_Other(self.x4drive_raw()),
+            0 => BarThree::Off,
+            1 => BarThree::On,
+            2 => BarThree::Oner,
+            3 => BarThree::Onest,
+            _ => BarThree::_Other(self.three_raw()),
         }
     }
 
-    /// Get raw value of _4DRIVE
+    /// Get raw value of Three
     ///
     /// - Start bit: 13
     /// - Signal size: 3 bits
@@ -777,166 +1222,118 @@ impl X4wd {
     /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn x4drive_raw(&self) -> u8 {
+    pub fn three_raw(&self) -> u8 {
         let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
 
         let factor = 1;
         u8::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Set value of _4DRIVE
+    /// Four
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
     #[inline(always)]
-    pub fn set_x4drive(&mut self, value: u8) -> Result<(), CanError> {
-        if value < 0_u8 || 7_u8 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: X4wd::MESSAGE_ID,
-            });
-        }
-        let factor = 1;
-        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: X4wd::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u8;
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
 
-        self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
-        Ok(())
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
     }
-}
-
-impl core::convert::TryFrom<&[u8]> for X4wd {
-    type Error = CanError;
 
+    /// Get raw value of Four
+    ///
+    /// - Start bit: 10
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
     #[inline(always)]
-    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
-        if payload.len() != 8 {
-            return Err(CanError::InvalidPayloadSize);
-        }
-        let mut raw = [0u8; 8];
-        raw.copy_from_slice(&payload[..8]);
-        Ok(Self { raw })
-    }
-}
+    pub fn four_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
 
-impl embedded_can::Frame for X4wd {
-    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
-        if id.into() != Self::MESSAGE_ID {
-            None
-        } else {
-            data.try_into().ok()
-        }
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
-        unimplemented!()
-    }
+    /// Type
+    ///
+    /// - Min: 0
+    /// - Max: 1
+    /// - Unit: "boolean"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn xtype(&self) -> BarType {
+        let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
 
-    fn is_extended(&self) -> bool {
-        match self.id() {
-            Id::Standard(_) => false,
-            Id::Extended(_) => true,
+        match signal {
+            0 => BarType::X0off,
+            _ => BarType::X1on,
         }
     }
 
-    fn is_remote_frame(&self) -> bool {
-        false
-    }
-
-    fn id(&self) -> Id {
-        Self::MESSAGE_ID
-    }
-
-    fn dlc(&self) -> usize {
-        self.raw.len()
-    }
+    /// Get raw value of Type
+    ///
+    /// - Start bit: 30
+    /// - Signal size: 1 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn xtype_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
 
-    fn data(&self) -> &[u8] {
-        &self.raw
-    }
-}
-impl core::fmt::Debug for X4wd {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if f.alternate() {
-            f.debug_struct("X4wd")
-                .field("x4drive", &self.x4drive())
-                .finish()
-        } else {
-            f.debug_tuple("X4wd").field(&self.raw).finish()
-        }
+        signal == 1
     }
 }
 
-impl defmt::Format for X4wd {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "X4wd {{ _4DRIVE={:?} }}", self.x4drive(),);
-    }
-}
+impl<'a> core::convert::TryFrom<&'a [u8]> for BarRef<'a> {
+    type Error = CanError;
 
-#[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for X4wd {
-    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let x4drive = u.int_in_range(0..=7)?;
-        X4wd::new(x4drive).map_err(|_| arbitrary::Error::IncorrectFormat)
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
     }
 }
-/// Defined values for _4DRIVE
-#[derive(Clone, Copy, PartialEq, Debug, defmt::Format)]
-pub enum X4wd4drive {
-    Off,
-    X2wd,
-    X4wd,
-    All,
-    _Other(u8),
-}
 
-impl From<X4wd4drive> for u8 {
-    fn from(val: X4wd4drive) -> u8 {
-        match val {
-            X4wd4drive::Off => 0,
-            X4wd4drive::X2wd => 1,
-            X4wd4drive::X4wd => 2,
-            X4wd4drive::All => 3,
-            X4wd4drive::_Other(x) => x,
-        }
+impl From<BarRef<'_>> for Bar {
+    fn from(view: BarRef<'_>) -> Self {
+        Self { raw: *view.raw }
     }
 }
 
-/// Amet
+/// Mutably borrowed view of a Bar payload
 ///
-/// - Standard ID: 1024 (0x400)
-/// - Size: 8 bytes
-/// - Transmitter: Sit
-#[derive(Clone, Copy)]
-pub struct Amet {
-    raw: [u8; 8],
+/// Accesses signals in place, without copying the payload.
+pub struct BarMut<'a> {
+    raw: &'a mut [u8; 8],
 }
 
-impl Amet {
-    pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x400) });
-
-    pub const ONE_MIN: u8 = 0_u8;
-    pub const ONE_MAX: u8 = 3_u8;
-    pub const TWO_MIN: f32 = 0_f32;
-    pub const TWO_MAX: f32 = 100_f32;
-    pub const THREE_MIN: u8 = 0_u8;
-    pub const THREE_MAX: u8 = 7_u8;
-    pub const FOUR_MIN: u8 = 0_u8;
-    pub const FOUR_MAX: u8 = 3_u8;
-
-    /// Construct new Amet from values
-    pub fn new(one: u8, two: f32, three: u8, four: u8, five: bool) -> Result<Self, CanError> {
-        let mut res = Self { raw: [0u8; 8] };
-        res.set_one(one)?;
-        res.set_two(two)?;
-        res.set_three(three)?;
-        res.set_four(four)?;
-        res.set_five(five)?;
-        Ok(res)
+impl<'a> BarMut<'a> {
+    /// View a payload buffer as Bar
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
     }
 
     /// Access message payload raw value
     pub fn raw(&self) -> &[u8; 8] {
-        &self.raw
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
     }
 
     /// One
@@ -971,12 +1368,12 @@ impl Amet {
     pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
         if value < 0_u8 || 3_u8 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: Amet::MESSAGE_ID,
+                message_id: Bar::MESSAGE_ID,
             });
         }
         let factor = 1;
         let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: Amet::MESSAGE_ID,
+            message_id: Bar::MESSAGE_ID,
         })?;
         let value = (value / factor) as u8;
 
@@ -1017,7 +1414,7 @@ impl Amet {
     pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
         if value < 0_f32 || 100_f32 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: Amet::MESSAGE_ID,
+                message_id: Bar::MESSAGE_ID,
             });
         }
         let factor = 0.39_f32;
@@ -1035,13 +1432,21 @@ impl Amet {
     /// - Unit: ""
     /// - Receivers: Dolor
     #[inline(always)]
-    pub fn three(&self) -> u8 {
-        self.three_raw()
+    pub fn three(&self) -> BarThree {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+        match signal {
+            0 => BarThree::Off,
+            1 => BarThree::On,
+            2 => BarThree::Oner,
+            3 => BarThree::Onest,
+            _ => BarThree::_Other(self.three_raw()),
+        }
     }
 
     /// Get raw value of Three
     ///
-    /// - Start bit: 20
+    /// - Start bit: 13
     /// - Signal size: 3 bits
     /// - Factor: 1
     /// - Offset: 0
@@ -1049,7 +1454,7 @@ impl Amet {
     /// - Value type: Unsigned
     #[inline(always)]
     pub fn three_raw(&self) -> u8 {
-        let signal = self.raw.view_bits::<Msb0>()[19..22].load_be::<u8>();
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
 
         let factor = 1;
         u8::from(signal).saturating_mul(factor).saturating_add(0)
@@ -1057,19 +1462,25 @@ impl Amet {
 
     /// Set value of Three
     #[inline(always)]
-    pub fn set_three(&mut self, value: u8) -> Result<(), CanError> {
+    pub fn set_three(&mut self, value: BarThree) -> Result<(), CanError> {
+        self.set_three_raw(value.into())
+    }
+
+    /// Set raw value of Three
+    #[inline(always)]
+    pub fn set_three_raw(&mut self, value: u8) -> Result<(), CanError> {
         if value < 0_u8 || 7_u8 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: Amet::MESSAGE_ID,
+                message_id: Bar::MESSAGE_ID,
             });
         }
         let factor = 1;
         let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: Amet::MESSAGE_ID,
+            message_id: Bar::MESSAGE_ID,
         })?;
         let value = (value / factor) as u8;
 
-        self.raw.view_bits_mut::<Msb0>()[19..22].store_be(value);
+        self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
         Ok(())
     }
 
@@ -1080,13 +1491,20 @@ impl Amet {
     /// - Unit: ""
     /// - Receivers: Dolor
     #[inline(always)]
-    pub fn four(&self) -> u8 {
-        self.four_raw()
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
     }
 
     /// Get raw value of Four
     ///
-    /// - Start bit: 30
+    /// - Start bit: 10
     /// - Signal size: 2 bits
     /// - Factor: 1
     /// - Offset: 0
@@ -1094,7 +1512,7 @@ impl Amet {
     /// - Value type: Unsigned
     #[inline(always)]
     pub fn four_raw(&self) -> u8 {
-        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
 
         let factor = 1;
         u8::from(signal).saturating_mul(factor).saturating_add(0)
@@ -1102,230 +1520,540 @@ impl Amet {
 
     /// Set value of Four
     #[inline(always)]
-    pub fn set_four(&mut self, value: u8) -> Result<(), CanError> {
+    pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+        self.set_four_raw(value.into())
+    }
+
+    /// Set raw value of Four
+    #[inline(always)]
+    pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
         if value < 0_u8 || 3_u8 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: Amet::MESSAGE_ID,
+                message_id: Bar::MESSAGE_ID,
             });
         }
         let factor = 1;
         let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: Amet::MESSAGE_ID,
+            message_id: Bar::MESSAGE_ID,
         })?;
         let value = (value / factor) as u8;
 
-        self.raw.view_bits_mut::<Msb0>()[25..27].store_be(value);
+        self.raw.view_bits_mut::<Msb0>()[13..15].store_be(value);
         Ok(())
     }
 
-    /// Five
+    /// Type
     ///
     /// - Min: 0
     /// - Max: 1
     /// - Unit: "boolean"
     /// - Receivers: Dolor
     #[inline(always)]
-    pub fn five(&self) -> bool {
-        self.five_raw()
+    pub fn xtype(&self) -> BarType {
+        let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
+
+        match signal {
+            0 => BarType::X0off,
+            _ => BarType::X1on,
+        }
     }
 
-    /// Get raw value of Five
+    /// Get raw value of Type
     ///
-    /// - Start bit: 40
+    /// - Start bit: 30
     /// - Signal size: 1 bits
     /// - Factor: 1
     /// - Offset: 0
     /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn five_raw(&self) -> bool {
-        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+    pub fn xtype_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
 
         signal == 1
     }
 
-    /// Set value of Five
+    /// Set value of Type
+    #[inline(always)]
+    pub fn set_xtype(&mut self, value: BarType) -> Result<(), CanError> {
+        self.set_xtype_raw(value.into())
+    }
+
+    /// Set raw value of Type
     #[inline(always)]
-    pub fn set_five(&mut self, value: bool) -> Result<(), CanError> {
+    pub fn set_xtype_raw(&mut self, value: bool) -> Result<(), CanError> {
         let value = value as u8;
-        self.raw.view_bits_mut::<Msb0>()[47..48].store_be(value);
+        self.raw.view_bits_mut::<Msb0>()[25..26].store_be(value);
         Ok(())
     }
 }
 
-impl core::convert::TryFrom<&[u8]> for Amet {
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for BarMut<'a> {
     type Error = CanError;
 
     #[inline(always)]
-    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
-        if payload.len() != 8 {
-            return Err(CanError::InvalidPayloadSize);
-        }
-        let mut raw = [0u8; 8];
-        raw.copy_from_slice(&payload[..8]);
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
         Ok(Self { raw })
     }
 }
 
-impl embedded_can::Frame for Amet {
-    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
-        if id.into() != Self::MESSAGE_ID {
-            None
-        } else {
-            data.try_into().ok()
-        }
+impl From<BarMut<'_>> for Bar {
+    fn from(view: BarMut<'_>) -> Self {
+        Self { raw: *view.raw }
     }
+}
 
-    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
-        unimplemented!()
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Bar")]
+    struct Signals {
+        #[serde(rename = "One")]
+        one: u8,
+        #[serde(rename = "Two")]
+        two: f32,
+        #[serde(rename = "Three")]
+        three: BarThree,
+        #[serde(rename = "Four")]
+        four: BarFour,
+        #[serde(rename = "Type")]
+        xtype: BarType,
+    }
+
+    impl Serialize for Bar {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                one: self.one(),
+                two: self.two(),
+                three: self.three(),
+                four: self.four(),
+                xtype: self.xtype(),
+            }
+            .serialize(serializer)
+        }
     }
 
-    fn is_extended(&self) -> bool {
-        match self.id() {
-            Id::Standard(_) => false,
-            Id::Extended(_) => true,
+    impl<'de> Deserialize<'de> for Bar {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            Bar::new(
+                signals.one,
+                signals.two,
+                signals.three,
+                signals.four,
+                signals.xtype,
+            )
+            .map_err(serde::de::Error::custom)
         }
     }
+};
 
-    fn is_remote_frame(&self) -> bool {
-        false
+/// Defined values for Three
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum BarThree {
+    #[cfg_attr(feature = "serde", serde(rename = "OFF"))]
+    Off,
+    #[cfg_attr(feature = "serde", serde(rename = "ON"))]
+    On,
+    #[cfg_attr(feature = "serde", serde(rename = "ONER"))]
+    Oner,
+    #[cfg_attr(feature = "serde", serde(rename = "ONEST"))]
+    Onest,
+    _Other(u8),
+}
+
+impl BarThree {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Off,
+            1 => Self::On,
+            2 => Self::Oner,
+            3 => Self::Onest,
+            x => Self::_Other(x),
+        }
     }
 
-    fn id(&self) -> Id {
-        Self::MESSAGE_ID
+    /// Label of this value as written in the DBC
+    ///
+    /// Returns `None` for values without a label.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Off => Some("OFF"),
+            Self::On => Some("ON"),
+            Self::Oner => Some("ONER"),
+            Self::Onest => Some("ONEST"),
+            Self::_Other(_) => None,
+        }
     }
+}
 
-    fn dlc(&self) -> usize {
-        self.raw.len()
+impl From<u8> for BarThree {
+    fn from(raw: u8) -> Self {
+        Self::from_raw(raw)
     }
+}
 
-    fn data(&self) -> &[u8] {
-        &self.raw
+impl From<BarThree> for u8 {
+    fn from(val: BarThree) -> u8 {
+        match val {
+            BarThree::Off => 0,
+            BarThree::On => 1,
+            BarThree::Oner => 2,
+            BarThree::Onest => 3,
+            BarThree::_Other(x) => x,
+        }
     }
 }
-impl core::fmt::Debug for Amet {
+
+impl core::fmt::Display for BarThree {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if f.alternate() {
-            f.debug_struct("Amet")
-                .field("one", &self.one())
-                .field("two", &self.two())
-                .field("three", &self.three())
-                .field("four", &self.four())
-                .field("five", &self.five())
-                .finish()
-        } else {
-            f.debug_tuple("Amet").field(&self.raw).finish()
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "{}", u8::from(*self)),
         }
     }
 }
 
-impl defmt::Format for Amet {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(
-            f,
-            "Amet {{ One={:?} Two={:?} Three={:?} Four={:?} Five={:?} }}",
-            self.one(),
-            self.two(),
-            self.three(),
-            self.four(),
-            self.five(),
-        );
+impl core::str::FromStr for BarThree {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == Some(s))
+            .copied()
+            .ok_or(CanError::UndefinedValue)
     }
 }
 
-#[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for Amet {
-    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let one = u.int_in_range(0..=3)?;
-        let two = u.float_in_range(0_f32..=100_f32)?;
-        let three = u.int_in_range(0..=7)?;
-        let four = u.int_in_range(0..=3)?;
-        let five = u.int_in_range(0..=1)? == 1;
-        Amet::new(one, two, three, four, five).map_err(|_| arbitrary::Error::IncorrectFormat)
+/// Defined values for Four
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum BarFour {
+    #[cfg_attr(feature = "serde", serde(rename = "Off"))]
+    Off,
+    #[cfg_attr(feature = "serde", serde(rename = "On"))]
+    On,
+    #[cfg_attr(feature = "serde", serde(rename = "Oner"))]
+    Oner,
+    #[cfg_attr(feature = "serde", serde(rename = "Onest"))]
+    Onest,
+}
+
+impl BarFour {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: u8) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Off),
+            1 => Some(Self::On),
+            2 => Some(Self::Oner),
+            3 => Some(Self::Onest),
+            _ => None,
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::On => "On",
+            Self::Oner => "Oner",
+            Self::Onest => "Onest",
+        }
     }
 }
 
-/// Dolor
+impl core::convert::TryFrom<u8> for BarFour {
+    type Error = CanError;
+
+    fn try_from(raw: u8) -> Result<Self, Self::Error> {
+        Self::from_raw(raw).ok_or(CanError::UndefinedValue)
+    }
+}
+
+impl From<BarFour> for u8 {
+    fn from(val: BarFour) -> u8 {
+        match val {
+            BarFour::Off => 0,
+            BarFour::On => 1,
+            BarFour::Oner => 2,
+            BarFour::Onest => 3,
+        }
+    }
+}
+
+impl core::fmt::Display for BarFour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for BarFour {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == s)
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// Defined values for Type
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum BarType {
+    #[cfg_attr(feature = "serde", serde(rename = "0Off"))]
+    X0off,
+    #[cfg_attr(feature = "serde", serde(rename = "1On"))]
+    X1on,
+}
+
+impl BarType {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::X0off, Self::X1on];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: bool) -> Option<Self> {
+        match raw {
+            false => Some(Self::X0off),
+            true => Some(Self::X1on),
+            _ => None,
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::X0off => "0Off",
+            Self::X1on => "1On",
+        }
+    }
+}
+
+impl core::convert::TryFrom<bool> for BarType {
+    type Error = CanError;
+
+    fn try_from(raw: bool) -> Result<Self, Self::Error> {
+        Self::from_raw(raw).ok_or(CanError::UndefinedValue)
+    }
+}
+
+impl From<BarType> for bool {
+    fn from(val: BarType) -> bool {
+        match val {
+            BarType::X0off => false,
+            BarType::X1on => true,
+        }
+    }
+}
+
+impl core::fmt::Display for BarType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for BarType {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == s)
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// _4WD
 ///
-/// - Standard ID: 1028 (0x404)
+/// - Standard ID: 768 (0x300)
 /// - Size: 8 bytes
-/// - Transmitter: Sit
-#[derive(Clone, Copy)]
-pub struct Dolor {
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct X4wd {
     raw: [u8; 8],
 }
 
-impl Dolor {
+impl X4wd {
     pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x404) });
+        Id::Standard(unsafe { StandardId::new_unchecked(0x300) });
 
-    pub const ONE_FLOAT_MIN: f32 = 0_f32;
-    pub const ONE_FLOAT_MAX: f32 = 130_f32;
+    pub const X4DRIVE_MIN: u8 = 0_u8;
+    pub const X4DRIVE_MAX: u8 = 7_u8;
 
-    /// Construct new Dolor from values
-    pub fn new(one_float: f32) -> Result<Self, CanError> {
+    /// Construct new _4WD from values
+    pub fn new(x4drive: X4wd4drive) -> Result<Self, CanError> {
         let mut res = Self { raw: [0u8; 8] };
-        res.set_one_float(one_float)?;
+        res.set_x4drive(x4drive)?;
+        Ok(res)
+    }
+
+    /// Construct new _4WD from raw values
+    pub fn new_raw(x4drive: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_x4drive_raw(x4drive)?;
         Ok(res)
     }
 
+    /// Construct new _4WD from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const(x4drive: u8) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_x4drive(x4drive) {
+            Ok(res) => res,
+            Err(_) => panic!("_4DRIVE is out of range"),
+        };
+        res
+    }
+
     /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 8] {
+    pub const fn raw(&self) -> &[u8; 8] {
         &self.raw
     }
 
-    /// OneFloat
+    /// Borrow the payload as a [`X4wdRef`]
+    pub fn view(&self) -> X4wdRef<'_> {
+        X4wdRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`X4wdMut`]
+    pub fn view_mut(&mut self) -> X4wdMut<'_> {
+        X4wdMut { raw: &mut self.raw }
+    }
+
+    /// _4DRIVE
     ///
     /// - Min: 0
-    /// - Max: 130
+    /// - Max: 7
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: Dolor
     #[inline(always)]
-    pub fn one_float(&self) -> DolorOneFloat {
-        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+    pub fn x4drive(&self) -> X4wd4drive {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
 
         match signal {
-            3 => DolorOneFloat::Dolor,
-            5 => DolorOneFloat::Other,
-            _ => DolorOneFloat::_Other(self.one_float_raw()),
+            0 => X4wd4drive::Off,
+            1 => X4wd4drive::X2wd,
+            2 => X4wd4drive::X4wd,
+            3 => X4wd4drive::All,
+            _ => X4wd4drive::_Other(self.x4drive_raw()),
         }
     }
 
-    /// Get raw value of OneFloat
+    /// Get raw value of _4DRIVE
     ///
-    /// - Start bit: 0
-    /// - Signal size: 12 bits
-    /// - Factor: 0.5
+    /// - Start bit: 13
+    /// - Signal size: 3 bits
+    /// - Factor: 1
     /// - Offset: 0
     /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn one_float_raw(&self) -> f32 {
-        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+    pub fn x4drive_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
 
-        let factor = 0.5_f32;
-        let offset = 0_f32;
-        (signal as f32) * factor + offset
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Set value of OneFloat
+    /// Set value of _4DRIVE
     #[inline(always)]
-    pub fn set_one_float(&mut self, value: f32) -> Result<(), CanError> {
-        if value < 0_f32 || 130_f32 < value {
+    pub fn set_x4drive(&mut self, value: X4wd4drive) -> Result<(), CanError> {
+        self.set_x4drive_raw(value.into())
+    }
+
+    /// Set raw value of _4DRIVE
+    #[inline(always)]
+    pub fn set_x4drive_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 7_u8 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: Dolor::MESSAGE_ID,
+                message_id: X4wd::MESSAGE_ID,
             });
         }
-        let factor = 0.5_f32;
-        let offset = 0_f32;
-        let value = ((value - offset) / factor) as u16;
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: X4wd::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
 
-        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+        self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
         Ok(())
     }
+
+    /// Get raw value of _4DRIVE in a `const` context
+    #[inline(always)]
+    pub const fn x4drive_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[1] >> 3) & 0x07) as u8;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of _4DRIVE in a `const` context
+    #[inline(always)]
+    pub const fn with_x4drive(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 7_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: X4wd::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: X4wd::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[1] = (raw[1] & 0xc7) | (((value as u8) << 3) & 0x38);
+        Ok(Self { raw })
+    }
 }
 
-impl core::convert::TryFrom<&[u8]> for Dolor {
+impl core::convert::TryFrom<&[u8]> for X4wd {
     type Error = CanError;
 
     #[inline(always)]
@@ -1339,7 +2067,7 @@ impl core::convert::TryFrom<&[u8]> for Dolor {
     }
 }
 
-impl embedded_can::Frame for Dolor {
+impl embedded_can::Frame for X4wd {
     fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
         if id.into() != Self::MESSAGE_ID {
             None
@@ -1375,761 +2103,5970 @@ impl embedded_can::Frame for Dolor {
         &self.raw
     }
 }
-impl core::fmt::Debug for Dolor {
+impl core::fmt::Debug for X4wd {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if f.alternate() {
-            f.debug_struct("Dolor")
-                .field("one_float", &self.one_float())
+            f.debug_struct("X4wd")
+                .field("x4drive", &self.x4drive())
                 .finish()
         } else {
-            f.debug_tuple("Dolor").field(&self.raw).finish()
+            f.debug_tuple("X4wd").field(&self.raw).finish()
         }
     }
 }
 
-impl defmt::Format for Dolor {
+impl defmt::Format for X4wd {
     fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "Dolor {{ OneFloat={:?} }}", self.one_float(),);
+        defmt::write!(f, "X4wd {{ _4DRIVE={:?} }}", self.x4drive(),);
     }
 }
 
 #[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for Dolor {
+impl<'a> Arbitrary<'a> for X4wd {
     fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let one_float = u.float_in_range(0_f32..=130_f32)?;
-        Dolor::new(one_float).map_err(|_| arbitrary::Error::IncorrectFormat)
-    }
-}
-/// Defined values for OneFloat
-#[derive(Clone, Copy, PartialEq, Debug, defmt::Format)]
-pub enum DolorOneFloat {
-    Dolor,
-    Other,
-    _Other(f32),
-}
-
-impl From<DolorOneFloat> for f32 {
-    fn from(val: DolorOneFloat) -> f32 {
-        match val {
-            DolorOneFloat::Dolor => 3_f32,
-            DolorOneFloat::Other => 5_f32,
-            DolorOneFloat::_Other(x) => x,
-        }
+        let x4drive = u.int_in_range(0..=7)?;
+        X4wd::new_raw(x4drive).map_err(|_| arbitrary::Error::IncorrectFormat)
     }
 }
 
-/// MultiplexTest
+/// Borrowed view of a _4WD payload
 ///
-/// - Standard ID: 200 (0xc8)
-/// - Size: 8 bytes
-/// - Transmitter: SENSOR
+/// Accesses signals in place, without copying the payload.
 #[derive(Clone, Copy)]
-pub struct MultiplexTest {
-    raw: [u8; 8],
+pub struct X4wdRef<'a> {
+    raw: &'a [u8; 8],
 }
 
-impl MultiplexTest {
-    pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0xc8) });
-
-    pub const MULTIPLEXOR_MIN: u8 = 0_u8;
-    pub const MULTIPLEXOR_MAX: u8 = 2_u8;
-    pub const UNMULTIPLEXED_SIGNAL_MIN: u8 = 0_u8;
-    pub const UNMULTIPLEXED_SIGNAL_MAX: u8 = 4_u8;
-    pub const MULTIPLEXED_SIGNAL_ZERO_A_MIN: f32 = 0_f32;
-    pub const MULTIPLEXED_SIGNAL_ZERO_A_MAX: f32 = 3_f32;
-    pub const MULTIPLEXED_SIGNAL_ZERO_B_MIN: f32 = 0_f32;
-    pub const MULTIPLEXED_SIGNAL_ZERO_B_MAX: f32 = 3_f32;
-    pub const MULTIPLEXED_SIGNAL_ONE_A_MIN: f32 = 0_f32;
-    pub const MULTIPLEXED_SIGNAL_ONE_A_MAX: f32 = 6_f32;
-    pub const MULTIPLEXED_SIGNAL_ONE_B_MIN: f32 = 0_f32;
-    pub const MULTIPLEXED_SIGNAL_ONE_B_MAX: f32 = 6_f32;
-
-    /// Construct new MultiplexTest from values
-    pub fn new(multiplexor: u8, unmultiplexed_signal: u8) -> Result<Self, CanError> {
-        let mut res = Self { raw: [0u8; 8] };
-        res.set_multiplexor(multiplexor)?;
-        res.set_unmultiplexed_signal(unmultiplexed_signal)?;
-        Ok(res)
+impl<'a> X4wdRef<'a> {
+    /// View a payload buffer as _4WD
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
     }
 
     /// Access message payload raw value
     pub fn raw(&self) -> &[u8; 8] {
-        &self.raw
+        self.raw
     }
 
-    /// Get raw value of Multiplexor
+    /// _4DRIVE
     ///
-    /// - Start bit: 0
-    /// - Signal size: 4 bits
+    /// - Min: 0
+    /// - Max: 7
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn x4drive(&self) -> X4wd4drive {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+        match signal {
+            0 => X4wd4drive::Off,
+            1 => X4wd4drive::X2wd,
+            2 => X4wd4drive::X4wd,
+            3 => X4wd4drive::All,
+            _ => X4wd4drive::_Other(self.x4drive_raw()),
+        }
+    }
+
+    /// Get raw value of _4DRIVE
+    ///
+    /// - Start bit: 13
+    /// - Signal size: 3 bits
     /// - Factor: 1
     /// - Offset: 0
-    /// - Byte order: LittleEndian
+    /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn multiplexor_raw(&self) -> u8 {
-        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+    pub fn x4drive_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
 
         let factor = 1;
         u8::from(signal).saturating_mul(factor).saturating_add(0)
     }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for X4wdRef<'a> {
+    type Error = CanError;
 
-    pub fn multiplexor(&mut self) -> Result<MultiplexTestMultiplexorIndex, CanError> {
-        match self.multiplexor_raw() {
-            0 => Ok(MultiplexTestMultiplexorIndex::M0(
-                MultiplexTestMultiplexorM0 { raw: self.raw },
-            )),
-            1 => Ok(MultiplexTestMultiplexorIndex::M1(
-                MultiplexTestMultiplexorM1 { raw: self.raw },
-            )),
-            multiplexor => Err(CanError::InvalidMultiplexor {
-                message_id: MultiplexTest::MESSAGE_ID,
-                multiplexor: multiplexor.into(),
-            }),
-        }
-    }
-    /// Set value of Multiplexor
     #[inline(always)]
-    fn set_multiplexor(&mut self, value: u8) -> Result<(), CanError> {
-        if value < 0_u8 || 2_u8 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: MultiplexTest::MESSAGE_ID,
-            });
-        }
-        let factor = 1;
-        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: MultiplexTest::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u8;
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
 
-        self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
-        Ok(())
+impl From<X4wdRef<'_>> for X4wd {
+    fn from(view: X4wdRef<'_>) -> Self {
+        Self { raw: *view.raw }
     }
+}
 
-    /// Set value of Multiplexor
-    #[inline(always)]
-    pub fn set_m0(&mut self, value: MultiplexTestMultiplexorM0) -> Result<(), CanError> {
-        let b0 = BitArray::<_, LocalBits>::new(self.raw);
-        let b1 = BitArray::<_, LocalBits>::new(value.raw);
-        self.raw = b0.bitor(b1).into_inner();
-        self.set_multiplexor(0)?;
-        Ok(())
+/// Mutably borrowed view of a _4WD payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct X4wdMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> X4wdMut<'a> {
+    /// View a payload buffer as _4WD
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
     }
 
-    /// Set value of Multiplexor
-    #[inline(always)]
-    pub fn set_m1(&mut self, value: MultiplexTestMultiplexorM1) -> Result<(), CanError> {
-        let b0 = BitArray::<_, LocalBits>::new(self.raw);
-        let b1 = BitArray::<_, LocalBits>::new(value.raw);
-        self.raw = b0.bitor(b1).into_inner();
-        self.set_multiplexor(1)?;
-        Ok(())
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
     }
 
-    /// UnmultiplexedSignal
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// _4DRIVE
     ///
     /// - Min: 0
-    /// - Max: 4
+    /// - Max: 7
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: Dolor
     #[inline(always)]
-    pub fn unmultiplexed_signal(&self) -> u8 {
-        self.unmultiplexed_signal_raw()
+    pub fn x4drive(&self) -> X4wd4drive {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+        match signal {
+            0 => X4wd4drive::Off,
+            1 => X4wd4drive::X2wd,
+            2 => X4wd4drive::X4wd,
+            3 => X4wd4drive::All,
+            _ => X4wd4drive::_Other(self.x4drive_raw()),
+        }
     }
 
-    /// Get raw value of UnmultiplexedSignal
+    /// Get raw value of _4DRIVE
     ///
-    /// - Start bit: 4
-    /// - Signal size: 8 bits
+    /// - Start bit: 13
+    /// - Signal size: 3 bits
     /// - Factor: 1
     /// - Offset: 0
-    /// - Byte order: LittleEndian
+    /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn unmultiplexed_signal_raw(&self) -> u8 {
-        let signal = self.raw.view_bits::<Lsb0>()[4..12].load_le::<u8>();
+    pub fn x4drive_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
 
         let factor = 1;
         u8::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Set value of UnmultiplexedSignal
+    /// Set value of _4DRIVE
     #[inline(always)]
-    pub fn set_unmultiplexed_signal(&mut self, value: u8) -> Result<(), CanError> {
-        if value < 0_u8 || 4_u8 < value {
+    pub fn set_x4drive(&mut self, value: X4wd4drive) -> Result<(), CanError> {
+        self.set_x4drive_raw(value.into())
+    }
+
+    /// Set raw value of _4DRIVE
+    #[inline(always)]
+    pub fn set_x4drive_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 7_u8 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: MultiplexTest::MESSAGE_ID,
+                message_id: X4wd::MESSAGE_ID,
             });
         }
         let factor = 1;
         let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: MultiplexTest::MESSAGE_ID,
+            message_id: X4wd::MESSAGE_ID,
         })?;
         let value = (value / factor) as u8;
 
-        self.raw.view_bits_mut::<Lsb0>()[4..12].store_le(value);
+        self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
         Ok(())
     }
 }
 
-impl core::convert::TryFrom<&[u8]> for MultiplexTest {
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for X4wdMut<'a> {
     type Error = CanError;
 
     #[inline(always)]
-    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
-        if payload.len() != 8 {
-            return Err(CanError::InvalidPayloadSize);
-        }
-        let mut raw = [0u8; 8];
-        raw.copy_from_slice(&payload[..8]);
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
         Ok(Self { raw })
     }
 }
 
-impl embedded_can::Frame for MultiplexTest {
-    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
-        if id.into() != Self::MESSAGE_ID {
-            None
-        } else {
-            data.try_into().ok()
-        }
+impl From<X4wdMut<'_>> for X4wd {
+    fn from(view: X4wdMut<'_>) -> Self {
+        Self { raw: *view.raw }
     }
+}
 
-    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
-        unimplemented!()
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "X4wd")]
+    struct Signals {
+        #[serde(rename = "_4DRIVE")]
+        x4drive: X4wd4drive,
     }
 
-    fn is_extended(&self) -> bool {
-        match self.id() {
-            Id::Standard(_) => false,
-            Id::Extended(_) => true,
+    impl Serialize for X4wd {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                x4drive: self.x4drive(),
+            }
+            .serialize(serializer)
         }
     }
 
-    fn is_remote_frame(&self) -> bool {
-        false
+    impl<'de> Deserialize<'de> for X4wd {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            X4wd::new(signals.x4drive).map_err(serde::de::Error::custom)
+        }
     }
+};
 
-    fn id(&self) -> Id {
-        Self::MESSAGE_ID
+/// Defined values for _4DRIVE
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum X4wd4drive {
+    #[cfg_attr(feature = "serde", serde(rename = "OFF"))]
+    Off,
+    #[cfg_attr(feature = "serde", serde(rename = "2WD"))]
+    X2wd,
+    #[cfg_attr(feature = "serde", serde(rename = "4WD"))]
+    X4wd,
+    #[cfg_attr(feature = "serde", serde(rename = "ALL"))]
+    All,
+    _Other(u8),
+}
+
+impl X4wd4drive {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Off, Self::X2wd, Self::X4wd, Self::All];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Off,
+            1 => Self::X2wd,
+            2 => Self::X4wd,
+            3 => Self::All,
+            x => Self::_Other(x),
+        }
     }
 
-    fn dlc(&self) -> usize {
-        self.raw.len()
+    /// Label of this value as written in the DBC
+    ///
+    /// Returns `None` for values without a label.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Off => Some("OFF"),
+            Self::X2wd => Some("2WD"),
+            Self::X4wd => Some("4WD"),
+            Self::All => Some("ALL"),
+            Self::_Other(_) => None,
+        }
     }
+}
 
-    fn data(&self) -> &[u8] {
-        &self.raw
+impl From<u8> for X4wd4drive {
+    fn from(raw: u8) -> Self {
+        Self::from_raw(raw)
     }
 }
-impl core::fmt::Debug for MultiplexTest {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if f.alternate() {
-            f.debug_struct("MultiplexTest")
-                .field("unmultiplexed_signal", &self.unmultiplexed_signal())
-                .finish()
-        } else {
-            f.debug_tuple("MultiplexTest").field(&self.raw).finish()
+
+impl From<X4wd4drive> for u8 {
+    fn from(val: X4wd4drive) -> u8 {
+        match val {
+            X4wd4drive::Off => 0,
+            X4wd4drive::X2wd => 1,
+            X4wd4drive::X4wd => 2,
+            X4wd4drive::All => 3,
+            X4wd4drive::_Other(x) => x,
         }
     }
 }
 
-impl defmt::Format for MultiplexTest {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(
-            f,
-            "MultiplexTest {{ UnmultiplexedSignal={:?} }}",
-            self.unmultiplexed_signal(),
-        );
+impl core::fmt::Display for X4wd4drive {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "{}", u8::from(*self)),
+        }
     }
 }
 
-#[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for MultiplexTest {
-    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let multiplexor = u.int_in_range(0..=2)?;
-        let unmultiplexed_signal = u.int_in_range(0..=4)?;
-        MultiplexTest::new(multiplexor, unmultiplexed_signal)
-            .map_err(|_| arbitrary::Error::IncorrectFormat)
+impl core::str::FromStr for X4wd4drive {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == Some(s))
+            .copied()
+            .ok_or(CanError::UndefinedValue)
     }
 }
-/// Defined values for multiplexed signal MultiplexTest
-#[derive(Debug, defmt::Format)]
-pub enum MultiplexTestMultiplexorIndex {
-    M0(MultiplexTestMultiplexorM0),
-    M1(MultiplexTestMultiplexorM1),
-}
 
-#[derive(Debug, defmt::Format, Default)]
-pub struct MultiplexTestMultiplexorM0 {
+/// Amet
+///
+/// - Standard ID: 1024 (0x400)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct Amet {
     raw: [u8; 8],
 }
 
-impl MultiplexTestMultiplexorM0 {
-    pub fn new() -> Self {
-        Self { raw: [0u8; 8] }
-    }
-    /// MultiplexedSignalZeroA
-    ///
-    /// - Min: 0
-    /// - Max: 3
-    /// - Unit: ""
-    /// - Receivers: Vector__XXX
-    #[inline(always)]
-    pub fn multiplexed_signal_zero_a(&self) -> f32 {
-        self.multiplexed_signal_zero_a_raw()
-    }
+impl Amet {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x400) });
 
-    /// Get raw value of MultiplexedSignalZeroA
-    ///
-    /// - Start bit: 12
-    /// - Signal size: 8 bits
-    /// - Factor: 0.1
-    /// - Offset: 0
-    /// - Byte order: LittleEndian
-    /// - Value type: Unsigned
-    #[inline(always)]
-    pub fn multiplexed_signal_zero_a_raw(&self) -> f32 {
-        let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+    pub const ONE_MIN: u8 = 0_u8;
+    pub const ONE_MAX: u8 = 3_u8;
+    pub const TWO_MIN: f32 = 0_f32;
+    pub const TWO_MAX: f32 = 100_f32;
+    pub const THREE_MIN: u8 = 0_u8;
+    pub const THREE_MAX: u8 = 7_u8;
+    pub const FOUR_MIN: u8 = 0_u8;
+    pub const FOUR_MAX: u8 = 3_u8;
 
-        let factor = 0.1_f32;
-        let offset = 0_f32;
-        (signal as f32) * factor + offset
+    /// Construct new Amet from values
+    pub fn new(one: u8, two: f32, three: u8, four: BarFour, five: OnOff) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one(one)?;
+        res.set_two(two)?;
+        res.set_three(three)?;
+        res.set_four(four)?;
+        res.set_five(five)?;
+        Ok(res)
     }
 
-    /// Set value of MultiplexedSignalZeroA
-    #[inline(always)]
-    pub fn set_multiplexed_signal_zero_a(&mut self, value: f32) -> Result<(), CanError> {
-        if value < 0_f32 || 3_f32 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: MultiplexTest::MESSAGE_ID,
-            });
-        }
-        let factor = 0.1_f32;
-        let offset = 0_f32;
-        let value = ((value - offset) / factor) as u8;
+    /// Construct new Amet from raw values
+    pub fn new_raw(one: u8, two: f32, three: u8, four: u8, five: bool) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one(one)?;
+        res.set_two(two)?;
+        res.set_three(three)?;
+        res.set_four_raw(four)?;
+        res.set_five_raw(five)?;
+        Ok(res)
+    }
 
-        self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
-        Ok(())
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
     }
 
-    /// MultiplexedSignalZeroB
+    /// Borrow the payload as a [`AmetRef`]
+    pub fn view(&self) -> AmetRef<'_> {
+        AmetRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`AmetMut`]
+    pub fn view_mut(&mut self) -> AmetMut<'_> {
+        AmetMut { raw: &mut self.raw }
+    }
+
+    /// One
     ///
     /// - Min: 0
     /// - Max: 3
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: Dolor
     #[inline(always)]
-    pub fn multiplexed_signal_zero_b(&self) -> f32 {
-        self.multiplexed_signal_zero_b_raw()
+    pub fn one(&self) -> u8 {
+        self.one_raw()
     }
 
-    /// Get raw value of MultiplexedSignalZeroB
+    /// Get raw value of One
     ///
-    /// - Start bit: 20
-    /// - Signal size: 8 bits
-    /// - Factor: 0.1
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
     /// - Offset: 0
-    /// - Byte order: LittleEndian
+    /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn multiplexed_signal_zero_b_raw(&self) -> f32 {
-        let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+    pub fn one_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
 
-        let factor = 0.1_f32;
-        let offset = 0_f32;
-        (signal as f32) * factor + offset
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Set value of MultiplexedSignalZeroB
+    /// Set value of One
     #[inline(always)]
-    pub fn set_multiplexed_signal_zero_b(&mut self, value: f32) -> Result<(), CanError> {
-        if value < 0_f32 || 3_f32 < value {
+    pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: MultiplexTest::MESSAGE_ID,
+                message_id: Amet::MESSAGE_ID,
             });
         }
-        let factor = 0.1_f32;
-        let offset = 0_f32;
-        let value = ((value - offset) / factor) as u8;
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
 
-        self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+        self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
         Ok(())
     }
-}
 
-#[derive(Debug, defmt::Format, Default)]
-pub struct MultiplexTestMultiplexorM1 {
-    raw: [u8; 8],
-}
+    /// Get raw value of One in a `const` context
+    #[inline(always)]
+    pub const fn one_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[1] >> 6) & 0x03) as u8;
 
-impl MultiplexTestMultiplexorM1 {
-    pub fn new() -> Self {
-        Self { raw: [0u8; 8] }
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
     }
-    /// MultiplexedSignalOneA
+
+    /// Set value of One in a `const` context
+    #[inline(always)]
+    pub const fn with_one(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[1] = (raw[1] & 0x3f) | (((value as u8) << 6) & 0xc0);
+        Ok(Self { raw })
+    }
+
+    /// Two
     ///
     /// - Min: 0
-    /// - Max: 6
-    /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Max: 100
+    /// - Unit: "%"
+    /// - Receivers: Dolor
     #[inline(always)]
-    pub fn multiplexed_signal_one_a(&self) -> f32 {
-        self.multiplexed_signal_one_a_raw()
+    pub fn two(&self) -> f32 {
+        self.two_raw()
     }
 
-    /// Get raw value of MultiplexedSignalOneA
+    /// Get raw value of Two
     ///
-    /// - Start bit: 12
+    /// - Start bit: 7
     /// - Signal size: 8 bits
-    /// - Factor: 0.1
+    /// - Factor: 0.39
     /// - Offset: 0
-    /// - Byte order: LittleEndian
+    /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn multiplexed_signal_one_a_raw(&self) -> f32 {
-        let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+    pub fn two_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
 
-        let factor = 0.1_f32;
+        let factor = 0.39_f32;
         let offset = 0_f32;
         (signal as f32) * factor + offset
     }
 
-    /// Set value of MultiplexedSignalOneA
+    /// Set value of Two
     #[inline(always)]
-    pub fn set_multiplexed_signal_one_a(&mut self, value: f32) -> Result<(), CanError> {
-        if value < 0_f32 || 6_f32 < value {
+    pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 100_f32 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: MultiplexTest::MESSAGE_ID,
+                message_id: Amet::MESSAGE_ID,
             });
         }
-        let factor = 0.1_f32;
+        let factor = 0.39_f32;
         let offset = 0_f32;
         let value = ((value - offset) / factor) as u8;
 
-        self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+        self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
         Ok(())
     }
 
-    /// MultiplexedSignalOneB
+    /// Three
     ///
     /// - Min: 0
-    /// - Max: 6
+    /// - Max: 7
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: Dolor
     #[inline(always)]
-    pub fn multiplexed_signal_one_b(&self) -> f32 {
-        self.multiplexed_signal_one_b_raw()
+    pub fn three(&self) -> u8 {
+        self.three_raw()
     }
 
-    /// Get raw value of MultiplexedSignalOneB
+    /// Get raw value of Three
     ///
     /// - Start bit: 20
-    /// - Signal size: 8 bits
-    /// - Factor: 0.1
+    /// - Signal size: 3 bits
+    /// - Factor: 1
     /// - Offset: 0
-    /// - Byte order: LittleEndian
+    /// - Byte order: BigEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn multiplexed_signal_one_b_raw(&self) -> f32 {
-        let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+    pub fn three_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[19..22].load_be::<u8>();
 
-        let factor = 0.1_f32;
-        let offset = 0_f32;
-        (signal as f32) * factor + offset
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Set value of MultiplexedSignalOneB
+    /// Set value of Three
     #[inline(always)]
-    pub fn set_multiplexed_signal_one_b(&mut self, value: f32) -> Result<(), CanError> {
-        if value < 0_f32 || 6_f32 < value {
+    pub fn set_three(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 7_u8 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: MultiplexTest::MESSAGE_ID,
+                message_id: Amet::MESSAGE_ID,
             });
         }
-        let factor = 0.1_f32;
-        let offset = 0_f32;
-        let value = ((value - offset) / factor) as u8;
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
 
-        self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+        self.raw.view_bits_mut::<Msb0>()[19..22].store_be(value);
         Ok(())
     }
-}
-
-/// IntegerFactorOffset
-///
-/// - Standard ID: 1337 (0x539)
-/// - Size: 8 bytes
-/// - Transmitter: Sit
-#[derive(Clone, Copy)]
-pub struct IntegerFactorOffset {
-    raw: [u8; 8],
-}
-
-impl IntegerFactorOffset {
-    pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x539) });
-
-    pub const BYTE_WITH_OFFSET_MIN: u16 = 1_u16;
-    pub const BYTE_WITH_OFFSET_MAX: u16 = 256_u16;
-    pub const BYTE_WITH_FACTOR_MIN: u16 = 0_u16;
-    pub const BYTE_WITH_FACTOR_MAX: u16 = 1020_u16;
-    pub const BYTE_WITH_BOTH_MIN: u16 = 16_u16;
-    pub const BYTE_WITH_BOTH_MAX: u16 = 526_u16;
-    pub const BYTE_WITH_NEGATIVE_OFFSET_MIN: i16 = 0_i16;
-    pub const BYTE_WITH_NEGATIVE_OFFSET_MAX: i16 = 255_i16;
-    pub const BYTE_WITH_NEGATIVE_MIN_MIN: i16 = -127_i16;
-    pub const BYTE_WITH_NEGATIVE_MIN_MAX: i16 = 127_i16;
 
-    /// Construct new IntegerFactorOffset from values
-    pub fn new(
-        byte_with_offset: u16,
-        byte_with_factor: u16,
-        byte_with_both: u16,
-        byte_with_negative_offset: i16,
-        byte_with_negative_min: i16,
-    ) -> Result<Self, CanError> {
-        let mut res = Self { raw: [0u8; 8] };
-        res.set_byte_with_offset(byte_with_offset)?;
-        res.set_byte_with_factor(byte_with_factor)?;
-        res.set_byte_with_both(byte_with_both)?;
-        res.set_byte_with_negative_offset(byte_with_negative_offset)?;
-        res.set_byte_with_negative_min(byte_with_negative_min)?;
-        Ok(res)
-    }
+    /// Get raw value of Three in a `const` context
+    #[inline(always)]
+    pub const fn three_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[2] >> 2) & 0x07) as u8;
 
-    /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 8] {
-        &self.raw
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
     }
 
-    /// ByteWithOffset
-    ///
+    /// Set value of Three in a `const` context
+    #[inline(always)]
+    pub const fn with_three(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 7_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[2] = (raw[2] & 0xe3) | (((value as u8) << 2) & 0x1c);
+        Ok(Self { raw })
+    }
+
+    /// Four
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
+    }
+
+    /// Get raw value of Four
+    ///
+    /// - Start bit: 30
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn four_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Four
+    #[inline(always)]
+    pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+        self.set_four_raw(value.into())
+    }
+
+    /// Set raw value of Four
+    #[inline(always)]
+    pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[25..27].store_be(value);
+        Ok(())
+    }
+
+    /// Get raw value of Four in a `const` context
+    #[inline(always)]
+    pub const fn four_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[3] >> 5) & 0x03) as u8;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Four in a `const` context
+    #[inline(always)]
+    pub const fn with_four(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: Amet::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[3] = (raw[3] & 0x9f) | (((value as u8) << 5) & 0x60);
+        Ok(Self { raw })
+    }
+
+    /// Five
+    ///
+    /// - Min: 0
+    /// - Max: 1
+    /// - Unit: "boolean"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn five(&self) -> OnOff {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        match signal {
+            1 => OnOff::On,
+            _ => OnOff::Off,
+        }
+    }
+
+    /// Get raw value of Five
+    ///
+    /// - Start bit: 40
+    /// - Signal size: 1 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn five_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        signal == 1
+    }
+
+    /// Set value of Five
+    #[inline(always)]
+    pub fn set_five(&mut self, value: OnOff) -> Result<(), CanError> {
+        self.set_five_raw(value.into())
+    }
+
+    /// Set raw value of Five
+    #[inline(always)]
+    pub fn set_five_raw(&mut self, value: bool) -> Result<(), CanError> {
+        let value = value as u8;
+        self.raw.view_bits_mut::<Msb0>()[47..48].store_be(value);
+        Ok(())
+    }
+
+    /// Get raw value of Five in a `const` context
+    #[inline(always)]
+    pub const fn five_raw_const(&self) -> bool {
+        let mut signal = 0u8;
+        signal |= (self.raw[5] & 0x01) as u8;
+
+        signal == 1
+    }
+
+    /// Set value of Five in a `const` context
+    #[inline(always)]
+    pub const fn with_five(self, value: bool) -> Result<Self, CanError> {
+        let value = value as u8;
+        let mut raw = self.raw;
+        raw[5] = (raw[5] & 0xfe) | ((value as u8) & 0x01);
+        Ok(Self { raw })
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for Amet {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for Amet {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for Amet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Amet")
+                .field("one", &self.one())
+                .field("two", &self.two())
+                .field("three", &self.three())
+                .field("four", &self.four())
+                .field("five", &self.five())
+                .finish()
+        } else {
+            f.debug_tuple("Amet").field(&self.raw).finish()
+        }
+    }
+}
+
+impl defmt::Format for Amet {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Amet {{ One={:?} Two={:?} Three={:?} Four={:?} Five={:?} }}",
+            self.one(),
+            self.two(),
+            self.three(),
+            self.four(),
+            self.five(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for Amet {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let one = u.int_in_range(0..=3)?;
+        let two = u.float_in_range(0_f32..=100_f32)?;
+        let three = u.int_in_range(0..=7)?;
+        let four = u.int_in_range(0..=3)?;
+        let five = u.int_in_range(0..=1)? == 1;
+        Amet::new_raw(one, two, three, four, five).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Borrowed view of a Amet payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct AmetRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> AmetRef<'a> {
+    /// View a payload buffer as Amet
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// One
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn one(&self) -> u8 {
+        self.one_raw()
+    }
+
+    /// Get raw value of One
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Two
+    ///
+    /// - Min: 0
+    /// - Max: 100
+    /// - Unit: "%"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn two(&self) -> f32 {
+        self.two_raw()
+    }
+
+    /// Get raw value of Two
+    ///
+    /// - Start bit: 7
+    /// - Signal size: 8 bits
+    /// - Factor: 0.39
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn two_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Three
+    ///
+    /// - Min: 0
+    /// - Max: 7
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn three(&self) -> u8 {
+        self.three_raw()
+    }
+
+    /// Get raw value of Three
+    ///
+    /// - Start bit: 20
+    /// - Signal size: 3 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn three_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[19..22].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Four
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
+    }
+
+    /// Get raw value of Four
+    ///
+    /// - Start bit: 30
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn four_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Five
+    ///
+    /// - Min: 0
+    /// - Max: 1
+    /// - Unit: "boolean"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn five(&self) -> OnOff {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        match signal {
+            1 => OnOff::On,
+            _ => OnOff::Off,
+        }
+    }
+
+    /// Get raw value of Five
+    ///
+    /// - Start bit: 40
+    /// - Signal size: 1 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn five_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        signal == 1
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for AmetRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<AmetRef<'_>> for Amet {
+    fn from(view: AmetRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a Amet payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct AmetMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> AmetMut<'a> {
+    /// View a payload buffer as Amet
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// One
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn one(&self) -> u8 {
+        self.one_raw()
+    }
+
+    /// Get raw value of One
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of One
+    #[inline(always)]
+    pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+        Ok(())
+    }
+
+    /// Two
+    ///
+    /// - Min: 0
+    /// - Max: 100
+    /// - Unit: "%"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn two(&self) -> f32 {
+        self.two_raw()
+    }
+
+    /// Get raw value of Two
+    ///
+    /// - Start bit: 7
+    /// - Signal size: 8 bits
+    /// - Factor: 0.39
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn two_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of Two
+    #[inline(always)]
+    pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 100_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
+        Ok(())
+    }
+
+    /// Three
+    ///
+    /// - Min: 0
+    /// - Max: 7
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn three(&self) -> u8 {
+        self.three_raw()
+    }
+
+    /// Get raw value of Three
+    ///
+    /// - Start bit: 20
+    /// - Signal size: 3 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn three_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[19..22].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Three
+    #[inline(always)]
+    pub fn set_three(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 7_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[19..22].store_be(value);
+        Ok(())
+    }
+
+    /// Four
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
+    }
+
+    /// Get raw value of Four
+    ///
+    /// - Start bit: 30
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn four_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Four
+    #[inline(always)]
+    pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+        self.set_four_raw(value.into())
+    }
+
+    /// Set raw value of Four
+    #[inline(always)]
+    pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[25..27].store_be(value);
+        Ok(())
+    }
+
+    /// Five
+    ///
+    /// - Min: 0
+    /// - Max: 1
+    /// - Unit: "boolean"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn five(&self) -> OnOff {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        match signal {
+            1 => OnOff::On,
+            _ => OnOff::Off,
+        }
+    }
+
+    /// Get raw value of Five
+    ///
+    /// - Start bit: 40
+    /// - Signal size: 1 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn five_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        signal == 1
+    }
+
+    /// Set value of Five
+    #[inline(always)]
+    pub fn set_five(&mut self, value: OnOff) -> Result<(), CanError> {
+        self.set_five_raw(value.into())
+    }
+
+    /// Set raw value of Five
+    #[inline(always)]
+    pub fn set_five_raw(&mut self, value: bool) -> Result<(), CanError> {
+        let value = value as u8;
+        self.raw.view_bits_mut::<Msb0>()[47..48].store_be(value);
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for AmetMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<AmetMut<'_>> for Amet {
+    fn from(view: AmetMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Amet")]
+    struct Signals {
+        #[serde(rename = "One")]
+        one: u8,
+        #[serde(rename = "Two")]
+        two: f32,
+        #[serde(rename = "Three")]
+        three: u8,
+        #[serde(rename = "Four")]
+        four: BarFour,
+        #[serde(rename = "Five")]
+        five: OnOff,
+    }
+
+    impl Serialize for Amet {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                one: self.one(),
+                two: self.two(),
+                three: self.three(),
+                four: self.four(),
+                five: self.five(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Amet {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            Amet::new(
+                signals.one,
+                signals.two,
+                signals.three,
+                signals.four,
+                signals.five,
+            )
+            .map_err(serde::de::Error::custom)
+        }
+    }
+};
+
+/// Dolor
+///
+/// - Standard ID: 1028 (0x404)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct Dolor {
+    raw: [u8; 8],
+}
+
+impl Dolor {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x404) });
+
+    pub const ONE_FLOAT_MIN: f32 = 0_f32;
+    pub const ONE_FLOAT_MAX: f32 = 130_f32;
+
+    /// Construct new Dolor from values
+    pub fn new(one_float: DolorOneFloat) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one_float(one_float)?;
+        Ok(res)
+    }
+
+    /// Construct new Dolor from raw values
+    pub fn new_raw(one_float: f32) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one_float_raw(one_float)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Borrow the payload as a [`DolorRef`]
+    pub fn view(&self) -> DolorRef<'_> {
+        DolorRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`DolorMut`]
+    pub fn view_mut(&mut self) -> DolorMut<'_> {
+        DolorMut { raw: &mut self.raw }
+    }
+
+    /// OneFloat
+    ///
+    /// - Min: 0
+    /// - Max: 130
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn one_float(&self) -> DolorOneFloat {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        match signal {
+            3 => DolorOneFloat::Dolor,
+            5 => DolorOneFloat::Other,
+            _ => DolorOneFloat::_Other(self.one_float_raw()),
+        }
+    }
+
+    /// Get raw value of OneFloat
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 0.5
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_float_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of OneFloat
+    #[inline(always)]
+    pub fn set_one_float(&mut self, value: DolorOneFloat) -> Result<(), CanError> {
+        self.set_one_float_raw(value.into())
+    }
+
+    /// Set raw value of OneFloat
+    #[inline(always)]
+    pub fn set_one_float_raw(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 130_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Dolor::MESSAGE_ID,
+            });
+        }
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u16;
+
+        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for Dolor {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for Dolor {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for Dolor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Dolor")
+                .field("one_float", &self.one_float())
+                .finish()
+        } else {
+            f.debug_tuple("Dolor").field(&self.raw).finish()
+        }
+    }
+}
+
+impl defmt::Format for Dolor {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "Dolor {{ OneFloat={:?} }}", self.one_float(),);
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for Dolor {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let one_float = u.float_in_range(0_f32..=130_f32)?;
+        Dolor::new_raw(one_float).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Borrowed view of a Dolor payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct DolorRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> DolorRef<'a> {
+    /// View a payload buffer as Dolor
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// OneFloat
+    ///
+    /// - Min: 0
+    /// - Max: 130
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn one_float(&self) -> DolorOneFloat {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        match signal {
+            3 => DolorOneFloat::Dolor,
+            5 => DolorOneFloat::Other,
+            _ => DolorOneFloat::_Other(self.one_float_raw()),
+        }
+    }
+
+    /// Get raw value of OneFloat
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 0.5
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_float_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for DolorRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<DolorRef<'_>> for Dolor {
+    fn from(view: DolorRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a Dolor payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct DolorMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> DolorMut<'a> {
+    /// View a payload buffer as Dolor
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// OneFloat
+    ///
+    /// - Min: 0
+    /// - Max: 130
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn one_float(&self) -> DolorOneFloat {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        match signal {
+            3 => DolorOneFloat::Dolor,
+            5 => DolorOneFloat::Other,
+            _ => DolorOneFloat::_Other(self.one_float_raw()),
+        }
+    }
+
+    /// Get raw value of OneFloat
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 0.5
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_float_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of OneFloat
+    #[inline(always)]
+    pub fn set_one_float(&mut self, value: DolorOneFloat) -> Result<(), CanError> {
+        self.set_one_float_raw(value.into())
+    }
+
+    /// Set raw value of OneFloat
+    #[inline(always)]
+    pub fn set_one_float_raw(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 130_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Dolor::MESSAGE_ID,
+            });
+        }
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u16;
+
+        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for DolorMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<DolorMut<'_>> for Dolor {
+    fn from(view: DolorMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Dolor")]
+    struct Signals {
+        #[serde(rename = "OneFloat")]
+        one_float: DolorOneFloat,
+    }
+
+    impl Serialize for Dolor {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                one_float: self.one_float(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Dolor {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            Dolor::new(signals.one_float).map_err(serde::de::Error::custom)
+        }
+    }
+};
+
+/// Defined values for OneFloat
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum DolorOneFloat {
+    #[cfg_attr(feature = "serde", serde(rename = "Dolor"))]
+    Dolor,
+    #[cfg_attr(feature = "serde", serde(rename = "Other"))]
+    Other,
+    _Other(f32),
+}
+
+impl DolorOneFloat {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Dolor, Self::Other];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: f32) -> Self {
+        match raw {
+            3_f32 => Self::Dolor,
+            5_f32 => Self::Other,
+            x => Self::_Other(x),
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    ///
+    /// Returns `None` for values without a label.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Dolor => Some("Dolor"),
+            Self::Other => Some("Other"),
+            Self::_Other(_) => None,
+        }
+    }
+}
+
+impl From<f32> for DolorOneFloat {
+    fn from(raw: f32) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<DolorOneFloat> for f32 {
+    fn from(val: DolorOneFloat) -> f32 {
+        match val {
+            DolorOneFloat::Dolor => 3_f32,
+            DolorOneFloat::Other => 5_f32,
+            DolorOneFloat::_Other(x) => x,
+        }
+    }
+}
+
+impl core::fmt::Display for DolorOneFloat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "{}", f32::from(*self)),
+        }
+    }
+}
+
+impl core::str::FromStr for DolorOneFloat {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == Some(s))
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// MultiplexTest
+///
+/// - Standard ID: 200 (0xc8)
+/// - Size: 8 bytes
+/// - Transmitter: SENSOR
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct MultiplexTest {
+    raw: [u8; 8],
+}
+
+impl MultiplexTest {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0xc8) });
+
+    pub const MULTIPLEXOR_MIN: u8 = 0_u8;
+    pub const MULTIPLEXOR_MAX: u8 = 2_u8;
+    pub const UNMULTIPLEXED_SIGNAL_MIN: u8 = 0_u8;
+    pub const UNMULTIPLEXED_SIGNAL_MAX: u8 = 4_u8;
+    pub const MULTIPLEXED_SIGNAL_ZERO_A_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ZERO_A_MAX: f32 = 3_f32;
+    pub const MULTIPLEXED_SIGNAL_ZERO_B_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ZERO_B_MAX: f32 = 3_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_A_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_A_MAX: f32 = 6_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_B_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_B_MAX: f32 = 6_f32;
+
+    /// Construct new MultiplexTest from values
+    pub fn new(multiplexor: u8, unmultiplexed_signal: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_multiplexor(multiplexor)?;
+        res.set_unmultiplexed_signal(unmultiplexed_signal)?;
+        Ok(res)
+    }
+
+    /// Construct new MultiplexTest from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const(multiplexor: u8, unmultiplexed_signal: u8) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_multiplexor(multiplexor) {
+            Ok(res) => res,
+            Err(_) => panic!("Multiplexor is out of range"),
+        };
+        let res = match res.with_unmultiplexed_signal(unmultiplexed_signal) {
+            Ok(res) => res,
+            Err(_) => panic!("UnmultiplexedSignal is out of range"),
+        };
+        res
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Borrow the payload as a [`MultiplexTestRef`]
+    pub fn view(&self) -> MultiplexTestRef<'_> {
+        MultiplexTestRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`MultiplexTestMut`]
+    pub fn view_mut(&mut self) -> MultiplexTestMut<'_> {
+        MultiplexTestMut { raw: &mut self.raw }
+    }
+
+    /// Get raw value of Multiplexor
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexor_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn multiplexor(&self) -> Result<MultiplexTestMultiplexorIndex, CanError> {
+        match self.multiplexor_raw() {
+            0 => Ok(MultiplexTestMultiplexorIndex::M0(
+                MultiplexTestMultiplexorM0 { raw: self.raw },
+            )),
+            1 => Ok(MultiplexTestMultiplexorIndex::M1(
+                MultiplexTestMultiplexorM1 { raw: self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: MultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+    /// Set value of Multiplexor
+    #[inline(always)]
+    fn set_multiplexor(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of Multiplexor in a `const` context
+    #[inline(always)]
+    pub const fn multiplexor_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= (self.raw[0] & 0x0f) as u8;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Multiplexor in a `const` context
+    #[inline(always)]
+    const fn with_multiplexor(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[0] = (raw[0] & 0xf0) | ((value as u8) & 0x0f);
+        Ok(Self { raw })
+    }
+
+    /// Set value of Multiplexor
+    #[inline(always)]
+    pub fn set_m0(&mut self, value: MultiplexTestMultiplexorM0) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_multiplexor(0)?;
+        Ok(())
+    }
+
+    /// Set value of Multiplexor
+    #[inline(always)]
+    pub fn set_m1(&mut self, value: MultiplexTestMultiplexorM1) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_multiplexor(1)?;
+        Ok(())
+    }
+
+    /// UnmultiplexedSignal
+    ///
+    /// - Min: 0
+    /// - Max: 4
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unmultiplexed_signal(&self) -> u8 {
+        self.unmultiplexed_signal_raw()
+    }
+
+    /// Get raw value of UnmultiplexedSignal
+    ///
+    /// - Start bit: 4
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unmultiplexed_signal_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[4..12].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnmultiplexedSignal
+    #[inline(always)]
+    pub fn set_unmultiplexed_signal(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 4_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[4..12].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of UnmultiplexedSignal in a `const` context
+    #[inline(always)]
+    pub const fn unmultiplexed_signal_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[0] >> 4) & 0x0f) as u8;
+        signal |= ((self.raw[1] & 0x0f) as u8) << 4;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnmultiplexedSignal in a `const` context
+    #[inline(always)]
+    pub const fn with_unmultiplexed_signal(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 4_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MultiplexTest::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[0] = (raw[0] & 0x0f) | (((value as u8) << 4) & 0xf0);
+        raw[1] = (raw[1] & 0xf0) | (((value >> 4) as u8) & 0x0f);
+        Ok(Self { raw })
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for MultiplexTest {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for MultiplexTest {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for MultiplexTest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            let mut d = f.debug_struct("MultiplexTest");
+            match self.multiplexor() {
+                Ok(multiplexor) => d.field("multiplexor", &multiplexor),
+                Err(_) => d.field("multiplexor", &self.multiplexor_raw()),
+            };
+            d.field("unmultiplexed_signal", &self.unmultiplexed_signal());
+            d.finish()
+        } else {
+            f.debug_tuple("MultiplexTest").field(&self.raw).finish()
+        }
+    }
+}
+
+impl defmt::Format for MultiplexTest {
+    fn format(&self, f: defmt::Formatter) {
+        match self.multiplexor() {
+            Ok(multiplexor) => {
+                defmt::write!(
+                    f,
+                    "MultiplexTest {{ Multiplexor={:?} UnmultiplexedSignal={:?} }}",
+                    multiplexor,
+                    self.unmultiplexed_signal(),
+                );
+            }
+            Err(_) => {
+                defmt::write!(
+                    f,
+                    "MultiplexTest {{ Multiplexor={:?} UnmultiplexedSignal={:?} }}",
+                    self.multiplexor_raw(),
+                    self.unmultiplexed_signal(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MultiplexTest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let multiplexor = u.int_in_range(0..=2)?;
+        let unmultiplexed_signal = u.int_in_range(0..=4)?;
+        let mut msg = MultiplexTest::new(multiplexor, unmultiplexed_signal)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        match u.int_in_range(0..=1_usize)? {
+            0 => msg.set_m0(u.arbitrary()?),
+            _ => msg.set_m1(u.arbitrary()?),
+        }
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+/// Borrowed view of a MultiplexTest payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct MultiplexTestRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> MultiplexTestRef<'a> {
+    /// View a payload buffer as MultiplexTest
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Get raw value of Multiplexor
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexor_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn multiplexor(&self) -> Result<MultiplexTestMultiplexorIndex, CanError> {
+        match self.multiplexor_raw() {
+            0 => Ok(MultiplexTestMultiplexorIndex::M0(
+                MultiplexTestMultiplexorM0 { raw: *self.raw },
+            )),
+            1 => Ok(MultiplexTestMultiplexorIndex::M1(
+                MultiplexTestMultiplexorM1 { raw: *self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: MultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+    /// UnmultiplexedSignal
+    ///
+    /// - Min: 0
+    /// - Max: 4
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unmultiplexed_signal(&self) -> u8 {
+        self.unmultiplexed_signal_raw()
+    }
+
+    /// Get raw value of UnmultiplexedSignal
+    ///
+    /// - Start bit: 4
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unmultiplexed_signal_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[4..12].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for MultiplexTestRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<MultiplexTestRef<'_>> for MultiplexTest {
+    fn from(view: MultiplexTestRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a MultiplexTest payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct MultiplexTestMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> MultiplexTestMut<'a> {
+    /// View a payload buffer as MultiplexTest
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// Get raw value of Multiplexor
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexor_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn multiplexor(&self) -> Result<MultiplexTestMultiplexorIndex, CanError> {
+        match self.multiplexor_raw() {
+            0 => Ok(MultiplexTestMultiplexorIndex::M0(
+                MultiplexTestMultiplexorM0 { raw: *self.raw },
+            )),
+            1 => Ok(MultiplexTestMultiplexorIndex::M1(
+                MultiplexTestMultiplexorM1 { raw: *self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: MultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+    /// Set value of Multiplexor
+    #[inline(always)]
+    fn set_multiplexor(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+        Ok(())
+    }
+
+    /// Set value of Multiplexor
+    #[inline(always)]
+    pub fn set_m0(&mut self, value: MultiplexTestMultiplexorM0) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(*self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        *self.raw = b0.bitor(b1).into_inner();
+        self.set_multiplexor(0)?;
+        Ok(())
+    }
+
+    /// Set value of Multiplexor
+    #[inline(always)]
+    pub fn set_m1(&mut self, value: MultiplexTestMultiplexorM1) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(*self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        *self.raw = b0.bitor(b1).into_inner();
+        self.set_multiplexor(1)?;
+        Ok(())
+    }
+
+    /// UnmultiplexedSignal
+    ///
+    /// - Min: 0
+    /// - Max: 4
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unmultiplexed_signal(&self) -> u8 {
+        self.unmultiplexed_signal_raw()
+    }
+
+    /// Get raw value of UnmultiplexedSignal
+    ///
+    /// - Start bit: 4
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unmultiplexed_signal_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[4..12].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnmultiplexedSignal
+    #[inline(always)]
+    pub fn set_unmultiplexed_signal(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 4_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[4..12].store_le(value);
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for MultiplexTestMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<MultiplexTestMut<'_>> for MultiplexTest {
+    fn from(view: MultiplexTestMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "MultiplexTest")]
+    struct Signals {
+        #[serde(rename = "Multiplexor")]
+        multiplexor: MultiplexTestMultiplexorIndex,
+        #[serde(rename = "UnmultiplexedSignal")]
+        unmultiplexed_signal: u8,
+    }
+
+    impl Serialize for MultiplexTest {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                multiplexor: self.multiplexor().map_err(serde::ser::Error::custom)?,
+                unmultiplexed_signal: self.unmultiplexed_signal(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MultiplexTest {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            let multiplexor = match &signals.multiplexor {
+                MultiplexTestMultiplexorIndex::M0(_) => 0,
+                MultiplexTestMultiplexorIndex::M1(_) => 1,
+            };
+            let mut res = MultiplexTest::new(multiplexor, signals.unmultiplexed_signal)
+                .map_err(serde::de::Error::custom)?;
+            match signals.multiplexor {
+                MultiplexTestMultiplexorIndex::M0(value) => res.set_m0(value),
+                MultiplexTestMultiplexorIndex::M1(value) => res.set_m1(value),
+            }
+            .map_err(serde::de::Error::custom)?;
+            Ok(res)
+        }
+    }
+};
+
+/// Defined values for multiplexed signal MultiplexTest
+#[derive(PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum MultiplexTestMultiplexorIndex {
+    M0(MultiplexTestMultiplexorM0),
+    M1(MultiplexTestMultiplexorM1),
+}
+
+#[derive(PartialEq, Eq, Hash, PartialOrd, Default, Ord)]
+pub struct MultiplexTestMultiplexorM0 {
+    raw: [u8; 8],
+}
+
+impl MultiplexTestMultiplexorM0 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// MultiplexedSignalZeroA
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_a(&self) -> f32 {
+        self.multiplexed_signal_zero_a_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalZeroA
+    ///
+    /// - Start bit: 12
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_a_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalZeroA
+    #[inline(always)]
+    pub fn set_multiplexed_signal_zero_a(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 3_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+        Ok(())
+    }
+
+    /// MultiplexedSignalZeroB
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_b(&self) -> f32 {
+        self.multiplexed_signal_zero_b_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalZeroB
+    ///
+    /// - Start bit: 20
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_b_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalZeroB
+    #[inline(always)]
+    pub fn set_multiplexed_signal_zero_b(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 3_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for MultiplexTestMultiplexorM0 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MultiplexTestMultiplexorM0")
+                .field(
+                    "multiplexed_signal_zero_a",
+                    &self.multiplexed_signal_zero_a(),
+                )
+                .field(
+                    "multiplexed_signal_zero_b",
+                    &self.multiplexed_signal_zero_b(),
+                )
+                .finish()
+        } else {
+            f.debug_tuple("MultiplexTestMultiplexorM0")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for MultiplexTestMultiplexorM0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f,
+            "MultiplexTestMultiplexorM0 {{ MultiplexedSignalZeroA={:?} MultiplexedSignalZeroB={:?} }}",
+            self.multiplexed_signal_zero_a(),
+            self.multiplexed_signal_zero_b(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM0 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_multiplexed_signal_zero_a(u.float_in_range(0_f32..=3_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        msg.set_multiplexed_signal_zero_b(u.float_in_range(0_f32..=3_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "MultiplexTestMultiplexorM0")]
+    struct Signals {
+        #[serde(rename = "MultiplexedSignalZeroA")]
+        multiplexed_signal_zero_a: f32,
+        #[serde(rename = "MultiplexedSignalZeroB")]
+        multiplexed_signal_zero_b: f32,
+    }
+
+    impl Serialize for MultiplexTestMultiplexorM0 {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                multiplexed_signal_zero_a: self.multiplexed_signal_zero_a(),
+                multiplexed_signal_zero_b: self.multiplexed_signal_zero_b(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MultiplexTestMultiplexorM0 {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            let mut res = Self::new();
+            res.set_multiplexed_signal_zero_a(signals.multiplexed_signal_zero_a)
+                .map_err(serde::de::Error::custom)?;
+            res.set_multiplexed_signal_zero_b(signals.multiplexed_signal_zero_b)
+                .map_err(serde::de::Error::custom)?;
+            Ok(res)
+        }
+    }
+};
+
+#[derive(PartialEq, Eq, Hash, PartialOrd, Default, Ord)]
+pub struct MultiplexTestMultiplexorM1 {
+    raw: [u8; 8],
+}
+
+impl MultiplexTestMultiplexorM1 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// MultiplexedSignalOneA
+    ///
+    /// - Min: 0
+    /// - Max: 6
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_one_a(&self) -> f32 {
+        self.multiplexed_signal_one_a_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalOneA
+    ///
+    /// - Start bit: 12
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_one_a_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalOneA
+    #[inline(always)]
+    pub fn set_multiplexed_signal_one_a(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 6_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+        Ok(())
+    }
+
+    /// MultiplexedSignalOneB
+    ///
+    /// - Min: 0
+    /// - Max: 6
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_one_b(&self) -> f32 {
+        self.multiplexed_signal_one_b_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalOneB
+    ///
+    /// - Start bit: 20
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_one_b_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalOneB
+    #[inline(always)]
+    pub fn set_multiplexed_signal_one_b(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 6_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for MultiplexTestMultiplexorM1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MultiplexTestMultiplexorM1")
+                .field("multiplexed_signal_one_a", &self.multiplexed_signal_one_a())
+                .field("multiplexed_signal_one_b", &self.multiplexed_signal_one_b())
+                .finish()
+        } else {
+            f.debug_tuple("MultiplexTestMultiplexorM1")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for MultiplexTestMultiplexorM1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f,
+            "MultiplexTestMultiplexorM1 {{ MultiplexedSignalOneA={:?} MultiplexedSignalOneB={:?} }}",
+            self.multiplexed_signal_one_a(),
+            self.multiplexed_signal_one_b(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM1 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_multiplexed_signal_one_a(u.float_in_range(0_f32..=6_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        msg.set_multiplexed_signal_one_b(u.float_in_range(0_f32..=6_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "MultiplexTestMultiplexorM1")]
+    struct Signals {
+        #[serde(rename = "MultiplexedSignalOneA")]
+        multiplexed_signal_one_a: f32,
+        #[serde(rename = "MultiplexedSignalOneB")]
+        multiplexed_signal_one_b: f32,
+    }
+
+    impl Serialize for MultiplexTestMultiplexorM1 {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                multiplexed_signal_one_a: self.multiplexed_signal_one_a(),
+                multiplexed_signal_one_b: self.multiplexed_signal_one_b(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MultiplexTestMultiplexorM1 {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            let mut res = Self::new();
+            res.set_multiplexed_signal_one_a(signals.multiplexed_signal_one_a)
+                .map_err(serde::de::Error::custom)?;
+            res.set_multiplexed_signal_one_b(signals.multiplexed_signal_one_b)
+                .map_err(serde::de::Error::custom)?;
+            Ok(res)
+        }
+    }
+};
+
+/// NamedMultiplexTest
+///
+/// - Standard ID: 201 (0xc9)
+/// - Size: 8 bytes
+/// - Transmitter: SENSOR
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct NamedMultiplexTest {
+    raw: [u8; 8],
+}
+
+impl NamedMultiplexTest {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0xc9) });
+
+    pub const KIND_MIN: u8 = 0_u8;
+    pub const KIND_MAX: u8 = 2_u8;
+    pub const CELSIUS_MIN: i8 = -40_i8;
+    pub const CELSIUS_MAX: i8 = 100_i8;
+    pub const KILOPASCAL_MIN: u16 = 0_u16;
+    pub const KILOPASCAL_MAX: u16 = 1000_u16;
+
+    /// Construct new NamedMultiplexTest from values
+    pub fn new(kind: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_kind(kind)?;
+        Ok(res)
+    }
+
+    /// Construct new NamedMultiplexTest from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const(kind: u8) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_kind(kind) {
+            Ok(res) => res,
+            Err(_) => panic!("Kind is out of range"),
+        };
+        res
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Borrow the payload as a [`NamedMultiplexTestRef`]
+    pub fn view(&self) -> NamedMultiplexTestRef<'_> {
+        NamedMultiplexTestRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`NamedMultiplexTestMut`]
+    pub fn view_mut(&mut self) -> NamedMultiplexTestMut<'_> {
+        NamedMultiplexTestMut { raw: &mut self.raw }
+    }
+
+    /// Get raw value of Kind
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn kind_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn kind(&self) -> Result<NamedMultiplexTestKindIndex, CanError> {
+        match self.kind_raw() {
+            0 => Ok(NamedMultiplexTestKindIndex::Temperature(
+                NamedMultiplexTestKindM0 { raw: self.raw },
+            )),
+            1 => Ok(NamedMultiplexTestKindIndex::Pressure(
+                NamedMultiplexTestKindM1 { raw: self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+    /// Set value of Kind
+    #[inline(always)]
+    fn set_kind(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NamedMultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of Kind in a `const` context
+    #[inline(always)]
+    pub const fn kind_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= (self.raw[0] & 0x0f) as u8;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Kind in a `const` context
+    #[inline(always)]
+    const fn with_kind(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[0] = (raw[0] & 0xf0) | ((value as u8) & 0x0f);
+        Ok(Self { raw })
+    }
+
+    /// Set value of Kind
+    #[inline(always)]
+    pub fn set_temperature(&mut self, value: NamedMultiplexTestKindM0) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_kind(0)?;
+        Ok(())
+    }
+
+    /// Set value of Kind
+    #[inline(always)]
+    pub fn set_pressure(&mut self, value: NamedMultiplexTestKindM1) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_kind(1)?;
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for NamedMultiplexTest {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for NamedMultiplexTest {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for NamedMultiplexTest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            let mut d = f.debug_struct("NamedMultiplexTest");
+            match self.kind() {
+                Ok(multiplexor) => d.field("kind", &multiplexor),
+                Err(_) => d.field("kind", &self.kind_raw()),
+            };
+            d.finish()
+        } else {
+            f.debug_tuple("NamedMultiplexTest")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for NamedMultiplexTest {
+    fn format(&self, f: defmt::Formatter) {
+        match self.kind() {
+            Ok(multiplexor) => {
+                defmt::write!(f, "NamedMultiplexTest {{ Kind={:?} }}", multiplexor,);
+            }
+            Err(_) => {
+                defmt::write!(f, "NamedMultiplexTest {{ Kind={:?} }}", self.kind_raw(),);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NamedMultiplexTest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let kind = u.int_in_range(0..=2)?;
+        let mut msg =
+            NamedMultiplexTest::new(kind).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        match u.int_in_range(0..=1_usize)? {
+            0 => msg.set_temperature(u.arbitrary()?),
+            _ => msg.set_pressure(u.arbitrary()?),
+        }
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+/// Borrowed view of a NamedMultiplexTest payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct NamedMultiplexTestRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> NamedMultiplexTestRef<'a> {
+    /// View a payload buffer as NamedMultiplexTest
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Get raw value of Kind
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn kind_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn kind(&self) -> Result<NamedMultiplexTestKindIndex, CanError> {
+        match self.kind_raw() {
+            0 => Ok(NamedMultiplexTestKindIndex::Temperature(
+                NamedMultiplexTestKindM0 { raw: *self.raw },
+            )),
+            1 => Ok(NamedMultiplexTestKindIndex::Pressure(
+                NamedMultiplexTestKindM1 { raw: *self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for NamedMultiplexTestRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<NamedMultiplexTestRef<'_>> for NamedMultiplexTest {
+    fn from(view: NamedMultiplexTestRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a NamedMultiplexTest payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct NamedMultiplexTestMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> NamedMultiplexTestMut<'a> {
+    /// View a payload buffer as NamedMultiplexTest
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// Get raw value of Kind
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn kind_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn kind(&self) -> Result<NamedMultiplexTestKindIndex, CanError> {
+        match self.kind_raw() {
+            0 => Ok(NamedMultiplexTestKindIndex::Temperature(
+                NamedMultiplexTestKindM0 { raw: *self.raw },
+            )),
+            1 => Ok(NamedMultiplexTestKindIndex::Pressure(
+                NamedMultiplexTestKindM1 { raw: *self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+    /// Set value of Kind
+    #[inline(always)]
+    fn set_kind(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NamedMultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+        Ok(())
+    }
+
+    /// Set value of Kind
+    #[inline(always)]
+    pub fn set_temperature(&mut self, value: NamedMultiplexTestKindM0) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(*self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        *self.raw = b0.bitor(b1).into_inner();
+        self.set_kind(0)?;
+        Ok(())
+    }
+
+    /// Set value of Kind
+    #[inline(always)]
+    pub fn set_pressure(&mut self, value: NamedMultiplexTestKindM1) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(*self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        *self.raw = b0.bitor(b1).into_inner();
+        self.set_kind(1)?;
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for NamedMultiplexTestMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<NamedMultiplexTestMut<'_>> for NamedMultiplexTest {
+    fn from(view: NamedMultiplexTestMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "NamedMultiplexTest")]
+    struct Signals {
+        #[serde(rename = "Kind")]
+        kind: NamedMultiplexTestKindIndex,
+    }
+
+    impl Serialize for NamedMultiplexTest {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                kind: self.kind().map_err(serde::ser::Error::custom)?,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NamedMultiplexTest {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            let kind = match &signals.kind {
+                NamedMultiplexTestKindIndex::Temperature(_) => 0,
+                NamedMultiplexTestKindIndex::Pressure(_) => 1,
+            };
+            let mut res = NamedMultiplexTest::new(kind).map_err(serde::de::Error::custom)?;
+            match signals.kind {
+                NamedMultiplexTestKindIndex::Temperature(value) => res.set_temperature(value),
+                NamedMultiplexTestKindIndex::Pressure(value) => res.set_pressure(value),
+            }
+            .map_err(serde::de::Error::custom)?;
+            Ok(res)
+        }
+    }
+};
+
+/// Defined values for multiplexed signal NamedMultiplexTest
+#[derive(PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum NamedMultiplexTestKindIndex {
+    #[cfg_attr(feature = "serde", serde(rename = "Temperature"))]
+    Temperature(NamedMultiplexTestKindM0),
+    #[cfg_attr(feature = "serde", serde(rename = "Pressure"))]
+    Pressure(NamedMultiplexTestKindM1),
+}
+
+#[derive(PartialEq, Eq, Hash, PartialOrd, Default, Ord)]
+pub struct NamedMultiplexTestKindM0 {
+    raw: [u8; 8],
+}
+
+impl NamedMultiplexTestKindM0 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// Celsius
+    ///
+    /// - Min: -40
+    /// - Max: 100
+    /// - Unit: "degC"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn celsius(&self) -> i8 {
+        self.celsius_raw()
+    }
+
+    /// Get raw value of Celsius
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn celsius_raw(&self) -> i8 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<i8>();
+
+        let factor = 1;
+        let signal = signal as i8;
+        i8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Celsius
+    #[inline(always)]
+    pub fn set_celsius(&mut self, value: i8) -> Result<(), CanError> {
+        if value < -40_i8 || 100_i8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NamedMultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i8;
+
+        let value = u8::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of Celsius in a `const` context
+    #[inline(always)]
+    pub const fn celsius_raw_const(&self) -> i8 {
+        let mut signal = 0u8;
+        signal |= self.raw[1] as u8;
+        let signal = signal as i8;
+
+        let factor = 1;
+        (signal as i8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Celsius in a `const` context
+    #[inline(always)]
+    pub const fn with_celsius(self, value: i8) -> Result<Self, CanError> {
+        if value < -40_i8 || 100_i8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as i8;
+
+        let value = u8::from_ne_bytes(value.to_ne_bytes());
+        let mut raw = self.raw;
+        raw[1] = value as u8;
+        Ok(Self { raw })
+    }
+}
+
+impl core::fmt::Debug for NamedMultiplexTestKindM0 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("NamedMultiplexTestKindM0")
+                .field("celsius", &self.celsius())
+                .finish()
+        } else {
+            f.debug_tuple("NamedMultiplexTestKindM0")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for NamedMultiplexTestKindM0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "NamedMultiplexTestKindM0 {{ Celsius={:?} }}",
+            self.celsius(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM0 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_celsius(u.int_in_range(-40..=100)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "NamedMultiplexTestKindM0")]
+    struct Signals {
+        #[serde(rename = "Celsius")]
+        celsius: i8,
+    }
+
+    impl Serialize for NamedMultiplexTestKindM0 {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                celsius: self.celsius(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NamedMultiplexTestKindM0 {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            let mut res = Self::new();
+            res.set_celsius(signals.celsius)
+                .map_err(serde::de::Error::custom)?;
+            Ok(res)
+        }
+    }
+};
+
+#[derive(PartialEq, Eq, Hash, PartialOrd, Default, Ord)]
+pub struct NamedMultiplexTestKindM1 {
+    raw: [u8; 8],
+}
+
+impl NamedMultiplexTestKindM1 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// Kilopascal
+    ///
+    /// - Min: 0
+    /// - Max: 1000
+    /// - Unit: "kPa"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn kilopascal(&self) -> u16 {
+        self.kilopascal_raw()
+    }
+
+    /// Get raw value of Kilopascal
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 16 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn kilopascal_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..24].load_le::<u16>();
+
+        let factor = 1;
+        u16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Kilopascal
+    #[inline(always)]
+    pub fn set_kilopascal(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 0_u16 || 1000_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NamedMultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of Kilopascal in a `const` context
+    #[inline(always)]
+    pub const fn kilopascal_raw_const(&self) -> u16 {
+        let mut signal = 0u16;
+        signal |= self.raw[1] as u16;
+        signal |= (self.raw[2] as u16) << 8;
+
+        let factor = 1;
+        (signal as u16).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Kilopascal in a `const` context
+    #[inline(always)]
+    pub const fn with_kilopascal(self, value: u16) -> Result<Self, CanError> {
+        if value < 0_u16 || 1000_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NamedMultiplexTest::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u16;
+
+        let mut raw = self.raw;
+        raw[1] = value as u8;
+        raw[2] = (value >> 8) as u8;
+        Ok(Self { raw })
+    }
+}
+
+impl core::fmt::Debug for NamedMultiplexTestKindM1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("NamedMultiplexTestKindM1")
+                .field("kilopascal", &self.kilopascal())
+                .finish()
+        } else {
+            f.debug_tuple("NamedMultiplexTestKindM1")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for NamedMultiplexTestKindM1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "NamedMultiplexTestKindM1 {{ Kilopascal={:?} }}",
+            self.kilopascal(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM1 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_kilopascal(u.int_in_range(0..=1000)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "NamedMultiplexTestKindM1")]
+    struct Signals {
+        #[serde(rename = "Kilopascal")]
+        kilopascal: u16,
+    }
+
+    impl Serialize for NamedMultiplexTestKindM1 {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                kilopascal: self.kilopascal(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NamedMultiplexTestKindM1 {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            let mut res = Self::new();
+            res.set_kilopascal(signals.kilopascal)
+                .map_err(serde::de::Error::custom)?;
+            Ok(res)
+        }
+    }
+};
+
+/// IntegerFactorOffset
+///
+/// - Standard ID: 1337 (0x539)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct IntegerFactorOffset {
+    raw: [u8; 8],
+}
+
+impl IntegerFactorOffset {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x539) });
+
+    pub const BYTE_WITH_OFFSET_MIN: u16 = 1_u16;
+    pub const BYTE_WITH_OFFSET_MAX: u16 = 256_u16;
+    pub const BYTE_WITH_FACTOR_MIN: u16 = 0_u16;
+    pub const BYTE_WITH_FACTOR_MAX: u16 = 1020_u16;
+    pub const BYTE_WITH_BOTH_MIN: u16 = 16_u16;
+    pub const BYTE_WITH_BOTH_MAX: u16 = 526_u16;
+    pub const BYTE_WITH_NEGATIVE_OFFSET_MIN: i16 = 0_i16;
+    pub const BYTE_WITH_NEGATIVE_OFFSET_MAX: i16 = 255_i16;
+    pub const BYTE_WITH_NEGATIVE_MIN_MIN: i16 = -127_i16;
+    pub const BYTE_WITH_NEGATIVE_MIN_MAX: i16 = 127_i16;
+
+    /// Construct new IntegerFactorOffset from values
+    pub fn new(
+        byte_with_offset: u16,
+        byte_with_factor: u16,
+        byte_with_both: u16,
+        byte_with_negative_offset: i16,
+        byte_with_negative_min: i16,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_byte_with_offset(byte_with_offset)?;
+        res.set_byte_with_factor(byte_with_factor)?;
+        res.set_byte_with_both(byte_with_both)?;
+        res.set_byte_with_negative_offset(byte_with_negative_offset)?;
+        res.set_byte_with_negative_min(byte_with_negative_min)?;
+        Ok(res)
+    }
+
+    /// Construct new IntegerFactorOffset from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const(
+        byte_with_offset: u16,
+        byte_with_factor: u16,
+        byte_with_both: u16,
+        byte_with_negative_offset: i16,
+        byte_with_negative_min: i16,
+    ) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_byte_with_offset(byte_with_offset) {
+            Ok(res) => res,
+            Err(_) => panic!("ByteWithOffset is out of range"),
+        };
+        let res = match res.with_byte_with_factor(byte_with_factor) {
+            Ok(res) => res,
+            Err(_) => panic!("ByteWithFactor is out of range"),
+        };
+        let res = match res.with_byte_with_both(byte_with_both) {
+            Ok(res) => res,
+            Err(_) => panic!("ByteWithBoth is out of range"),
+        };
+        let res = match res.with_byte_with_negative_offset(byte_with_negative_offset) {
+            Ok(res) => res,
+            Err(_) => panic!("ByteWithNegativeOffset is out of range"),
+        };
+        let res = match res.with_byte_with_negative_min(byte_with_negative_min) {
+            Ok(res) => res,
+            Err(_) => panic!("ByteWithNegativeMin is out of range"),
+        };
+        res
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Borrow the payload as a [`IntegerFactorOffsetRef`]
+    pub fn view(&self) -> IntegerFactorOffsetRef<'_> {
+        IntegerFactorOffsetRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`IntegerFactorOffsetMut`]
+    pub fn view_mut(&mut self) -> IntegerFactorOffsetMut<'_> {
+        IntegerFactorOffsetMut { raw: &mut self.raw }
+    }
+
+    /// ByteWithOffset
+    ///
+    /// - Min: 1
+    /// - Max: 256
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_offset(&self) -> u16 {
+        self.byte_with_offset_raw()
+    }
+
+    /// Get raw value of ByteWithOffset
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_offset_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+        let factor = 1;
+        u16::from(signal).saturating_mul(factor).saturating_add(1)
+    }
+
+    /// Set value of ByteWithOffset
+    #[inline(always)]
+    pub fn set_byte_with_offset(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 1_u16 || 256_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of ByteWithOffset in a `const` context
+    #[inline(always)]
+    pub const fn byte_with_offset_raw_const(&self) -> u16 {
+        let mut signal = 0u8;
+        signal |= self.raw[0] as u8;
+
+        let factor = 1;
+        (signal as u16).saturating_mul(factor).saturating_add(1)
+    }
+
+    /// Set value of ByteWithOffset in a `const` context
+    #[inline(always)]
+    pub const fn with_byte_with_offset(self, value: u16) -> Result<Self, CanError> {
+        if value < 1_u16 || 256_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(1) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[0] = value as u8;
+        Ok(Self { raw })
+    }
+
+    /// ByteWithFactor
+    ///
+    /// - Min: 0
+    /// - Max: 1020
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_factor(&self) -> u16 {
+        self.byte_with_factor_raw()
+    }
+
+    /// Get raw value of ByteWithFactor
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 4
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_factor_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+        let factor = 4;
+        u16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of ByteWithFactor
+    #[inline(always)]
+    pub fn set_byte_with_factor(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 0_u16 || 1020_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 4;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of ByteWithFactor in a `const` context
+    #[inline(always)]
+    pub const fn byte_with_factor_raw_const(&self) -> u16 {
+        let mut signal = 0u8;
+        signal |= self.raw[1] as u8;
+
+        let factor = 4;
+        (signal as u16).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of ByteWithFactor in a `const` context
+    #[inline(always)]
+    pub const fn with_byte_with_factor(self, value: u16) -> Result<Self, CanError> {
+        if value < 0_u16 || 1020_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 4;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[1] = value as u8;
+        Ok(Self { raw })
+    }
+
+    /// ByteWithBoth
+    ///
+    /// - Min: 16
+    /// - Max: 526
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_both(&self) -> u16 {
+        self.byte_with_both_raw()
+    }
+
+    /// Get raw value of ByteWithBoth
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 8 bits
+    /// - Factor: 2
+    /// - Offset: 16
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_both_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+        let factor = 2;
+        u16::from(signal).saturating_mul(factor).saturating_add(16)
+    }
+
+    /// Set value of ByteWithBoth
+    #[inline(always)]
+    pub fn set_byte_with_both(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 16_u16 || 526_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 2;
+        let value = value.checked_sub(16).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of ByteWithBoth in a `const` context
+    #[inline(always)]
+    pub const fn byte_with_both_raw_const(&self) -> u16 {
+        let mut signal = 0u8;
+        signal |= self.raw[2] as u8;
+
+        let factor = 2;
+        (signal as u16).saturating_mul(factor).saturating_add(16)
+    }
+
+    /// Set value of ByteWithBoth in a `const` context
+    #[inline(always)]
+    pub const fn with_byte_with_both(self, value: u16) -> Result<Self, CanError> {
+        if value < 16_u16 || 526_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 2;
+        let value = match value.checked_sub(16) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[2] = value as u8;
+        Ok(Self { raw })
+    }
+
+    /// ByteWithNegativeOffset
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_offset(&self) -> i16 {
+        self.byte_with_negative_offset_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeOffset
+    ///
+    /// - Start bit: 24
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_offset_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeOffset
+    #[inline(always)]
+    pub fn set_byte_with_negative_offset(&mut self, value: i16) -> Result<(), CanError> {
+        if value < 0_i16 || 255_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of ByteWithNegativeOffset in a `const` context
+    #[inline(always)]
+    pub const fn byte_with_negative_offset_raw_const(&self) -> i16 {
+        let mut signal = 0u8;
+        signal |= self.raw[3] as u8;
+
+        let factor = 1;
+        (signal as i16).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeOffset in a `const` context
+    #[inline(always)]
+    pub const fn with_byte_with_negative_offset(self, value: i16) -> Result<Self, CanError> {
+        if value < 0_i16 || 255_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_add(1) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[3] = value as u8;
+        Ok(Self { raw })
+    }
+
+    /// ByteWithNegativeMin
+    ///
+    /// - Min: -127
+    /// - Max: 127
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_min(&self) -> i16 {
+        self.byte_with_negative_min_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeMin
+    ///
+    /// - Start bit: 32
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_min_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeMin
+    #[inline(always)]
+    pub fn set_byte_with_negative_min(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -127_i16 || 127_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of ByteWithNegativeMin in a `const` context
+    #[inline(always)]
+    pub const fn byte_with_negative_min_raw_const(&self) -> i16 {
+        let mut signal = 0u8;
+        signal |= self.raw[4] as u8;
+
+        let factor = 1;
+        (signal as i16).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeMin in a `const` context
+    #[inline(always)]
+    pub const fn with_byte_with_negative_min(self, value: i16) -> Result<Self, CanError> {
+        if value < -127_i16 || 127_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_add(1) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: IntegerFactorOffset::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[4] = value as u8;
+        Ok(Self { raw })
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for IntegerFactorOffset {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for IntegerFactorOffset {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for IntegerFactorOffset {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("IntegerFactorOffset")
+                .field("byte_with_offset", &self.byte_with_offset())
+                .field("byte_with_factor", &self.byte_with_factor())
+                .field("byte_with_both", &self.byte_with_both())
+                .field(
+                    "byte_with_negative_offset",
+                    &self.byte_with_negative_offset(),
+                )
+                .field("byte_with_negative_min", &self.byte_with_negative_min())
+                .finish()
+        } else {
+            f.debug_tuple("IntegerFactorOffset")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for IntegerFactorOffset {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f,
+            "IntegerFactorOffset {{ ByteWithOffset={:?} ByteWithFactor={:?} ByteWithBoth={:?} ByteWithNegativeOffset={:?} ByteWithNegativeMin={:?} }}",
+            self.byte_with_offset(),
+            self.byte_with_factor(),
+            self.byte_with_both(),
+            self.byte_with_negative_offset(),
+            self.byte_with_negative_min(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for IntegerFactorOffset {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let byte_with_offset = u.int_in_range(1..=256)?;
+        let byte_with_factor = u.int_in_range(0..=1020)?;
+        let byte_with_both = u.int_in_range(16..=526)?;
+        let byte_with_negative_offset = u.int_in_range(0..=255)?;
+        let byte_with_negative_min = u.int_in_range(-127..=127)?;
+        IntegerFactorOffset::new(
+            byte_with_offset,
+            byte_with_factor,
+            byte_with_both,
+            byte_with_negative_offset,
+            byte_with_negative_min,
+        )
+        .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Borrowed view of a IntegerFactorOffset payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct IntegerFactorOffsetRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> IntegerFactorOffsetRef<'a> {
+    /// View a payload buffer as IntegerFactorOffset
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// ByteWithOffset
+    ///
+    /// - Min: 1
+    /// - Max: 256
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_offset(&self) -> u16 {
+        self.byte_with_offset_raw()
+    }
+
+    /// Get raw value of ByteWithOffset
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_offset_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+        let factor = 1;
+        u16::from(signal).saturating_mul(factor).saturating_add(1)
+    }
+
+    /// ByteWithFactor
+    ///
+    /// - Min: 0
+    /// - Max: 1020
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_factor(&self) -> u16 {
+        self.byte_with_factor_raw()
+    }
+
+    /// Get raw value of ByteWithFactor
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 4
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_factor_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+        let factor = 4;
+        u16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// ByteWithBoth
+    ///
+    /// - Min: 16
+    /// - Max: 526
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_both(&self) -> u16 {
+        self.byte_with_both_raw()
+    }
+
+    /// Get raw value of ByteWithBoth
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 8 bits
+    /// - Factor: 2
+    /// - Offset: 16
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_both_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+        let factor = 2;
+        u16::from(signal).saturating_mul(factor).saturating_add(16)
+    }
+
+    /// ByteWithNegativeOffset
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_offset(&self) -> i16 {
+        self.byte_with_negative_offset_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeOffset
+    ///
+    /// - Start bit: 24
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_offset_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// ByteWithNegativeMin
+    ///
+    /// - Min: -127
+    /// - Max: 127
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_min(&self) -> i16 {
+        self.byte_with_negative_min_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeMin
+    ///
+    /// - Start bit: 32
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_min_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for IntegerFactorOffsetRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<IntegerFactorOffsetRef<'_>> for IntegerFactorOffset {
+    fn from(view: IntegerFactorOffsetRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a IntegerFactorOffset payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct IntegerFactorOffsetMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> IntegerFactorOffsetMut<'a> {
+    /// View a payload buffer as IntegerFactorOffset
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// ByteWithOffset
+    ///
     /// - Min: 1
     /// - Max: 256
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_offset(&self) -> u16 {
+        self.byte_with_offset_raw()
+    }
+
+    /// Get raw value of ByteWithOffset
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_offset_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+        let factor = 1;
+        u16::from(signal).saturating_mul(factor).saturating_add(1)
+    }
+
+    /// Set value of ByteWithOffset
+    #[inline(always)]
+    pub fn set_byte_with_offset(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 1_u16 || 256_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithFactor
+    ///
+    /// - Min: 0
+    /// - Max: 1020
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_factor(&self) -> u16 {
+        self.byte_with_factor_raw()
+    }
+
+    /// Get raw value of ByteWithFactor
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 4
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_factor_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+        let factor = 4;
+        u16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of ByteWithFactor
+    #[inline(always)]
+    pub fn set_byte_with_factor(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 0_u16 || 1020_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 4;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithBoth
+    ///
+    /// - Min: 16
+    /// - Max: 526
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_both(&self) -> u16 {
+        self.byte_with_both_raw()
+    }
+
+    /// Get raw value of ByteWithBoth
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 8 bits
+    /// - Factor: 2
+    /// - Offset: 16
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_both_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+        let factor = 2;
+        u16::from(signal).saturating_mul(factor).saturating_add(16)
+    }
+
+    /// Set value of ByteWithBoth
+    #[inline(always)]
+    pub fn set_byte_with_both(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 16_u16 || 526_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 2;
+        let value = value.checked_sub(16).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithNegativeOffset
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_offset(&self) -> i16 {
+        self.byte_with_negative_offset_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeOffset
+    ///
+    /// - Start bit: 24
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_offset_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeOffset
+    #[inline(always)]
+    pub fn set_byte_with_negative_offset(&mut self, value: i16) -> Result<(), CanError> {
+        if value < 0_i16 || 255_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithNegativeMin
+    ///
+    /// - Min: -127
+    /// - Max: 127
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_min(&self) -> i16 {
+        self.byte_with_negative_min_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeMin
+    ///
+    /// - Start bit: 32
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_min_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeMin
+    #[inline(always)]
+    pub fn set_byte_with_negative_min(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -127_i16 || 127_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for IntegerFactorOffsetMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<IntegerFactorOffsetMut<'_>> for IntegerFactorOffset {
+    fn from(view: IntegerFactorOffsetMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "IntegerFactorOffset")]
+    struct Signals {
+        #[serde(rename = "ByteWithOffset")]
+        byte_with_offset: u16,
+        #[serde(rename = "ByteWithFactor")]
+        byte_with_factor: u16,
+        #[serde(rename = "ByteWithBoth")]
+        byte_with_both: u16,
+        #[serde(rename = "ByteWithNegativeOffset")]
+        byte_with_negative_offset: i16,
+        #[serde(rename = "ByteWithNegativeMin")]
+        byte_with_negative_min: i16,
+    }
+
+    impl Serialize for IntegerFactorOffset {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                byte_with_offset: self.byte_with_offset(),
+                byte_with_factor: self.byte_with_factor(),
+                byte_with_both: self.byte_with_both(),
+                byte_with_negative_offset: self.byte_with_negative_offset(),
+                byte_with_negative_min: self.byte_with_negative_min(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for IntegerFactorOffset {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            IntegerFactorOffset::new(
+                signals.byte_with_offset,
+                signals.byte_with_factor,
+                signals.byte_with_both,
+                signals.byte_with_negative_offset,
+                signals.byte_with_negative_min,
+            )
+            .map_err(serde::de::Error::custom)
+        }
+    }
+};
+
+/// NegativeFactorTest
+///
+/// - Standard ID: 1344 (0x540)
+/// - Size: 4 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct NegativeFactorTest {
+    raw: [u8; 4],
+}
+
+impl NegativeFactorTest {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x540) });
+
+    pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MIN: i32 = -65535_i32;
+    pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MAX: i32 = 0_i32;
+    pub const WIDTH_MORE_THAN_MIN_MAX_MIN: i16 = -2_i16;
+    pub const WIDTH_MORE_THAN_MIN_MAX_MAX: i16 = 2_i16;
+
+    /// Construct new NegativeFactorTest from values
+    pub fn new(
+        unsigned_negative_factor_signal: i32,
+        width_more_than_min_max: i16,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 4] };
+        res.set_unsigned_negative_factor_signal(unsigned_negative_factor_signal)?;
+        res.set_width_more_than_min_max(width_more_than_min_max)?;
+        Ok(res)
+    }
+
+    /// Construct new NegativeFactorTest from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const(
+        unsigned_negative_factor_signal: i32,
+        width_more_than_min_max: i16,
+    ) -> Self {
+        let res = Self { raw: [0u8; 4] };
+        let res = match res.with_unsigned_negative_factor_signal(unsigned_negative_factor_signal) {
+            Ok(res) => res,
+            Err(_) => panic!("UnsignedNegativeFactorSignal is out of range"),
+        };
+        let res = match res.with_width_more_than_min_max(width_more_than_min_max) {
+            Ok(res) => res,
+            Err(_) => panic!("WidthMoreThanMinMax is out of range"),
+        };
+        res
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 4] {
+        &self.raw
+    }
+
+    /// Borrow the payload as a [`NegativeFactorTestRef`]
+    pub fn view(&self) -> NegativeFactorTestRef<'_> {
+        NegativeFactorTestRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`NegativeFactorTestMut`]
+    pub fn view_mut(&mut self) -> NegativeFactorTestMut<'_> {
+        NegativeFactorTestMut { raw: &mut self.raw }
+    }
+
+    /// UnsignedNegativeFactorSignal
+    ///
+    /// - Min: -65535
+    /// - Max: 0
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal(&self) -> i32 {
+        self.unsigned_negative_factor_signal_raw()
+    }
+
+    /// Get raw value of UnsignedNegativeFactorSignal
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 16 bits
+    /// - Factor: -1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<u16>();
+
+        let factor = -1;
+        i32::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnsignedNegativeFactorSignal
+    #[inline(always)]
+    pub fn set_unsigned_negative_factor_signal(&mut self, value: i32) -> Result<(), CanError> {
+        if value < -65535_i32 || 0_i32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = -1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NegativeFactorTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of UnsignedNegativeFactorSignal in a `const` context
+    #[inline(always)]
+    pub const fn unsigned_negative_factor_signal_raw_const(&self) -> i32 {
+        let mut signal = 0u16;
+        signal |= self.raw[0] as u16;
+        signal |= (self.raw[1] as u16) << 8;
+
+        let factor = -1;
+        (signal as i32).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnsignedNegativeFactorSignal in a `const` context
+    #[inline(always)]
+    pub const fn with_unsigned_negative_factor_signal(self, value: i32) -> Result<Self, CanError> {
+        if value < -65535_i32 || 0_i32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = -1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NegativeFactorTest::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u16;
+
+        let mut raw = self.raw;
+        raw[0] = value as u8;
+        raw[1] = (value >> 8) as u8;
+        Ok(Self { raw })
+    }
+
+    /// WidthMoreThanMinMax
+    ///
+    /// - Min: -2
+    /// - Max: 2
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn width_more_than_min_max(&self) -> i16 {
+        self.width_more_than_min_max_raw()
+    }
+
+    /// Get raw value of WidthMoreThanMinMax
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 10 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn width_more_than_min_max_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..26].load_le::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of WidthMoreThanMinMax
+    #[inline(always)]
+    pub fn set_width_more_than_min_max(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -2_i16 || 2_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NegativeFactorTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[16..26].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of WidthMoreThanMinMax in a `const` context
+    #[inline(always)]
+    pub const fn width_more_than_min_max_raw_const(&self) -> i16 {
+        let mut signal = 0u16;
+        signal |= self.raw[2] as u16;
+        signal |= ((self.raw[3] & 0x03) as u16) << 8;
+        let signal = ((signal << 6) as i16) >> 6;
+
+        let factor = 1;
+        (signal as i16).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of WidthMoreThanMinMax in a `const` context
+    #[inline(always)]
+    pub const fn with_width_more_than_min_max(self, value: i16) -> Result<Self, CanError> {
+        if value < -2_i16 || 2_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: NegativeFactorTest::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        let mut raw = self.raw;
+        raw[2] = value as u8;
+        raw[3] = (raw[3] & 0xfc) | (((value >> 8) as u8) & 0x03);
+        Ok(Self { raw })
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for NegativeFactorTest {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 4 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 4];
+        raw.copy_from_slice(&payload[..4]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for NegativeFactorTest {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for NegativeFactorTest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("NegativeFactorTest")
+                .field(
+                    "unsigned_negative_factor_signal",
+                    &self.unsigned_negative_factor_signal(),
+                )
+                .field("width_more_than_min_max", &self.width_more_than_min_max())
+                .finish()
+        } else {
+            f.debug_tuple("NegativeFactorTest")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for NegativeFactorTest {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "NegativeFactorTest {{ UnsignedNegativeFactorSignal={:?} WidthMoreThanMinMax={:?} }}",
+            self.unsigned_negative_factor_signal(),
+            self.width_more_than_min_max(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NegativeFactorTest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let unsigned_negative_factor_signal = u.int_in_range(-65535..=0)?;
+        let width_more_than_min_max = u.int_in_range(-2..=2)?;
+        NegativeFactorTest::new(unsigned_negative_factor_signal, width_more_than_min_max)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Borrowed view of a NegativeFactorTest payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct NegativeFactorTestRef<'a> {
+    raw: &'a [u8; 4],
+}
+
+impl<'a> NegativeFactorTestRef<'a> {
+    /// View a payload buffer as NegativeFactorTest
+    pub fn from_raw(raw: &'a [u8; 4]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 4] {
+        self.raw
+    }
+
+    /// UnsignedNegativeFactorSignal
+    ///
+    /// - Min: -65535
+    /// - Max: 0
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal(&self) -> i32 {
+        self.unsigned_negative_factor_signal_raw()
+    }
+
+    /// Get raw value of UnsignedNegativeFactorSignal
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 16 bits
+    /// - Factor: -1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<u16>();
+
+        let factor = -1;
+        i32::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// WidthMoreThanMinMax
+    ///
+    /// - Min: -2
+    /// - Max: 2
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn width_more_than_min_max(&self) -> i16 {
+        self.width_more_than_min_max_raw()
+    }
+
+    /// Get raw value of WidthMoreThanMinMax
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 10 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn width_more_than_min_max_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..26].load_le::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for NegativeFactorTestRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<NegativeFactorTestRef<'_>> for NegativeFactorTest {
+    fn from(view: NegativeFactorTestRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a NegativeFactorTest payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct NegativeFactorTestMut<'a> {
+    raw: &'a mut [u8; 4],
+}
+
+impl<'a> NegativeFactorTestMut<'a> {
+    /// View a payload buffer as NegativeFactorTest
+    pub fn from_raw(raw: &'a mut [u8; 4]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 4] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 4] {
+        self.raw
+    }
+
+    /// UnsignedNegativeFactorSignal
+    ///
+    /// - Min: -65535
+    /// - Max: 0
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal(&self) -> i32 {
+        self.unsigned_negative_factor_signal_raw()
+    }
+
+    /// Get raw value of UnsignedNegativeFactorSignal
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 16 bits
+    /// - Factor: -1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<u16>();
+
+        let factor = -1;
+        i32::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnsignedNegativeFactorSignal
+    #[inline(always)]
+    pub fn set_unsigned_negative_factor_signal(&mut self, value: i32) -> Result<(), CanError> {
+        if value < -65535_i32 || 0_i32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = -1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NegativeFactorTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+        Ok(())
+    }
+
+    /// WidthMoreThanMinMax
+    ///
+    /// - Min: -2
+    /// - Max: 2
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn width_more_than_min_max(&self) -> i16 {
+        self.width_more_than_min_max_raw()
+    }
+
+    /// Get raw value of WidthMoreThanMinMax
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 10 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn width_more_than_min_max_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..26].load_le::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of WidthMoreThanMinMax
+    #[inline(always)]
+    pub fn set_width_more_than_min_max(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -2_i16 || 2_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NegativeFactorTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[16..26].store_le(value);
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for NegativeFactorTestMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<NegativeFactorTestMut<'_>> for NegativeFactorTest {
+    fn from(view: NegativeFactorTestMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "NegativeFactorTest")]
+    struct Signals {
+        #[serde(rename = "UnsignedNegativeFactorSignal")]
+        unsigned_negative_factor_signal: i32,
+        #[serde(rename = "WidthMoreThanMinMax")]
+        width_more_than_min_max: i16,
+    }
+
+    impl Serialize for NegativeFactorTest {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                unsigned_negative_factor_signal: self.unsigned_negative_factor_signal(),
+                width_more_than_min_max: self.width_more_than_min_max(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NegativeFactorTest {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            NegativeFactorTest::new(
+                signals.unsigned_negative_factor_signal,
+                signals.width_more_than_min_max,
+            )
+            .map_err(serde::de::Error::custom)
+        }
+    }
+};
+
+/// LargerIntsWithOffsets
+///
+/// - Standard ID: 1338 (0x53a)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct LargerIntsWithOffsets {
+    raw: [u8; 8],
+}
+
+impl LargerIntsWithOffsets {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x53a) });
+
+    pub const TWELVE_MIN: i16 = -1000_i16;
+    pub const TWELVE_MAX: i16 = 3000_i16;
+    pub const SIXTEEN_MIN: i32 = -1000_i32;
+    pub const SIXTEEN_MAX: i32 = 64535_i32;
+
+    /// Construct new LargerIntsWithOffsets from values
+    pub fn new(twelve: i16, sixteen: i32) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_twelve(twelve)?;
+        res.set_sixteen(sixteen)?;
+        Ok(res)
+    }
+
+    /// Construct new LargerIntsWithOffsets from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const(twelve: i16, sixteen: i32) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_twelve(twelve) {
+            Ok(res) => res,
+            Err(_) => panic!("Twelve is out of range"),
+        };
+        let res = match res.with_sixteen(sixteen) {
+            Ok(res) => res,
+            Err(_) => panic!("Sixteen is out of range"),
+        };
+        res
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Borrow the payload as a [`LargerIntsWithOffsetsRef`]
+    pub fn view(&self) -> LargerIntsWithOffsetsRef<'_> {
+        LargerIntsWithOffsetsRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`LargerIntsWithOffsetsMut`]
+    pub fn view_mut(&mut self) -> LargerIntsWithOffsetsMut<'_> {
+        LargerIntsWithOffsetsMut { raw: &mut self.raw }
+    }
+
+    /// Twelve
+    ///
+    /// - Min: -1000
+    /// - Max: 3000
+    /// - Unit: ""
+    /// - Receivers: XXX
+    #[inline(always)]
+    pub fn twelve(&self) -> i16 {
+        self.twelve_raw()
+    }
+
+    /// Get raw value of Twelve
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: -1000
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn twelve_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<u16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
+    }
+
+    /// Set value of Twelve
+    #[inline(always)]
+    pub fn set_twelve(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -1000_i16 || 3000_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value
+            .checked_add(1000)
+            .ok_or(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of Twelve in a `const` context
+    #[inline(always)]
+    pub const fn twelve_raw_const(&self) -> i16 {
+        let mut signal = 0u16;
+        signal |= self.raw[0] as u16;
+        signal |= ((self.raw[1] & 0x0f) as u16) << 8;
+
+        let factor = 1;
+        (signal as i16).saturating_mul(factor).saturating_sub(1000)
+    }
+
+    /// Set value of Twelve in a `const` context
+    #[inline(always)]
+    pub const fn with_twelve(self, value: i16) -> Result<Self, CanError> {
+        if value < -1000_i16 || 3000_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_add(1000) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u16;
+
+        let mut raw = self.raw;
+        raw[0] = value as u8;
+        raw[1] = (raw[1] & 0xf0) | (((value >> 8) as u8) & 0x0f);
+        Ok(Self { raw })
+    }
+
+    /// Sixteen
+    ///
+    /// - Min: -1000
+    /// - Max: 64535
+    /// - Unit: ""
+    /// - Receivers: XXX
+    #[inline(always)]
+    pub fn sixteen(&self) -> i32 {
+        self.sixteen_raw()
+    }
+
+    /// Get raw value of Sixteen
+    ///
+    /// - Start bit: 12
+    /// - Signal size: 16 bits
+    /// - Factor: 1
+    /// - Offset: -1000
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn sixteen_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..28].load_le::<u16>();
+
+        let factor = 1;
+        i32::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
+    }
+
+    /// Set value of Sixteen
+    #[inline(always)]
+    pub fn set_sixteen(&mut self, value: i32) -> Result<(), CanError> {
+        if value < -1000_i32 || 64535_i32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value
+            .checked_add(1000)
+            .ok_or(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[12..28].store_le(value);
+        Ok(())
+    }
+
+    /// Get raw value of Sixteen in a `const` context
+    #[inline(always)]
+    pub const fn sixteen_raw_const(&self) -> i32 {
+        let mut signal = 0u16;
+        signal |= ((self.raw[1] >> 4) & 0x0f) as u16;
+        signal |= (self.raw[2] as u16) << 4;
+        signal |= ((self.raw[3] & 0x0f) as u16) << 12;
+
+        let factor = 1;
+        (signal as i32).saturating_mul(factor).saturating_sub(1000)
+    }
+
+    /// Set value of Sixteen in a `const` context
+    #[inline(always)]
+    pub const fn with_sixteen(self, value: i32) -> Result<Self, CanError> {
+        if value < -1000_i32 || 64535_i32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_add(1000) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u16;
+
+        let mut raw = self.raw;
+        raw[1] = (raw[1] & 0x0f) | (((value as u8) << 4) & 0xf0);
+        raw[2] = (value >> 4) as u8;
+        raw[3] = (raw[3] & 0xf0) | (((value >> 12) as u8) & 0x0f);
+        Ok(Self { raw })
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for LargerIntsWithOffsets {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for LargerIntsWithOffsets {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for LargerIntsWithOffsets {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("LargerIntsWithOffsets")
+                .field("twelve", &self.twelve())
+                .field("sixteen", &self.sixteen())
+                .finish()
+        } else {
+            f.debug_tuple("LargerIntsWithOffsets")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+impl defmt::Format for LargerIntsWithOffsets {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "LargerIntsWithOffsets {{ Twelve={:?} Sixteen={:?} }}",
+            self.twelve(),
+            self.sixteen(),
+        );
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for LargerIntsWithOffsets {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let twelve = u.int_in_range(-1000..=3000)?;
+        let sixteen = u.int_in_range(-1000..=64535)?;
+        LargerIntsWithOffsets::new(twelve, sixteen).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Borrowed view of a LargerIntsWithOffsets payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct LargerIntsWithOffsetsRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> LargerIntsWithOffsetsRef<'a> {
+    /// View a payload buffer as LargerIntsWithOffsets
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Twelve
+    ///
+    /// - Min: -1000
+    /// - Max: 3000
+    /// - Unit: ""
+    /// - Receivers: XXX
     #[inline(always)]
-    pub fn byte_with_offset(&self) -> u16 {
-        self.byte_with_offset_raw()
+    pub fn twelve(&self) -> i16 {
+        self.twelve_raw()
     }
 
-    /// Get raw value of ByteWithOffset
+    /// Get raw value of Twelve
     ///
     /// - Start bit: 0
-    /// - Signal size: 8 bits
+    /// - Signal size: 12 bits
     /// - Factor: 1
-    /// - Offset: 1
+    /// - Offset: -1000
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn byte_with_offset_raw(&self) -> u16 {
-        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
-
-        let factor = 1;
-        u16::from(signal).saturating_mul(factor).saturating_add(1)
-    }
+    pub fn twelve_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<u16>();
 
-    /// Set value of ByteWithOffset
-    #[inline(always)]
-    pub fn set_byte_with_offset(&mut self, value: u16) -> Result<(), CanError> {
-        if value < 1_u16 || 256_u16 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: IntegerFactorOffset::MESSAGE_ID,
-            });
-        }
         let factor = 1;
-        let value = value.checked_sub(1).ok_or(CanError::ParameterOutOfRange {
-            message_id: IntegerFactorOffset::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u8;
-
-        self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
-        Ok(())
+        let signal = signal as i16;
+        i16::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
     }
 
-    /// ByteWithFactor
+    /// Sixteen
     ///
-    /// - Min: 0
-    /// - Max: 1020
+    /// - Min: -1000
+    /// - Max: 64535
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: XXX
     #[inline(always)]
-    pub fn byte_with_factor(&self) -> u16 {
-        self.byte_with_factor_raw()
+    pub fn sixteen(&self) -> i32 {
+        self.sixteen_raw()
     }
 
-    /// Get raw value of ByteWithFactor
+    /// Get raw value of Sixteen
     ///
-    /// - Start bit: 8
-    /// - Signal size: 8 bits
-    /// - Factor: 4
-    /// - Offset: 0
+    /// - Start bit: 12
+    /// - Signal size: 16 bits
+    /// - Factor: 1
+    /// - Offset: -1000
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn byte_with_factor_raw(&self) -> u16 {
-        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+    pub fn sixteen_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..28].load_le::<u16>();
 
-        let factor = 4;
-        u16::from(signal).saturating_mul(factor).saturating_add(0)
+        let factor = 1;
+        i32::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
     }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for LargerIntsWithOffsetsRef<'a> {
+    type Error = CanError;
 
-    /// Set value of ByteWithFactor
     #[inline(always)]
-    pub fn set_byte_with_factor(&mut self, value: u16) -> Result<(), CanError> {
-        if value < 0_u16 || 1020_u16 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: IntegerFactorOffset::MESSAGE_ID,
-            });
-        }
-        let factor = 4;
-        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: IntegerFactorOffset::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u8;
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
 
-        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
-        Ok(())
+impl From<LargerIntsWithOffsetsRef<'_>> for LargerIntsWithOffsets {
+    fn from(view: LargerIntsWithOffsetsRef<'_>) -> Self {
+        Self { raw: *view.raw }
     }
+}
 
-    /// ByteWithBoth
+/// Mutably borrowed view of a LargerIntsWithOffsets payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct LargerIntsWithOffsetsMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> LargerIntsWithOffsetsMut<'a> {
+    /// View a payload buffer as LargerIntsWithOffsets
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// Twelve
     ///
-    /// - Min: 16
-    /// - Max: 526
+    /// - Min: -1000
+    /// - Max: 3000
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: XXX
     #[inline(always)]
-    pub fn byte_with_both(&self) -> u16 {
-        self.byte_with_both_raw()
+    pub fn twelve(&self) -> i16 {
+        self.twelve_raw()
     }
 
-    /// Get raw value of ByteWithBoth
+    /// Get raw value of Twelve
     ///
-    /// - Start bit: 16
-    /// - Signal size: 8 bits
-    /// - Factor: 2
-    /// - Offset: 16
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: -1000
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn byte_with_both_raw(&self) -> u16 {
-        let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+    pub fn twelve_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<u16>();
 
-        let factor = 2;
-        u16::from(signal).saturating_mul(factor).saturating_add(16)
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
     }
 
-    /// Set value of ByteWithBoth
+    /// Set value of Twelve
     #[inline(always)]
-    pub fn set_byte_with_both(&mut self, value: u16) -> Result<(), CanError> {
-        if value < 16_u16 || 526_u16 < value {
+    pub fn set_twelve(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -1000_i16 || 3000_i16 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: IntegerFactorOffset::MESSAGE_ID,
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
             });
         }
-        let factor = 2;
-        let value = value.checked_sub(16).ok_or(CanError::ParameterOutOfRange {
-            message_id: IntegerFactorOffset::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u8;
+        let factor = 1;
+        let value = value
+            .checked_add(1000)
+            .ok_or(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            })?;
+        let value = (value / factor) as u16;
 
-        self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
         Ok(())
     }
 
-    /// ByteWithNegativeOffset
+    /// Sixteen
     ///
-    /// - Min: 0
-    /// - Max: 255
+    /// - Min: -1000
+    /// - Max: 64535
     /// - Unit: ""
-    /// - Receivers: Vector__XXX
+    /// - Receivers: XXX
     #[inline(always)]
-    pub fn byte_with_negative_offset(&self) -> i16 {
-        self.byte_with_negative_offset_raw()
+    pub fn sixteen(&self) -> i32 {
+        self.sixteen_raw()
     }
 
-    /// Get raw value of ByteWithNegativeOffset
+    /// Get raw value of Sixteen
     ///
-    /// - Start bit: 24
-    /// - Signal size: 8 bits
+    /// - Start bit: 12
+    /// - Signal size: 16 bits
     /// - Factor: 1
-    /// - Offset: -1
+    /// - Offset: -1000
     /// - Byte order: LittleEndian
     /// - Value type: Unsigned
     #[inline(always)]
-    pub fn byte_with_negative_offset_raw(&self) -> i16 {
-        let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+    pub fn sixteen_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..28].load_le::<u16>();
 
         let factor = 1;
-        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+        i32::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
     }
 
-    /// Set value of ByteWithNegativeOffset
+    /// Set value of Sixteen
     #[inline(always)]
-    pub fn set_byte_with_negative_offset(&mut self, value: i16) -> Result<(), CanError> {
-        if value < 0_i16 || 255_i16 < value {
+    pub fn set_sixteen(&mut self, value: i32) -> Result<(), CanError> {
+        if value < -1000_i32 || 64535_i32 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: IntegerFactorOffset::MESSAGE_ID,
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
             });
         }
         let factor = 1;
-        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
-            message_id: IntegerFactorOffset::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u8;
+        let value = value
+            .checked_add(1000)
+            .ok_or(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            })?;
+        let value = (value / factor) as u16;
 
-        self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        self.raw.view_bits_mut::<Lsb0>()[12..28].store_le(value);
         Ok(())
     }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for LargerIntsWithOffsetsMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<LargerIntsWithOffsetsMut<'_>> for LargerIntsWithOffsets {
+    fn from(view: LargerIntsWithOffsetsMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "LargerIntsWithOffsets")]
+    struct Signals {
+        #[serde(rename = "Twelve")]
+        twelve: i16,
+        #[serde(rename = "Sixteen")]
+        sixteen: i32,
+    }
+
+    impl Serialize for LargerIntsWithOffsets {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                twelve: self.twelve(),
+                sixteen: self.sixteen(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LargerIntsWithOffsets {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            LargerIntsWithOffsets::new(signals.twelve, signals.sixteen)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+};
 
-    /// ByteWithNegativeMin
-    ///
-    /// - Min: -127
-    /// - Max: 127
-    /// - Unit: ""
-    /// - Receivers: Vector__XXX
-    #[inline(always)]
-    pub fn byte_with_negative_min(&self) -> i16 {
-        self.byte_with_negative_min_raw()
+/// MsgWithoutSignals
+///
+/// - Standard ID: 513 (0x201)
+/// - Size: 8 bytes
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct MsgWithoutSignals {
+    raw: [u8; 8],
+}
+
+impl MsgWithoutSignals {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x201) });
+
+    /// Construct new MsgWithoutSignals from values
+    pub fn new() -> Result<Self, CanError> {
+        let res = Self { raw: [0u8; 8] };
+        Ok(res)
     }
 
-    /// Get raw value of ByteWithNegativeMin
+    /// Construct new MsgWithoutSignals from values in a `const` context
     ///
-    /// - Start bit: 32
-    /// - Signal size: 8 bits
-    /// - Factor: 1
-    /// - Offset: -1
-    /// - Byte order: LittleEndian
-    /// - Value type: Unsigned
-    #[inline(always)]
-    pub fn byte_with_negative_min_raw(&self) -> i16 {
-        let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const() -> Self {
+        let res = Self { raw: [0u8; 8] };
+        res
+    }
 
-        let factor = 1;
-        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
     }
 
-    /// Set value of ByteWithNegativeMin
-    #[inline(always)]
-    pub fn set_byte_with_negative_min(&mut self, value: i16) -> Result<(), CanError> {
-        if value < -127_i16 || 127_i16 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: IntegerFactorOffset::MESSAGE_ID,
-            });
-        }
-        let factor = 1;
-        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
-            message_id: IntegerFactorOffset::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u8;
+    /// Borrow the payload as a [`MsgWithoutSignalsRef`]
+    pub fn view(&self) -> MsgWithoutSignalsRef<'_> {
+        MsgWithoutSignalsRef { raw: &self.raw }
+    }
 
-        self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
-        Ok(())
+    /// Mutably borrow the payload as a [`MsgWithoutSignalsMut`]
+    pub fn view_mut(&mut self) -> MsgWithoutSignalsMut<'_> {
+        MsgWithoutSignalsMut { raw: &mut self.raw }
     }
 }
 
-impl core::convert::TryFrom<&[u8]> for IntegerFactorOffset {
+impl core::convert::TryFrom<&[u8]> for MsgWithoutSignals {
     type Error = CanError;
 
     #[inline(always)]
@@ -2143,7 +8080,7 @@ impl core::convert::TryFrom<&[u8]> for IntegerFactorOffset {
     }
 }
 
-impl embedded_can::Frame for IntegerFactorOffset {
+impl embedded_can::Frame for MsgWithoutSignals {
     fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
         if id.into() != Self::MESSAGE_ID {
             None
@@ -2179,409 +8116,285 @@ impl embedded_can::Frame for IntegerFactorOffset {
         &self.raw
     }
 }
-impl core::fmt::Debug for IntegerFactorOffset {
+impl core::fmt::Debug for MsgWithoutSignals {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if f.alternate() {
-            f.debug_struct("IntegerFactorOffset")
-                .field("byte_with_offset", &self.byte_with_offset())
-                .field("byte_with_factor", &self.byte_with_factor())
-                .field("byte_with_both", &self.byte_with_both())
-                .field(
-                    "byte_with_negative_offset",
-                    &self.byte_with_negative_offset(),
-                )
-                .field("byte_with_negative_min", &self.byte_with_negative_min())
-                .finish()
+            f.debug_struct("MsgWithoutSignals").finish()
         } else {
-            f.debug_tuple("IntegerFactorOffset")
-                .field(&self.raw)
-                .finish()
+            f.debug_tuple("MsgWithoutSignals").field(&self.raw).finish()
         }
     }
 }
 
-impl defmt::Format for IntegerFactorOffset {
+impl defmt::Format for MsgWithoutSignals {
     fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f,
-            "IntegerFactorOffset {{ ByteWithOffset={:?} ByteWithFactor={:?} ByteWithBoth={:?} ByteWithNegativeOffset={:?} ByteWithNegativeMin={:?} }}",
-            self.byte_with_offset(),
-            self.byte_with_factor(),
-            self.byte_with_both(),
-            self.byte_with_negative_offset(),
-            self.byte_with_negative_min(),
-            );
+        defmt::write!(f, "MsgWithoutSignals {{ }}",);
     }
 }
 
 #[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for IntegerFactorOffset {
-    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let byte_with_offset = u.int_in_range(1..=256)?;
-        let byte_with_factor = u.int_in_range(0..=1020)?;
-        let byte_with_both = u.int_in_range(16..=526)?;
-        let byte_with_negative_offset = u.int_in_range(0..=255)?;
-        let byte_with_negative_min = u.int_in_range(-127..=127)?;
-        IntegerFactorOffset::new(
-            byte_with_offset,
-            byte_with_factor,
-            byte_with_both,
-            byte_with_negative_offset,
-            byte_with_negative_min,
-        )
-        .map_err(|_| arbitrary::Error::IncorrectFormat)
+impl<'a> Arbitrary<'a> for MsgWithoutSignals {
+    fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        MsgWithoutSignals::new().map_err(|_| arbitrary::Error::IncorrectFormat)
     }
 }
 
-/// NegativeFactorTest
+/// Borrowed view of a MsgWithoutSignals payload
 ///
-/// - Standard ID: 1344 (0x540)
-/// - Size: 4 bytes
-/// - Transmitter: Sit
+/// Accesses signals in place, without copying the payload.
 #[derive(Clone, Copy)]
-pub struct NegativeFactorTest {
-    raw: [u8; 4],
+pub struct MsgWithoutSignalsRef<'a> {
+    raw: &'a [u8; 8],
 }
 
-impl NegativeFactorTest {
-    pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x540) });
-
-    pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MIN: i32 = -65535_i32;
-    pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MAX: i32 = 0_i32;
-    pub const WIDTH_MORE_THAN_MIN_MAX_MIN: i16 = -2_i16;
-    pub const WIDTH_MORE_THAN_MIN_MAX_MAX: i16 = 2_i16;
-
-    /// Construct new NegativeFactorTest from values
-    pub fn new(
-        unsigned_negative_factor_signal: i32,
-        width_more_than_min_max: i16,
-    ) -> Result<Self, CanError> {
-        let mut res = Self { raw: [0u8; 4] };
-        res.set_unsigned_negative_factor_signal(unsigned_negative_factor_signal)?;
-        res.set_width_more_than_min_max(width_more_than_min_max)?;
-        Ok(res)
+impl<'a> MsgWithoutSignalsRef<'a> {
+    /// View a payload buffer as MsgWithoutSignals
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
     }
 
     /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 4] {
-        &self.raw
-    }
-
-    /// UnsignedNegativeFactorSignal
-    ///
-    /// - Min: -65535
-    /// - Max: 0
-    /// - Unit: ""
-    /// - Receivers: Vector__XXX
-    #[inline(always)]
-    pub fn unsigned_negative_factor_signal(&self) -> i32 {
-        self.unsigned_negative_factor_signal_raw()
-    }
-
-    /// Get raw value of UnsignedNegativeFactorSignal
-    ///
-    /// - Start bit: 0
-    /// - Signal size: 16 bits
-    /// - Factor: -1
-    /// - Offset: 0
-    /// - Byte order: LittleEndian
-    /// - Value type: Unsigned
-    #[inline(always)]
-    pub fn unsigned_negative_factor_signal_raw(&self) -> i32 {
-        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<u16>();
-
-        let factor = -1;
-        i32::from(signal).saturating_mul(factor).saturating_add(0)
-    }
-
-    /// Set value of UnsignedNegativeFactorSignal
-    #[inline(always)]
-    pub fn set_unsigned_negative_factor_signal(&mut self, value: i32) -> Result<(), CanError> {
-        if value < -65535_i32 || 0_i32 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: NegativeFactorTest::MESSAGE_ID,
-            });
-        }
-        let factor = -1;
-        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: NegativeFactorTest::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as u16;
-
-        self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
-        Ok(())
-    }
-
-    /// WidthMoreThanMinMax
-    ///
-    /// - Min: -2
-    /// - Max: 2
-    /// - Unit: ""
-    /// - Receivers: Vector__XXX
-    #[inline(always)]
-    pub fn width_more_than_min_max(&self) -> i16 {
-        self.width_more_than_min_max_raw()
-    }
-
-    /// Get raw value of WidthMoreThanMinMax
-    ///
-    /// - Start bit: 16
-    /// - Signal size: 10 bits
-    /// - Factor: 1
-    /// - Offset: 0
-    /// - Byte order: LittleEndian
-    /// - Value type: Signed
-    #[inline(always)]
-    pub fn width_more_than_min_max_raw(&self) -> i16 {
-        let signal = self.raw.view_bits::<Lsb0>()[16..26].load_le::<i16>();
-
-        let factor = 1;
-        let signal = signal as i16;
-        i16::from(signal).saturating_mul(factor).saturating_add(0)
-    }
-
-    /// Set value of WidthMoreThanMinMax
-    #[inline(always)]
-    pub fn set_width_more_than_min_max(&mut self, value: i16) -> Result<(), CanError> {
-        if value < -2_i16 || 2_i16 < value {
-            return Err(CanError::ParameterOutOfRange {
-                message_id: NegativeFactorTest::MESSAGE_ID,
-            });
-        }
-        let factor = 1;
-        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: NegativeFactorTest::MESSAGE_ID,
-        })?;
-        let value = (value / factor) as i16;
-
-        let value = u16::from_ne_bytes(value.to_ne_bytes());
-        self.raw.view_bits_mut::<Lsb0>()[16..26].store_le(value);
-        Ok(())
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
     }
 }
 
-impl core::convert::TryFrom<&[u8]> for NegativeFactorTest {
+impl<'a> core::convert::TryFrom<&'a [u8]> for MsgWithoutSignalsRef<'a> {
     type Error = CanError;
 
     #[inline(always)]
-    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
-        if payload.len() != 4 {
-            return Err(CanError::InvalidPayloadSize);
-        }
-        let mut raw = [0u8; 4];
-        raw.copy_from_slice(&payload[..4]);
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
         Ok(Self { raw })
     }
-}
-
-impl embedded_can::Frame for NegativeFactorTest {
-    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
-        if id.into() != Self::MESSAGE_ID {
-            None
-        } else {
-            data.try_into().ok()
-        }
-    }
-
-    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
-        unimplemented!()
-    }
-
-    fn is_extended(&self) -> bool {
-        match self.id() {
-            Id::Standard(_) => false,
-            Id::Extended(_) => true,
-        }
-    }
+}
 
-    fn is_remote_frame(&self) -> bool {
-        false
+impl From<MsgWithoutSignalsRef<'_>> for MsgWithoutSignals {
+    fn from(view: MsgWithoutSignalsRef<'_>) -> Self {
+        Self { raw: *view.raw }
     }
+}
 
-    fn id(&self) -> Id {
-        Self::MESSAGE_ID
+/// Mutably borrowed view of a MsgWithoutSignals payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct MsgWithoutSignalsMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> MsgWithoutSignalsMut<'a> {
+    /// View a payload buffer as MsgWithoutSignals
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
     }
 
-    fn dlc(&self) -> usize {
-        self.raw.len()
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
     }
 
-    fn data(&self) -> &[u8] {
-        &self.raw
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
     }
 }
-impl core::fmt::Debug for NegativeFactorTest {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if f.alternate() {
-            f.debug_struct("NegativeFactorTest")
-                .field(
-                    "unsigned_negative_factor_signal",
-                    &self.unsigned_negative_factor_signal(),
-                )
-                .field("width_more_than_min_max", &self.width_more_than_min_max())
-                .finish()
-        } else {
-            f.debug_tuple("NegativeFactorTest")
-                .field(&self.raw)
-                .finish()
-        }
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for MsgWithoutSignalsMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
     }
 }
 
-impl defmt::Format for NegativeFactorTest {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(
-            f,
-            "NegativeFactorTest {{ UnsignedNegativeFactorSignal={:?} WidthMoreThanMinMax={:?} }}",
-            self.unsigned_negative_factor_signal(),
-            self.width_more_than_min_max(),
-        );
+impl From<MsgWithoutSignalsMut<'_>> for MsgWithoutSignals {
+    fn from(view: MsgWithoutSignalsMut<'_>) -> Self {
+        Self { raw: *view.raw }
     }
 }
 
-#[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for NegativeFactorTest {
-    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let unsigned_negative_factor_signal = u.int_in_range(-65535..=0)?;
-        let width_more_than_min_max = u.int_in_range(-2..=2)?;
-        NegativeFactorTest::new(unsigned_negative_factor_signal, width_more_than_min_max)
-            .map_err(|_| arbitrary::Error::IncorrectFormat)
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "MsgWithoutSignals")]
+    struct Signals {}
+
+    impl Serialize for MsgWithoutSignals {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {}.serialize(serializer)
+        }
     }
-}
 
-/// LargerIntsWithOffsets
+    impl<'de> Deserialize<'de> for MsgWithoutSignals {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let _signals = Signals::deserialize(deserializer)?;
+            MsgWithoutSignals::new().map_err(serde::de::Error::custom)
+        }
+    }
+};
+
+/// TruncatedBeSignal
 ///
-/// - Standard ID: 1338 (0x53a)
+/// - Standard ID: 9001 (0x2329)
 /// - Size: 8 bytes
-/// - Transmitter: Sit
-#[derive(Clone, Copy)]
-pub struct LargerIntsWithOffsets {
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct TruncatedBeSignal {
     raw: [u8; 8],
 }
 
-impl LargerIntsWithOffsets {
+impl TruncatedBeSignal {
     pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x53a) });
+        Id::Standard(unsafe { StandardId::new_unchecked(0x2329) });
 
-    pub const TWELVE_MIN: i16 = -1000_i16;
-    pub const TWELVE_MAX: i16 = 3000_i16;
-    pub const SIXTEEN_MIN: i32 = -1000_i32;
-    pub const SIXTEEN_MAX: i32 = 64535_i32;
+    pub const FOO_MIN: i16 = -100_i16;
+    pub const FOO_MAX: i16 = 100_i16;
 
-    /// Construct new LargerIntsWithOffsets from values
-    pub fn new(twelve: i16, sixteen: i32) -> Result<Self, CanError> {
+    /// Construct new TruncatedBeSignal from values
+    #[allow(clippy::disallowed_names)]
+    pub fn new(foo: i16) -> Result<Self, CanError> {
         let mut res = Self { raw: [0u8; 8] };
-        res.set_twelve(twelve)?;
-        res.set_sixteen(sixteen)?;
+        res.set_foo(foo)?;
         Ok(res)
     }
 
+    /// Construct new TruncatedBeSignal from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    #[allow(clippy::disallowed_names)]
+    pub const fn new_const(foo: i16) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_foo(foo) {
+            Ok(res) => res,
+            Err(_) => panic!("Foo is out of range"),
+        };
+        res
+    }
+
     /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 8] {
+    pub const fn raw(&self) -> &[u8; 8] {
         &self.raw
     }
 
-    /// Twelve
+    /// Borrow the payload as a [`TruncatedBeSignalRef`]
+    pub fn view(&self) -> TruncatedBeSignalRef<'_> {
+        TruncatedBeSignalRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`TruncatedBeSignalMut`]
+    pub fn view_mut(&mut self) -> TruncatedBeSignalMut<'_> {
+        TruncatedBeSignalMut { raw: &mut self.raw }
+    }
+
+    /// Foo
     ///
-    /// - Min: -1000
-    /// - Max: 3000
+    /// - Min: -100
+    /// - Max: 100
     /// - Unit: ""
-    /// - Receivers: XXX
+    /// - Receivers: Vector__XXX
     #[inline(always)]
-    pub fn twelve(&self) -> i16 {
-        self.twelve_raw()
+    pub fn foo(&self) -> i16 {
+        self.foo_raw()
     }
 
-    /// Get raw value of Twelve
+    /// Get raw value of Foo
     ///
     /// - Start bit: 0
     /// - Signal size: 12 bits
     /// - Factor: 1
-    /// - Offset: -1000
-    /// - Byte order: LittleEndian
-    /// - Value type: Unsigned
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Signed
     #[inline(always)]
-    pub fn twelve_raw(&self) -> i16 {
-        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<u16>();
+    pub fn foo_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<i16>();
 
         let factor = 1;
         let signal = signal as i16;
-        i16::from(signal)
-            .saturating_mul(factor)
-            .saturating_sub(1000)
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Set value of Twelve
+    /// Set value of Foo
     #[inline(always)]
-    pub fn set_twelve(&mut self, value: i16) -> Result<(), CanError> {
-        if value < -1000_i16 || 3000_i16 < value {
+    pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -100_i16 || 100_i16 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                message_id: TruncatedBeSignal::MESSAGE_ID,
             });
         }
         let factor = 1;
-        let value = value
-            .checked_add(1000)
-            .ok_or(CanError::ParameterOutOfRange {
-                message_id: LargerIntsWithOffsets::MESSAGE_ID,
-            })?;
-        let value = (value / factor) as u16;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: TruncatedBeSignal::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i16;
 
-        self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
         Ok(())
     }
 
-    /// Sixteen
-    ///
-    /// - Min: -1000
-    /// - Max: 64535
-    /// - Unit: ""
-    /// - Receivers: XXX
-    #[inline(always)]
-    pub fn sixteen(&self) -> i32 {
-        self.sixteen_raw()
-    }
-
-    /// Get raw value of Sixteen
-    ///
-    /// - Start bit: 12
-    /// - Signal size: 16 bits
-    /// - Factor: 1
-    /// - Offset: -1000
-    /// - Byte order: LittleEndian
-    /// - Value type: Unsigned
+    /// Get raw value of Foo in a `const` context
     #[inline(always)]
-    pub fn sixteen_raw(&self) -> i32 {
-        let signal = self.raw.view_bits::<Lsb0>()[12..28].load_le::<u16>();
+    pub const fn foo_raw_const(&self) -> i16 {
+        let mut signal = 0u16;
+        signal |= ((self.raw[0] & 0x01) as u16) << 11;
+        signal |= (self.raw[1] as u16) << 3;
+        signal |= ((self.raw[2] >> 5) & 0x07) as u16;
+        let signal = ((signal << 4) as i16) >> 4;
 
         let factor = 1;
-        i32::from(signal)
-            .saturating_mul(factor)
-            .saturating_sub(1000)
+        (signal as i16).saturating_mul(factor).saturating_add(0)
     }
 
-    /// Set value of Sixteen
+    /// Set value of Foo in a `const` context
     #[inline(always)]
-    pub fn set_sixteen(&mut self, value: i32) -> Result<(), CanError> {
-        if value < -1000_i32 || 64535_i32 < value {
+    pub const fn with_foo(self, value: i16) -> Result<Self, CanError> {
+        if value < -100_i16 || 100_i16 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+                message_id: TruncatedBeSignal::MESSAGE_ID,
             });
         }
         let factor = 1;
-        let value = value
-            .checked_add(1000)
-            .ok_or(CanError::ParameterOutOfRange {
-                message_id: LargerIntsWithOffsets::MESSAGE_ID,
-            })?;
-        let value = (value / factor) as u16;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: TruncatedBeSignal::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as i16;
 
-        self.raw.view_bits_mut::<Lsb0>()[12..28].store_le(value);
-        Ok(())
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        let mut raw = self.raw;
+        raw[0] = (raw[0] & 0xfe) | (((value >> 11) as u8) & 0x01);
+        raw[1] = (value >> 3) as u8;
+        raw[2] = (raw[2] & 0x1f) | (((value as u8) << 5) & 0xe0);
+        Ok(Self { raw })
     }
 }
 
-impl core::convert::TryFrom<&[u8]> for LargerIntsWithOffsets {
+impl core::convert::TryFrom<&[u8]> for TruncatedBeSignal {
     type Error = CanError;
 
     #[inline(always)]
@@ -2595,7 +8408,7 @@ impl core::convert::TryFrom<&[u8]> for LargerIntsWithOffsets {
     }
 }
 
-impl embedded_can::Frame for LargerIntsWithOffsets {
+impl embedded_can::Frame for TruncatedBeSignal {
     fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
         if id.into() != Self::MESSAGE_ID {
             None
@@ -2631,169 +8444,278 @@ impl embedded_can::Frame for LargerIntsWithOffsets {
         &self.raw
     }
 }
-impl core::fmt::Debug for LargerIntsWithOffsets {
+impl core::fmt::Debug for TruncatedBeSignal {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if f.alternate() {
-            f.debug_struct("LargerIntsWithOffsets")
-                .field("twelve", &self.twelve())
-                .field("sixteen", &self.sixteen())
+            f.debug_struct("TruncatedBeSignal")
+                .field("foo", &self.foo())
                 .finish()
         } else {
-            f.debug_tuple("LargerIntsWithOffsets")
-                .field(&self.raw)
-                .finish()
+            f.debug_tuple("TruncatedBeSignal").field(&self.raw).finish()
         }
     }
 }
 
-impl defmt::Format for LargerIntsWithOffsets {
+impl defmt::Format for TruncatedBeSignal {
     fn format(&self, f: defmt::Formatter) {
-        defmt::write!(
-            f,
-            "LargerIntsWithOffsets {{ Twelve={:?} Sixteen={:?} }}",
-            self.twelve(),
-            self.sixteen(),
-        );
+        defmt::write!(f, "TruncatedBeSignal {{ Foo={:?} }}", self.foo(),);
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for TruncatedBeSignal {
+    #[allow(clippy::disallowed_names)]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let foo = u.int_in_range(-100..=100)?;
+        TruncatedBeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Borrowed view of a TruncatedBeSignal payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct TruncatedBeSignalRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> TruncatedBeSignalRef<'a> {
+    /// View a payload buffer as TruncatedBeSignal
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Foo
+    ///
+    /// - Min: -100
+    /// - Max: 100
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn foo(&self) -> i16 {
+        self.foo_raw()
+    }
+
+    /// Get raw value of Foo
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn foo_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
     }
 }
 
-#[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for LargerIntsWithOffsets {
-    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let twelve = u.int_in_range(-1000..=3000)?;
-        let sixteen = u.int_in_range(-1000..=64535)?;
-        LargerIntsWithOffsets::new(twelve, sixteen).map_err(|_| arbitrary::Error::IncorrectFormat)
+impl<'a> core::convert::TryFrom<&'a [u8]> for TruncatedBeSignalRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
     }
 }
 
-/// MsgWithoutSignals
-///
-/// - Standard ID: 513 (0x201)
-/// - Size: 8 bytes
-/// - Transmitter: Ipsum
-#[derive(Clone, Copy)]
-pub struct MsgWithoutSignals {
-    raw: [u8; 8],
+impl From<TruncatedBeSignalRef<'_>> for TruncatedBeSignal {
+    fn from(view: TruncatedBeSignalRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
 }
 
-impl MsgWithoutSignals {
-    pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x201) });
+/// Mutably borrowed view of a TruncatedBeSignal payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct TruncatedBeSignalMut<'a> {
+    raw: &'a mut [u8; 8],
+}
 
-    /// Construct new MsgWithoutSignals from values
-    pub fn new() -> Result<Self, CanError> {
-        let res = Self { raw: [0u8; 8] };
-        Ok(res)
+impl<'a> TruncatedBeSignalMut<'a> {
+    /// View a payload buffer as TruncatedBeSignal
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
     }
 
     /// Access message payload raw value
     pub fn raw(&self) -> &[u8; 8] {
-        &self.raw
+        self.raw
     }
-}
 
-impl core::convert::TryFrom<&[u8]> for MsgWithoutSignals {
-    type Error = CanError;
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
 
+    /// Foo
+    ///
+    /// - Min: -100
+    /// - Max: 100
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
     #[inline(always)]
-    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
-        if payload.len() != 8 {
-            return Err(CanError::InvalidPayloadSize);
-        }
-        let mut raw = [0u8; 8];
-        raw.copy_from_slice(&payload[..8]);
-        Ok(Self { raw })
+    pub fn foo(&self) -> i16 {
+        self.foo_raw()
     }
-}
 
-impl embedded_can::Frame for MsgWithoutSignals {
-    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
-        if id.into() != Self::MESSAGE_ID {
-            None
-        } else {
-            data.try_into().ok()
-        }
-    }
+    /// Get raw value of Foo
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn foo_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<i16>();
 
-    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
-        unimplemented!()
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
     }
 
-    fn is_extended(&self) -> bool {
-        match self.id() {
-            Id::Standard(_) => false,
-            Id::Extended(_) => true,
+    /// Set value of Foo
+    #[inline(always)]
+    pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -100_i16 || 100_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: TruncatedBeSignal::MESSAGE_ID,
+            });
         }
-    }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: TruncatedBeSignal::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i16;
 
-    fn is_remote_frame(&self) -> bool {
-        false
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+        Ok(())
     }
+}
 
-    fn id(&self) -> Id {
-        Self::MESSAGE_ID
-    }
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for TruncatedBeSignalMut<'a> {
+    type Error = CanError;
 
-    fn dlc(&self) -> usize {
-        self.raw.len()
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
     }
+}
 
-    fn data(&self) -> &[u8] {
-        &self.raw
+impl From<TruncatedBeSignalMut<'_>> for TruncatedBeSignal {
+    fn from(view: TruncatedBeSignalMut<'_>) -> Self {
+        Self { raw: *view.raw }
     }
 }
-impl core::fmt::Debug for MsgWithoutSignals {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if f.alternate() {
-            f.debug_struct("MsgWithoutSignals").finish()
-        } else {
-            f.debug_tuple("MsgWithoutSignals").field(&self.raw).finish()
-        }
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "TruncatedBeSignal")]
+    struct Signals {
+        #[serde(rename = "Foo")]
+        foo: i16,
     }
-}
 
-impl defmt::Format for MsgWithoutSignals {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "MsgWithoutSignals {{ }}",);
+    impl Serialize for TruncatedBeSignal {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals { foo: self.foo() }.serialize(serializer)
+        }
     }
-}
 
-#[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for MsgWithoutSignals {
-    fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        MsgWithoutSignals::new().map_err(|_| arbitrary::Error::IncorrectFormat)
+    impl<'de> Deserialize<'de> for TruncatedBeSignal {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            TruncatedBeSignal::new(signals.foo).map_err(serde::de::Error::custom)
+        }
     }
-}
+};
 
-/// TruncatedBeSignal
+/// TruncatedLeSignal
 ///
-/// - Standard ID: 9001 (0x2329)
+/// - Standard ID: 9002 (0x232a)
 /// - Size: 8 bytes
 /// - Transmitter: Ipsum
-#[derive(Clone, Copy)]
-pub struct TruncatedBeSignal {
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
+pub struct TruncatedLeSignal {
     raw: [u8; 8],
 }
 
-impl TruncatedBeSignal {
+impl TruncatedLeSignal {
     pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x2329) });
+        Id::Standard(unsafe { StandardId::new_unchecked(0x232a) });
 
     pub const FOO_MIN: i16 = -100_i16;
     pub const FOO_MAX: i16 = 100_i16;
 
-    /// Construct new TruncatedBeSignal from values
+    /// Construct new TruncatedLeSignal from values
+    #[allow(clippy::disallowed_names)]
     pub fn new(foo: i16) -> Result<Self, CanError> {
         let mut res = Self { raw: [0u8; 8] };
         res.set_foo(foo)?;
         Ok(res)
     }
 
+    /// Construct new TruncatedLeSignal from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    #[allow(clippy::disallowed_names)]
+    pub const fn new_const(foo: i16) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_foo(foo) {
+            Ok(res) => res,
+            Err(_) => panic!("Foo is out of range"),
+        };
+        res
+    }
+
     /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 8] {
+    pub const fn raw(&self) -> &[u8; 8] {
         &self.raw
     }
 
+    /// Borrow the payload as a [`TruncatedLeSignalRef`]
+    pub fn view(&self) -> TruncatedLeSignalRef<'_> {
+        TruncatedLeSignalRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`TruncatedLeSignalMut`]
+    pub fn view_mut(&mut self) -> TruncatedLeSignalMut<'_> {
+        TruncatedLeSignalMut { raw: &mut self.raw }
+    }
+
     /// Foo
     ///
     /// - Min: -100
@@ -2811,11 +8733,11 @@ impl TruncatedBeSignal {
     /// - Signal size: 12 bits
     /// - Factor: 1
     /// - Offset: 0
-    /// - Byte order: BigEndian
+    /// - Byte order: LittleEndian
     /// - Value type: Signed
     #[inline(always)]
     pub fn foo_raw(&self) -> i16 {
-        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<i16>();
+        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<i16>();
 
         let factor = 1;
         let signal = signal as i16;
@@ -2827,22 +8749,60 @@ impl TruncatedBeSignal {
     pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
         if value < -100_i16 || 100_i16 < value {
             return Err(CanError::ParameterOutOfRange {
-                message_id: TruncatedBeSignal::MESSAGE_ID,
+                message_id: TruncatedLeSignal::MESSAGE_ID,
             });
         }
         let factor = 1;
         let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
-            message_id: TruncatedBeSignal::MESSAGE_ID,
+            message_id: TruncatedLeSignal::MESSAGE_ID,
         })?;
         let value = (value / factor) as i16;
 
         let value = u16::from_ne_bytes(value.to_ne_bytes());
-        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+        self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
         Ok(())
     }
+
+    /// Get raw value of Foo in a `const` context
+    #[inline(always)]
+    pub const fn foo_raw_const(&self) -> i16 {
+        let mut signal = 0u16;
+        signal |= self.raw[0] as u16;
+        signal |= ((self.raw[1] & 0x0f) as u16) << 8;
+        let signal = ((signal << 4) as i16) >> 4;
+
+        let factor = 1;
+        (signal as i16).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Foo in a `const` context
+    #[inline(always)]
+    pub const fn with_foo(self, value: i16) -> Result<Self, CanError> {
+        if value < -100_i16 || 100_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: TruncatedLeSignal::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: TruncatedLeSignal::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        let mut raw = self.raw;
+        raw[0] = value as u8;
+        raw[1] = (raw[1] & 0xf0) | (((value >> 8) as u8) & 0x0f);
+        Ok(Self { raw })
+    }
 }
 
-impl core::convert::TryFrom<&[u8]> for TruncatedBeSignal {
+impl core::convert::TryFrom<&[u8]> for TruncatedLeSignal {
     type Error = CanError;
 
     #[inline(always)]
@@ -2856,7 +8816,7 @@ impl core::convert::TryFrom<&[u8]> for TruncatedBeSignal {
     }
 }
 
-impl embedded_can::Frame for TruncatedBeSignal {
+impl embedded_can::Frame for TruncatedLeSignal {
     fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
         if id.into() != Self::MESSAGE_ID {
             None
@@ -2892,59 +8852,120 @@ impl embedded_can::Frame for TruncatedBeSignal {
         &self.raw
     }
 }
-impl core::fmt::Debug for TruncatedBeSignal {
+impl core::fmt::Debug for TruncatedLeSignal {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if f.alternate() {
-            f.debug_struct("TruncatedBeSignal")
+            f.debug_struct("TruncatedLeSignal")
                 .field("foo", &self.foo())
                 .finish()
         } else {
-            f.debug_tuple("TruncatedBeSignal").field(&self.raw).finish()
+            f.debug_tuple("TruncatedLeSignal").field(&self.raw).finish()
         }
     }
 }
 
-impl defmt::Format for TruncatedBeSignal {
+impl defmt::Format for TruncatedLeSignal {
     fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "TruncatedBeSignal {{ Foo={:?} }}", self.foo(),);
+        defmt::write!(f, "TruncatedLeSignal {{ Foo={:?} }}", self.foo(),);
     }
 }
 
 #[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for TruncatedBeSignal {
+impl<'a> Arbitrary<'a> for TruncatedLeSignal {
+    #[allow(clippy::disallowed_names)]
     fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
         let foo = u.int_in_range(-100..=100)?;
-        TruncatedBeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+        TruncatedLeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
     }
 }
 
-/// TruncatedLeSignal
+/// Borrowed view of a TruncatedLeSignal payload
 ///
-/// - Standard ID: 9002 (0x232a)
-/// - Size: 8 bytes
-/// - Transmitter: Ipsum
+/// Accesses signals in place, without copying the payload.
 #[derive(Clone, Copy)]
-pub struct TruncatedLeSignal {
-    raw: [u8; 8],
+pub struct TruncatedLeSignalRef<'a> {
+    raw: &'a [u8; 8],
 }
 
-impl TruncatedLeSignal {
-    pub const MESSAGE_ID: embedded_can::Id =
-        Id::Standard(unsafe { StandardId::new_unchecked(0x232a) });
+impl<'a> TruncatedLeSignalRef<'a> {
+    /// View a payload buffer as TruncatedLeSignal
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
 
-    pub const FOO_MIN: i16 = -100_i16;
-    pub const FOO_MAX: i16 = 100_i16;
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Foo
+    ///
+    /// - Min: -100
+    /// - Max: 100
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn foo(&self) -> i16 {
+        self.foo_raw()
+    }
+
+    /// Get raw value of Foo
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn foo_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for TruncatedLeSignalRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<TruncatedLeSignalRef<'_>> for TruncatedLeSignal {
+    fn from(view: TruncatedLeSignalRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a TruncatedLeSignal payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct TruncatedLeSignalMut<'a> {
+    raw: &'a mut [u8; 8],
+}
 
-    /// Construct new TruncatedLeSignal from values
-    pub fn new(foo: i16) -> Result<Self, CanError> {
-        let mut res = Self { raw: [0u8; 8] };
-        res.set_foo(foo)?;
-        Ok(res)
+impl<'a> TruncatedLeSignalMut<'a> {
+    /// View a payload buffer as TruncatedLeSignal
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
     }
 
     /// Access message payload raw value
     pub fn raw(&self) -> &[u8; 8] {
-        &self.raw
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
     }
 
     /// Foo
@@ -2995,88 +9016,65 @@ impl TruncatedLeSignal {
     }
 }
 
-impl core::convert::TryFrom<&[u8]> for TruncatedLeSignal {
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for TruncatedLeSignalMut<'a> {
     type Error = CanError;
 
     #[inline(always)]
-    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
-        if payload.len() != 8 {
-            return Err(CanError::InvalidPayloadSize);
-        }
-        let mut raw = [0u8; 8];
-        raw.copy_from_slice(&payload[..8]);
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
         Ok(Self { raw })
     }
 }
 
-impl embedded_can::Frame for TruncatedLeSignal {
-    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
-        if id.into() != Self::MESSAGE_ID {
-            None
-        } else {
-            data.try_into().ok()
-        }
+impl From<TruncatedLeSignalMut<'_>> for TruncatedLeSignal {
+    fn from(view: TruncatedLeSignalMut<'_>) -> Self {
+        Self { raw: *view.raw }
     }
+}
 
-    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
-        unimplemented!()
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "TruncatedLeSignal")]
+    struct Signals {
+        #[serde(rename = "Foo")]
+        foo: i16,
     }
 
-    fn is_extended(&self) -> bool {
-        match self.id() {
-            Id::Standard(_) => false,
-            Id::Extended(_) => true,
+    impl Serialize for TruncatedLeSignal {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals { foo: self.foo() }.serialize(serializer)
         }
     }
 
-    fn is_remote_frame(&self) -> bool {
-        false
-    }
-
-    fn id(&self) -> Id {
-        Self::MESSAGE_ID
-    }
-
-    fn dlc(&self) -> usize {
-        self.raw.len()
-    }
-
-    fn data(&self) -> &[u8] {
-        &self.raw
-    }
-}
-impl core::fmt::Debug for TruncatedLeSignal {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if f.alternate() {
-            f.debug_struct("TruncatedLeSignal")
-                .field("foo", &self.foo())
-                .finish()
-        } else {
-            f.debug_tuple("TruncatedLeSignal").field(&self.raw).finish()
+    impl<'de> Deserialize<'de> for TruncatedLeSignal {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            TruncatedLeSignal::new(signals.foo).map_err(serde::de::Error::custom)
         }
     }
-}
-
-impl defmt::Format for TruncatedLeSignal {
-    fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "TruncatedLeSignal {{ Foo={:?} }}", self.foo(),);
-    }
-}
-
-#[cfg(feature = "arb")]
-impl<'a> Arbitrary<'a> for TruncatedLeSignal {
-    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
-        let foo = u.int_in_range(-100..=100)?;
-        TruncatedLeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
-    }
-}
+};
 
 /// MsgExtendedId
 ///
 /// - Extended ID: 4660 (0x1234)
 /// - Size: 8 bytes
 /// - Transmitter: Sit
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::KnownLayout,
+        zerocopy::Immutable
+    )
+)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[derive(Ord)]
+#[cfg_attr(any(feature = "zerocopy", feature = "bytemuck"), repr(transparent))]
 pub struct MsgExtendedId {
     raw: [u8; 8],
 }
@@ -3095,11 +9093,35 @@ impl MsgExtendedId {
         Ok(res)
     }
 
+    /// Construct new MsgExtendedId from values in a `const` context
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value is out of range. In a `const` item this is a compile error.
+    pub const fn new_const(dummy: u8) -> Self {
+        let res = Self { raw: [0u8; 8] };
+        let res = match res.with_dummy(dummy) {
+            Ok(res) => res,
+            Err(_) => panic!("Dummy is out of range"),
+        };
+        res
+    }
+
     /// Access message payload raw value
-    pub fn raw(&self) -> &[u8; 8] {
+    pub const fn raw(&self) -> &[u8; 8] {
         &self.raw
     }
 
+    /// Borrow the payload as a [`MsgExtendedIdRef`]
+    pub fn view(&self) -> MsgExtendedIdRef<'_> {
+        MsgExtendedIdRef { raw: &self.raw }
+    }
+
+    /// Mutably borrow the payload as a [`MsgExtendedIdMut`]
+    pub fn view_mut(&mut self) -> MsgExtendedIdMut<'_> {
+        MsgExtendedIdMut { raw: &mut self.raw }
+    }
+
     /// Dummy
     ///
     /// - Min: 0
@@ -3144,6 +9166,40 @@ impl MsgExtendedId {
         self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
         Ok(())
     }
+
+    /// Get raw value of Dummy in a `const` context
+    #[inline(always)]
+    pub const fn dummy_raw_const(&self) -> u8 {
+        let mut signal = 0u8;
+        signal |= ((self.raw[1] >> 6) & 0x03) as u8;
+
+        let factor = 1;
+        (signal as u8).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Dummy in a `const` context
+    #[inline(always)]
+    pub const fn with_dummy(self, value: u8) -> Result<Self, CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MsgExtendedId::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = match value.checked_sub(0) {
+            Some(value) => value,
+            None => {
+                return Err(CanError::ParameterOutOfRange {
+                    message_id: MsgExtendedId::MESSAGE_ID,
+                })
+            }
+        };
+        let value = (value / factor) as u8;
+
+        let mut raw = self.raw;
+        raw[1] = (raw[1] & 0x3f) | (((value as u8) << 6) & 0xc0);
+        Ok(Self { raw })
+    }
 }
 
 impl core::convert::TryFrom<&[u8]> for MsgExtendedId {
@@ -3222,11 +9278,259 @@ impl<'a> Arbitrary<'a> for MsgExtendedId {
     }
 }
 
+/// Borrowed view of a MsgExtendedId payload
+///
+/// Accesses signals in place, without copying the payload.
+#[derive(Clone, Copy)]
+pub struct MsgExtendedIdRef<'a> {
+    raw: &'a [u8; 8],
+}
+
+impl<'a> MsgExtendedIdRef<'a> {
+    /// View a payload buffer as MsgExtendedId
+    pub fn from_raw(raw: &'a [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Dummy
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: XXX
+    #[inline(always)]
+    pub fn dummy(&self) -> u8 {
+        self.dummy_raw()
+    }
+
+    /// Get raw value of Dummy
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn dummy_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a [u8]> for MsgExtendedIdRef<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<MsgExtendedIdRef<'_>> for MsgExtendedId {
+    fn from(view: MsgExtendedIdRef<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+/// Mutably borrowed view of a MsgExtendedId payload
+///
+/// Accesses signals in place, without copying the payload.
+pub struct MsgExtendedIdMut<'a> {
+    raw: &'a mut [u8; 8],
+}
+
+impl<'a> MsgExtendedIdMut<'a> {
+    /// View a payload buffer as MsgExtendedId
+    pub fn from_raw(raw: &'a mut [u8; 8]) -> Self {
+        Self { raw }
+    }
+
+    /// Access message payload raw value
+    pub fn raw(&self) -> &[u8; 8] {
+        self.raw
+    }
+
+    /// Mutably access message payload raw value
+    pub fn raw_mut(&mut self) -> &mut [u8; 8] {
+        self.raw
+    }
+
+    /// Dummy
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: XXX
+    #[inline(always)]
+    pub fn dummy(&self) -> u8 {
+        self.dummy_raw()
+    }
+
+    /// Get raw value of Dummy
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn dummy_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Dummy
+    #[inline(always)]
+    pub fn set_dummy(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MsgExtendedId::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MsgExtendedId::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+        Ok(())
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a mut [u8]> for MsgExtendedIdMut<'a> {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &'a mut [u8]) -> Result<Self, Self::Error> {
+        let raw = payload
+            .try_into()
+            .map_err(|_| CanError::InvalidPayloadSize)?;
+        Ok(Self { raw })
+    }
+}
+
+impl From<MsgExtendedIdMut<'_>> for MsgExtendedId {
+    fn from(view: MsgExtendedIdMut<'_>) -> Self {
+        Self { raw: *view.raw }
+    }
+}
+
+#[cfg(feature = "serde")]
+const _: () = {
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "MsgExtendedId")]
+    struct Signals {
+        #[serde(rename = "Dummy")]
+        dummy: u8,
+    }
+
+    impl Serialize for MsgExtendedId {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Signals {
+                dummy: self.dummy(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MsgExtendedId {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let signals = Signals::deserialize(deserializer)?;
+            MsgExtendedId::new(signals.dummy).map_err(serde::de::Error::custom)
+        }
+    }
+};
+
+/// Defined values for value table OnOff
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Debug, defmt::Format)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::KnownLayout))]
+pub enum OnOff {
+    #[cfg_attr(feature = "serde", serde(rename = "On"))]
+    On,
+    #[cfg_attr(feature = "serde", serde(rename = "Off"))]
+    Off,
+}
+
+impl OnOff {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::On, Self::Off];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: bool) -> Option<Self> {
+        match raw {
+            true => Some(Self::On),
+            false => Some(Self::Off),
+            _ => None,
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::On => "On",
+            Self::Off => "Off",
+        }
+    }
+}
+
+impl core::convert::TryFrom<bool> for OnOff {
+    type Error = CanError;
+
+    fn try_from(raw: bool) -> Result<Self, Self::Error> {
+        Self::from_raw(raw).ok_or(CanError::UndefinedValue)
+    }
+}
+
+impl From<OnOff> for bool {
+    fn from(val: OnOff) -> bool {
+        match val {
+            OnOff::On => true,
+            OnOff::Off => false,
+        }
+    }
+}
+
+impl core::fmt::Display for OnOff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for OnOff {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == s)
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
 /// This is just to make testing easier
 #[allow(dead_code)]
 fn main() {}
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Hash, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CanError {
     UnknownMessageId(embedded_can::Id),
     /// Signal parameter is not within the range
@@ -3243,6 +9547,8 @@ pub enum CanError {
         /// Multiplexor value not defined in the dbc
         multiplexor: u16,
     },
+    /// Value or label not defined in the value table
+    UndefinedValue,
 }
 
 impl core::fmt::Display for CanError {
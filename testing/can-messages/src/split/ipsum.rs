@@ -0,0 +1,1242 @@
+// Generated code!
+
+use super::*;
+
+/// Bar
+///
+/// - Standard ID: 512 (0x200)
+/// - Size: 8 bytes
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Bar {
+    raw: [u8; 8],
+}
+
+impl Bar {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x200) });
+
+    pub const ONE_MIN: u8 = 0_u8;
+    pub const ONE_MAX: u8 = 3_u8;
+    pub const TWO_MIN: f32 = 0_f32;
+    pub const TWO_MAX: f32 = 100_f32;
+    pub const THREE_MIN: u8 = 0_u8;
+    pub const THREE_MAX: u8 = 7_u8;
+    pub const FOUR_MIN: u8 = 0_u8;
+    pub const FOUR_MAX: u8 = 3_u8;
+
+    /// Construct new Bar from values
+    pub fn new(
+        one: u8,
+        two: f32,
+        three: BarThree,
+        four: BarFour,
+        xtype: BarType,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one(one)?;
+        res.set_two(two)?;
+        res.set_three(three)?;
+        res.set_four(four)?;
+        res.set_xtype(xtype)?;
+        Ok(res)
+    }
+
+    /// Construct new Bar from raw values
+    pub fn new_raw(one: u8, two: f32, three: u8, four: u8, xtype: bool) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one(one)?;
+        res.set_two(two)?;
+        res.set_three_raw(three)?;
+        res.set_four_raw(four)?;
+        res.set_xtype_raw(xtype)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// One
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn one(&self) -> u8 {
+        self.one_raw()
+    }
+
+    /// Get raw value of One
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of One
+    #[inline(always)]
+    pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Bar::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+        Ok(())
+    }
+
+    /// Two
+    ///
+    /// - Min: 0
+    /// - Max: 100
+    /// - Unit: "%"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn two(&self) -> f32 {
+        self.two_raw()
+    }
+
+    /// Get raw value of Two
+    ///
+    /// - Start bit: 7
+    /// - Signal size: 8 bits
+    /// - Factor: 0.39
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn two_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of Two
+    #[inline(always)]
+    pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 100_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            });
+        }
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
+        Ok(())
+    }
+
+    /// Three
+    ///
+    /// - Min: 0
+    /// - Max: 7
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn three(&self) -> BarThree {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+        match signal {
+            0 => BarThree::Off,
+            1 => BarThree::On,
+            2 => BarThree::Oner,
+            3 => BarThree::Onest,
+            _ => BarThree::_Other(self.three_raw()),
+        }
+    }
+
+    /// Get raw value of Three
+    ///
+    /// - Start bit: 13
+    /// - Signal size: 3 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn three_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Three
+    #[inline(always)]
+    pub fn set_three(&mut self, value: BarThree) -> Result<(), CanError> {
+        self.set_three_raw(value.into())
+    }
+
+    /// Set raw value of Three
+    #[inline(always)]
+    pub fn set_three_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 7_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Bar::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
+        Ok(())
+    }
+
+    /// Four
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
+    }
+
+    /// Get raw value of Four
+    ///
+    /// - Start bit: 10
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn four_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[13..15].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Four
+    #[inline(always)]
+    pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+        self.set_four_raw(value.into())
+    }
+
+    /// Set raw value of Four
+    #[inline(always)]
+    pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Bar::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Bar::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[13..15].store_be(value);
+        Ok(())
+    }
+
+    /// Type
+    ///
+    /// - Min: 0
+    /// - Max: 1
+    /// - Unit: "boolean"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn xtype(&self) -> BarType {
+        let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
+
+        match signal {
+            0 => BarType::X0off,
+            _ => BarType::X1on,
+        }
+    }
+
+    /// Get raw value of Type
+    ///
+    /// - Start bit: 30
+    /// - Signal size: 1 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn xtype_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Msb0>()[25..26].load_be::<u8>();
+
+        signal == 1
+    }
+
+    /// Set value of Type
+    #[inline(always)]
+    pub fn set_xtype(&mut self, value: BarType) -> Result<(), CanError> {
+        self.set_xtype_raw(value.into())
+    }
+
+    /// Set raw value of Type
+    #[inline(always)]
+    pub fn set_xtype_raw(&mut self, value: bool) -> Result<(), CanError> {
+        let value = value as u8;
+        self.raw.view_bits_mut::<Msb0>()[25..26].store_be(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for Bar {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for Bar {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for Bar {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Bar")
+                .field("one", &self.one())
+                .field("two", &self.two())
+                .field("three", &self.three())
+                .field("four", &self.four())
+                .field("xtype", &self.xtype())
+                .finish()
+        } else {
+            f.debug_tuple("Bar").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for Bar {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let one = u.int_in_range(0..=3)?;
+        let two = u.float_in_range(0_f32..=100_f32)?;
+        let three = u.int_in_range(0..=7)?;
+        let four = u.int_in_range(0..=3)?;
+        let xtype = u.int_in_range(0..=1)? == 1;
+        Bar::new_raw(one, two, three, four, xtype).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Defined values for Three
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BarThree {
+    Off,
+    On,
+    Oner,
+    Onest,
+    _Other(u8),
+}
+
+impl BarThree {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Off,
+            1 => Self::On,
+            2 => Self::Oner,
+            3 => Self::Onest,
+            x => Self::_Other(x),
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    ///
+    /// Returns `None` for values without a label.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Off => Some("OFF"),
+            Self::On => Some("ON"),
+            Self::Oner => Some("ONER"),
+            Self::Onest => Some("ONEST"),
+            Self::_Other(_) => None,
+        }
+    }
+}
+
+impl From<u8> for BarThree {
+    fn from(raw: u8) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<BarThree> for u8 {
+    fn from(val: BarThree) -> u8 {
+        match val {
+            BarThree::Off => 0,
+            BarThree::On => 1,
+            BarThree::Oner => 2,
+            BarThree::Onest => 3,
+            BarThree::_Other(x) => x,
+        }
+    }
+}
+
+impl core::fmt::Display for BarThree {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "{}", u8::from(*self)),
+        }
+    }
+}
+
+impl core::str::FromStr for BarThree {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == Some(s))
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// Defined values for Four
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BarFour {
+    Off,
+    On,
+    Oner,
+    Onest,
+}
+
+impl BarFour {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Off, Self::On, Self::Oner, Self::Onest];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: u8) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Off),
+            1 => Some(Self::On),
+            2 => Some(Self::Oner),
+            3 => Some(Self::Onest),
+            _ => None,
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::On => "On",
+            Self::Oner => "Oner",
+            Self::Onest => "Onest",
+        }
+    }
+}
+
+impl core::convert::TryFrom<u8> for BarFour {
+    type Error = CanError;
+
+    fn try_from(raw: u8) -> Result<Self, Self::Error> {
+        Self::from_raw(raw).ok_or(CanError::UndefinedValue)
+    }
+}
+
+impl From<BarFour> for u8 {
+    fn from(val: BarFour) -> u8 {
+        match val {
+            BarFour::Off => 0,
+            BarFour::On => 1,
+            BarFour::Oner => 2,
+            BarFour::Onest => 3,
+        }
+    }
+}
+
+impl core::fmt::Display for BarFour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for BarFour {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == s)
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// Defined values for Type
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BarType {
+    X0off,
+    X1on,
+}
+
+impl BarType {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::X0off, Self::X1on];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: bool) -> Option<Self> {
+        match raw {
+            false => Some(Self::X0off),
+            true => Some(Self::X1on),
+            _ => None,
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::X0off => "0Off",
+            Self::X1on => "1On",
+        }
+    }
+}
+
+impl core::convert::TryFrom<bool> for BarType {
+    type Error = CanError;
+
+    fn try_from(raw: bool) -> Result<Self, Self::Error> {
+        Self::from_raw(raw).ok_or(CanError::UndefinedValue)
+    }
+}
+
+impl From<BarType> for bool {
+    fn from(val: BarType) -> bool {
+        match val {
+            BarType::X0off => false,
+            BarType::X1on => true,
+        }
+    }
+}
+
+impl core::fmt::Display for BarType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for BarType {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == s)
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// _4WD
+///
+/// - Standard ID: 768 (0x300)
+/// - Size: 8 bytes
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct X4wd {
+    raw: [u8; 8],
+}
+
+impl X4wd {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x300) });
+
+    pub const X4DRIVE_MIN: u8 = 0_u8;
+    pub const X4DRIVE_MAX: u8 = 7_u8;
+
+    /// Construct new _4WD from values
+    pub fn new(x4drive: X4wd4drive) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_x4drive(x4drive)?;
+        Ok(res)
+    }
+
+    /// Construct new _4WD from raw values
+    pub fn new_raw(x4drive: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_x4drive_raw(x4drive)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// _4DRIVE
+    ///
+    /// - Min: 0
+    /// - Max: 7
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn x4drive(&self) -> X4wd4drive {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+        match signal {
+            0 => X4wd4drive::Off,
+            1 => X4wd4drive::X2wd,
+            2 => X4wd4drive::X4wd,
+            3 => X4wd4drive::All,
+            _ => X4wd4drive::_Other(self.x4drive_raw()),
+        }
+    }
+
+    /// Get raw value of _4DRIVE
+    ///
+    /// - Start bit: 13
+    /// - Signal size: 3 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn x4drive_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[10..13].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of _4DRIVE
+    #[inline(always)]
+    pub fn set_x4drive(&mut self, value: X4wd4drive) -> Result<(), CanError> {
+        self.set_x4drive_raw(value.into())
+    }
+
+    /// Set raw value of _4DRIVE
+    #[inline(always)]
+    pub fn set_x4drive_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 7_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: X4wd::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: X4wd::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[10..13].store_be(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for X4wd {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for X4wd {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for X4wd {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("X4wd")
+                .field("x4drive", &self.x4drive())
+                .finish()
+        } else {
+            f.debug_tuple("X4wd").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for X4wd {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let x4drive = u.int_in_range(0..=7)?;
+        X4wd::new_raw(x4drive).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Defined values for _4DRIVE
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum X4wd4drive {
+    Off,
+    X2wd,
+    X4wd,
+    All,
+    _Other(u8),
+}
+
+impl X4wd4drive {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Off, Self::X2wd, Self::X4wd, Self::All];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Off,
+            1 => Self::X2wd,
+            2 => Self::X4wd,
+            3 => Self::All,
+            x => Self::_Other(x),
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    ///
+    /// Returns `None` for values without a label.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Off => Some("OFF"),
+            Self::X2wd => Some("2WD"),
+            Self::X4wd => Some("4WD"),
+            Self::All => Some("ALL"),
+            Self::_Other(_) => None,
+        }
+    }
+}
+
+impl From<u8> for X4wd4drive {
+    fn from(raw: u8) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<X4wd4drive> for u8 {
+    fn from(val: X4wd4drive) -> u8 {
+        match val {
+            X4wd4drive::Off => 0,
+            X4wd4drive::X2wd => 1,
+            X4wd4drive::X4wd => 2,
+            X4wd4drive::All => 3,
+            X4wd4drive::_Other(x) => x,
+        }
+    }
+}
+
+impl core::fmt::Display for X4wd4drive {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "{}", u8::from(*self)),
+        }
+    }
+}
+
+impl core::str::FromStr for X4wd4drive {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == Some(s))
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// MsgWithoutSignals
+///
+/// - Standard ID: 513 (0x201)
+/// - Size: 8 bytes
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MsgWithoutSignals {
+    raw: [u8; 8],
+}
+
+impl MsgWithoutSignals {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x201) });
+
+    /// Construct new MsgWithoutSignals from values
+    pub fn new() -> Result<Self, CanError> {
+        let res = Self { raw: [0u8; 8] };
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for MsgWithoutSignals {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for MsgWithoutSignals {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for MsgWithoutSignals {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MsgWithoutSignals").finish()
+        } else {
+            f.debug_tuple("MsgWithoutSignals").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MsgWithoutSignals {
+    fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        MsgWithoutSignals::new().map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// TruncatedBeSignal
+///
+/// - Standard ID: 9001 (0x2329)
+/// - Size: 8 bytes
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TruncatedBeSignal {
+    raw: [u8; 8],
+}
+
+impl TruncatedBeSignal {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x2329) });
+
+    pub const FOO_MIN: i16 = -100_i16;
+    pub const FOO_MAX: i16 = 100_i16;
+
+    /// Construct new TruncatedBeSignal from values
+    #[allow(clippy::disallowed_names)]
+    pub fn new(foo: i16) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_foo(foo)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Foo
+    ///
+    /// - Min: -100
+    /// - Max: 100
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn foo(&self) -> i16 {
+        self.foo_raw()
+    }
+
+    /// Get raw value of Foo
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn foo_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Foo
+    #[inline(always)]
+    pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -100_i16 || 100_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: TruncatedBeSignal::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: TruncatedBeSignal::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for TruncatedBeSignal {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for TruncatedBeSignal {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for TruncatedBeSignal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("TruncatedBeSignal")
+                .field("foo", &self.foo())
+                .finish()
+        } else {
+            f.debug_tuple("TruncatedBeSignal").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for TruncatedBeSignal {
+    #[allow(clippy::disallowed_names)]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let foo = u.int_in_range(-100..=100)?;
+        TruncatedBeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// TruncatedLeSignal
+///
+/// - Standard ID: 9002 (0x232a)
+/// - Size: 8 bytes
+/// - Transmitter: Ipsum
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct TruncatedLeSignal {
+    raw: [u8; 8],
+}
+
+impl TruncatedLeSignal {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x232a) });
+
+    pub const FOO_MIN: i16 = -100_i16;
+    pub const FOO_MAX: i16 = 100_i16;
+
+    /// Construct new TruncatedLeSignal from values
+    #[allow(clippy::disallowed_names)]
+    pub fn new(foo: i16) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_foo(foo)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Foo
+    ///
+    /// - Min: -100
+    /// - Max: 100
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn foo(&self) -> i16 {
+        self.foo_raw()
+    }
+
+    /// Get raw value of Foo
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn foo_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Foo
+    #[inline(always)]
+    pub fn set_foo(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -100_i16 || 100_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: TruncatedLeSignal::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: TruncatedLeSignal::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for TruncatedLeSignal {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for TruncatedLeSignal {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for TruncatedLeSignal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("TruncatedLeSignal")
+                .field("foo", &self.foo())
+                .finish()
+        } else {
+            f.debug_tuple("TruncatedLeSignal").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for TruncatedLeSignal {
+    #[allow(clippy::disallowed_names)]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let foo = u.int_in_range(-100..=100)?;
+        TruncatedLeSignal::new(foo).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
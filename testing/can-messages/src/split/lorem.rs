@@ -0,0 +1,197 @@
+// Generated code!
+
+use super::*;
+
+/// Foo
+///
+/// - Standard ID: 256 (0x100)
+/// - Size: 4 bytes
+/// - Transmitter: Lorem
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Foo {
+    raw: [u8; 4],
+}
+
+impl Foo {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x100) });
+
+    pub const VOLTAGE_MIN: f32 = 0_f32;
+    pub const VOLTAGE_MAX: f32 = 63.9990234375_f32;
+    pub const CURRENT_MIN: f32 = -2048_f32;
+    pub const CURRENT_MAX: f32 = 2047.9375_f32;
+
+    /// Construct new Foo from values
+    pub fn new(voltage: f32, current: f32) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 4] };
+        res.set_voltage(voltage)?;
+        res.set_current(current)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 4] {
+        &self.raw
+    }
+
+    /// Voltage
+    ///
+    /// - Min: 0
+    /// - Max: 63.9990234375
+    /// - Unit: "V"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn voltage(&self) -> f32 {
+        self.voltage_raw()
+    }
+
+    /// Get raw value of Voltage
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 16 bits
+    /// - Factor: 0.000976562
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn voltage_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..32].load_le::<u16>();
+
+        let factor = 0.000976562_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of Voltage
+    #[inline(always)]
+    pub fn set_voltage(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 63.9990234375_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Foo::MESSAGE_ID,
+            });
+        }
+        let factor = 0.000976562_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..32].store_le(value);
+        Ok(())
+    }
+
+    /// Current
+    ///
+    /// - Min: -2048
+    /// - Max: 2047.9375
+    /// - Unit: "A"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn current(&self) -> f32 {
+        self.current_raw()
+    }
+
+    /// Get raw value of Current
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 16 bits
+    /// - Factor: 0.0625
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn current_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<i16>();
+
+        let factor = 0.0625_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of Current
+    #[inline(always)]
+    pub fn set_current(&mut self, value: f32) -> Result<(), CanError> {
+        if value < -2048_f32 || 2047.9375_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Foo::MESSAGE_ID,
+            });
+        }
+        let factor = 0.0625_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for Foo {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 4 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 4];
+        raw.copy_from_slice(&payload[..4]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for Foo {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for Foo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Foo")
+                .field("voltage", &self.voltage())
+                .field("current", &self.current())
+                .finish()
+        } else {
+            f.debug_tuple("Foo").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for Foo {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let voltage = u.float_in_range(0_f32..=63.9990234375_f32)?;
+        let current = u.float_in_range(-2048_f32..=2047.9375_f32)?;
+        Foo::new(voltage, current).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
@@ -0,0 +1,213 @@
+// Generated code!
+#![allow(unused_comparisons, unreachable_patterns, unused_imports)]
+#![allow(clippy::let_and_return, clippy::eq_op)]
+#![allow(clippy::useless_conversion, clippy::unnecessary_cast)]
+#![allow(
+    clippy::excessive_precision,
+    clippy::manual_range_contains,
+    clippy::absurd_extreme_comparisons,
+    clippy::too_many_arguments
+)]
+#![deny(clippy::arithmetic_side_effects)]
+
+//! Message definitions from file `"example.dbc"`
+//!
+//! - Version: `Version("43")`
+
+#[cfg(feature = "arb")]
+use arbitrary::{Arbitrary, Unstructured};
+use bitvec::prelude::*;
+use core::ops::BitOr;
+use embedded_can::{ExtendedId, Id, StandardId};
+
+mod lorem;
+pub use lorem::*;
+mod ipsum;
+pub use ipsum::*;
+mod sit;
+pub use sit::*;
+mod sensor;
+pub use sensor::*;
+
+/// All messages
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Messages {
+    /// Foo
+    Foo(Foo),
+    /// Bar
+    Bar(Bar),
+    /// _4WD
+    X4wd(X4wd),
+    /// Amet
+    Amet(Amet),
+    /// Dolor
+    Dolor(Dolor),
+    /// MultiplexTest
+    MultiplexTest(MultiplexTest),
+    /// NamedMultiplexTest
+    NamedMultiplexTest(NamedMultiplexTest),
+    /// IntegerFactorOffset
+    IntegerFactorOffset(IntegerFactorOffset),
+    /// NegativeFactorTest
+    NegativeFactorTest(NegativeFactorTest),
+    /// LargerIntsWithOffsets
+    LargerIntsWithOffsets(LargerIntsWithOffsets),
+    /// MsgWithoutSignals
+    MsgWithoutSignals(MsgWithoutSignals),
+    /// TruncatedBeSignal
+    TruncatedBeSignal(TruncatedBeSignal),
+    /// TruncatedLeSignal
+    TruncatedLeSignal(TruncatedLeSignal),
+    /// MsgExtendedId
+    MsgExtendedId(MsgExtendedId),
+}
+
+impl Messages {
+    /// Read message from CAN frame
+    #[inline(never)]
+    pub fn from_can_message(id: Id, payload: &[u8]) -> Result<Self, CanError> {
+        let res = match id {
+            Foo::MESSAGE_ID => Messages::Foo(Foo::try_from(payload)?),
+            Bar::MESSAGE_ID => Messages::Bar(Bar::try_from(payload)?),
+            X4wd::MESSAGE_ID => Messages::X4wd(X4wd::try_from(payload)?),
+            Amet::MESSAGE_ID => Messages::Amet(Amet::try_from(payload)?),
+            Dolor::MESSAGE_ID => Messages::Dolor(Dolor::try_from(payload)?),
+            MultiplexTest::MESSAGE_ID => Messages::MultiplexTest(MultiplexTest::try_from(payload)?),
+            NamedMultiplexTest::MESSAGE_ID => {
+                Messages::NamedMultiplexTest(NamedMultiplexTest::try_from(payload)?)
+            }
+            IntegerFactorOffset::MESSAGE_ID => {
+                Messages::IntegerFactorOffset(IntegerFactorOffset::try_from(payload)?)
+            }
+            NegativeFactorTest::MESSAGE_ID => {
+                Messages::NegativeFactorTest(NegativeFactorTest::try_from(payload)?)
+            }
+            LargerIntsWithOffsets::MESSAGE_ID => {
+                Messages::LargerIntsWithOffsets(LargerIntsWithOffsets::try_from(payload)?)
+            }
+            MsgWithoutSignals::MESSAGE_ID => {
+                Messages::MsgWithoutSignals(MsgWithoutSignals::try_from(payload)?)
+            }
+            TruncatedBeSignal::MESSAGE_ID => {
+                Messages::TruncatedBeSignal(TruncatedBeSignal::try_from(payload)?)
+            }
+            TruncatedLeSignal::MESSAGE_ID => {
+                Messages::TruncatedLeSignal(TruncatedLeSignal::try_from(payload)?)
+            }
+            MsgExtendedId::MESSAGE_ID => Messages::MsgExtendedId(MsgExtendedId::try_from(payload)?),
+            id => return Err(CanError::UnknownMessageId(id)),
+        };
+        Ok(res)
+    }
+}
+
+/// Defined values for value table OnOff
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OnOff {
+    On,
+    Off,
+}
+
+impl OnOff {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::On, Self::Off];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: bool) -> Option<Self> {
+        match raw {
+            true => Some(Self::On),
+            false => Some(Self::Off),
+            _ => None,
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::On => "On",
+            Self::Off => "Off",
+        }
+    }
+}
+
+impl core::convert::TryFrom<bool> for OnOff {
+    type Error = CanError;
+
+    fn try_from(raw: bool) -> Result<Self, Self::Error> {
+        Self::from_raw(raw).ok_or(CanError::UndefinedValue)
+    }
+}
+
+impl From<OnOff> for bool {
+    fn from(val: OnOff) -> bool {
+        match val {
+            OnOff::On => true,
+            OnOff::Off => false,
+        }
+    }
+}
+
+impl core::fmt::Display for OnOff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for OnOff {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == s)
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CanError {
+    UnknownMessageId(embedded_can::Id),
+    /// Signal parameter is not within the range
+    /// defined in the dbc
+    ParameterOutOfRange {
+        /// dbc message id
+        message_id: embedded_can::Id,
+    },
+    InvalidPayloadSize,
+    /// Multiplexor value not defined in the dbc
+    InvalidMultiplexor {
+        /// dbc message id
+        message_id: embedded_can::Id,
+        /// Multiplexor value not defined in the dbc
+        multiplexor: u16,
+    },
+    /// Value or label not defined in the value table
+    UndefinedValue,
+}
+
+impl core::fmt::Display for CanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for CanError {}
+#[cfg(feature = "arb")]
+trait UnstructuredFloatExt {
+    fn float_in_range(&mut self, range: core::ops::RangeInclusive<f32>) -> arbitrary::Result<f32>;
+}
+
+#[cfg(feature = "arb")]
+impl UnstructuredFloatExt for arbitrary::Unstructured<'_> {
+    fn float_in_range(&mut self, range: core::ops::RangeInclusive<f32>) -> arbitrary::Result<f32> {
+        let min = range.start();
+        let max = range.end();
+        let steps = u32::MAX;
+        let factor = (max - min) / (steps as f32);
+        let random_int: u32 = self.int_in_range(0..=steps)?;
+        let random = min + factor * (random_int as f32);
+        Ok(random)
+    }
+}
@@ -0,0 +1,853 @@
+// Generated code!
+
+use super::*;
+
+/// MultiplexTest
+///
+/// - Standard ID: 200 (0xc8)
+/// - Size: 8 bytes
+/// - Transmitter: SENSOR
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MultiplexTest {
+    raw: [u8; 8],
+}
+
+impl MultiplexTest {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0xc8) });
+
+    pub const MULTIPLEXOR_MIN: u8 = 0_u8;
+    pub const MULTIPLEXOR_MAX: u8 = 2_u8;
+    pub const UNMULTIPLEXED_SIGNAL_MIN: u8 = 0_u8;
+    pub const UNMULTIPLEXED_SIGNAL_MAX: u8 = 4_u8;
+    pub const MULTIPLEXED_SIGNAL_ZERO_A_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ZERO_A_MAX: f32 = 3_f32;
+    pub const MULTIPLEXED_SIGNAL_ZERO_B_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ZERO_B_MAX: f32 = 3_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_A_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_A_MAX: f32 = 6_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_B_MIN: f32 = 0_f32;
+    pub const MULTIPLEXED_SIGNAL_ONE_B_MAX: f32 = 6_f32;
+
+    /// Construct new MultiplexTest from values
+    pub fn new(multiplexor: u8, unmultiplexed_signal: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_multiplexor(multiplexor)?;
+        res.set_unmultiplexed_signal(unmultiplexed_signal)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Get raw value of Multiplexor
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexor_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn multiplexor(&self) -> Result<MultiplexTestMultiplexorIndex, CanError> {
+        match self.multiplexor_raw() {
+            0 => Ok(MultiplexTestMultiplexorIndex::M0(
+                MultiplexTestMultiplexorM0 { raw: self.raw },
+            )),
+            1 => Ok(MultiplexTestMultiplexorIndex::M1(
+                MultiplexTestMultiplexorM1 { raw: self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: MultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+    /// Set value of Multiplexor
+    #[inline(always)]
+    fn set_multiplexor(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+        Ok(())
+    }
+
+    /// Set value of Multiplexor
+    #[inline(always)]
+    pub fn set_m0(&mut self, value: MultiplexTestMultiplexorM0) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_multiplexor(0)?;
+        Ok(())
+    }
+
+    /// Set value of Multiplexor
+    #[inline(always)]
+    pub fn set_m1(&mut self, value: MultiplexTestMultiplexorM1) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_multiplexor(1)?;
+        Ok(())
+    }
+
+    /// UnmultiplexedSignal
+    ///
+    /// - Min: 0
+    /// - Max: 4
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unmultiplexed_signal(&self) -> u8 {
+        self.unmultiplexed_signal_raw()
+    }
+
+    /// Get raw value of UnmultiplexedSignal
+    ///
+    /// - Start bit: 4
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unmultiplexed_signal_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[4..12].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnmultiplexedSignal
+    #[inline(always)]
+    pub fn set_unmultiplexed_signal(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 4_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[4..12].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for MultiplexTest {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for MultiplexTest {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for MultiplexTest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            let mut d = f.debug_struct("MultiplexTest");
+            match self.multiplexor() {
+                Ok(multiplexor) => d.field("multiplexor", &multiplexor),
+                Err(_) => d.field("multiplexor", &self.multiplexor_raw()),
+            };
+            d.field("unmultiplexed_signal", &self.unmultiplexed_signal());
+            d.finish()
+        } else {
+            f.debug_tuple("MultiplexTest").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MultiplexTest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let multiplexor = u.int_in_range(0..=2)?;
+        let unmultiplexed_signal = u.int_in_range(0..=4)?;
+        let mut msg = MultiplexTest::new(multiplexor, unmultiplexed_signal)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        match u.int_in_range(0..=1_usize)? {
+            0 => msg.set_m0(u.arbitrary()?),
+            _ => msg.set_m1(u.arbitrary()?),
+        }
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+/// Defined values for multiplexed signal MultiplexTest
+#[derive(PartialEq, Eq, Debug)]
+pub enum MultiplexTestMultiplexorIndex {
+    M0(MultiplexTestMultiplexorM0),
+    M1(MultiplexTestMultiplexorM1),
+}
+
+#[derive(PartialEq, Eq, Default)]
+pub struct MultiplexTestMultiplexorM0 {
+    raw: [u8; 8],
+}
+
+impl MultiplexTestMultiplexorM0 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// MultiplexedSignalZeroA
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_a(&self) -> f32 {
+        self.multiplexed_signal_zero_a_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalZeroA
+    ///
+    /// - Start bit: 12
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_a_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalZeroA
+    #[inline(always)]
+    pub fn set_multiplexed_signal_zero_a(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 3_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+        Ok(())
+    }
+
+    /// MultiplexedSignalZeroB
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_b(&self) -> f32 {
+        self.multiplexed_signal_zero_b_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalZeroB
+    ///
+    /// - Start bit: 20
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_zero_b_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalZeroB
+    #[inline(always)]
+    pub fn set_multiplexed_signal_zero_b(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 3_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for MultiplexTestMultiplexorM0 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MultiplexTestMultiplexorM0")
+                .field(
+                    "multiplexed_signal_zero_a",
+                    &self.multiplexed_signal_zero_a(),
+                )
+                .field(
+                    "multiplexed_signal_zero_b",
+                    &self.multiplexed_signal_zero_b(),
+                )
+                .finish()
+        } else {
+            f.debug_tuple("MultiplexTestMultiplexorM0")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM0 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_multiplexed_signal_zero_a(u.float_in_range(0_f32..=3_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        msg.set_multiplexed_signal_zero_b(u.float_in_range(0_f32..=3_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+#[derive(PartialEq, Eq, Default)]
+pub struct MultiplexTestMultiplexorM1 {
+    raw: [u8; 8],
+}
+
+impl MultiplexTestMultiplexorM1 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// MultiplexedSignalOneA
+    ///
+    /// - Min: 0
+    /// - Max: 6
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_one_a(&self) -> f32 {
+        self.multiplexed_signal_one_a_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalOneA
+    ///
+    /// - Start bit: 12
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_one_a_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..20].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalOneA
+    #[inline(always)]
+    pub fn set_multiplexed_signal_one_a(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 6_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[12..20].store_le(value);
+        Ok(())
+    }
+
+    /// MultiplexedSignalOneB
+    ///
+    /// - Min: 0
+    /// - Max: 6
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn multiplexed_signal_one_b(&self) -> f32 {
+        self.multiplexed_signal_one_b_raw()
+    }
+
+    /// Get raw value of MultiplexedSignalOneB
+    ///
+    /// - Start bit: 20
+    /// - Signal size: 8 bits
+    /// - Factor: 0.1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn multiplexed_signal_one_b_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Lsb0>()[20..28].load_le::<u8>();
+
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of MultiplexedSignalOneB
+    #[inline(always)]
+    pub fn set_multiplexed_signal_one_b(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 6_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 0.1_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[20..28].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for MultiplexTestMultiplexorM1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MultiplexTestMultiplexorM1")
+                .field("multiplexed_signal_one_a", &self.multiplexed_signal_one_a())
+                .field("multiplexed_signal_one_b", &self.multiplexed_signal_one_b())
+                .finish()
+        } else {
+            f.debug_tuple("MultiplexTestMultiplexorM1")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MultiplexTestMultiplexorM1 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_multiplexed_signal_one_a(u.float_in_range(0_f32..=6_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        msg.set_multiplexed_signal_one_b(u.float_in_range(0_f32..=6_f32)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+/// NamedMultiplexTest
+///
+/// - Standard ID: 201 (0xc9)
+/// - Size: 8 bytes
+/// - Transmitter: SENSOR
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NamedMultiplexTest {
+    raw: [u8; 8],
+}
+
+impl NamedMultiplexTest {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0xc9) });
+
+    pub const KIND_MIN: u8 = 0_u8;
+    pub const KIND_MAX: u8 = 2_u8;
+    pub const CELSIUS_MIN: i8 = -40_i8;
+    pub const CELSIUS_MAX: i8 = 100_i8;
+    pub const KILOPASCAL_MIN: u16 = 0_u16;
+    pub const KILOPASCAL_MAX: u16 = 1000_u16;
+
+    /// Construct new NamedMultiplexTest from values
+    pub fn new(kind: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_kind(kind)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Get raw value of Kind
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 4 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn kind_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..4].load_le::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    pub fn kind(&self) -> Result<NamedMultiplexTestKindIndex, CanError> {
+        match self.kind_raw() {
+            0 => Ok(NamedMultiplexTestKindIndex::Temperature(
+                NamedMultiplexTestKindM0 { raw: self.raw },
+            )),
+            1 => Ok(NamedMultiplexTestKindIndex::Pressure(
+                NamedMultiplexTestKindM1 { raw: self.raw },
+            )),
+            multiplexor => Err(CanError::InvalidMultiplexor {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+                multiplexor: multiplexor.into(),
+            }),
+        }
+    }
+    /// Set value of Kind
+    #[inline(always)]
+    fn set_kind(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 2_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NamedMultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..4].store_le(value);
+        Ok(())
+    }
+
+    /// Set value of Kind
+    #[inline(always)]
+    pub fn set_temperature(&mut self, value: NamedMultiplexTestKindM0) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_kind(0)?;
+        Ok(())
+    }
+
+    /// Set value of Kind
+    #[inline(always)]
+    pub fn set_pressure(&mut self, value: NamedMultiplexTestKindM1) -> Result<(), CanError> {
+        let b0 = BitArray::<_, LocalBits>::new(self.raw);
+        let b1 = BitArray::<_, LocalBits>::new(value.raw);
+        self.raw = b0.bitor(b1).into_inner();
+        self.set_kind(1)?;
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for NamedMultiplexTest {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for NamedMultiplexTest {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for NamedMultiplexTest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            let mut d = f.debug_struct("NamedMultiplexTest");
+            match self.kind() {
+                Ok(multiplexor) => d.field("kind", &multiplexor),
+                Err(_) => d.field("kind", &self.kind_raw()),
+            };
+            d.finish()
+        } else {
+            f.debug_tuple("NamedMultiplexTest")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NamedMultiplexTest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let kind = u.int_in_range(0..=2)?;
+        let mut msg =
+            NamedMultiplexTest::new(kind).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        match u.int_in_range(0..=1_usize)? {
+            0 => msg.set_temperature(u.arbitrary()?),
+            _ => msg.set_pressure(u.arbitrary()?),
+        }
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+/// Defined values for multiplexed signal NamedMultiplexTest
+#[derive(PartialEq, Eq, Debug)]
+pub enum NamedMultiplexTestKindIndex {
+    Temperature(NamedMultiplexTestKindM0),
+    Pressure(NamedMultiplexTestKindM1),
+}
+
+#[derive(PartialEq, Eq, Default)]
+pub struct NamedMultiplexTestKindM0 {
+    raw: [u8; 8],
+}
+
+impl NamedMultiplexTestKindM0 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// Celsius
+    ///
+    /// - Min: -40
+    /// - Max: 100
+    /// - Unit: "degC"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn celsius(&self) -> i8 {
+        self.celsius_raw()
+    }
+
+    /// Get raw value of Celsius
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn celsius_raw(&self) -> i8 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<i8>();
+
+        let factor = 1;
+        let signal = signal as i8;
+        i8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Celsius
+    #[inline(always)]
+    pub fn set_celsius(&mut self, value: i8) -> Result<(), CanError> {
+        if value < -40_i8 || 100_i8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NamedMultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i8;
+
+        let value = u8::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for NamedMultiplexTestKindM0 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("NamedMultiplexTestKindM0")
+                .field("celsius", &self.celsius())
+                .finish()
+        } else {
+            f.debug_tuple("NamedMultiplexTestKindM0")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM0 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_celsius(u.int_in_range(-40..=100)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
+
+#[derive(PartialEq, Eq, Default)]
+pub struct NamedMultiplexTestKindM1 {
+    raw: [u8; 8],
+}
+
+impl NamedMultiplexTestKindM1 {
+    pub fn new() -> Self {
+        Self { raw: [0u8; 8] }
+    }
+    /// Kilopascal
+    ///
+    /// - Min: 0
+    /// - Max: 1000
+    /// - Unit: "kPa"
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn kilopascal(&self) -> u16 {
+        self.kilopascal_raw()
+    }
+
+    /// Get raw value of Kilopascal
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 16 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn kilopascal_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..24].load_le::<u16>();
+
+        let factor = 1;
+        u16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Kilopascal
+    #[inline(always)]
+    pub fn set_kilopascal(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 0_u16 || 1000_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NamedMultiplexTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NamedMultiplexTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..24].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for NamedMultiplexTestKindM1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("NamedMultiplexTestKindM1")
+                .field("kilopascal", &self.kilopascal())
+                .finish()
+        } else {
+            f.debug_tuple("NamedMultiplexTestKindM1")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NamedMultiplexTestKindM1 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let mut msg = Self::new();
+        msg.set_kilopascal(u.int_in_range(0..=1000)?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        Ok(msg)
+    }
+}
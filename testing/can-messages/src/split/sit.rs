@@ -0,0 +1,1523 @@
+// Generated code!
+
+use super::*;
+
+/// Amet
+///
+/// - Standard ID: 1024 (0x400)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Amet {
+    raw: [u8; 8],
+}
+
+impl Amet {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x400) });
+
+    pub const ONE_MIN: u8 = 0_u8;
+    pub const ONE_MAX: u8 = 3_u8;
+    pub const TWO_MIN: f32 = 0_f32;
+    pub const TWO_MAX: f32 = 100_f32;
+    pub const THREE_MIN: u8 = 0_u8;
+    pub const THREE_MAX: u8 = 7_u8;
+    pub const FOUR_MIN: u8 = 0_u8;
+    pub const FOUR_MAX: u8 = 3_u8;
+
+    /// Construct new Amet from values
+    pub fn new(one: u8, two: f32, three: u8, four: BarFour, five: OnOff) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one(one)?;
+        res.set_two(two)?;
+        res.set_three(three)?;
+        res.set_four(four)?;
+        res.set_five(five)?;
+        Ok(res)
+    }
+
+    /// Construct new Amet from raw values
+    pub fn new_raw(one: u8, two: f32, three: u8, four: u8, five: bool) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one(one)?;
+        res.set_two(two)?;
+        res.set_three(three)?;
+        res.set_four_raw(four)?;
+        res.set_five_raw(five)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// One
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn one(&self) -> u8 {
+        self.one_raw()
+    }
+
+    /// Get raw value of One
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of One
+    #[inline(always)]
+    pub fn set_one(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+        Ok(())
+    }
+
+    /// Two
+    ///
+    /// - Min: 0
+    /// - Max: 100
+    /// - Unit: "%"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn two(&self) -> f32 {
+        self.two_raw()
+    }
+
+    /// Get raw value of Two
+    ///
+    /// - Start bit: 7
+    /// - Signal size: 8 bits
+    /// - Factor: 0.39
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn two_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[0..8].load_be::<u8>();
+
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of Two
+    #[inline(always)]
+    pub fn set_two(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 100_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 0.39_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[0..8].store_be(value);
+        Ok(())
+    }
+
+    /// Three
+    ///
+    /// - Min: 0
+    /// - Max: 7
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn three(&self) -> u8 {
+        self.three_raw()
+    }
+
+    /// Get raw value of Three
+    ///
+    /// - Start bit: 20
+    /// - Signal size: 3 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn three_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[19..22].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Three
+    #[inline(always)]
+    pub fn set_three(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 7_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[19..22].store_be(value);
+        Ok(())
+    }
+
+    /// Four
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn four(&self) -> BarFour {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        match signal {
+            0 => BarFour::Off,
+            1 => BarFour::On,
+            2 => BarFour::Oner,
+            _ => BarFour::Onest,
+        }
+    }
+
+    /// Get raw value of Four
+    ///
+    /// - Start bit: 30
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn four_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[25..27].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Four
+    #[inline(always)]
+    pub fn set_four(&mut self, value: BarFour) -> Result<(), CanError> {
+        self.set_four_raw(value.into())
+    }
+
+    /// Set raw value of Four
+    #[inline(always)]
+    pub fn set_four_raw(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Amet::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: Amet::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[25..27].store_be(value);
+        Ok(())
+    }
+
+    /// Five
+    ///
+    /// - Min: 0
+    /// - Max: 1
+    /// - Unit: "boolean"
+    /// - Receivers: Dolor
+    #[inline(always)]
+    pub fn five(&self) -> OnOff {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        match signal {
+            1 => OnOff::On,
+            _ => OnOff::Off,
+        }
+    }
+
+    /// Get raw value of Five
+    ///
+    /// - Start bit: 40
+    /// - Signal size: 1 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn five_raw(&self) -> bool {
+        let signal = self.raw.view_bits::<Msb0>()[47..48].load_be::<u8>();
+
+        signal == 1
+    }
+
+    /// Set value of Five
+    #[inline(always)]
+    pub fn set_five(&mut self, value: OnOff) -> Result<(), CanError> {
+        self.set_five_raw(value.into())
+    }
+
+    /// Set raw value of Five
+    #[inline(always)]
+    pub fn set_five_raw(&mut self, value: bool) -> Result<(), CanError> {
+        let value = value as u8;
+        self.raw.view_bits_mut::<Msb0>()[47..48].store_be(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for Amet {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for Amet {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for Amet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Amet")
+                .field("one", &self.one())
+                .field("two", &self.two())
+                .field("three", &self.three())
+                .field("four", &self.four())
+                .field("five", &self.five())
+                .finish()
+        } else {
+            f.debug_tuple("Amet").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for Amet {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let one = u.int_in_range(0..=3)?;
+        let two = u.float_in_range(0_f32..=100_f32)?;
+        let three = u.int_in_range(0..=7)?;
+        let four = u.int_in_range(0..=3)?;
+        let five = u.int_in_range(0..=1)? == 1;
+        Amet::new_raw(one, two, three, four, five).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Dolor
+///
+/// - Standard ID: 1028 (0x404)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Dolor {
+    raw: [u8; 8],
+}
+
+impl Dolor {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x404) });
+
+    pub const ONE_FLOAT_MIN: f32 = 0_f32;
+    pub const ONE_FLOAT_MAX: f32 = 130_f32;
+
+    /// Construct new Dolor from values
+    pub fn new(one_float: DolorOneFloat) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one_float(one_float)?;
+        Ok(res)
+    }
+
+    /// Construct new Dolor from raw values
+    pub fn new_raw(one_float: f32) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_one_float_raw(one_float)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// OneFloat
+    ///
+    /// - Min: 0
+    /// - Max: 130
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn one_float(&self) -> DolorOneFloat {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        match signal {
+            3 => DolorOneFloat::Dolor,
+            5 => DolorOneFloat::Other,
+            _ => DolorOneFloat::_Other(self.one_float_raw()),
+        }
+    }
+
+    /// Get raw value of OneFloat
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 0.5
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn one_float_raw(&self) -> f32 {
+        let signal = self.raw.view_bits::<Msb0>()[7..19].load_be::<u16>();
+
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        (signal as f32) * factor + offset
+    }
+
+    /// Set value of OneFloat
+    #[inline(always)]
+    pub fn set_one_float(&mut self, value: DolorOneFloat) -> Result<(), CanError> {
+        self.set_one_float_raw(value.into())
+    }
+
+    /// Set raw value of OneFloat
+    #[inline(always)]
+    pub fn set_one_float_raw(&mut self, value: f32) -> Result<(), CanError> {
+        if value < 0_f32 || 130_f32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: Dolor::MESSAGE_ID,
+            });
+        }
+        let factor = 0.5_f32;
+        let offset = 0_f32;
+        let value = ((value - offset) / factor) as u16;
+
+        self.raw.view_bits_mut::<Msb0>()[7..19].store_be(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for Dolor {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for Dolor {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for Dolor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Dolor")
+                .field("one_float", &self.one_float())
+                .finish()
+        } else {
+            f.debug_tuple("Dolor").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for Dolor {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let one_float = u.float_in_range(0_f32..=130_f32)?;
+        Dolor::new_raw(one_float).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// Defined values for OneFloat
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DolorOneFloat {
+    Dolor,
+    Other,
+    _Other(f32),
+}
+
+impl DolorOneFloat {
+    /// All values defined in the DBC
+    pub const VARIANTS: &'static [Self] = &[Self::Dolor, Self::Other];
+
+    /// Convert a raw signal value
+    pub fn from_raw(raw: f32) -> Self {
+        match raw {
+            3_f32 => Self::Dolor,
+            5_f32 => Self::Other,
+            x => Self::_Other(x),
+        }
+    }
+
+    /// Label of this value as written in the DBC
+    ///
+    /// Returns `None` for values without a label.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::Dolor => Some("Dolor"),
+            Self::Other => Some("Other"),
+            Self::_Other(_) => None,
+        }
+    }
+}
+
+impl From<f32> for DolorOneFloat {
+    fn from(raw: f32) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<DolorOneFloat> for f32 {
+    fn from(val: DolorOneFloat) -> f32 {
+        match val {
+            DolorOneFloat::Dolor => 3_f32,
+            DolorOneFloat::Other => 5_f32,
+            DolorOneFloat::_Other(x) => x,
+        }
+    }
+}
+
+impl core::fmt::Display for DolorOneFloat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.as_str() {
+            Some(label) => f.write_str(label),
+            None => write!(f, "{}", f32::from(*self)),
+        }
+    }
+}
+
+impl core::str::FromStr for DolorOneFloat {
+    type Err = CanError;
+
+    /// Parse a label as written in the DBC
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::VARIANTS
+            .iter()
+            .find(|v| v.as_str() == Some(s))
+            .copied()
+            .ok_or(CanError::UndefinedValue)
+    }
+}
+
+/// IntegerFactorOffset
+///
+/// - Standard ID: 1337 (0x539)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct IntegerFactorOffset {
+    raw: [u8; 8],
+}
+
+impl IntegerFactorOffset {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x539) });
+
+    pub const BYTE_WITH_OFFSET_MIN: u16 = 1_u16;
+    pub const BYTE_WITH_OFFSET_MAX: u16 = 256_u16;
+    pub const BYTE_WITH_FACTOR_MIN: u16 = 0_u16;
+    pub const BYTE_WITH_FACTOR_MAX: u16 = 1020_u16;
+    pub const BYTE_WITH_BOTH_MIN: u16 = 16_u16;
+    pub const BYTE_WITH_BOTH_MAX: u16 = 526_u16;
+    pub const BYTE_WITH_NEGATIVE_OFFSET_MIN: i16 = 0_i16;
+    pub const BYTE_WITH_NEGATIVE_OFFSET_MAX: i16 = 255_i16;
+    pub const BYTE_WITH_NEGATIVE_MIN_MIN: i16 = -127_i16;
+    pub const BYTE_WITH_NEGATIVE_MIN_MAX: i16 = 127_i16;
+
+    /// Construct new IntegerFactorOffset from values
+    pub fn new(
+        byte_with_offset: u16,
+        byte_with_factor: u16,
+        byte_with_both: u16,
+        byte_with_negative_offset: i16,
+        byte_with_negative_min: i16,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_byte_with_offset(byte_with_offset)?;
+        res.set_byte_with_factor(byte_with_factor)?;
+        res.set_byte_with_both(byte_with_both)?;
+        res.set_byte_with_negative_offset(byte_with_negative_offset)?;
+        res.set_byte_with_negative_min(byte_with_negative_min)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// ByteWithOffset
+    ///
+    /// - Min: 1
+    /// - Max: 256
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_offset(&self) -> u16 {
+        self.byte_with_offset_raw()
+    }
+
+    /// Get raw value of ByteWithOffset
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: 1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_offset_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..8].load_le::<u8>();
+
+        let factor = 1;
+        u16::from(signal).saturating_mul(factor).saturating_add(1)
+    }
+
+    /// Set value of ByteWithOffset
+    #[inline(always)]
+    pub fn set_byte_with_offset(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 1_u16 || 256_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..8].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithFactor
+    ///
+    /// - Min: 0
+    /// - Max: 1020
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_factor(&self) -> u16 {
+        self.byte_with_factor_raw()
+    }
+
+    /// Get raw value of ByteWithFactor
+    ///
+    /// - Start bit: 8
+    /// - Signal size: 8 bits
+    /// - Factor: 4
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_factor_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[8..16].load_le::<u8>();
+
+        let factor = 4;
+        u16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of ByteWithFactor
+    #[inline(always)]
+    pub fn set_byte_with_factor(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 0_u16 || 1020_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 4;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[8..16].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithBoth
+    ///
+    /// - Min: 16
+    /// - Max: 526
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_both(&self) -> u16 {
+        self.byte_with_both_raw()
+    }
+
+    /// Get raw value of ByteWithBoth
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 8 bits
+    /// - Factor: 2
+    /// - Offset: 16
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_both_raw(&self) -> u16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..24].load_le::<u8>();
+
+        let factor = 2;
+        u16::from(signal).saturating_mul(factor).saturating_add(16)
+    }
+
+    /// Set value of ByteWithBoth
+    #[inline(always)]
+    pub fn set_byte_with_both(&mut self, value: u16) -> Result<(), CanError> {
+        if value < 16_u16 || 526_u16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 2;
+        let value = value.checked_sub(16).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[16..24].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithNegativeOffset
+    ///
+    /// - Min: 0
+    /// - Max: 255
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_offset(&self) -> i16 {
+        self.byte_with_negative_offset_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeOffset
+    ///
+    /// - Start bit: 24
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_offset_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[24..32].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeOffset
+    #[inline(always)]
+    pub fn set_byte_with_negative_offset(&mut self, value: i16) -> Result<(), CanError> {
+        if value < 0_i16 || 255_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[24..32].store_le(value);
+        Ok(())
+    }
+
+    /// ByteWithNegativeMin
+    ///
+    /// - Min: -127
+    /// - Max: 127
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn byte_with_negative_min(&self) -> i16 {
+        self.byte_with_negative_min_raw()
+    }
+
+    /// Get raw value of ByteWithNegativeMin
+    ///
+    /// - Start bit: 32
+    /// - Signal size: 8 bits
+    /// - Factor: 1
+    /// - Offset: -1
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn byte_with_negative_min_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[32..40].load_le::<u8>();
+
+        let factor = 1;
+        i16::from(signal).saturating_mul(factor).saturating_sub(1)
+    }
+
+    /// Set value of ByteWithNegativeMin
+    #[inline(always)]
+    pub fn set_byte_with_negative_min(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -127_i16 || 127_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: IntegerFactorOffset::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_add(1).ok_or(CanError::ParameterOutOfRange {
+            message_id: IntegerFactorOffset::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Lsb0>()[32..40].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for IntegerFactorOffset {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for IntegerFactorOffset {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for IntegerFactorOffset {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("IntegerFactorOffset")
+                .field("byte_with_offset", &self.byte_with_offset())
+                .field("byte_with_factor", &self.byte_with_factor())
+                .field("byte_with_both", &self.byte_with_both())
+                .field(
+                    "byte_with_negative_offset",
+                    &self.byte_with_negative_offset(),
+                )
+                .field("byte_with_negative_min", &self.byte_with_negative_min())
+                .finish()
+        } else {
+            f.debug_tuple("IntegerFactorOffset")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for IntegerFactorOffset {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let byte_with_offset = u.int_in_range(1..=256)?;
+        let byte_with_factor = u.int_in_range(0..=1020)?;
+        let byte_with_both = u.int_in_range(16..=526)?;
+        let byte_with_negative_offset = u.int_in_range(0..=255)?;
+        let byte_with_negative_min = u.int_in_range(-127..=127)?;
+        IntegerFactorOffset::new(
+            byte_with_offset,
+            byte_with_factor,
+            byte_with_both,
+            byte_with_negative_offset,
+            byte_with_negative_min,
+        )
+        .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// NegativeFactorTest
+///
+/// - Standard ID: 1344 (0x540)
+/// - Size: 4 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NegativeFactorTest {
+    raw: [u8; 4],
+}
+
+impl NegativeFactorTest {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x540) });
+
+    pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MIN: i32 = -65535_i32;
+    pub const UNSIGNED_NEGATIVE_FACTOR_SIGNAL_MAX: i32 = 0_i32;
+    pub const WIDTH_MORE_THAN_MIN_MAX_MIN: i16 = -2_i16;
+    pub const WIDTH_MORE_THAN_MIN_MAX_MAX: i16 = 2_i16;
+
+    /// Construct new NegativeFactorTest from values
+    pub fn new(
+        unsigned_negative_factor_signal: i32,
+        width_more_than_min_max: i16,
+    ) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 4] };
+        res.set_unsigned_negative_factor_signal(unsigned_negative_factor_signal)?;
+        res.set_width_more_than_min_max(width_more_than_min_max)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 4] {
+        &self.raw
+    }
+
+    /// UnsignedNegativeFactorSignal
+    ///
+    /// - Min: -65535
+    /// - Max: 0
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal(&self) -> i32 {
+        self.unsigned_negative_factor_signal_raw()
+    }
+
+    /// Get raw value of UnsignedNegativeFactorSignal
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 16 bits
+    /// - Factor: -1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn unsigned_negative_factor_signal_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..16].load_le::<u16>();
+
+        let factor = -1;
+        i32::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of UnsignedNegativeFactorSignal
+    #[inline(always)]
+    pub fn set_unsigned_negative_factor_signal(&mut self, value: i32) -> Result<(), CanError> {
+        if value < -65535_i32 || 0_i32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = -1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NegativeFactorTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..16].store_le(value);
+        Ok(())
+    }
+
+    /// WidthMoreThanMinMax
+    ///
+    /// - Min: -2
+    /// - Max: 2
+    /// - Unit: ""
+    /// - Receivers: Vector__XXX
+    #[inline(always)]
+    pub fn width_more_than_min_max(&self) -> i16 {
+        self.width_more_than_min_max_raw()
+    }
+
+    /// Get raw value of WidthMoreThanMinMax
+    ///
+    /// - Start bit: 16
+    /// - Signal size: 10 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: LittleEndian
+    /// - Value type: Signed
+    #[inline(always)]
+    pub fn width_more_than_min_max_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[16..26].load_le::<i16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of WidthMoreThanMinMax
+    #[inline(always)]
+    pub fn set_width_more_than_min_max(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -2_i16 || 2_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: NegativeFactorTest::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: NegativeFactorTest::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as i16;
+
+        let value = u16::from_ne_bytes(value.to_ne_bytes());
+        self.raw.view_bits_mut::<Lsb0>()[16..26].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for NegativeFactorTest {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 4 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 4];
+        raw.copy_from_slice(&payload[..4]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for NegativeFactorTest {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for NegativeFactorTest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("NegativeFactorTest")
+                .field(
+                    "unsigned_negative_factor_signal",
+                    &self.unsigned_negative_factor_signal(),
+                )
+                .field("width_more_than_min_max", &self.width_more_than_min_max())
+                .finish()
+        } else {
+            f.debug_tuple("NegativeFactorTest")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for NegativeFactorTest {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let unsigned_negative_factor_signal = u.int_in_range(-65535..=0)?;
+        let width_more_than_min_max = u.int_in_range(-2..=2)?;
+        NegativeFactorTest::new(unsigned_negative_factor_signal, width_more_than_min_max)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// LargerIntsWithOffsets
+///
+/// - Standard ID: 1338 (0x53a)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LargerIntsWithOffsets {
+    raw: [u8; 8],
+}
+
+impl LargerIntsWithOffsets {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Standard(unsafe { StandardId::new_unchecked(0x53a) });
+
+    pub const TWELVE_MIN: i16 = -1000_i16;
+    pub const TWELVE_MAX: i16 = 3000_i16;
+    pub const SIXTEEN_MIN: i32 = -1000_i32;
+    pub const SIXTEEN_MAX: i32 = 64535_i32;
+
+    /// Construct new LargerIntsWithOffsets from values
+    pub fn new(twelve: i16, sixteen: i32) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_twelve(twelve)?;
+        res.set_sixteen(sixteen)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Twelve
+    ///
+    /// - Min: -1000
+    /// - Max: 3000
+    /// - Unit: ""
+    /// - Receivers: XXX
+    #[inline(always)]
+    pub fn twelve(&self) -> i16 {
+        self.twelve_raw()
+    }
+
+    /// Get raw value of Twelve
+    ///
+    /// - Start bit: 0
+    /// - Signal size: 12 bits
+    /// - Factor: 1
+    /// - Offset: -1000
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn twelve_raw(&self) -> i16 {
+        let signal = self.raw.view_bits::<Lsb0>()[0..12].load_le::<u16>();
+
+        let factor = 1;
+        let signal = signal as i16;
+        i16::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
+    }
+
+    /// Set value of Twelve
+    #[inline(always)]
+    pub fn set_twelve(&mut self, value: i16) -> Result<(), CanError> {
+        if value < -1000_i16 || 3000_i16 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value
+            .checked_add(1000)
+            .ok_or(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[0..12].store_le(value);
+        Ok(())
+    }
+
+    /// Sixteen
+    ///
+    /// - Min: -1000
+    /// - Max: 64535
+    /// - Unit: ""
+    /// - Receivers: XXX
+    #[inline(always)]
+    pub fn sixteen(&self) -> i32 {
+        self.sixteen_raw()
+    }
+
+    /// Get raw value of Sixteen
+    ///
+    /// - Start bit: 12
+    /// - Signal size: 16 bits
+    /// - Factor: 1
+    /// - Offset: -1000
+    /// - Byte order: LittleEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn sixteen_raw(&self) -> i32 {
+        let signal = self.raw.view_bits::<Lsb0>()[12..28].load_le::<u16>();
+
+        let factor = 1;
+        i32::from(signal)
+            .saturating_mul(factor)
+            .saturating_sub(1000)
+    }
+
+    /// Set value of Sixteen
+    #[inline(always)]
+    pub fn set_sixteen(&mut self, value: i32) -> Result<(), CanError> {
+        if value < -1000_i32 || 64535_i32 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value
+            .checked_add(1000)
+            .ok_or(CanError::ParameterOutOfRange {
+                message_id: LargerIntsWithOffsets::MESSAGE_ID,
+            })?;
+        let value = (value / factor) as u16;
+
+        self.raw.view_bits_mut::<Lsb0>()[12..28].store_le(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for LargerIntsWithOffsets {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for LargerIntsWithOffsets {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for LargerIntsWithOffsets {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("LargerIntsWithOffsets")
+                .field("twelve", &self.twelve())
+                .field("sixteen", &self.sixteen())
+                .finish()
+        } else {
+            f.debug_tuple("LargerIntsWithOffsets")
+                .field(&self.raw)
+                .finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for LargerIntsWithOffsets {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let twelve = u.int_in_range(-1000..=3000)?;
+        let sixteen = u.int_in_range(-1000..=64535)?;
+        LargerIntsWithOffsets::new(twelve, sixteen).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+/// MsgExtendedId
+///
+/// - Extended ID: 4660 (0x1234)
+/// - Size: 8 bytes
+/// - Transmitter: Sit
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MsgExtendedId {
+    raw: [u8; 8],
+}
+
+impl MsgExtendedId {
+    pub const MESSAGE_ID: embedded_can::Id =
+        Id::Extended(unsafe { ExtendedId::new_unchecked(0x1234) });
+
+    pub const DUMMY_MIN: u8 = 0_u8;
+    pub const DUMMY_MAX: u8 = 3_u8;
+
+    /// Construct new MsgExtendedId from values
+    pub fn new(dummy: u8) -> Result<Self, CanError> {
+        let mut res = Self { raw: [0u8; 8] };
+        res.set_dummy(dummy)?;
+        Ok(res)
+    }
+
+    /// Access message payload raw value
+    pub const fn raw(&self) -> &[u8; 8] {
+        &self.raw
+    }
+
+    /// Dummy
+    ///
+    /// - Min: 0
+    /// - Max: 3
+    /// - Unit: ""
+    /// - Receivers: XXX
+    #[inline(always)]
+    pub fn dummy(&self) -> u8 {
+        self.dummy_raw()
+    }
+
+    /// Get raw value of Dummy
+    ///
+    /// - Start bit: 15
+    /// - Signal size: 2 bits
+    /// - Factor: 1
+    /// - Offset: 0
+    /// - Byte order: BigEndian
+    /// - Value type: Unsigned
+    #[inline(always)]
+    pub fn dummy_raw(&self) -> u8 {
+        let signal = self.raw.view_bits::<Msb0>()[8..10].load_be::<u8>();
+
+        let factor = 1;
+        u8::from(signal).saturating_mul(factor).saturating_add(0)
+    }
+
+    /// Set value of Dummy
+    #[inline(always)]
+    pub fn set_dummy(&mut self, value: u8) -> Result<(), CanError> {
+        if value < 0_u8 || 3_u8 < value {
+            return Err(CanError::ParameterOutOfRange {
+                message_id: MsgExtendedId::MESSAGE_ID,
+            });
+        }
+        let factor = 1;
+        let value = value.checked_sub(0).ok_or(CanError::ParameterOutOfRange {
+            message_id: MsgExtendedId::MESSAGE_ID,
+        })?;
+        let value = (value / factor) as u8;
+
+        self.raw.view_bits_mut::<Msb0>()[8..10].store_be(value);
+        Ok(())
+    }
+}
+
+impl core::convert::TryFrom<&[u8]> for MsgExtendedId {
+    type Error = CanError;
+
+    #[inline(always)]
+    fn try_from(payload: &[u8]) -> Result<Self, Self::Error> {
+        if payload.len() != 8 {
+            return Err(CanError::InvalidPayloadSize);
+        }
+        let mut raw = [0u8; 8];
+        raw.copy_from_slice(&payload[..8]);
+        Ok(Self { raw })
+    }
+}
+
+impl embedded_can::Frame for MsgExtendedId {
+    fn new(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if id.into() != Self::MESSAGE_ID {
+            None
+        } else {
+            data.try_into().ok()
+        }
+    }
+
+    fn new_remote(_id: impl Into<Id>, _dlc: usize) -> Option<Self> {
+        unimplemented!()
+    }
+
+    fn is_extended(&self) -> bool {
+        match self.id() {
+            Id::Standard(_) => false,
+            Id::Extended(_) => true,
+        }
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        false
+    }
+
+    fn id(&self) -> Id {
+        Self::MESSAGE_ID
+    }
+
+    fn dlc(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.raw
+    }
+}
+impl core::fmt::Debug for MsgExtendedId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("MsgExtendedId")
+                .field("dummy", &self.dummy())
+                .finish()
+        } else {
+            f.debug_tuple("MsgExtendedId").field(&self.raw).finish()
+        }
+    }
+}
+
+#[cfg(feature = "arb")]
+impl<'a> Arbitrary<'a> for MsgExtendedId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let dummy = u.int_in_range(0..=3)?;
+        MsgExtendedId::new(dummy).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
@@ -1,15 +1,25 @@
-#![allow(clippy::float_cmp)]
+#![allow(
+    clippy::float_cmp,
+    clippy::excessive_precision,
+    clippy::bool_assert_comparison
+)]
 
 use can_messages::{
-    Amet, Bar, BarThree, CanError, Foo, LargerIntsWithOffsets, MsgExtendedId, MultiplexTest,
-    MultiplexTestMultiplexorIndex, MultiplexTestMultiplexorM0, NegativeFactorTest,
-    TruncatedBeSignal, TruncatedLeSignal,
+    Amet, AmetMut, Bar, BarFour, BarMut, BarRef, BarThree, BarType, CanError, Foo,
+    IntegerFactorOffset, LargerIntsWithOffsets, MsgExtendedId, MultiplexTest,
+    MultiplexTestMultiplexorIndex, MultiplexTestMultiplexorM0, MultiplexTestRef,
+    NegativeFactorTest, TruncatedBeSignal, TruncatedLeSignal,
+};
+use can_messages::{
+    DolorOneFloat, Messages, NamedMultiplexTest, NamedMultiplexTestKindIndex,
+    NamedMultiplexTestKindM0, NamedMultiplexTestKindM1, OnOff,
 };
 use embedded_can::{ExtendedId, Id, StandardId};
+use std::collections::HashSet;
 
 #[test]
 fn check_range_value_error() {
-    let result = Bar::new(1, 2.0, 3, 4, true);
+    let result = Bar::new_raw(1, 2.0, 3, 4, true);
     assert_eq!(
         result.unwrap_err(),
         CanError::ParameterOutOfRange {
@@ -20,7 +30,7 @@ fn check_range_value_error() {
 
 #[test]
 fn check_range_value_valid() {
-    let result = Bar::new(1, 2.0, 3, 3, true);
+    let result = Bar::new_raw(1, 2.0, 3, 3, true);
     assert!(result.is_ok());
 }
 
@@ -52,7 +62,7 @@ fn pack_unpack_message_negative() {
 
 #[test]
 fn pack_unpack_message2() {
-    let result = Amet::new(1, 0.39, 3, 3, true).unwrap();
+    let result = Amet::new_raw(1, 0.39, 3, 3, true).unwrap();
     assert_eq!(result.one_raw(), 1);
     assert_eq!(result.two_raw(), 0.39);
     assert_eq!(result.three_raw(), 3);
@@ -138,7 +148,7 @@ fn offset_integers() {
 #[test]
 #[cfg(feature = "debug")]
 fn debug_impl() {
-    let result = Bar::new(1, 2.0, 3, 3, true).unwrap();
+    let result = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
     let dbg = format!("{:?}", result);
     assert_eq!(&dbg, "Bar([5, 94, 0, 64, 0, 0, 0, 0])");
 }
@@ -146,7 +156,7 @@ fn debug_impl() {
 #[test]
 #[cfg(feature = "debug")]
 fn debug_alternative_impl() {
-    let result = Bar::new(1, 2.0, 3, 3, true).unwrap();
+    let result = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
     let dbg = format!("{:#?}", result);
     assert_eq!(
         &dbg,
@@ -193,3 +203,489 @@ fn test_extended_id() {
         Id::Extended(ExtendedId::new(0x1234).unwrap())
     )
 }
+
+const TRUNCATED_BE: TruncatedBeSignal = TruncatedBeSignal::new_const(-42);
+const OFFSETS: LargerIntsWithOffsets = LargerIntsWithOffsets::new_const(-1000, 64535);
+
+#[test]
+fn const_new_matches_new() {
+    assert_eq!(
+        TRUNCATED_BE.raw(),
+        TruncatedBeSignal::new(-42).unwrap().raw()
+    );
+    assert_eq!(
+        OFFSETS.raw(),
+        LargerIntsWithOffsets::new(-1000, 64535).unwrap().raw()
+    );
+}
+
+#[test]
+fn const_getters_match_getters() {
+    for value in [-100, -42, -1, 0, 1, 42, 100] {
+        let be = TruncatedBeSignal::new(value).unwrap();
+        assert_eq!(be.foo_raw_const(), be.foo_raw());
+        let le = TruncatedLeSignal::new(value).unwrap();
+        assert_eq!(le.foo_raw_const(), le.foo_raw());
+    }
+
+    let bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    assert_eq!(bar.one_raw_const(), bar.one_raw());
+    assert_eq!(bar.three_raw_const(), bar.three_raw());
+    assert_eq!(bar.four_raw_const(), bar.four_raw());
+    assert_eq!(bar.xtype_raw_const(), bar.xtype_raw());
+
+    let m = IntegerFactorOffset::new(1, 1020, 16, 0, -1).unwrap();
+    assert_eq!(m.byte_with_offset_raw_const(), m.byte_with_offset_raw());
+    assert_eq!(m.byte_with_factor_raw_const(), m.byte_with_factor_raw());
+    assert_eq!(m.byte_with_both_raw_const(), m.byte_with_both_raw());
+    assert_eq!(
+        m.byte_with_negative_offset_raw_const(),
+        m.byte_with_negative_offset_raw()
+    );
+    assert_eq!(
+        m.byte_with_negative_min_raw_const(),
+        m.byte_with_negative_min_raw()
+    );
+}
+
+#[test]
+fn const_setters_match_setters() {
+    let mut bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    let with = bar.with_three(5).unwrap().with_xtype(false).unwrap();
+    bar.set_three_raw(5).unwrap();
+    bar.set_xtype_raw(false).unwrap();
+    assert_eq!(with.raw(), bar.raw());
+
+    assert_eq!(
+        OFFSETS.with_twelve(3001).unwrap_err(),
+        CanError::ParameterOutOfRange {
+            message_id: LargerIntsWithOffsets::MESSAGE_ID
+        }
+    );
+}
+
+#[test]
+fn ref_view_reads_borrowed_payload() {
+    let bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    let payload = *bar.raw();
+
+    let view = BarRef::try_from(&payload[..]).unwrap();
+    assert_eq!(view.raw(), &payload);
+    assert_eq!(view.one(), bar.one());
+    assert_eq!(view.two(), bar.two());
+    assert_eq!(view.three(), BarThree::Onest);
+    assert_eq!(view.xtype_raw(), bar.xtype_raw());
+    assert_eq!(Bar::from(view).raw(), bar.raw());
+
+    assert_eq!(
+        BarRef::try_from(&payload[..4]).err(),
+        Some(CanError::InvalidPayloadSize)
+    );
+}
+
+#[test]
+fn mut_view_writes_borrowed_payload() {
+    let mut payload = [0u8; 8];
+    let mut view = BarMut::from_raw(&mut payload);
+    view.set_one(1).unwrap();
+    view.set_two(2.0).unwrap();
+    view.set_three_raw(3).unwrap();
+    view.set_four_raw(3).unwrap();
+    view.set_xtype_raw(true).unwrap();
+    assert_eq!(view.three(), BarThree::Onest);
+
+    assert_eq!(&payload, Bar::new_raw(1, 2.0, 3, 3, true).unwrap().raw());
+
+    let mut bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    bar.view_mut().set_one(2).unwrap();
+    assert_eq!(bar.one(), 2);
+    assert_eq!(bar.view().one(), 2);
+}
+
+#[test]
+fn ref_view_multiplexor() {
+    let mut result = MultiplexTest::new(0, 2).unwrap();
+    let mut m0 = MultiplexTestMultiplexorM0::new();
+    m0.set_multiplexed_signal_zero_a(1.2).unwrap();
+    result.set_m0(m0).unwrap();
+
+    let view = MultiplexTestRef::from_raw(result.raw());
+    match view.multiplexor().unwrap() {
+        MultiplexTestMultiplexorIndex::M0(m0) => {
+            assert_eq!(m0.multiplexed_signal_zero_a(), 1.2);
+        }
+        _ => panic!("Invalid multiplexor value"),
+    }
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn zerocopy_cast() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    assert_eq!(bar.as_bytes(), bar.raw());
+    let cast = Bar::ref_from_bytes(bar.raw()).unwrap();
+    assert_eq!(cast.raw(), bar.raw());
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytemuck_cast() {
+    let bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    let cast: &Bar = bytemuck::from_bytes(bar.raw());
+    assert_eq!(cast.raw(), bar.raw());
+    assert_eq!(bytemuck::bytes_of(&bar), bar.raw());
+}
+
+#[test]
+fn compare_and_hash_messages() {
+    let a = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    let b = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    let c = Bar::new_raw(2, 2.0, 3, 3, true).unwrap();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(a < c);
+
+    let decoded: HashSet<Messages> = [a, b, c]
+        .iter()
+        .map(|m| Messages::from_can_message(Bar::MESSAGE_ID, m.raw()).unwrap())
+        .collect();
+    assert_eq!(decoded.len(), 2);
+    assert!(decoded.contains(&Messages::Bar(c)));
+}
+
+#[test]
+fn compare_multiplexed_and_enums() {
+    let mut a = MultiplexTestMultiplexorM0::new();
+    a.set_multiplexed_signal_zero_a(1.2).unwrap();
+    let mut b = MultiplexTestMultiplexorM0::new();
+    b.set_multiplexed_signal_zero_a(1.2).unwrap();
+    assert_eq!(a, b);
+
+    let mut msg = MultiplexTest::new(0, 2).unwrap();
+    msg.set_m0(a).unwrap();
+    let mut other = MultiplexTest::new(0, 2).unwrap();
+    other.set_m0(b).unwrap();
+    assert_eq!(msg.multiplexor().unwrap(), other.multiplexor().unwrap());
+
+    assert!(BarThree::Off < BarThree::On);
+    let set: HashSet<BarThree> = [BarThree::On, BarThree::On, BarThree::_Other(7)].into();
+    assert_eq!(set.len(), 2);
+
+    assert!(DolorOneFloat::_Other(1.0) < DolorOneFloat::_Other(2.0));
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn extra_attributes() {
+    fn ord<T: Ord>() {}
+    fn known_layout<T: zerocopy::KnownLayout>() {}
+
+    ord::<Bar>();
+    ord::<MultiplexTestMultiplexorM0>();
+    known_layout::<BarThree>();
+    known_layout::<MultiplexTestMultiplexorIndex>();
+    known_layout::<Messages>();
+
+    let errors: HashSet<CanError> = [CanError::InvalidPayloadSize].into();
+    assert!(errors.contains(&CanError::InvalidPayloadSize));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_signals_roundtrip() {
+    let bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    let json = serde_json::to_value(bar).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "One": 1,
+            "Two": 1.9499999284744263,
+            "Three": "ONEST",
+            "Four": "Onest",
+            "Type": "1On",
+        })
+    );
+    let decoded: Bar = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded, bar);
+
+    let decoded: Messages = serde_json::from_str(
+        r#"{"Bar": {"One": 2, "Two": 0.0, "Three": {"_Other": 7}, "Four": "Off", "Type": "0Off"}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        decoded,
+        Messages::Bar(Bar::new_raw(2, 0.0, 7, 0, false).unwrap())
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_signals_validate_ranges() {
+    let err = serde_json::from_str::<Bar>(
+        r#"{"One": 4, "Two": 0.0, "Three": "OFF", "Four": "Off", "Type": "0Off"}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("ParameterOutOfRange"));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_signals_multiplexed() {
+    let mut msg = MultiplexTest::new(0, 2).unwrap();
+    let mut m1 = can_messages::MultiplexTestMultiplexorM1::new();
+    m1.set_multiplexed_signal_one_a(1.0).unwrap();
+    m1.set_multiplexed_signal_one_b(5.0).unwrap();
+    msg.set_m1(m1).unwrap();
+
+    let json = serde_json::to_value(msg).unwrap();
+    assert_eq!(json["Multiplexor"]["M1"]["MultiplexedSignalOneB"], 5.0);
+    assert_eq!(json["UnmultiplexedSignal"], 2);
+
+    let decoded: MultiplexTest = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.raw(), msg.raw());
+}
+
+#[test]
+fn debug_multiplexed() {
+    let mut msg = MultiplexTest::new(0, 2).unwrap();
+    let mut m0 = MultiplexTestMultiplexorM0::new();
+    m0.set_multiplexed_signal_zero_a(1.0).unwrap();
+    msg.set_m0(m0).unwrap();
+
+    assert_eq!(
+        format!("{:#?}", msg),
+        "MultiplexTest {\n    multiplexor: M0(\n        MultiplexTestMultiplexorM0 {\n            multiplexed_signal_zero_a: 1.0,\n            multiplexed_signal_zero_b: 0.0,\n        },\n    ),\n    unmultiplexed_signal: 2,\n}"
+    );
+
+    // Multiplexor values without signals fall back to the raw value
+    let msg = MultiplexTest::try_from([0x02, 0, 0, 0, 0, 0, 0, 0].as_slice()).unwrap();
+    assert_eq!(
+        format!("{:#?}", msg),
+        "MultiplexTest {\n    multiplexor: 2,\n    unmultiplexed_signal: 0,\n}"
+    );
+}
+
+#[test]
+#[cfg(feature = "arb")]
+fn arbitrary_multiplexed() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let mut seen = HashSet::new();
+    for seed in 0..=u8::MAX {
+        let data: Vec<u8> = (0..64).map(|i: u8| i.wrapping_mul(31) ^ seed).collect();
+        let mut u = Unstructured::new(&data);
+        if let Ok(msg) = MultiplexTest::arbitrary(&mut u) {
+            match msg.multiplexor().unwrap() {
+                MultiplexTestMultiplexorIndex::M0(_) => seen.insert(0),
+                MultiplexTestMultiplexorIndex::M1(_) => seen.insert(1),
+            };
+        }
+    }
+    assert_eq!(seen.len(), 2, "both multiplexed branches are generated");
+}
+
+#[test]
+fn enum_raw_conversions() {
+    assert_eq!(BarThree::from_raw(3), BarThree::Onest);
+    assert_eq!(BarThree::from(7), BarThree::_Other(7));
+    assert_eq!(u8::from(BarThree::from_raw(2)), 2);
+    assert_eq!(BarThree::VARIANTS.len(), 4);
+    assert_eq!(DolorOneFloat::from_raw(5.0), DolorOneFloat::Other);
+    assert_eq!(DolorOneFloat::from_raw(4.0), DolorOneFloat::_Other(4.0));
+}
+
+#[test]
+fn enum_labels() {
+    assert_eq!(BarThree::Oner.as_str(), Some("ONER"));
+    assert_eq!(BarThree::_Other(7).as_str(), None);
+    assert_eq!(BarThree::Onest.to_string(), "ONEST");
+    assert_eq!(BarThree::_Other(7).to_string(), "7");
+    assert_eq!("ON".parse::<BarThree>(), Ok(BarThree::On));
+    assert!("on".parse::<BarThree>().is_err());
+    assert_eq!("1On".parse::<BarType>(), Ok(BarType::X1on));
+
+    let labels: Vec<String> = BarThree::VARIANTS.iter().map(|v| v.to_string()).collect();
+    assert_eq!(labels, ["OFF", "ON", "ONER", "ONEST"]);
+}
+
+#[test]
+fn strict_enum() {
+    // The value table of `Type` covers both values of the 1-bit signal, so there is no `_Other`
+    assert_eq!(BarType::VARIANTS, &[BarType::X0off, BarType::X1on]);
+    assert_eq!(BarType::try_from(true), Ok(BarType::X1on));
+    assert_eq!(BarType::X0off.as_str(), "0Off");
+    assert_eq!(BarType::X0off.to_string(), "0Off");
+
+    let bar = Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    assert_eq!(bar.xtype(), BarType::X1on);
+}
+
+#[test]
+fn enum_setters() {
+    let mut bar = Bar::new(1, 2.0, BarThree::Onest, BarFour::On, BarType::X1on).unwrap();
+    assert_eq!(bar.raw(), Bar::new_raw(1, 2.0, 3, 1, true).unwrap().raw());
+
+    bar.set_three(bar.three()).unwrap();
+    assert_eq!(bar.three(), BarThree::Onest);
+
+    bar.set_three(BarThree::Oner).unwrap();
+    assert_eq!(bar.three_raw(), 2);
+    bar.set_three_raw(7).unwrap();
+    assert_eq!(bar.three(), BarThree::_Other(7));
+
+    let mut payload = [0u8; 8];
+    let mut view = BarMut::from_raw(&mut payload);
+    view.set_four(BarFour::Oner).unwrap();
+    assert_eq!(view.four(), BarFour::Oner);
+}
+
+#[test]
+fn shared_value_tables() {
+    // `Five` matches `VAL_TABLE_ OnOff`, `Four` has the same descriptions as `Bar`'s
+    let mut amet = Amet::new(1, 0.39, 1, BarFour::Oner, OnOff::On).unwrap();
+    assert_eq!(amet.four(), BarFour::Oner);
+    assert_eq!(amet.five(), OnOff::On);
+
+    let bar = Bar::new(1, 2.0, BarThree::On, BarFour::Onest, BarType::X0off).unwrap();
+    amet.set_four(bar.four()).unwrap();
+    assert_eq!(amet.four(), BarFour::Onest);
+
+    let mut payload = [0u8; 8];
+    AmetMut::from_raw(&mut payload)
+        .set_five(OnOff::Off)
+        .unwrap();
+    assert_eq!(OnOff::try_from(true), Ok(OnOff::On));
+    assert_eq!("Off".parse::<OnOff>(), Ok(OnOff::Off));
+    assert_eq!("Maybe".parse::<OnOff>(), Err(CanError::UndefinedValue));
+}
+
+#[test]
+fn named_multiplexor_values() {
+    let mut msg = NamedMultiplexTest::new(0).unwrap();
+    let mut pressure = NamedMultiplexTestKindM1::new();
+    pressure.set_kilopascal(980).unwrap();
+    msg.set_pressure(pressure).unwrap();
+
+    assert_eq!(msg.kind_raw(), 1);
+    match msg.kind().unwrap() {
+        NamedMultiplexTestKindIndex::Pressure(p) => assert_eq!(p.kilopascal(), 980),
+        other => panic!("unexpected multiplexor {other:?}"),
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_named_multiplexor_values() {
+    let mut msg = NamedMultiplexTest::new(0).unwrap();
+    let mut temperature = NamedMultiplexTestKindM0::new();
+    temperature.set_celsius(-5).unwrap();
+    msg.set_temperature(temperature).unwrap();
+
+    let json = serde_json::to_value(msg).unwrap();
+    assert_eq!(json["Kind"]["Temperature"]["Celsius"], -5);
+    let decoded: NamedMultiplexTest = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.raw(), msg.raw());
+}
+
+#[test]
+fn split_modules_match_single_file() {
+    use can_messages::split;
+
+    // `Amet` (transmitted by `Sit`) uses the enum of `Bar` (transmitted by `Ipsum`)
+    let amet = split::Amet::new(1, 0.39, 1, split::BarFour::Oner, split::OnOff::On).unwrap();
+    let expected = Amet::new(1, 0.39, 1, BarFour::Oner, OnOff::On).unwrap();
+    assert_eq!(amet.raw(), expected.raw());
+
+    let bar = Bar::new(1, 2.0, BarThree::On, BarFour::Onest, BarType::X0off).unwrap();
+    match split::Messages::from_can_message(Bar::MESSAGE_ID, bar.raw()).unwrap() {
+        split::Messages::Bar(decoded) => assert_eq!(decoded.four(), split::BarFour::Onest),
+        other => panic!("unexpected message {other:?}"),
+    }
+    assert_eq!(
+        split::Foo::new(100.0, 0.0).unwrap_err(),
+        split::CanError::ParameterOutOfRange {
+            message_id: Foo::MESSAGE_ID
+        }
+    );
+}
+
+#[test]
+fn buses_share_error_type() {
+    use can_messages::buses::{self, body, chassis, Bus};
+
+    let chassis_err = chassis::Foo::new(100.0, 0.0).unwrap_err();
+    let body_err = body::Foo::new(100.0, 0.0).unwrap_err();
+    let expected = buses::CanError::ParameterOutOfRange {
+        message_id: chassis::Foo::MESSAGE_ID,
+    };
+    assert_eq!(chassis_err, expected);
+    assert_eq!(body_err, expected);
+
+    let bar = body::Bar::new_raw(1, 2.0, 3, 3, true).unwrap();
+    let bus =
+        Bus::Body(body::Messages::from_can_message(body::Bar::MESSAGE_ID, bar.raw()).unwrap());
+    match bus {
+        Bus::Body(body::Messages::Bar(decoded)) => assert_eq!(decoded.raw(), bar.raw()),
+        other => panic!("unexpected message {other:?}"),
+    }
+}
+
+#[test]
+fn lin_frames() {
+    use can_messages::lin::{
+        self, LinChecksum, LinScheduleEntry, MirrorStatus, SeatControl, SeatControlSeatHeating,
+    };
+    use core::time::Duration;
+
+    let seat = SeatControl::new(42.5, SeatControlSeatHeating::High).unwrap();
+    assert_eq!(seat.raw(), &[85, 2]);
+    assert_eq!(
+        SeatControl::new(120.0, SeatControlSeatHeating::Off).unwrap_err(),
+        lin::CanError::ParameterOutOfRange {
+            message_id: SeatControl::MESSAGE_ID
+        }
+    );
+    let mirror = MirrorStatus::new(-12.5, true, 0x123456).unwrap();
+    assert_eq!(mirror.mirror_angle(), -12.5);
+    assert_eq!(mirror.mirror_serial(), 0x123456);
+
+    assert_eq!(
+        SeatControl::MESSAGE_ID,
+        Id::Standard(StandardId::new(0x10).unwrap())
+    );
+    assert_eq!(SeatControl::LIN_FRAME_ID, 0x10);
+    assert_eq!(SeatControl::LIN_PROTECTED_ID, 0x50);
+    assert_eq!(SeatControl::LIN_CHECKSUM, LinChecksum::Enhanced);
+    assert_eq!(lin::SlaveResp::LIN_PROTECTED_ID, 0x7d);
+    assert_eq!(lin::SlaveResp::LIN_CHECKSUM, LinChecksum::Classic);
+
+    assert_eq!(lin::LIN_SPEED, 19200);
+    assert_eq!(
+        lin::SCHEDULE_NORMAL,
+        &[
+            LinScheduleEntry {
+                frame_id: SeatControl::LIN_FRAME_ID,
+                frame_ids: &[SeatControl::LIN_FRAME_ID],
+                delay: Duration::from_millis(10),
+            },
+            LinScheduleEntry {
+                frame_id: MirrorStatus::LIN_FRAME_ID,
+                frame_ids: &[MirrorStatus::LIN_FRAME_ID],
+                delay: Duration::from_millis(15),
+            },
+        ]
+    );
+    // Event triggered frames have their own ID and carry one of their frames
+    assert_eq!(lin::SCHEDULE_EVENTS[1].frame_id, 0x3a);
+    assert_eq!(
+        lin::SCHEDULE_EVENTS[1].frame_ids,
+        &[MirrorStatus::LIN_FRAME_ID]
+    );
+    // Node configuration commands are sent as master requests
+    assert_eq!(
+        lin::SCHEDULE_CONFIGURATION[0].frame_id,
+        lin::MasterReq::LIN_FRAME_ID
+    );
+}